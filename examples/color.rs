@@ -8,10 +8,8 @@ use coffee::{Game, Result, Timer};
 fn main() -> Result<()> {
     Colors::run(WindowSettings {
         title: String::from("Color - Coffee"),
-        size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
-        maximized: false,
+        ..Default::default()
     })
 }
 
@@ -65,6 +63,7 @@ impl Game for Colors {
                 },
                 position: Point::new(0.0, 0.0),
                 size: (500.0, 500.0),
+                ..Quad::default()
             },
             target,
         );
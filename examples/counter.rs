@@ -12,10 +12,8 @@ use coffee::{Game, Result, Timer};
 pub fn main() -> Result<()> {
     <Counter as UserInterface>::run(WindowSettings {
         title: String::from("Counter - Coffee"),
-        size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
-        maximized: false,
+        ..Default::default()
     })
 }
 
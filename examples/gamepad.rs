@@ -10,10 +10,8 @@ use coffee::{Game, Result, Timer};
 fn main() -> Result<()> {
     <GamepadExample as UserInterface>::run(WindowSettings {
         title: String::from("Gamepad - Coffee"),
-        size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
-        maximized: false,
+        ..Default::default()
     })
 }
 
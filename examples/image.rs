@@ -11,10 +11,8 @@ use coffee::{Game, Result, Timer};
 pub fn main() -> Result<()> {
     <ImageScreen as UserInterface>::run(WindowSettings {
         title: String::from("ImageScreen - Coffee"),
-        size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
-        maximized: false,
+        ..Default::default()
     })
 }
 
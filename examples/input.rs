@@ -15,10 +15,8 @@ use coffee::{Game, Result, Timer};
 fn main() -> Result<()> {
     <InputExample as UserInterface>::run(WindowSettings {
         title: String::from("Input - Coffee"),
-        size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
-        maximized: false,
+        ..Default::default()
     })
 }
 
@@ -155,6 +153,7 @@ impl Game for InputExample {
                 },
                 position: self.cursor_position - Vector::new(3.0, 3.0),
                 scale: (6.0, 6.0),
+                ..Sprite::default()
             },
             &mut frame.as_target(),
         );
@@ -15,10 +15,8 @@ use std::ops::RangeInclusive;
 fn main() -> Result<()> {
     <Example as UserInterface>::run(WindowSettings {
         title: String::from("Mesh - Coffee"),
-        size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
-        maximized: false,
+        ..Default::default()
     })
 }
 
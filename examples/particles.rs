@@ -17,10 +17,8 @@ use coffee::{Game, Result, Timer};
 fn main() -> Result<()> {
     <Particles as UserInterface>::run(WindowSettings {
         title: String::from("Particles - Coffee"),
-        size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
-        maximized: false,
+        ..Default::default()
     })
 }
 
@@ -150,6 +148,7 @@ impl Game for Particles {
                 },
                 position: particle.position + velocity * delta_factor,
                 scale: (1.0, 1.0),
+                ..Sprite::default()
             }
         });
 
@@ -7,10 +7,7 @@ use coffee::{Game, Timer};
 fn main() -> coffee::Result<()> {
     Example::run(WindowSettings {
         title: String::from("Rectangle - Coffee"),
-        size: (1280, 1024),
-        resizable: true,
-        fullscreen: false,
-        maximized: false,
+        ..Default::default()
     })
 }
 
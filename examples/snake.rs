@@ -1,7 +1,8 @@
 extern crate coffee;
 
 use coffee::graphics::{
-    Color, Font, Frame, Mesh, Point, Rectangle, Shape, Text, Window, WindowSettings,
+    Color, Font, Frame, Mesh, Point, Rectangle, Shape, Text, Window,
+    WindowSettings,
 };
 use coffee::input::keyboard::KeyCode;
 use coffee::input::{self, keyboard, Input};
@@ -16,8 +17,7 @@ fn main() {
         title: String::from("Snake"),
         size: (900, 600),
         resizable: false,
-        maximized: false,
-        fullscreen: false,
+        ..Default::default()
     })
     .expect("An error occured while starting the game");
 }
@@ -11,10 +11,8 @@ use coffee::{Game, Result, Timer};
 fn main() -> Result<()> {
     <Tour as UserInterface>::run(WindowSettings {
         title: String::from("User Interface - Coffee"),
-        size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
-        maximized: false,
+        ..Default::default()
     })
 }
 
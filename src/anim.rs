@@ -0,0 +1,48 @@
+//! Animate values over time using tweens, easing curves, and frame-by-frame
+//! animations.
+//!
+//! A [`Tween`] interpolates between two values during some time following an
+//! [`Easing`] curve. It works with any type implementing [`Interpolate`], like
+//! `f32`, [`Point`], [`Vector`], [`Color`], or [`Transformation`].
+//!
+//! # Example
+//! ```
+//! use coffee::anim::{Easing, Tween};
+//! use coffee::graphics::Point;
+//! use std::time::Duration;
+//!
+//! let mut tween = Tween::new(
+//!     Point::new(0.0, 0.0),
+//!     Point::new(100.0, 0.0),
+//!     Duration::from_secs(1),
+//! )
+//! .easing(Easing::QuadOut);
+//!
+//! // Advance the tween in `Game::update`...
+//! tween.advance(Duration::from_millis(500));
+//!
+//! assert_eq!(tween.value(), Point::new(75.0, 0.0));
+//! ```
+//!
+//! An [`Animation`] shows the frames of a spritesheet, each one during its own
+//! duration. When the `json` feature is enabled, animations authored in
+//! Aseprite can be loaded using [`graphics::Aseprite`].
+//!
+//! [`Tween`]: struct.Tween.html
+//! [`Animation`]: struct.Animation.html
+//! [`graphics::Aseprite`]: ../graphics/struct.Aseprite.html
+//! [`Easing`]: enum.Easing.html
+//! [`Interpolate`]: trait.Interpolate.html
+//! [`Point`]: ../graphics/type.Point.html
+//! [`Vector`]: ../graphics/type.Vector.html
+//! [`Color`]: ../graphics/struct.Color.html
+//! [`Transformation`]: ../graphics/struct.Transformation.html
+mod animation;
+mod easing;
+mod interpolate;
+mod tween;
+
+pub use animation::{Animation, Frame};
+pub use easing::Easing;
+pub use interpolate::Interpolate;
+pub use tween::{Repeat, Tween};
@@ -0,0 +1,174 @@
+use crate::anim::Repeat;
+use crate::graphics::{Point, Rectangle, Sprite};
+
+use std::time::Duration;
+
+/// A frame-by-frame animation of a spritesheet.
+///
+/// Every [`Frame`] of an [`Animation`] has its own duration. Like a
+/// [`Tween`], an [`Animation`] does not depend on real time. You need to
+/// [`advance`] it, normally in [`Game::update`].
+///
+/// [`Frame`]: struct.Frame.html
+/// [`Animation`]: struct.Animation.html
+/// [`Tween`]: struct.Tween.html
+/// [`advance`]: #method.advance
+/// [`Game::update`]: ../trait.Game.html#method.update
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation {
+    frames: Vec<Frame>,
+    repeat: Repeat,
+    elapsed: Duration,
+}
+
+/// A frame of an [`Animation`].
+///
+/// [`Animation`]: struct.Animation.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame {
+    /// The portion of the spritesheet containing the frame, in absolute
+    /// coordinates.
+    pub source: Rectangle<u16>,
+
+    /// How long the frame is shown.
+    pub duration: Duration,
+}
+
+impl Animation {
+    /// Creates a new [`Animation`] with the given frames.
+    ///
+    /// By default, it loops.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn new(frames: Vec<Frame>) -> Animation {
+        Animation {
+            frames,
+            repeat: Repeat::Loop,
+            elapsed: Duration::from_secs(0),
+        }
+    }
+
+    /// Sets the [`Repeat`] behavior of the [`Animation`].
+    ///
+    /// [`Repeat::PingPong`] does not show the first and last frames twice in a
+    /// row.
+    ///
+    /// [`Repeat`]: enum.Repeat.html
+    /// [`Animation`]: struct.Animation.html
+    /// [`Repeat::PingPong`]: enum.Repeat.html#variant.PingPong
+    pub fn repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Returns the frames of the [`Animation`].
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Returns the duration of a single cycle of the [`Animation`].
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn duration(&self) -> Duration {
+        self.sequence().map(|i| self.frames[i].duration).sum()
+    }
+
+    /// Advances the [`Animation`] by the given [`Duration`].
+    ///
+    /// [`Animation`]: struct.Animation.html
+    /// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    pub fn advance(&mut self, delta: Duration) {
+        self.elapsed += delta;
+
+        if self.repeat == Repeat::Never {
+            self.elapsed = self.elapsed.min(self.duration());
+        }
+    }
+
+    /// Returns the index of the current frame of the [`Animation`].
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn frame_index(&self) -> usize {
+        let period = self.duration().as_nanos();
+
+        if period == 0 {
+            return 0;
+        }
+
+        let mut elapsed = match self.repeat {
+            Repeat::Never if self.elapsed.as_nanos() >= period => {
+                return self.frames.len() - 1;
+            }
+            Repeat::Never => self.elapsed.as_nanos(),
+            Repeat::Loop | Repeat::PingPong => self.elapsed.as_nanos() % period,
+        };
+
+        for i in self.sequence() {
+            let duration = self.frames[i].duration.as_nanos();
+
+            if elapsed < duration {
+                return i;
+            }
+
+            elapsed -= duration;
+        }
+
+        self.frames.len() - 1
+    }
+
+    /// Returns the current [`Frame`] of the [`Animation`], if it has any.
+    ///
+    /// [`Frame`]: struct.Frame.html
+    /// [`Animation`]: struct.Animation.html
+    pub fn frame(&self) -> Option<Frame> {
+        self.frames.get(self.frame_index()).copied()
+    }
+
+    /// Returns a [`Sprite`] of the current frame of the [`Animation`] at the
+    /// given position.
+    ///
+    /// [`Sprite`]: ../graphics/struct.Sprite.html
+    /// [`Animation`]: struct.Animation.html
+    pub fn sprite(&self, position: Point) -> Sprite {
+        match self.frame() {
+            Some(frame) => Sprite {
+                source: frame.source,
+                position,
+                ..Sprite::default()
+            },
+            None => Sprite {
+                position,
+                ..Sprite::default()
+            },
+        }
+    }
+
+    /// Returns whether the [`Animation`] has reached its end.
+    ///
+    /// A repeating [`Animation`] never finishes.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn is_finished(&self) -> bool {
+        self.repeat == Repeat::Never && self.elapsed >= self.duration()
+    }
+
+    /// Restarts the [`Animation`] from its first frame.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::from_secs(0);
+    }
+
+    fn sequence(&self) -> impl Iterator<Item = usize> {
+        let len = self.frames.len();
+
+        let back = match self.repeat {
+            Repeat::PingPong => 1..len.saturating_sub(1),
+            Repeat::Never | Repeat::Loop => 0..0,
+        };
+
+        (0..len).chain(back.rev())
+    }
+}
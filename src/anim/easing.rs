@@ -0,0 +1,213 @@
+use std::f32::consts::PI;
+
+/// An easing curve.
+///
+/// It maps the linear progress of an animation to the actual progress of the
+/// animated value.
+///
+/// You can visualize most of these curves at [easings.net].
+///
+/// [easings.net]: https://easings.net
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Easing {
+    /// Constant speed
+    Linear,
+
+    /// Quadratic, accelerating from zero velocity
+    QuadIn,
+
+    /// Quadratic, decelerating to zero velocity
+    QuadOut,
+
+    /// Quadratic, accelerating until halfway, then decelerating
+    QuadInOut,
+
+    /// Cubic, accelerating from zero velocity
+    CubicIn,
+
+    /// Cubic, decelerating to zero velocity
+    CubicOut,
+
+    /// Cubic, accelerating until halfway, then decelerating
+    CubicInOut,
+
+    /// Sinusoidal, accelerating from zero velocity
+    SineIn,
+
+    /// Sinusoidal, decelerating to zero velocity
+    SineOut,
+
+    /// Sinusoidal, accelerating until halfway, then decelerating
+    SineInOut,
+
+    /// Exponentially decaying sine wave at the start
+    ElasticIn,
+
+    /// Exponentially decaying sine wave at the end
+    ElasticOut,
+
+    /// Exponentially decaying sine wave at both ends
+    ElasticInOut,
+
+    /// Bouncing at the start
+    BounceIn,
+
+    /// Bouncing at the end
+    BounceOut,
+
+    /// Bouncing at both ends
+    BounceInOut,
+}
+
+impl Easing {
+    /// Applies the [`Easing`] curve to the given progress.
+    ///
+    /// The progress is clamped to the `[0.0, 1.0]` interval. The result may
+    /// fall outside of this interval for some curves, like the elastic ones.
+    ///
+    /// [`Easing`]: enum.Easing.html
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.max(0.0).min(1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => t * (2.0 - t),
+            Easing::QuadInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    -1.0 + (4.0 - 2.0 * t) * t
+                }
+            }
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => {
+                let f = t - 1.0;
+
+                f * f * f + 1.0
+            }
+            Easing::CubicInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    let f = 2.0 * t - 2.0;
+
+                    0.5 * f * f * f + 1.0
+                }
+            }
+            Easing::SineIn => 1.0 - (t * PI / 2.0).cos(),
+            Easing::SineOut => (t * PI / 2.0).sin(),
+            Easing::SineInOut => -0.5 * ((PI * t).cos() - 1.0),
+            Easing::ElasticIn => elastic_in(t),
+            Easing::ElasticOut => 1.0 - elastic_in(1.0 - t),
+            Easing::ElasticInOut => {
+                if t < 0.5 {
+                    0.5 * elastic_in(2.0 * t)
+                } else {
+                    0.5 + 0.5 * (1.0 - elastic_in(2.0 - 2.0 * t))
+                }
+            }
+            Easing::BounceIn => 1.0 - bounce_out(1.0 - t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut => {
+                if t < 0.5 {
+                    0.5 * (1.0 - bounce_out(1.0 - 2.0 * t))
+                } else {
+                    0.5 + 0.5 * bounce_out(2.0 * t - 1.0)
+                }
+            }
+        }
+    }
+}
+
+impl Default for Easing {
+    fn default() -> Easing {
+        Easing::Linear
+    }
+}
+
+fn elastic_in(t: f32) -> f32 {
+    if t == 0.0 || t == 1.0 {
+        return t;
+    }
+
+    -(2.0f32.powf(10.0 * (t - 1.0))) * ((t - 1.075) * (2.0 * PI) / 0.3).sin()
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+
+        N * t * t + 0.984375
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURVES: [Easing; 16] = [
+        Easing::Linear,
+        Easing::QuadIn,
+        Easing::QuadOut,
+        Easing::QuadInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::SineIn,
+        Easing::SineOut,
+        Easing::SineInOut,
+        Easing::ElasticIn,
+        Easing::ElasticOut,
+        Easing::ElasticInOut,
+        Easing::BounceIn,
+        Easing::BounceOut,
+        Easing::BounceInOut,
+    ];
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} is not close to {}", a, b);
+    }
+
+    #[test]
+    fn curves_start_at_zero_and_end_at_one() {
+        for curve in CURVES.iter() {
+            assert_close(curve.apply(0.0), 0.0);
+            assert_close(curve.apply(1.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn symmetric_curves_are_halfway_at_the_middle() {
+        for curve in &[
+            Easing::Linear,
+            Easing::QuadInOut,
+            Easing::CubicInOut,
+            Easing::SineInOut,
+            Easing::BounceInOut,
+        ] {
+            assert_close(curve.apply(0.5), 0.5);
+        }
+    }
+
+    #[test]
+    fn progress_is_clamped() {
+        for curve in CURVES.iter() {
+            assert_close(curve.apply(-1.0), curve.apply(0.0));
+            assert_close(curve.apply(2.0), curve.apply(1.0));
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::graphics::{Color, Point, Transformation, Vector};
+
+/// A value that can be interpolated.
+pub trait Interpolate {
+    /// Interpolates linearly between `self` and `other`.
+    ///
+    /// A `t` of `0.0` produces `self` and a `t` of `1.0` produces `other`.
+    /// Values outside of the `[0.0, 1.0]` interval extrapolate.
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &f32, t: f32) -> f32 {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Point, t: f32) -> Point {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(&self, other: &Vector, t: f32) -> Vector {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Color, t: f32) -> Color {
+        Color {
+            r: self.r.interpolate(&other.r, t),
+            g: self.g.interpolate(&other.g, t),
+            b: self.b.interpolate(&other.b, t),
+            a: self.a.interpolate(&other.a, t),
+        }
+    }
+}
+
+/// Transformations are interpolated component-wise. This works well for
+/// translations and scales, but rotations may look skewed halfway through.
+impl Interpolate for Transformation {
+    fn interpolate(&self, other: &Transformation, t: f32) -> Transformation {
+        let from: nalgebra::Matrix3<f32> = (*self).into();
+        let to: nalgebra::Matrix3<f32> = (*other).into();
+
+        Transformation::from(from + (to - from) * t)
+    }
+}
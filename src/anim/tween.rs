@@ -0,0 +1,244 @@
+use crate::anim::{Easing, Interpolate};
+use crate::Timer;
+
+use std::time::Duration;
+
+/// An animation between two values.
+///
+/// A [`Tween`] does not depend on real time. You need to [`advance`] it,
+/// normally in [`Game::update`], by the duration of a tick. This keeps your
+/// animations deterministic.
+///
+/// [`Tween`]: struct.Tween.html
+/// [`advance`]: #method.advance
+/// [`Game::update`]: ../trait.Game.html#method.update
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tween<T> {
+    from: T,
+    to: T,
+    duration: Duration,
+    elapsed: Duration,
+    easing: Easing,
+    repeat: Repeat,
+    last_delta: Duration,
+}
+
+/// The repetition behavior of a [`Tween`].
+///
+/// [`Tween`]: struct.Tween.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repeat {
+    /// The [`Tween`] stops once it reaches the end.
+    ///
+    /// [`Tween`]: struct.Tween.html
+    Never,
+
+    /// The [`Tween`] starts over once it reaches the end.
+    ///
+    /// [`Tween`]: struct.Tween.html
+    Loop,
+
+    /// The [`Tween`] goes back and forth between its values.
+    ///
+    /// [`Tween`]: struct.Tween.html
+    PingPong,
+}
+
+impl<T: Interpolate> Tween<T> {
+    /// Creates a new [`Tween`] from a value to another that lasts the given
+    /// [`Duration`].
+    ///
+    /// By default, it uses [`Easing::Linear`] and it does not repeat.
+    ///
+    /// [`Tween`]: struct.Tween.html
+    /// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    /// [`Easing::Linear`]: enum.Easing.html#variant.Linear
+    pub fn new(from: T, to: T, duration: Duration) -> Tween<T> {
+        Tween {
+            from,
+            to,
+            duration,
+            elapsed: Duration::from_secs(0),
+            easing: Easing::Linear,
+            repeat: Repeat::Never,
+            last_delta: Duration::from_secs(0),
+        }
+    }
+
+    /// Sets the [`Easing`] curve of the [`Tween`].
+    ///
+    /// [`Easing`]: enum.Easing.html
+    /// [`Tween`]: struct.Tween.html
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the [`Repeat`] behavior of the [`Tween`].
+    ///
+    /// [`Repeat`]: enum.Repeat.html
+    /// [`Tween`]: struct.Tween.html
+    pub fn repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Advances the [`Tween`] by the given [`Duration`].
+    ///
+    /// [`Tween`]: struct.Tween.html
+    /// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    pub fn advance(&mut self, delta: Duration) {
+        self.last_delta = delta;
+        self.elapsed += delta;
+
+        if self.repeat == Repeat::Never && self.elapsed > self.duration {
+            self.elapsed = self.duration;
+        }
+    }
+
+    /// Returns the current value of the [`Tween`].
+    ///
+    /// [`Tween`]: struct.Tween.html
+    pub fn value(&self) -> T {
+        self.value_at(self.elapsed)
+    }
+
+    /// Returns the value of the [`Tween`] interpolated between the last and
+    /// the next tick, using [`Timer::next_tick_proximity`].
+    ///
+    /// Use this in [`Game::draw`] to obtain smooth animations independently
+    /// of the tick rate. It assumes that the [`Tween`] is advanced the same
+    /// amount every tick.
+    ///
+    /// [`Tween`]: struct.Tween.html
+    /// [`Timer::next_tick_proximity`]: ../struct.Timer.html#method.next_tick_proximity
+    /// [`Game::draw`]: ../trait.Game.html#tymethod.draw
+    pub fn interpolated(&self, timer: &Timer) -> T {
+        let proximity = timer.next_tick_proximity().max(0.0).min(1.0);
+
+        self.value_at(self.elapsed + self.last_delta.mul_f32(proximity))
+    }
+
+    /// Returns the progress of the [`Tween`] before applying the easing
+    /// curve, in the `[0.0, 1.0]` interval.
+    ///
+    /// [`Tween`]: struct.Tween.html
+    pub fn progress(&self) -> f32 {
+        self.progress_at(self.elapsed)
+    }
+
+    /// Returns whether the [`Tween`] has reached its end.
+    ///
+    /// A repeating [`Tween`] never finishes.
+    ///
+    /// [`Tween`]: struct.Tween.html
+    pub fn is_finished(&self) -> bool {
+        self.repeat == Repeat::Never && self.elapsed >= self.duration
+    }
+
+    /// Restarts the [`Tween`] from the beginning.
+    ///
+    /// [`Tween`]: struct.Tween.html
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::from_secs(0);
+        self.last_delta = Duration::from_secs(0);
+    }
+
+    fn value_at(&self, elapsed: Duration) -> T {
+        let t = self.easing.apply(self.progress_at(elapsed));
+
+        self.from.interpolate(&self.to, t)
+    }
+
+    fn progress_at(&self, elapsed: Duration) -> f32 {
+        let duration = self.duration.as_secs_f32();
+
+        if duration <= 0.0 {
+            return 1.0;
+        }
+
+        let cycles = elapsed.as_secs_f32() / duration;
+
+        match self.repeat {
+            Repeat::Never => cycles.min(1.0),
+            Repeat::Loop => cycles.fract(),
+            Repeat::PingPong => {
+                let phase = cycles % 2.0;
+
+                if phase > 1.0 {
+                    2.0 - phase
+                } else {
+                    phase
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tween(repeat: Repeat) -> Tween<f32> {
+        Tween::new(0.0, 10.0, Duration::from_secs(1)).repeat(repeat)
+    }
+
+    #[test]
+    fn advances_linearly_by_default() {
+        let mut tween = tween(Repeat::Never);
+
+        assert_eq!(tween.value(), 0.0);
+
+        tween.advance(Duration::from_millis(250));
+        assert_eq!(tween.value(), 2.5);
+        assert_eq!(tween.progress(), 0.25);
+        assert!(!tween.is_finished());
+    }
+
+    #[test]
+    fn applies_the_easing_curve() {
+        let mut tween = tween(Repeat::Never).easing(Easing::QuadIn);
+
+        tween.advance(Duration::from_millis(500));
+
+        assert_eq!(tween.progress(), 0.5);
+        assert_eq!(tween.value(), 2.5);
+    }
+
+    #[test]
+    fn stops_at_the_end() {
+        let mut tween = tween(Repeat::Never);
+
+        tween.advance(Duration::from_secs(3));
+
+        assert_eq!(tween.value(), 10.0);
+        assert!(tween.is_finished());
+
+        tween.reset();
+
+        assert_eq!(tween.value(), 0.0);
+        assert!(!tween.is_finished());
+    }
+
+    #[test]
+    fn repeats() {
+        let mut looped = tween(Repeat::Loop);
+        let mut ping_pong = tween(Repeat::PingPong);
+
+        looped.advance(Duration::from_millis(1250));
+        ping_pong.advance(Duration::from_millis(1250));
+
+        assert_eq!(looped.value(), 2.5);
+        assert_eq!(ping_pong.value(), 7.5);
+        assert!(!looped.is_finished());
+        assert!(!ping_pong.is_finished());
+    }
+
+    #[test]
+    fn empty_tweens_are_finished() {
+        let tween = Tween::new(0.0, 10.0, Duration::from_secs(0));
+
+        assert_eq!(tween.value(), 10.0);
+        assert!(tween.is_finished());
+    }
+}
@@ -0,0 +1,223 @@
+//! Choose where your game assets are read from.
+//!
+//! All the loaders in Coffee that take a path, like [`Image::load`] or
+//! [`texture_array::Loader::add`], read their bytes from the currently
+//! mounted [`Source`]. By default, paths are read directly from the
+//! filesystem, or from the assets bundled in the APK on Android.
+//!
+//! You can [`mount`] a different [`Source`] to change this behavior. For
+//! instance, you can embed your assets in the executable with an
+//! [`Embedded`] source, or let players override some of them by stacking
+//! a mod folder on top of your assets with an [`Overlay`]:
+//!
+//! ```
+//! use coffee::assets::{self, Folder, Overlay};
+//!
+//! assets::mount(
+//!     Overlay::new()
+//!         .push(Folder::new("mods/my-mod"))
+//!         .push(Folder::new("assets")),
+//! );
+//! ```
+//!
+//! Assets loaded through a [`Cache`] are deduplicated, sharing the same handle
+//! when the same path is loaded multiple times.
+//!
+//! When releasing your game, you can bundle all your assets in a single file
+//! using a [`Packer`], and read them at runtime with a [`Pack`].
+//!
+//! You can also implement [`Source`] for your own types.
+//!
+//! [`Image::load`]: ../graphics/struct.Image.html#method.load
+//! [`texture_array::Loader::add`]: ../graphics/texture_array/struct.Loader.html#method.add
+//! [`Source`]: trait.Source.html
+//! [`mount`]: fn.mount.html
+//! [`Embedded`]: struct.Embedded.html
+//! [`Overlay`]: struct.Overlay.html
+//! [`Packer`]: struct.Packer.html
+//! [`Pack`]: struct.Pack.html
+//! [`Cache`]: struct.Cache.html
+#[cfg(target_os = "android")]
+mod apk;
+mod cache;
+mod embedded;
+mod folder;
+pub(crate) mod glob;
+mod overlay;
+mod pack;
+
+#[cfg(target_os = "android")]
+pub use apk::Apk;
+pub use cache::Cache;
+pub use embedded::Embedded;
+pub use folder::Folder;
+pub use overlay::Overlay;
+pub use pack::{Compression, Pack, Packer};
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
+
+/// A place where assets can be read from.
+///
+/// Sources are shared between threads, as some loaders decode assets in the
+/// background.
+pub trait Source: Send + Sync {
+    /// Reads all the bytes of the asset at the given path.
+    ///
+    /// If the asset does not exist, an error of kind
+    /// [`io::ErrorKind::NotFound`] should be returned. This allows an
+    /// [`Overlay`] to fall back to other sources.
+    ///
+    /// [`io::ErrorKind::NotFound`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.NotFound
+    /// [`Overlay`]: struct.Overlay.html
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+    /// Opens the asset at the given path to read it on demand.
+    ///
+    /// This is used to stream long music tracks. By default, the whole asset
+    /// is read in memory using [`read`].
+    ///
+    /// [`read`]: #tymethod.read
+    fn open(&self, path: &Path) -> io::Result<Box<dyn Reader>> {
+        Ok(Box::new(io::Cursor::new(self.read(path)?)))
+    }
+
+    /// Returns the last time the asset at the given path was modified, if
+    /// known.
+    ///
+    /// This is used to detect changes when [hot reloading]. By default, it
+    /// returns `None`, which means the assets of the [`Source`] never change.
+    ///
+    /// [hot reloading]: ../load/hot_reload/index.html
+    /// [`Source`]: trait.Source.html
+    fn modified(&self, _path: &Path) -> Option<SystemTime> {
+        None
+    }
+
+    /// Lists the paths of all the assets inside the given directory,
+    /// recursively.
+    ///
+    /// This is used to [`glob`] assets. By default, it returns an error, which
+    /// means the [`Source`] does not support listing its assets.
+    ///
+    /// [`glob`]: fn.glob.html
+    /// [`Source`]: trait.Source.html
+    fn list(&self, _directory: &Path) -> io::Result<Vec<PathBuf>> {
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            "The mounted source does not support listing assets",
+        ))
+    }
+}
+
+/// An asset opened with [`Source::open`].
+///
+/// It is implemented for any type that implements [`Read`] and [`Seek`] and
+/// can be sent between threads.
+///
+/// [`Source::open`]: trait.Source.html#method.open
+/// [`Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
+/// [`Seek`]: https://doc.rust-lang.org/std/io/trait.Seek.html
+pub trait Reader: io::Read + io::Seek + Send {}
+
+impl<T: io::Read + io::Seek + Send> Reader for T {}
+
+static MOUNTED: RwLock<Option<Arc<dyn Source>>> = RwLock::new(None);
+
+/// Mounts a [`Source`], replacing the current one.
+///
+/// Any asset read after this call will be read from the given [`Source`].
+///
+/// [`Source`]: trait.Source.html
+pub fn mount<S: 'static + Source>(source: S) {
+    let mut mounted = MOUNTED.write().expect("Lock assets source");
+
+    *mounted = Some(Arc::new(source));
+}
+
+/// Unmounts the current [`Source`], if any.
+///
+/// Assets will be read directly from the filesystem again.
+///
+/// [`Source`]: trait.Source.html
+pub fn unmount() {
+    let mut mounted = MOUNTED.write().expect("Lock assets source");
+
+    *mounted = None;
+}
+
+/// Reads all the bytes of the asset at the given path from the currently
+/// mounted [`Source`].
+///
+/// [`Source`]: trait.Source.html
+pub fn read<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
+    let mounted = MOUNTED.read().expect("Lock assets source").clone();
+
+    match mounted {
+        Some(source) => source.read(path.as_ref()),
+        #[cfg(target_os = "android")]
+        None => Apk.read(path.as_ref()),
+        #[cfg(not(target_os = "android"))]
+        None => std::fs::read(path),
+    }
+}
+
+/// Opens the asset at the given path in the currently mounted [`Source`] to
+/// read it on demand.
+///
+/// [`Source`]: trait.Source.html
+pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Box<dyn Reader>> {
+    let mounted = MOUNTED.read().expect("Lock assets source").clone();
+
+    match mounted {
+        Some(source) => source.open(path.as_ref()),
+        #[cfg(target_os = "android")]
+        None => Apk.open(path.as_ref()),
+        #[cfg(not(target_os = "android"))]
+        None => Ok(Box::new(io::BufReader::new(std::fs::File::open(path)?))),
+    }
+}
+
+/// Returns the last time the asset at the given path was modified in the
+/// currently mounted [`Source`], if known.
+///
+/// [`Source`]: trait.Source.html
+pub fn modified<P: AsRef<Path>>(path: P) -> Option<SystemTime> {
+    let mounted = MOUNTED.read().expect("Lock assets source").clone();
+
+    match mounted {
+        Some(source) => source.modified(path.as_ref()),
+        None => std::fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok(),
+    }
+}
+
+/// Finds the paths of all the assets in the currently mounted [`Source`] that
+/// match the given glob pattern.
+///
+/// Patterns support `*` and `?` wildcards inside path components, and `**` to
+/// match any amount of nested directories. For instance, `sprites/**/*.png`
+/// matches all the PNG files inside the `sprites` directory.
+///
+/// The returned paths are sorted.
+///
+/// [`Source`]: trait.Source.html
+pub fn glob(pattern: &str) -> io::Result<Vec<PathBuf>> {
+    let pattern = glob::Pattern::new(pattern);
+    let base = pattern.base();
+
+    let mounted = MOUNTED.read().expect("Lock assets source").clone();
+
+    let mut paths = match mounted {
+        Some(source) => source.list(&base)?,
+        None => folder::walk(Path::new(""), &base)?,
+    };
+
+    paths.retain(|path| pattern.matches(path));
+    paths.sort();
+
+    Ok(paths)
+}
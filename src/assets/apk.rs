@@ -0,0 +1,49 @@
+use super::Source;
+
+use std::ffi::CString;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// A [`Source`] that reads the assets bundled in the APK of an Android app.
+///
+/// It is the default [`Source`] on Android.
+///
+/// It is only available when targeting Android.
+///
+/// [`Source`]: trait.Source.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Apk;
+
+impl Apk {
+    /// Creates a new [`Apk`] source.
+    ///
+    /// [`Apk`]: struct.Apk.html
+    pub fn new() -> Apk {
+        Apk
+    }
+}
+
+impl Source for Apk {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let not_found = || {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} is not bundled in the APK", path.display()),
+            )
+        };
+
+        // APK assets always use forward slashes
+        let name = path.to_string_lossy().replace('\\', "/");
+        let name = CString::new(name).map_err(|_| not_found())?;
+
+        let mut asset = ndk_glue::native_activity()
+            .asset_manager()
+            .open(&name)
+            .ok_or_else(not_found)?;
+
+        let mut bytes = Vec::new();
+        let _ = asset.read_to_end(&mut bytes)?;
+
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,175 @@
+use crate::graphics::{Gpu, Image};
+use crate::load::{hot_reload, Task};
+use crate::Result;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+/// A cache of loaded assets keyed by path.
+///
+/// A [`Cache`] returns shared handles to the assets it loads. Loading the
+/// same path twice will produce the same handle, avoiding uploading the same
+/// texture to the GPU multiple times.
+///
+/// Cloning a [`Cache`] is cheap, and all the clones share the same assets.
+/// This way, you can hand it to the different systems of your game.
+///
+/// # Example
+///
+/// ```
+/// use coffee::assets::Cache;
+/// use coffee::graphics::Image;
+/// use coffee::load::{Join, Task};
+///
+/// struct Level {
+///     tiles: Image,
+///     background: Image,
+/// }
+///
+/// impl Level {
+///     fn load(cache: &Cache) -> Task<Level> {
+///         (
+///             cache.load_image("tiles.png"),
+///             cache.load_image("background.png"),
+///         )
+///             .join()
+///             .map(|(tiles, background)| Level { tiles, background })
+///     }
+/// }
+/// ```
+///
+/// [`Cache`]: struct.Cache.html
+#[derive(Clone, Default)]
+pub struct Cache {
+    images: Rc<RefCell<HashMap<PathBuf, Image>>>,
+}
+
+impl Cache {
+    /// Creates a new empty [`Cache`].
+    ///
+    /// [`Cache`]: struct.Cache.html
+    pub fn new() -> Cache {
+        Cache {
+            images: Rc::new(RefCell::new(HashMap::new())),
+        }
+    }
+
+    /// Obtains the [`Image`] in the given path, loading it if it is not
+    /// present in the [`Cache`].
+    ///
+    /// [`Image`]: ../graphics/struct.Image.html
+    /// [`Cache`]: struct.Cache.html
+    pub fn image<P: AsRef<Path>>(
+        &self,
+        gpu: &mut Gpu,
+        path: P,
+    ) -> Result<Image> {
+        if let Some(image) = self.cached_image(path.as_ref()) {
+            return Ok(image);
+        }
+
+        let image = Image::new(gpu, &path)?;
+
+        Ok(self.insert_image(path.as_ref().to_path_buf(), image))
+    }
+
+    /// Creates a [`Task`] that obtains the [`Image`] in the given path,
+    /// loading it if it is not present in the [`Cache`].
+    ///
+    /// Like [`Image::load`], the image is decoded on a background thread.
+    /// If multiple tasks load the same path concurrently, the image may be
+    /// decoded more than once, but it will only be uploaded to the GPU once.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Image`]: ../graphics/struct.Image.html
+    /// [`Cache`]: struct.Cache.html
+    /// [`Image::load`]: ../graphics/struct.Image.html#method.load
+    pub fn load_image<P: Into<PathBuf>>(&self, path: P) -> Task<Image> {
+        let path = path.into();
+
+        if let Some(image) = self.cached_image(&path) {
+            return Task::succeed(move || image);
+        }
+
+        let cache = self.clone();
+        let p = path.clone();
+
+        Task::offload_then(
+            move || Image::decode(&p),
+            move |gpu, image| {
+                if let Some(image) = cache.cached_image(&path) {
+                    return Ok(image);
+                }
+
+                let image = Image::from_image(gpu, &image)?;
+
+                hot_reload::watch_image(path.clone(), &image.texture);
+
+                Ok(cache.insert_image(path, image))
+            },
+        )
+    }
+
+    /// Removes all the assets that are only referenced by the [`Cache`],
+    /// freeing their resources. It returns the amount of removed assets.
+    ///
+    /// This is useful during level transitions. Drop the assets of the old
+    /// level, call this method, and then load the new level.
+    ///
+    /// [`Cache`]: struct.Cache.html
+    pub fn unload_unused(&self) -> usize {
+        let mut images = self.images.borrow_mut();
+        let before = images.len();
+
+        images.retain(|_, image| Rc::strong_count(&image.texture) > 1);
+
+        before - images.len()
+    }
+
+    /// Removes all the assets from the [`Cache`].
+    ///
+    /// Assets that are still in use will remain valid, but loading them again
+    /// will produce a new handle.
+    ///
+    /// [`Cache`]: struct.Cache.html
+    pub fn clear(&self) {
+        self.images.borrow_mut().clear();
+    }
+
+    /// Returns the amount of assets in the [`Cache`].
+    ///
+    /// [`Cache`]: struct.Cache.html
+    pub fn len(&self) -> usize {
+        self.images.borrow().len()
+    }
+
+    /// Returns true if the [`Cache`] contains no assets.
+    ///
+    /// [`Cache`]: struct.Cache.html
+    pub fn is_empty(&self) -> bool {
+        self.images.borrow().is_empty()
+    }
+
+    fn cached_image(&self, path: &Path) -> Option<Image> {
+        self.images.borrow().get(path).cloned()
+    }
+
+    fn insert_image(&self, path: PathBuf, image: Image) -> Image {
+        self.images
+            .borrow_mut()
+            .entry(path)
+            .or_insert(image)
+            .clone()
+    }
+}
+
+impl fmt::Debug for Cache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cache")
+            .field("images", &self.images.borrow().len())
+            .finish()
+    }
+}
@@ -0,0 +1,76 @@
+use super::{Reader, Source};
+
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A [`Source`] that serves assets embedded in the executable.
+///
+/// # Example
+///
+/// ```
+/// use coffee::assets::{self, Embedded};
+///
+/// // You would normally use `include_bytes!("path/to/player.png")` here
+/// const PLAYER: &[u8] = &[];
+///
+/// assets::mount(Embedded::new().file("player.png", PLAYER));
+/// ```
+///
+/// [`Source`]: trait.Source.html
+#[derive(Debug, Clone, Default)]
+pub struct Embedded {
+    files: HashMap<PathBuf, &'static [u8]>,
+}
+
+impl Embedded {
+    /// Creates a new empty [`Embedded`] source.
+    ///
+    /// [`Embedded`]: struct.Embedded.html
+    pub fn new() -> Embedded {
+        Embedded {
+            files: HashMap::new(),
+        }
+    }
+
+    /// Adds a file with the given path and contents to the [`Embedded`]
+    /// source.
+    ///
+    /// [`Embedded`]: struct.Embedded.html
+    pub fn file<P: Into<PathBuf>>(
+        mut self,
+        path: P,
+        bytes: &'static [u8],
+    ) -> Embedded {
+        let _ = self.files.insert(path.into(), bytes);
+        self
+    }
+
+    fn get(&self, path: &Path) -> io::Result<&'static [u8]> {
+        self.files.get(path).copied().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Embedded asset not found: {}", path.display()),
+            )
+        })
+    }
+}
+
+impl Source for Embedded {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.get(path).map(<[u8]>::to_vec)
+    }
+
+    fn open(&self, path: &Path) -> io::Result<Box<dyn Reader>> {
+        Ok(Box::new(io::Cursor::new(self.get(path)?)))
+    }
+
+    fn list(&self, directory: &Path) -> io::Result<Vec<PathBuf>> {
+        Ok(self
+            .files
+            .keys()
+            .filter(|path| path.starts_with(directory))
+            .cloned()
+            .collect())
+    }
+}
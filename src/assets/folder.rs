@@ -0,0 +1,77 @@
+use super::{Reader, Source};
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// A [`Source`] that reads assets from a folder in the filesystem.
+///
+/// [`Source`]: trait.Source.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Folder {
+    root: PathBuf,
+}
+
+impl Folder {
+    /// Creates a new [`Folder`] source rooted at the given path.
+    ///
+    /// Asset paths will be resolved relative to it.
+    ///
+    /// [`Folder`]: struct.Folder.html
+    pub fn new<P: Into<PathBuf>>(root: P) -> Folder {
+        Folder { root: root.into() }
+    }
+
+    /// Returns the root path of the [`Folder`].
+    ///
+    /// [`Folder`]: struct.Folder.html
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl Source for Folder {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        std::fs::read(self.root.join(path))
+    }
+
+    fn open(&self, path: &Path) -> io::Result<Box<dyn Reader>> {
+        let file = std::fs::File::open(self.root.join(path))?;
+
+        Ok(Box::new(io::BufReader::new(file)))
+    }
+
+    fn modified(&self, path: &Path) -> Option<SystemTime> {
+        std::fs::metadata(self.root.join(path))
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+
+    fn list(&self, directory: &Path) -> io::Result<Vec<PathBuf>> {
+        walk(&self.root, directory)
+    }
+}
+
+/// Lists all the files inside a directory of the given root, recursively.
+///
+/// The returned paths are relative to the root.
+pub(super) fn walk(root: &Path, directory: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![root.join(directory)];
+
+    while let Some(directory) = pending.pop() {
+        for entry in std::fs::read_dir(&directory)? {
+            let path = entry?.path();
+
+            if path.is_dir() {
+                pending.push(path);
+            } else {
+                files.push(
+                    path.strip_prefix(root).unwrap_or(&path).to_path_buf(),
+                );
+            }
+        }
+    }
+
+    Ok(files)
+}
@@ -0,0 +1,118 @@
+use std::path::{Component, Path, PathBuf};
+
+/// A parsed glob pattern.
+///
+/// It supports `*` and `?` inside path components, and `**` to match any
+/// amount of nested directories.
+pub struct Pattern {
+    components: Vec<String>,
+}
+
+impl Pattern {
+    pub fn new(pattern: &str) -> Pattern {
+        Pattern {
+            components: pattern
+                .split(|c| c == '/' || c == '\\')
+                .filter(|component| !component.is_empty() && *component != ".")
+                .map(String::from)
+                .collect(),
+        }
+    }
+
+    /// Returns the longest directory of the pattern without wildcards.
+    pub fn base(&self) -> PathBuf {
+        self.components
+            .iter()
+            .take_while(|component| !has_wildcards(component))
+            .take(self.components.len().saturating_sub(1))
+            .collect()
+    }
+
+    pub fn matches(&self, path: &Path) -> bool {
+        let components: Vec<String> = path
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(part) => {
+                    Some(part.to_string_lossy().into_owned())
+                }
+                _ => None,
+            })
+            .collect();
+
+        matches_components(&self.components, &components)
+    }
+}
+
+fn has_wildcards(component: &str) -> bool {
+    component.contains(|c| c == '*' || c == '?')
+}
+
+fn matches_components(pattern: &[String], path: &[String]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((first, rest)) if first == "**" => {
+            (0..=path.len()).any(|i| matches_components(rest, &path[i..]))
+        }
+        Some((first, rest)) => match path.split_first() {
+            Some((component, path)) => {
+                matches_wildcard(first.as_bytes(), component.as_bytes())
+                    && matches_components(rest, path)
+            }
+            None => false,
+        },
+    }
+}
+
+fn matches_wildcard(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => {
+            (0..=text.len()).any(|i| matches_wildcard(rest, &text[i..]))
+        }
+        Some((b'?', rest)) => {
+            !text.is_empty() && matches_wildcard(rest, &text[1..])
+        }
+        Some((c, rest)) => {
+            text.first() == Some(c) && matches_wildcard(rest, &text[1..])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, path: &str) -> bool {
+        Pattern::new(pattern).matches(Path::new(path))
+    }
+
+    #[test]
+    fn wildcards_match_inside_components() {
+        assert!(matches("sprites/*.png", "sprites/player.png"));
+        assert!(matches("sprites/player_?.png", "sprites/player_1.png"));
+        assert!(matches("./sprites/*", "sprites/player.png"));
+
+        assert!(!matches("sprites/*.png", "sprites/player.jpg"));
+        assert!(!matches("sprites/*.png", "sprites/enemies/bat.png"));
+        assert!(!matches("sprites/player_?.png", "sprites/player_10.png"));
+    }
+
+    #[test]
+    fn double_star_matches_nested_directories() {
+        assert!(matches("sprites/**/*.png", "sprites/player.png"));
+        assert!(matches("sprites/**/*.png", "sprites/enemies/bat.png"));
+        assert!(matches("**/bat.png", "sprites/enemies/bat.png"));
+
+        assert!(!matches("sprites/**/*.png", "music/theme.png"));
+    }
+
+    #[test]
+    fn base_stops_at_wildcards() {
+        let base = |pattern| Pattern::new(pattern).base();
+
+        assert_eq!(base("sprites/enemies/*.png"), Path::new("sprites/enemies"));
+        assert_eq!(base("sprites/**/bat.png"), Path::new("sprites"));
+        assert_eq!(base("sprites/bat.png"), Path::new("sprites"));
+        assert_eq!(base("*.png"), Path::new(""));
+    }
+}
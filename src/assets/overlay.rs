@@ -0,0 +1,99 @@
+use super::{Reader, Source};
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// A [`Source`] that stacks multiple sources on top of each other.
+///
+/// Assets are read from the first [`Source`] that contains them. This is
+/// useful to support mods or patches that override only some of the assets
+/// of your game.
+///
+/// [`Source`]: trait.Source.html
+#[derive(Default)]
+pub struct Overlay {
+    sources: Vec<Box<dyn Source>>,
+}
+
+impl Overlay {
+    /// Creates a new empty [`Overlay`].
+    ///
+    /// [`Overlay`]: struct.Overlay.html
+    pub fn new() -> Overlay {
+        Overlay {
+            sources: Vec::new(),
+        }
+    }
+
+    /// Adds a [`Source`] to the bottom of the [`Overlay`].
+    ///
+    /// It will only be used for the assets that are not present in the
+    /// sources pushed before it.
+    ///
+    /// [`Source`]: trait.Source.html
+    /// [`Overlay`]: struct.Overlay.html
+    pub fn push<S: 'static + Source>(mut self, source: S) -> Overlay {
+        self.sources.push(Box::new(source));
+        self
+    }
+}
+
+impl fmt::Debug for Overlay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Overlay")
+            .field("sources", &self.sources.len())
+            .finish()
+    }
+}
+
+impl Source for Overlay {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        for source in &self.sources {
+            match source.read(path) {
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                result => return result,
+            }
+        }
+
+        Err(not_found(path))
+    }
+
+    fn open(&self, path: &Path) -> io::Result<Box<dyn Reader>> {
+        for source in &self.sources {
+            match source.open(path) {
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                result => return result,
+            }
+        }
+
+        Err(not_found(path))
+    }
+
+    fn list(&self, directory: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut paths = BTreeSet::new();
+
+        for source in &self.sources {
+            match source.list(directory) {
+                Ok(listed) => paths.extend(listed),
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(paths.into_iter().collect())
+    }
+
+    fn modified(&self, path: &Path) -> Option<SystemTime> {
+        self.sources.iter().find_map(|source| source.modified(path))
+    }
+}
+
+fn not_found(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("Asset not found: {}", path.display()),
+    )
+}
@@ -0,0 +1,690 @@
+use super::{folder, Reader, Source};
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+const MAGIC: &[u8; 4] = b"CPAK";
+const VERSION: u32 = 1;
+
+// The size of an entry in the index of a pack, without its path
+const ENTRY_SIZE: u64 = 4 + 8 + 8 + 1;
+
+/// A [`Source`] that reads assets from a pack file.
+///
+/// Pack files bundle many assets in a single file, optionally compressing
+/// them. You can create them with a [`Packer`].
+///
+/// Only the index of the pack is read when opening it. Assets are read from
+/// the file on demand.
+///
+/// # Example
+///
+/// ```no_run
+/// use coffee::assets::{self, Pack};
+///
+/// # fn main() -> std::io::Result<()> {
+/// assets::mount(Pack::open("assets.pak")?);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Source`]: trait.Source.html
+/// [`Packer`]: struct.Packer.html
+pub struct Pack {
+    path: PathBuf,
+    file: Mutex<File>,
+    entries: HashMap<String, Entry>,
+}
+
+impl Pack {
+    /// Opens the pack file at the given path.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Pack> {
+        let path = path.as_ref().to_path_buf();
+        let mut file = File::open(&path)?;
+        let length = file.metadata()?.len();
+
+        let mut magic = [0; 4];
+        file.read_exact(&mut magic)?;
+
+        if &magic != MAGIC {
+            return Err(invalid_data("Invalid pack file"));
+        }
+
+        let version = read_u32(&mut file)?;
+
+        if version != VERSION {
+            return Err(invalid_data(format!(
+                "Unsupported pack version: {}",
+                version
+            )));
+        }
+
+        let count = read_u32(&mut file)?;
+
+        // A corrupted index must not make us allocate more than the size of
+        // the file
+        if u64::from(count) * ENTRY_SIZE > length {
+            return Err(invalid_data("Invalid asset count in pack"));
+        }
+
+        let mut entries = HashMap::with_capacity(count as usize);
+
+        for _ in 0..count {
+            let name_length = read_u32(&mut file)?;
+
+            if u64::from(name_length) > length {
+                return Err(invalid_data("Invalid asset path in pack"));
+            }
+
+            let mut name = vec![0; name_length as usize];
+            file.read_exact(&mut name)?;
+
+            let name = String::from_utf8(name)
+                .map_err(|_| invalid_data("Invalid asset path in pack"))?;
+
+            let entry = Entry {
+                offset: read_u64(&mut file)?,
+                size: read_u64(&mut file)?,
+                compression: Compression::from_byte(read_u8(&mut file)?)?,
+            };
+
+            match entry.offset.checked_add(entry.size) {
+                Some(end) if end <= length => {}
+                _ => return Err(invalid_data("Invalid asset entry in pack")),
+            }
+
+            let _ = entries.insert(name, entry);
+        }
+
+        Ok(Pack {
+            path,
+            file: Mutex::new(file),
+            entries,
+        })
+    }
+
+    /// Returns true if the [`Pack`] contains an asset with the given path.
+    ///
+    /// [`Pack`]: struct.Pack.html
+    pub fn contains<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.entries.contains_key(&normalize(path.as_ref()))
+    }
+
+    /// Returns the amount of assets in the [`Pack`].
+    ///
+    /// [`Pack`]: struct.Pack.html
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the [`Pack`] contains no assets.
+    ///
+    /// [`Pack`]: struct.Pack.html
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn entry(&self, path: &Path) -> io::Result<Entry> {
+        self.entries.get(&normalize(path)).copied().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Asset not found in pack: {}", path.display()),
+            )
+        })
+    }
+}
+
+impl fmt::Debug for Pack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pack")
+            .field("entries", &self.entries.len())
+            .finish()
+    }
+}
+
+impl Source for Pack {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let entry = self.entry(path)?;
+
+        let mut data = vec![0; entry.size as usize];
+
+        {
+            let mut file = self.file.lock().expect("Lock pack file");
+            let _ = file.seek(SeekFrom::Start(entry.offset))?;
+            file.read_exact(&mut data)?;
+        }
+
+        entry.compression.decompress(data)
+    }
+
+    fn open(&self, path: &Path) -> io::Result<Box<dyn Reader>> {
+        let entry = self.entry(path)?;
+
+        match entry.compression {
+            Compression::None => {
+                let mut file = File::open(&self.path)?;
+                let _ = file.seek(SeekFrom::Start(entry.offset))?;
+
+                Ok(Box::new(Slice {
+                    file: io::BufReader::new(file),
+                    start: entry.offset,
+                    size: entry.size,
+                    position: 0,
+                }))
+            }
+            // Compressed assets need to be decompressed as a whole
+            Compression::Deflate => {
+                Ok(Box::new(io::Cursor::new(self.read(path)?)))
+            }
+        }
+    }
+
+    fn list(&self, directory: &Path) -> io::Result<Vec<PathBuf>> {
+        let directory = PathBuf::from(normalize(directory));
+
+        Ok(self
+            .entries
+            .keys()
+            .map(PathBuf::from)
+            .filter(|path| path.starts_with(&directory))
+            .collect())
+    }
+}
+
+/// A builder of pack files.
+///
+/// Use it in a build script or a separate tool to bundle the assets of your
+/// game in a single file, which can be read at runtime using a [`Pack`].
+///
+/// # Example
+///
+/// ```no_run
+/// use coffee::assets::{Compression, Packer};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut packer = Packer::new().compression(Compression::Deflate);
+///
+/// packer.add_folder("assets")?;
+/// packer.write("assets.pak")?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Pack`]: struct.Pack.html
+#[derive(Debug, Clone)]
+pub struct Packer {
+    files: Vec<(String, PathBuf)>,
+    compression: Compression,
+}
+
+impl Packer {
+    /// Creates a new empty [`Packer`].
+    ///
+    /// By default, assets are stored without compression.
+    ///
+    /// [`Packer`]: struct.Packer.html
+    pub fn new() -> Packer {
+        Packer {
+            files: Vec::new(),
+            compression: Compression::None,
+        }
+    }
+
+    /// Sets the [`Compression`] used to store the assets.
+    ///
+    /// [`Compression`]: enum.Compression.html
+    pub fn compression(mut self, compression: Compression) -> Packer {
+        self.compression = compression;
+        self
+    }
+
+    /// Adds the file in the given path of the filesystem to the pack, storing
+    /// it with the provided asset path.
+    ///
+    /// The file is not read until the pack is written.
+    pub fn add_file<A: AsRef<Path>, P: Into<PathBuf>>(
+        &mut self,
+        asset_path: A,
+        path: P,
+    ) {
+        self.files
+            .push((normalize(asset_path.as_ref()), path.into()));
+    }
+
+    /// Adds all the files inside the given folder, recursively, to the pack.
+    ///
+    /// Asset paths are stored relative to the folder.
+    pub fn add_folder<P: AsRef<Path>>(&mut self, folder: P) -> io::Result<()> {
+        let root = folder.as_ref();
+
+        for asset_path in folder::walk(root, Path::new(""))? {
+            let path = root.join(&asset_path);
+
+            self.add_file(asset_path, path);
+        }
+
+        Ok(())
+    }
+
+    /// Writes the pack file to the given path.
+    ///
+    /// Files are streamed into the pack one by one, so they are never loaded
+    /// fully in memory.
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let header_size = MAGIC.len() as u64
+            + 8
+            + self
+                .files
+                .iter()
+                .map(|(asset_path, _)| ENTRY_SIZE + asset_path.len() as u64)
+                .sum::<u64>();
+
+        let mut file = io::BufWriter::new(File::create(path)?);
+
+        // The index is written last, once the size of every asset is known
+        let _ = file.seek(SeekFrom::Start(header_size))?;
+
+        let mut index = Vec::with_capacity(self.files.len());
+        let mut offset = header_size;
+
+        for (asset_path, path) in &self.files {
+            let mut asset = File::open(path)?;
+            let size = self.compression.copy(&mut asset, &mut file)?;
+
+            index.push((asset_path, offset, size));
+            offset += size;
+        }
+
+        let _ = file.seek(SeekFrom::Start(0))?;
+
+        file.write_all(MAGIC)?;
+        file.write_all(&VERSION.to_le_bytes())?;
+        file.write_all(&(index.len() as u32).to_le_bytes())?;
+
+        for (asset_path, offset, size) in index {
+            file.write_all(&(asset_path.len() as u32).to_le_bytes())?;
+            file.write_all(asset_path.as_bytes())?;
+            file.write_all(&offset.to_le_bytes())?;
+            file.write_all(&size.to_le_bytes())?;
+            file.write_all(&[self.compression.to_byte()])?;
+        }
+
+        file.flush()
+    }
+}
+
+impl Default for Packer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The compression of the assets in a pack file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// Assets are stored as they are.
+    None,
+
+    /// Assets are compressed using DEFLATE.
+    ///
+    /// Reading and writing compressed packs requires the `compression`
+    /// feature.
+    Deflate,
+}
+
+impl Compression {
+    fn to_byte(self) -> u8 {
+        match self {
+            Compression::None => 0,
+            Compression::Deflate => 1,
+        }
+    }
+
+    fn from_byte(byte: u8) -> io::Result<Compression> {
+        match byte {
+            0 => Ok(Compression::None),
+            1 => Ok(Compression::Deflate),
+            _ => Err(invalid_data("Unknown pack compression")),
+        }
+    }
+
+    // Compresses the contents of the reader into the writer, returning the
+    // amount of bytes written
+    fn copy(
+        self,
+        reader: &mut impl Read,
+        writer: &mut impl Write,
+    ) -> io::Result<u64> {
+        match self {
+            Compression::None => io::copy(reader, writer),
+            #[cfg(feature = "compression")]
+            Compression::Deflate => {
+                let mut encoder = flate2::write::DeflateEncoder::new(
+                    writer,
+                    flate2::Compression::default(),
+                );
+
+                let _ = io::copy(reader, &mut encoder)?;
+                encoder.try_finish()?;
+
+                Ok(encoder.total_out())
+            }
+            #[cfg(not(feature = "compression"))]
+            Compression::Deflate => Err(compression_disabled()),
+        }
+    }
+
+    fn decompress(self, data: Vec<u8>) -> io::Result<Vec<u8>> {
+        match self {
+            Compression::None => Ok(data),
+            #[cfg(feature = "compression")]
+            Compression::Deflate => {
+                let mut decoder =
+                    flate2::read::DeflateDecoder::new(data.as_slice());
+                let mut decompressed = Vec::new();
+
+                let _ = decoder.read_to_end(&mut decompressed)?;
+
+                Ok(decompressed)
+            }
+            #[cfg(not(feature = "compression"))]
+            Compression::Deflate => Err(compression_disabled()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    offset: u64,
+    size: u64,
+    compression: Compression,
+}
+
+// An uncompressed asset inside a pack file, read on demand
+struct Slice {
+    file: io::BufReader<File>,
+    start: u64,
+    size: u64,
+    position: u64,
+}
+
+impl Read for Slice {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.size.saturating_sub(self.position);
+        let length = (buffer.len() as u64).min(remaining) as usize;
+
+        let read = self.file.read(&mut buffer[..length])?;
+        self.position += read as u64;
+
+        Ok(read)
+    }
+}
+
+impl Seek for Slice {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        let offset = |base: u64, offset: i64| {
+            if offset >= 0 {
+                base.checked_add(offset as u64)
+            } else {
+                base.checked_sub(offset.wrapping_neg() as u64)
+            }
+        };
+
+        let position = match position {
+            SeekFrom::Start(position) => Some(position),
+            SeekFrom::Current(delta) => offset(self.position, delta),
+            SeekFrom::End(delta) => offset(self.size, delta),
+        }
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Invalid seek to a negative position",
+            )
+        })?;
+
+        let _ = self.file.seek(SeekFrom::Start(self.start + position))?;
+        self.position = position;
+
+        Ok(position)
+    }
+}
+
+// Paths are stored relative to the root of the pack, using forward slashes.
+// Parent components are resolved, but never above the root.
+fn normalize(path: &Path) -> String {
+    let mut parts = Vec::new();
+
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_string_lossy()),
+            Component::ParentDir => {
+                let _ = parts.pop();
+            }
+            Component::CurDir | Component::RootDir | Component::Prefix(_) => {}
+        }
+    }
+
+    parts.join("/")
+}
+
+fn read_u8(reader: &mut impl Read) -> io::Result<u8> {
+    let mut bytes = [0; 1];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(bytes[0])
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn invalid_data<E>(error: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+#[cfg(not(feature = "compression"))]
+fn compression_disabled() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Other,
+        "Compressed packs require the `compression` feature",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    // A temporary folder with some assets, removed when dropped
+    struct Fixture {
+        root: PathBuf,
+    }
+
+    impl Fixture {
+        fn new(name: &str) -> Fixture {
+            let root = std::env::temp_dir().join(format!(
+                "coffee-pack-{}-{}",
+                name,
+                std::process::id()
+            ));
+
+            let _ = fs::remove_dir_all(&root);
+            fs::create_dir_all(root.join("assets/sprites")).expect("Create");
+
+            fs::write(root.join("assets/config.ron"), b"(volume: 0.5)")
+                .expect("Write config");
+            fs::write(root.join("assets/sprites/player.png"), vec![7u8; 4096])
+                .expect("Write sprite");
+
+            Fixture { root }
+        }
+
+        fn pack(&self, compression: Compression) -> PathBuf {
+            let path = self.root.join("assets.pak");
+            let mut packer = Packer::new().compression(compression);
+
+            packer.add_folder(self.root.join("assets")).expect("Add");
+            packer.write(&path).expect("Write pack");
+
+            path
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.root);
+        }
+    }
+
+    fn assert_round_trip(fixture: &Fixture, compression: Compression) {
+        let pack = Pack::open(fixture.pack(compression)).expect("Open pack");
+
+        assert_eq!(pack.len(), 2);
+        assert!(pack.contains("config.ron"));
+        assert!(pack.contains("./sprites/../sprites/player.png"));
+        assert!(!pack.contains("assets.pak"));
+
+        assert_eq!(
+            pack.read(Path::new("config.ron")).expect("Read config"),
+            b"(volume: 0.5)"
+        );
+        assert_eq!(
+            pack.read(Path::new("sprites/player.png"))
+                .expect("Read sprite"),
+            vec![7; 4096]
+        );
+        assert_eq!(
+            pack.list(Path::new("sprites")).expect("List"),
+            vec![PathBuf::from("sprites/player.png")]
+        );
+
+        let mut reader =
+            pack.open(Path::new("config.ron")).expect("Open config");
+        let mut contents = String::new();
+
+        let _ = reader.seek(SeekFrom::Start(1)).expect("Seek");
+        let _ = reader.read_to_string(&mut contents).expect("Read");
+
+        assert_eq!(contents, "volume: 0.5)");
+    }
+
+    #[test]
+    fn round_trip() {
+        let fixture = Fixture::new("round-trip");
+
+        assert_round_trip(&fixture, Compression::None);
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn compressed_round_trip() {
+        let fixture = Fixture::new("compressed-round-trip");
+
+        assert_round_trip(&fixture, Compression::Deflate);
+    }
+
+    #[test]
+    fn normalize_resolves_components() {
+        assert_eq!(
+            normalize(Path::new("sprites/player.png")),
+            "sprites/player.png"
+        );
+        assert_eq!(
+            normalize(Path::new("./sprites//player.png")),
+            "sprites/player.png"
+        );
+        assert_eq!(
+            normalize(Path::new("/sprites/player.png")),
+            "sprites/player.png"
+        );
+        assert_eq!(
+            normalize(Path::new("sprites/../music/theme.ogg")),
+            "music/theme.ogg"
+        );
+        assert_eq!(normalize(Path::new("../../theme.ogg")), "theme.ogg");
+        assert_eq!(normalize(Path::new("")), "");
+    }
+
+    #[test]
+    fn truncated_packs_are_rejected() {
+        let fixture = Fixture::new("truncated");
+        let path = fixture.pack(Compression::None);
+        let length = fs::metadata(&path).expect("Metadata").len();
+
+        for truncated in &[length - 1, 20, 10, 2] {
+            let file = fs::OpenOptions::new()
+                .write(true)
+                .open(&path)
+                .expect("Open pack");
+
+            file.set_len(*truncated).expect("Truncate pack");
+
+            assert!(Pack::open(&path).is_err());
+        }
+    }
+
+    #[test]
+    fn corrupted_indexes_are_rejected() {
+        let fixture = Fixture::new("corrupted");
+        let path = fixture.pack(Compression::None);
+        let original = fs::read(&path).expect("Read pack");
+
+        let corrupt = |offset: usize, bytes: &[u8]| {
+            let mut data = original.clone();
+            data[offset..offset + bytes.len()].copy_from_slice(bytes);
+
+            fs::write(&path, data).expect("Write pack");
+
+            Pack::open(&path).expect_err("Open corrupted pack").kind()
+        };
+
+        // Magic
+        assert_eq!(corrupt(0, b"NOPE"), io::ErrorKind::InvalidData);
+
+        // Version
+        assert_eq!(corrupt(4, &[9, 0, 0, 0]), io::ErrorKind::InvalidData);
+
+        // Asset count
+        assert_eq!(corrupt(8, &[255; 4]), io::ErrorKind::InvalidData);
+
+        // Path length of the first entry
+        assert_eq!(corrupt(12, &[255; 4]), io::ErrorKind::InvalidData);
+
+        // Offset of the first entry
+        let name_length = u32::from_le_bytes([
+            original[12],
+            original[13],
+            original[14],
+            original[15],
+        ]) as usize;
+
+        assert_eq!(
+            corrupt(16 + name_length, &[255; 8]),
+            io::ErrorKind::InvalidData
+        );
+
+        // Compression of the first entry
+        assert_eq!(
+            corrupt(16 + name_length + 16, &[9]),
+            io::ErrorKind::InvalidData
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Play sound effects and music.
+//!
+//! A [`Sound`] is fully decoded when loaded, which makes it cheap to play many
+//! times. A [`Music`] track is decoded while it plays, which is better suited
+//! for long tracks.
+//!
+//! Both are played using the [`Mixer`] of the [`Window`], producing a
+//! [`Playback`] that can be paused, resumed, stopped, and have its volume
+//! changed:
+//!
+//! ```no_run
+//! use coffee::audio::{Music, Sound};
+//! use coffee::graphics::Window;
+//!
+//! fn play(window: &Window, jump: &Sound, theme: &Music) {
+//!     let _ = jump.play(window.audio());
+//!
+//!     let theme = theme.play_looped(window.audio());
+//!     theme.set_volume(0.5);
+//! }
+//! ```
+//!
+//! Every [`Playback`] belongs to a [`Channel`] of the [`Mixer`], which can be
+//! used to control the volume of music, sound effects, and voices separately.
+//!
+//! A [`Sound`] can also be played at a position of a 2D world, relative to a
+//! [`Listener`].
+//!
+//! The audio of a game can be ducked or paused automatically while its window is
+//! not focused, by setting [`Game::BACKGROUND_AUDIO`].
+//!
+//! This module is only available when the `audio` feature is enabled.
+//!
+//! [`Sound`]: struct.Sound.html
+//! [`Music`]: struct.Music.html
+//! [`Mixer`]: struct.Mixer.html
+//! [`Window`]: ../graphics/struct.Window.html
+//! [`Playback`]: struct.Playback.html
+//! [`Channel`]: enum.Channel.html
+//! [`Listener`]: struct.Listener.html
+//! [`Game::BACKGROUND_AUDIO`]: ../trait.Game.html#associatedconstant.BACKGROUND_AUDIO
+mod background;
+mod channel;
+mod fade;
+mod listener;
+mod mixer;
+mod music;
+mod playback;
+mod sound;
+
+pub use background::Background;
+pub use channel::Channel;
+pub use listener::Listener;
+pub use mixer::Mixer;
+pub use music::Music;
+pub use playback::Playback;
+pub use sound::Sound;
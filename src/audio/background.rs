@@ -0,0 +1,23 @@
+/// The behavior of the audio of a game while its window is not focused or is
+/// minimized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Background {
+    /// The audio keeps playing as usual.
+    Continue,
+
+    /// The volume of every [`Channel`] is multiplied by the given factor.
+    ///
+    /// [`Channel`]: enum.Channel.html
+    Duck(f32),
+
+    /// Everything being played is paused until the window is focused again.
+    ///
+    /// Anything that was already paused stays paused afterwards.
+    Pause,
+}
+
+impl Default for Background {
+    fn default() -> Background {
+        Background::Continue
+    }
+}
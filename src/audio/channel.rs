@@ -0,0 +1,118 @@
+use std::cell::Cell;
+
+/// A group of sounds in a [`Mixer`] with its own volume.
+///
+/// Every [`Playback`] belongs to a [`Channel`]. By default, a [`Sound`] is
+/// played in the [`Sfx`] channel and a [`Music`] track in the [`Music`]
+/// channel. The [`Master`] channel affects every [`Playback`].
+///
+/// Channels map directly to the typical volume sliders of a settings menu.
+///
+/// [`Mixer`]: struct.Mixer.html
+/// [`Playback`]: struct.Playback.html
+/// [`Channel`]: enum.Channel.html
+/// [`Sound`]: struct.Sound.html
+/// [`Music`]: struct.Music.html
+/// [`Sfx`]: #variant.Sfx
+/// [`Music`]: #variant.Music
+/// [`Master`]: #variant.Master
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Channel {
+    /// The channel that affects everything
+    Master,
+
+    /// The channel of music tracks
+    Music,
+
+    /// The channel of sound effects
+    Sfx,
+
+    /// The channel of voices and dialogue
+    Voice,
+}
+
+impl Channel {
+    /// All the channels.
+    pub const ALL: [Channel; 4] = [
+        Channel::Master,
+        Channel::Music,
+        Channel::Sfx,
+        Channel::Voice,
+    ];
+
+    fn index(self) -> usize {
+        match self {
+            Channel::Master => 0,
+            Channel::Music => 1,
+            Channel::Sfx => 2,
+            Channel::Voice => 3,
+        }
+    }
+}
+
+// The volume and mute state of every channel, shared by the mixer and its
+// playbacks
+#[derive(Debug)]
+pub(super) struct Levels {
+    volumes: [Cell<f32>; 4],
+    muted: [Cell<bool>; 4],
+    ducking: Cell<f32>,
+}
+
+impl Levels {
+    pub fn new() -> Levels {
+        Levels {
+            volumes: [
+                Cell::new(1.0),
+                Cell::new(1.0),
+                Cell::new(1.0),
+                Cell::new(1.0),
+            ],
+            muted: [
+                Cell::new(false),
+                Cell::new(false),
+                Cell::new(false),
+                Cell::new(false),
+            ],
+            ducking: Cell::new(1.0),
+        }
+    }
+
+    pub fn volume(&self, channel: Channel) -> f32 {
+        self.volumes[channel.index()].get()
+    }
+
+    pub fn set_volume(&self, channel: Channel, volume: f32) {
+        self.volumes[channel.index()].set(volume.max(0.0));
+    }
+
+    pub fn is_muted(&self, channel: Channel) -> bool {
+        self.muted[channel.index()].get()
+    }
+
+    pub fn set_muted(&self, channel: Channel, muted: bool) {
+        self.muted[channel.index()].set(muted);
+    }
+
+    pub fn set_ducking(&self, ducking: f32) {
+        self.ducking.set(ducking.max(0.0));
+    }
+
+    // The volume applied to a playback of the given channel
+    pub fn gain(&self, channel: Channel) -> f32 {
+        let level = |channel| {
+            if self.is_muted(channel) {
+                0.0
+            } else {
+                self.volume(channel)
+            }
+        };
+
+        let master = level(Channel::Master) * self.ducking.get();
+
+        match channel {
+            Channel::Master => master,
+            _ => master * level(channel),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+// A source that can be faded out while it plays
+pub(super) struct Fade<S> {
+    input: S,
+    control: Arc<AtomicU64>,
+    fade_out: Option<(f32, f32)>,
+}
+
+impl<S> Fade<S> {
+    pub fn new(input: S) -> Fade<S> {
+        Fade {
+            input,
+            control: Arc::new(AtomicU64::new(0)),
+            fade_out: None,
+        }
+    }
+
+    pub fn control(&self) -> Control {
+        Control(self.control.clone())
+    }
+}
+
+impl<S> Iterator for Fade<S>
+where
+    S: rodio::Source,
+    S::Item: rodio::Sample,
+{
+    type Item = S::Item;
+
+    fn next(&mut self) -> Option<S::Item> {
+        if self.fade_out.is_none() {
+            let micros = self.control.load(Ordering::Relaxed);
+
+            if micros > 0 {
+                let samples = micros as f32 / 1_000_000.0
+                    * self.input.sample_rate() as f32
+                    * f32::from(self.input.channels());
+
+                self.fade_out = Some((samples.max(1.0), samples.max(1.0)));
+            }
+        }
+
+        let sample = self.input.next()?;
+
+        match &mut self.fade_out {
+            Some((_, remaining)) if *remaining <= 0.0 => None,
+            Some((total, remaining)) => {
+                let gain = *remaining / *total;
+                *remaining -= 1.0;
+
+                Some(rodio::Sample::amplify(sample, gain))
+            }
+            None => Some(sample),
+        }
+    }
+}
+
+impl<S> rodio::Source for Fade<S>
+where
+    S: rodio::Source,
+    S::Item: rodio::Sample,
+{
+    fn current_frame_len(&self) -> Option<usize> {
+        self.input.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.input.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.input.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.input.total_duration()
+    }
+}
+
+// Starts fading out a source from another thread
+#[derive(Debug, Clone)]
+pub(super) struct Control(Arc<AtomicU64>);
+
+impl Control {
+    pub fn fade_out(&self, duration: Duration) {
+        let micros = (duration.as_micros() as u64).max(1);
+
+        let _ = self.0.compare_exchange(
+            0,
+            micros,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
+    }
+}
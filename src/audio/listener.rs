@@ -0,0 +1,59 @@
+use std::f32::consts::FRAC_PI_4;
+
+use crate::graphics::Point;
+
+/// The point of view of the player in a 2D world.
+///
+/// A [`Sound`] played with [`Sound::play_at`] is attenuated by its distance to
+/// the [`Listener`] and panned to the left or right speaker depending on its
+/// horizontal offset.
+///
+/// Usually, you will want to keep the [`Listener`] at the center of your
+/// camera.
+///
+/// [`Sound`]: struct.Sound.html
+/// [`Sound::play_at`]: struct.Sound.html#method.play_at
+/// [`Listener`]: struct.Listener.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Listener {
+    /// The position of the [`Listener`] in the world.
+    ///
+    /// [`Listener`]: struct.Listener.html
+    pub position: Point,
+
+    /// The distance at which sounds become inaudible.
+    ///
+    /// Sounds are fully panned when their horizontal offset reaches this
+    /// distance.
+    pub range: f32,
+}
+
+impl Listener {
+    /// Creates a new [`Listener`] at the given position with the given range.
+    ///
+    /// [`Listener`]: struct.Listener.html
+    pub fn new(position: Point, range: f32) -> Listener {
+        Listener { position, range }
+    }
+
+    // Returns the volume of the left and right channels of a sound played at
+    // the given position
+    pub(super) fn gains(&self, position: Point) -> [f32; 2] {
+        let range = self.range.max(std::f32::EPSILON);
+        let offset = position - self.position;
+
+        let attenuation = (1.0 - offset.norm() / range).max(0.0);
+        let pan = (offset.x / range).max(-1.0).min(1.0);
+
+        // Constant power panning
+        let angle = (pan + 1.0) * FRAC_PI_4;
+
+        [attenuation * angle.cos(), attenuation * angle.sin()]
+    }
+}
+
+impl Default for Listener {
+    fn default() -> Listener {
+        Listener::new(Point::new(0.0, 0.0), 1000.0)
+    }
+}
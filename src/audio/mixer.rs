@@ -0,0 +1,260 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::audio::channel::Levels;
+use crate::audio::fade::Fade;
+use crate::audio::{Background, Channel, Playback};
+
+/// The audio output of your game.
+///
+/// There is a single [`Mixer`] per [`Window`], and you can obtain it using
+/// [`Window::audio`]. It keeps track of everything being played, allowing you
+/// to control the volume of each [`Channel`] or to pause everything at once.
+///
+/// If no audio device is available, the [`Mixer`] stays silent.
+///
+/// [`Mixer`]: struct.Mixer.html
+/// [`Window`]: ../graphics/struct.Window.html
+/// [`Window::audio`]: ../graphics/struct.Window.html#method.audio
+/// [`Channel`]: enum.Channel.html
+pub struct Mixer {
+    device: Option<rodio::Device>,
+    levels: Rc<Levels>,
+    playbacks: RefCell<Vec<Playback>>,
+    background: Cell<Option<Background>>,
+    paused_in_background: RefCell<Vec<Playback>>,
+    seed: Cell<u64>,
+}
+
+impl Mixer {
+    pub(crate) fn new() -> Mixer {
+        let device = rodio::default_output_device();
+
+        if device.is_none() {
+            log::warn!("No audio output device available");
+        }
+
+        Mixer {
+            device,
+            levels: Rc::new(Levels::new()),
+            playbacks: RefCell::new(Vec::new()),
+            background: Cell::new(None),
+            paused_in_background: RefCell::new(Vec::new()),
+            seed: Cell::new(
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|time| time.as_nanos() as u64)
+                    .unwrap_or(0)
+                    | 1,
+            ),
+        }
+    }
+
+    /// Returns true if the [`Mixer`] is connected to an audio device.
+    ///
+    /// [`Mixer`]: struct.Mixer.html
+    pub fn is_available(&self) -> bool {
+        self.device.is_some()
+    }
+
+    /// Returns the master volume of the [`Mixer`].
+    ///
+    /// [`Mixer`]: struct.Mixer.html
+    pub fn volume(&self) -> f32 {
+        self.channel_volume(Channel::Master)
+    }
+
+    /// Sets the master volume of the [`Mixer`].
+    ///
+    /// It is the same as setting the volume of the [`Channel::Master`].
+    ///
+    /// [`Mixer`]: struct.Mixer.html
+    /// [`Channel::Master`]: enum.Channel.html#variant.Master
+    pub fn set_volume(&self, volume: f32) {
+        self.set_channel_volume(Channel::Master, volume);
+    }
+
+    /// Returns the volume of the given [`Channel`].
+    ///
+    /// [`Channel`]: enum.Channel.html
+    pub fn channel_volume(&self, channel: Channel) -> f32 {
+        self.levels.volume(channel)
+    }
+
+    /// Sets the volume of the given [`Channel`].
+    ///
+    /// It is multiplied by the volume of every [`Playback`] in the
+    /// [`Channel`]. The volume of the [`Channel::Master`] is applied to every
+    /// [`Playback`].
+    ///
+    /// [`Channel`]: enum.Channel.html
+    /// [`Playback`]: struct.Playback.html
+    /// [`Channel::Master`]: enum.Channel.html#variant.Master
+    pub fn set_channel_volume(&self, channel: Channel, volume: f32) {
+        self.levels.set_volume(channel, volume);
+        self.sync_volumes();
+    }
+
+    /// Returns true if the given [`Channel`] is muted.
+    ///
+    /// [`Channel`]: enum.Channel.html
+    pub fn is_muted(&self, channel: Channel) -> bool {
+        self.levels.is_muted(channel)
+    }
+
+    /// Mutes or unmutes the given [`Channel`], keeping its volume.
+    ///
+    /// [`Channel`]: enum.Channel.html
+    pub fn set_muted(&self, channel: Channel, muted: bool) {
+        self.levels.set_muted(channel, muted);
+        self.sync_volumes();
+    }
+
+    /// Pauses everything being played.
+    pub fn pause(&self) {
+        for playback in self.playbacks.borrow().iter() {
+            playback.pause();
+        }
+    }
+
+    /// Resumes everything that was paused.
+    pub fn resume(&self) {
+        for playback in self.playbacks.borrow().iter() {
+            playback.resume();
+        }
+    }
+
+    /// Stops everything being played.
+    pub fn stop(&self) {
+        for playback in self.playbacks.borrow_mut().drain(..) {
+            playback.stop();
+        }
+    }
+
+    // Plays a source, remembering the track it comes from
+    pub(super) fn play_track<S>(
+        &self,
+        source: S,
+        channel: Channel,
+        track: Option<usize>,
+    ) -> Playback
+    where
+        S: 'static + rodio::Source + Send,
+        S::Item: rodio::Sample + Send,
+    {
+        let source = Fade::new(source);
+        let fade = source.control();
+
+        let sink = self.device.as_ref().map(|device| {
+            let sink = rodio::Sink::new(device);
+            sink.append(source);
+            sink
+        });
+
+        let playback =
+            Playback::new(sink, fade, channel, track, self.levels.clone());
+
+        if self.background.get() == Some(Background::Pause) {
+            playback.pause();
+            self.paused_in_background
+                .borrow_mut()
+                .push(playback.clone());
+        }
+
+        let mut playbacks = self.playbacks.borrow_mut();
+
+        playbacks.retain(|playback| !playback.is_finished());
+        playbacks.push(playback.clone());
+
+        playback
+    }
+
+    // Applies the given behavior while the window is in the background, or
+    // restores the audio when `None`
+    pub(crate) fn set_background(&self, background: Option<Background>) {
+        if background == self.background.get() {
+            return;
+        }
+
+        self.background.set(background);
+
+        for playback in self.paused_in_background.borrow_mut().drain(..) {
+            playback.resume();
+        }
+
+        match background {
+            Some(Background::Duck(factor)) => self.levels.set_ducking(factor),
+            Some(Background::Pause) => {
+                self.levels.set_ducking(1.0);
+
+                let mut paused = self.paused_in_background.borrow_mut();
+
+                for playback in self.playbacks.borrow().iter() {
+                    if !playback.is_paused() && !playback.is_finished() {
+                        playback.pause();
+                        paused.push(playback.clone());
+                    }
+                }
+            }
+            Some(Background::Continue) | None => self.levels.set_ducking(1.0),
+        }
+
+        self.sync_volumes();
+    }
+
+    pub(super) fn fade_out_track(&self, track: usize, duration: Duration) {
+        for playback in self.playbacks.borrow().iter() {
+            if playback.track() == Some(track) {
+                playback.fade_out(duration);
+            }
+        }
+    }
+
+    // Stops the oldest playbacks of a track until there is room for a new one
+    pub(super) fn limit_track(&self, track: usize, max: usize) {
+        let playbacks = self.playbacks.borrow();
+
+        let mut active: Vec<&Playback> = playbacks
+            .iter()
+            .filter(|playback| {
+                playback.track() == Some(track) && !playback.is_finished()
+            })
+            .collect();
+
+        while active.len() >= max.max(1) {
+            active.remove(0).stop();
+        }
+    }
+
+    // Returns a random number in [-1, 1] using a xorshift generator
+    pub(super) fn random(&self) -> f32 {
+        let mut x = self.seed.get();
+
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+
+        self.seed.set(x);
+
+        (x >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+    }
+
+    fn sync_volumes(&self) {
+        for playback in self.playbacks.borrow().iter() {
+            playback.sync_volume();
+        }
+    }
+}
+
+impl std::fmt::Debug for Mixer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Mixer {{ is_available: {}, volume: {}, playbacks: {} }}",
+            self.is_available(),
+            self.volume(),
+            self.playbacks.borrow().len()
+        )
+    }
+}
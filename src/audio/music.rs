@@ -0,0 +1,298 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rodio::Source;
+
+use crate::assets;
+use crate::audio::{Channel, Mixer, Playback};
+use crate::load::Task;
+use crate::{Error, Result};
+
+/// A music track.
+///
+/// Unlike a [`Sound`], a [`Music`] track is only decoded while it is being
+/// played. This keeps memory usage low for long tracks. Very long tracks can
+/// also be streamed from the mounted [`assets::Source`] using
+/// [`Music::stream`].
+///
+/// A [`Music`] track is played in the [`Channel::Music`] by default.
+///
+/// Cloning a [`Music`] track is cheap, it only clones a handle.
+///
+/// [`Sound`]: struct.Sound.html
+/// [`Music`]: struct.Music.html
+/// [`Channel::Music`]: enum.Channel.html#variant.Music
+/// [`assets::Source`]: ../assets/trait.Source.html
+/// [`Music::stream`]: #method.stream
+#[derive(Clone)]
+pub struct Music {
+    data: Data,
+    channel: Channel,
+}
+
+#[derive(Clone)]
+enum Data {
+    Bytes(Arc<[u8]>),
+    Stream(Arc<Path>),
+}
+
+impl Music {
+    /// Loads a [`Music`] track from the given path.
+    ///
+    /// WAV, Vorbis, FLAC, and MP3 files are supported.
+    ///
+    /// [`Music`]: struct.Music.html
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Music> {
+        let read = || -> Result<Music> {
+            let bytes = assets::read(&path)?;
+
+            Music::from_bytes(bytes)
+        };
+
+        read().map_err(|error| Error::Asset {
+            path: path.as_ref().to_path_buf(),
+            source: Box::new(error),
+        })
+    }
+
+    /// Creates a [`Task`] that loads a [`Music`] track from the given path.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Music`]: struct.Music.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<Music> {
+        let path = path.into();
+
+        Task::offload(move || Music::new(path))
+    }
+
+    /// Creates a [`Music`] track from the given encoded bytes.
+    ///
+    /// [`Music`]: struct.Music.html
+    pub fn from_bytes<B: Into<Vec<u8>>>(bytes: B) -> Result<Music> {
+        Music::with_data(Data::Bytes(bytes.into().into()))
+    }
+
+    /// Creates a [`Music`] track that is streamed from the asset at the given
+    /// path while it plays.
+    ///
+    /// The asset is opened using the mounted [`assets::Source`]. Folders and
+    /// uncompressed packs never load it fully in memory, which is useful for
+    /// very long tracks.
+    ///
+    /// [`Music`]: struct.Music.html
+    /// [`assets::Source`]: ../assets/trait.Source.html
+    pub fn stream<P: AsRef<Path>>(path: P) -> Result<Music> {
+        Music::with_data(Data::Stream(path.as_ref().into())).map_err(|error| {
+            Error::Asset {
+                path: path.as_ref().to_path_buf(),
+                source: Box::new(error),
+            }
+        })
+    }
+
+    /// Creates a [`Task`] that prepares a [`Music`] track to be streamed from
+    /// the asset at the given path.
+    ///
+    /// See [`Music::stream`].
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Music`]: struct.Music.html
+    /// [`Music::stream`]: #method.stream
+    pub fn load_stream<P: Into<PathBuf>>(path: P) -> Task<Music> {
+        let path = path.into();
+
+        Task::offload(move || Music::stream(path))
+    }
+
+    fn with_data(data: Data) -> Result<Music> {
+        let music = Music {
+            data,
+            channel: Channel::Music,
+        };
+
+        // Make sure the track can be decoded before playing it
+        let _ = music.data.decoder()?;
+
+        Ok(music)
+    }
+
+    /// Sets the [`Channel`] where the [`Music`] track will be played.
+    ///
+    /// [`Channel`]: enum.Channel.html
+    /// [`Music`]: struct.Music.html
+    pub fn channel(mut self, channel: Channel) -> Music {
+        self.channel = channel;
+        self
+    }
+
+    /// Plays the [`Music`] track once using the given [`Mixer`].
+    ///
+    /// [`Music`]: struct.Music.html
+    /// [`Mixer`]: struct.Mixer.html
+    pub fn play(&self, mixer: &Mixer) -> Playback {
+        self.play_with(mixer, false)
+    }
+
+    /// Plays the [`Music`] track in a loop using the given [`Mixer`], until
+    /// the resulting [`Playback`] is stopped.
+    ///
+    /// [`Music`]: struct.Music.html
+    /// [`Mixer`]: struct.Mixer.html
+    /// [`Playback`]: struct.Playback.html
+    pub fn play_looped(&self, mixer: &Mixer) -> Playback {
+        self.play_with(mixer, true)
+    }
+
+    /// Fades out every [`Playback`] of this [`Music`] track in the given
+    /// [`Mixer`] while the `other` track fades in, during the given duration.
+    ///
+    /// The `other` track is played in a loop. This is useful to smoothly
+    /// change the music during scene transitions.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    /// [`Music`]: struct.Music.html
+    /// [`Mixer`]: struct.Mixer.html
+    pub fn crossfade_to(
+        &self,
+        other: &Music,
+        mixer: &Mixer,
+        duration: Duration,
+    ) -> Playback {
+        mixer.fade_out_track(self.data.id(), duration);
+
+        other.play_faded(mixer, true, duration)
+    }
+
+    fn play_with(&self, mixer: &Mixer, is_looped: bool) -> Playback {
+        self.play_faded(mixer, is_looped, Duration::from_secs(0))
+    }
+
+    fn play_faded(
+        &self,
+        mixer: &Mixer,
+        is_looped: bool,
+        fade_in: Duration,
+    ) -> Playback {
+        let track = Some(self.data.id());
+
+        match self.data.decoder() {
+            Ok(decoder) => mixer.play_track(
+                Stream {
+                    data: self.data.clone(),
+                    decoder,
+                    is_looped,
+                }
+                .fade_in(fade_in),
+                self.channel,
+                track,
+            ),
+            Err(error) => {
+                log::warn!("Music failed to play: {}", error);
+
+                mixer.play_track(
+                    rodio::source::Empty::<i16>::new(),
+                    self.channel,
+                    track,
+                )
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for Music {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.data {
+            Data::Bytes(bytes) => write!(
+                f,
+                "Music {{ channel: {:?}, bytes: {} }}",
+                self.channel,
+                bytes.len()
+            ),
+            Data::Stream(path) => write!(
+                f,
+                "Music {{ channel: {:?}, path: {:?} }}",
+                self.channel, path
+            ),
+        }
+    }
+}
+
+type Decoder = Box<dyn Source<Item = i16> + Send>;
+
+impl Data {
+    // Identifies the playbacks of a track
+    fn id(&self) -> usize {
+        match self {
+            Data::Bytes(bytes) => bytes.as_ptr() as usize,
+            Data::Stream(path) => {
+                path.as_os_str() as *const _ as *const u8 as usize
+            }
+        }
+    }
+
+    fn decoder(&self) -> Result<Decoder> {
+        let decoder: Decoder = match self {
+            Data::Bytes(bytes) => Box::new(
+                rodio::Decoder::new(io::Cursor::new(bytes.clone()))
+                    .map_err(|error| Error::Audio(error.to_string()))?,
+            ),
+            Data::Stream(path) => {
+                let reader = assets::open(path)?;
+
+                Box::new(
+                    rodio::Decoder::new(reader)
+                        .map_err(|error| Error::Audio(error.to_string()))?,
+                )
+            }
+        };
+
+        Ok(decoder)
+    }
+}
+
+// Decodes a track while it plays, starting over when looped
+struct Stream {
+    data: Data,
+    decoder: Decoder,
+    is_looped: bool,
+}
+
+impl Iterator for Stream {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        match self.decoder.next() {
+            Some(sample) => Some(sample),
+            None if self.is_looped => {
+                self.decoder = self.data.decoder().ok()?;
+
+                self.decoder.next()
+            }
+            None => None,
+        }
+    }
+}
+
+impl Source for Stream {
+    fn current_frame_len(&self) -> Option<usize> {
+        self.decoder.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.decoder.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.decoder.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        if self.is_looped {
+            None
+        } else {
+            self.decoder.total_duration()
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::audio::channel::Levels;
+use crate::audio::fade;
+use crate::audio::Channel;
+
+/// A [`Sound`] or [`Music`] being played by a [`Mixer`].
+///
+/// Cloning a [`Playback`] is cheap, it only clones a handle. The audio keeps
+/// playing even if every handle is dropped.
+///
+/// [`Sound`]: struct.Sound.html
+/// [`Music`]: struct.Music.html
+/// [`Mixer`]: struct.Mixer.html
+/// [`Playback`]: struct.Playback.html
+#[derive(Clone)]
+pub struct Playback {
+    inner: Rc<Inner>,
+}
+
+struct Inner {
+    sink: Option<rodio::Sink>,
+    fade: fade::Control,
+    channel: Channel,
+    track: Option<usize>,
+    volume: Cell<f32>,
+    levels: Rc<Levels>,
+}
+
+impl Playback {
+    pub(super) fn new(
+        sink: Option<rodio::Sink>,
+        fade: fade::Control,
+        channel: Channel,
+        track: Option<usize>,
+        levels: Rc<Levels>,
+    ) -> Playback {
+        let playback = Playback {
+            inner: Rc::new(Inner {
+                sink,
+                fade,
+                channel,
+                track,
+                volume: Cell::new(1.0),
+                levels,
+            }),
+        };
+
+        playback.sync_volume();
+        playback
+    }
+
+    /// Pauses the [`Playback`].
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn pause(&self) {
+        if let Some(sink) = &self.inner.sink {
+            sink.pause();
+        }
+    }
+
+    /// Resumes the [`Playback`] if it was paused.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn resume(&self) {
+        if let Some(sink) = &self.inner.sink {
+            sink.play();
+        }
+    }
+
+    /// Stops the [`Playback`]. It cannot be resumed afterwards.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn stop(&self) {
+        if let Some(sink) = &self.inner.sink {
+            sink.stop();
+        }
+    }
+
+    /// Fades out the [`Playback`] during the given duration, stopping it
+    /// afterwards.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn fade_out(&self, duration: Duration) {
+        self.inner.fade.fade_out(duration);
+    }
+
+    /// Returns true if the [`Playback`] is paused.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn is_paused(&self) -> bool {
+        self.inner
+            .sink
+            .as_ref()
+            .map(rodio::Sink::is_paused)
+            .unwrap_or(false)
+    }
+
+    /// Returns true if the [`Playback`] has finished or has been stopped.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn is_finished(&self) -> bool {
+        self.inner
+            .sink
+            .as_ref()
+            .map(rodio::Sink::empty)
+            .unwrap_or(true)
+    }
+
+    /// Returns the [`Channel`] of the [`Playback`].
+    ///
+    /// [`Channel`]: enum.Channel.html
+    /// [`Playback`]: struct.Playback.html
+    pub fn channel(&self) -> Channel {
+        self.inner.channel
+    }
+
+    /// Returns the volume of the [`Playback`].
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn volume(&self) -> f32 {
+        self.inner.volume.get()
+    }
+
+    /// Sets the volume of the [`Playback`].
+    ///
+    /// `1.0` is the original volume of the audio. The volume of its
+    /// [`Channel`] is applied on top of it.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    /// [`Channel`]: enum.Channel.html
+    pub fn set_volume(&self, volume: f32) {
+        self.inner.volume.set(volume.max(0.0));
+        self.sync_volume();
+    }
+
+    pub(super) fn track(&self) -> Option<usize> {
+        self.inner.track
+    }
+
+    pub(super) fn sync_volume(&self) {
+        if let Some(sink) = &self.inner.sink {
+            sink.set_volume(
+                self.inner.volume.get()
+                    * self.inner.levels.gain(self.inner.channel),
+            );
+        }
+    }
+}
+
+impl std::fmt::Debug for Playback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Playback {{ channel: {:?}, volume: {}, is_paused: {}, is_finished: {} }}",
+            self.channel(),
+            self.volume(),
+            self.is_paused(),
+            self.is_finished()
+        )
+    }
+}
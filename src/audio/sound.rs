@@ -0,0 +1,293 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rodio::Source;
+
+use crate::assets;
+use crate::audio::{Channel, Listener, Mixer, Playback};
+use crate::graphics::Point;
+use crate::load::Task;
+use crate::{Error, Result};
+
+/// A decoded sound effect.
+///
+/// The whole sound is decoded when loaded. Therefore, it can be played many
+/// times without decoding it again. Use [`Music`] for long tracks instead.
+///
+/// A [`Sound`] is played in the [`Channel::Sfx`] by default.
+///
+/// Many instances of the same [`Sound`] can play at the same time. Each one of
+/// them can have a slightly different volume and pitch using
+/// [`Sound::variation`], and the number of simultaneous instances can be
+/// limited using [`Sound::max_voices`].
+///
+/// Cloning a [`Sound`] is cheap, it only clones a handle to its samples.
+///
+/// [`Music`]: struct.Music.html
+/// [`Sound`]: struct.Sound.html
+/// [`Channel::Sfx`]: enum.Channel.html#variant.Sfx
+/// [`Sound::variation`]: #method.variation
+/// [`Sound::max_voices`]: #method.max_voices
+#[derive(Clone)]
+pub struct Sound {
+    samples: Arc<[i16]>,
+    channels: u16,
+    sample_rate: u32,
+    channel: Channel,
+    speed: f32,
+    volume_variation: f32,
+    speed_variation: f32,
+    max_voices: Option<usize>,
+}
+
+impl Sound {
+    /// Loads a [`Sound`] from the given path.
+    ///
+    /// WAV, Vorbis, FLAC, and MP3 files are supported.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Sound> {
+        let decode = || -> Result<Sound> {
+            let bytes = assets::read(&path)?;
+
+            Sound::from_bytes(bytes)
+        };
+
+        decode().map_err(|error| Error::Asset {
+            path: path.as_ref().to_path_buf(),
+            source: Box::new(error),
+        })
+    }
+
+    /// Creates a [`Task`] that loads a [`Sound`] from the given path.
+    ///
+    /// The sound is decoded on a background thread.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Sound`]: struct.Sound.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<Sound> {
+        let path = path.into();
+
+        Task::offload(move || Sound::new(path))
+    }
+
+    /// Decodes a [`Sound`] from the given encoded bytes.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn from_bytes<B: Into<Vec<u8>>>(bytes: B) -> Result<Sound> {
+        let decoder = rodio::Decoder::new(io::Cursor::new(bytes.into()))
+            .map_err(|error| Error::Audio(error.to_string()))?;
+
+        let channels = decoder.channels();
+        let sample_rate = decoder.sample_rate();
+        let samples: Vec<i16> = decoder.collect();
+
+        Ok(Sound {
+            samples: samples.into(),
+            channels,
+            sample_rate,
+            channel: Channel::Sfx,
+            speed: 1.0,
+            volume_variation: 0.0,
+            speed_variation: 0.0,
+            max_voices: None,
+        })
+    }
+
+    /// Sets the [`Channel`] where the [`Sound`] will be played.
+    ///
+    /// [`Channel`]: enum.Channel.html
+    /// [`Sound`]: struct.Sound.html
+    pub fn channel(mut self, channel: Channel) -> Sound {
+        self.channel = channel;
+        self
+    }
+
+    /// Sets the playback speed of the [`Sound`].
+    ///
+    /// Changing the speed also changes the pitch. `1.0` is the original speed.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn speed(mut self, speed: f32) -> Sound {
+        self.speed = speed.max(0.01);
+        self
+    }
+
+    /// Sets the random variation of the volume and speed of the [`Sound`].
+    ///
+    /// Every time the [`Sound`] is played, a random amount within
+    /// `[-variation, variation]` is added to its volume and speed. This makes
+    /// sounds that are played often, like footsteps or shots, less
+    /// repetitive.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn variation(mut self, volume: f32, speed: f32) -> Sound {
+        self.volume_variation = volume.max(0.0);
+        self.speed_variation = speed.max(0.0);
+        self
+    }
+
+    /// Limits the number of instances of the [`Sound`] that can play at the
+    /// same time.
+    ///
+    /// When the limit is reached, the oldest instance is stopped before
+    /// playing a new one.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn max_voices(mut self, max_voices: usize) -> Sound {
+        self.max_voices = Some(max_voices.max(1));
+        self
+    }
+
+    /// Returns the duration of the [`Sound`].
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn duration(&self) -> Duration {
+        let frames =
+            self.samples.len() as u64 / u64::from(self.channels.max(1));
+
+        Duration::from_micros(
+            frames * 1_000_000 / u64::from(self.sample_rate.max(1)),
+        )
+    }
+
+    /// Plays the [`Sound`] once using the given [`Mixer`].
+    ///
+    /// [`Sound`]: struct.Sound.html
+    /// [`Mixer`]: struct.Mixer.html
+    pub fn play(&self, mixer: &Mixer) -> Playback {
+        self.play_source(mixer, self.source(false))
+    }
+
+    /// Plays the [`Sound`] in a loop using the given [`Mixer`], until the
+    /// resulting [`Playback`] is stopped.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    /// [`Mixer`]: struct.Mixer.html
+    /// [`Playback`]: struct.Playback.html
+    pub fn play_looped(&self, mixer: &Mixer) -> Playback {
+        self.play_source(mixer, self.source(true))
+    }
+
+    /// Plays the [`Sound`] once at the given position, as heard by the given
+    /// [`Listener`].
+    ///
+    /// The [`Sound`] is attenuated by its distance to the [`Listener`] and
+    /// panned between the left and right speakers.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    /// [`Listener`]: struct.Listener.html
+    pub fn play_at(
+        &self,
+        mixer: &Mixer,
+        position: Point,
+        listener: &Listener,
+    ) -> Playback {
+        let [left, right] = listener.gains(position);
+
+        self.play_source(
+            mixer,
+            rodio::source::ChannelVolume::new(
+                self.source(false),
+                vec![left, right],
+            ),
+        )
+    }
+
+    fn play_source<S>(&self, mixer: &Mixer, source: S) -> Playback
+    where
+        S: 'static + Source + Send,
+        S::Item: rodio::Sample + Send,
+    {
+        // The samples identify the instances of a sound
+        let track = self.samples.as_ptr() as usize;
+
+        if let Some(max_voices) = self.max_voices {
+            mixer.limit_track(track, max_voices);
+        }
+
+        let speed =
+            (self.speed + self.speed_variation * mixer.random()).max(0.01);
+        let volume = (1.0 + self.volume_variation * mixer.random()).max(0.0);
+
+        let playback =
+            mixer.play_track(source.speed(speed), self.channel, Some(track));
+
+        playback.set_volume(volume);
+        playback
+    }
+
+    fn source(&self, is_looped: bool) -> Samples {
+        Samples {
+            samples: self.samples.clone(),
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            position: 0,
+            is_looped,
+        }
+    }
+}
+
+impl std::fmt::Debug for Sound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Sound {{ channel: {:?}, channels: {}, sample_rate: {}, duration: {:?} }}",
+            self.channel,
+            self.channels,
+            self.sample_rate,
+            self.duration()
+        )
+    }
+}
+
+struct Samples {
+    samples: Arc<[i16]>,
+    channels: u16,
+    sample_rate: u32,
+    position: usize,
+    is_looped: bool,
+}
+
+impl Iterator for Samples {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        if self.position >= self.samples.len() {
+            if !self.is_looped || self.samples.is_empty() {
+                return None;
+            }
+
+            self.position = 0;
+        }
+
+        let sample = self.samples[self.position];
+        self.position += 1;
+
+        Some(sample)
+    }
+}
+
+impl Source for Samples {
+    fn current_frame_len(&self) -> Option<usize> {
+        if self.is_looped {
+            None
+        } else {
+            Some(self.samples.len() - self.position)
+        }
+    }
+
+    fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
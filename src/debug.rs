@@ -1,3 +1,5 @@
+mod settings;
+
 #[cfg(not(any(debug_assertions, feature = "debug")))]
 mod null;
 
@@ -9,3 +11,5 @@ pub use null::Debug;
 
 #[cfg(any(debug_assertions, feature = "debug"))]
 pub use basic::Debug;
+
+pub use settings::{DebugPosition, DebugSettings};
@@ -1,6 +1,14 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
 use std::time;
 
+use instant::Instant;
+
+use super::{DebugPosition, DebugSettings};
 use crate::graphics;
+use crate::ui::core::CacheStatistics;
+use crate::Result;
 
 /// A bunch of performance information about your game. It can be drawn!
 ///
@@ -19,28 +27,41 @@ use crate::graphics;
 pub struct Debug {
     font: graphics::Font,
     enabled: bool,
-    load_start: time::Instant,
+    load_start: Instant,
     load_duration: time::Duration,
-    frame_start: time::Instant,
+    frame_start: Instant,
     frame_durations: TimeBuffer,
-    interact_start: time::Instant,
+    frame_history: TimeBuffer,
+    frame_graph: graphics::Mesh,
+    interact_start: Instant,
     interact_duration: time::Duration,
-    update_start: time::Instant,
+    update_start: Instant,
     update_durations: TimeBuffer,
-    draw_start: time::Instant,
+    draw_start: Instant,
     draw_durations: TimeBuffer,
-    ui_start: time::Instant,
+    ui_start: Instant,
     ui_durations: TimeBuffer,
-    debug_start: time::Instant,
+    ui_cache: CacheStatistics,
+    ui_draw_calls: u32,
+    debug_start: Instant,
     debug_durations: TimeBuffer,
+    missed_frames: u64,
+    missed_ratio: f32,
+    dropped_ticks: u64,
+    memory_usage: graphics::MemoryUsage,
+    timer_speed: f32,
     text: Vec<(String, String)>,
-    draw_rate: u16,
+    settings: DebugSettings,
+    panel_size: (f32, f32),
     frames_until_refresh: u16,
 }
 
 impl Debug {
-    pub(crate) fn new(gpu: &mut graphics::Gpu) -> Self {
-        let now = time::Instant::now();
+    pub(crate) fn new(
+        gpu: &mut graphics::Gpu,
+        settings: DebugSettings,
+    ) -> Self {
+        let now = Instant::now();
 
         Self {
             font: graphics::Font::from_bytes(gpu, graphics::Font::DEFAULT)
@@ -50,6 +71,8 @@ impl Debug {
             load_duration: time::Duration::from_secs(0),
             frame_start: now,
             frame_durations: TimeBuffer::new(200),
+            frame_history: TimeBuffer::new(1000),
+            frame_graph: graphics::Mesh::new(),
             interact_start: now,
             interact_duration: time::Duration::from_secs(0),
             update_start: now,
@@ -58,20 +81,35 @@ impl Debug {
             draw_durations: TimeBuffer::new(200),
             ui_start: now,
             ui_durations: TimeBuffer::new(200),
+            ui_cache: CacheStatistics::default(),
+            ui_draw_calls: 0,
             debug_start: now,
             debug_durations: TimeBuffer::new(200),
+            missed_frames: 0,
+            missed_ratio: 0.0,
+            dropped_ticks: 0,
+            memory_usage: graphics::MemoryUsage::default(),
+            timer_speed: 1.0,
             text: Vec::new(),
-            draw_rate: 10,
+            settings,
+            panel_size: (0.0, 0.0),
             frames_until_refresh: 0,
         }
     }
 
+    // Uploads the debug font again after the graphics device is reset
+    pub(crate) fn reset(&mut self, gpu: &mut graphics::Gpu) -> Result<()> {
+        self.font = graphics::Font::from_bytes(gpu, graphics::Font::DEFAULT)?;
+
+        Ok(())
+    }
+
     pub(crate) fn loading_started(&mut self) {
-        self.load_start = time::Instant::now();
+        self.load_start = Instant::now();
     }
 
     pub(crate) fn loading_finished(&mut self) {
-        self.load_duration = time::Instant::now() - self.load_start;
+        self.load_duration = Instant::now() - self.load_start;
     }
 
     /// Returns the time spent loading your [`Game`].
@@ -82,11 +120,13 @@ impl Debug {
     }
 
     pub(crate) fn frame_started(&mut self) {
-        self.frame_start = time::Instant::now();
+        self.frame_start = Instant::now();
     }
     pub(crate) fn frame_finished(&mut self) {
-        self.frame_durations
-            .push(time::Instant::now() - self.frame_start);
+        let duration = Instant::now() - self.frame_start;
+
+        self.frame_durations.push(duration);
+        self.frame_history.push(duration);
     }
 
     /// Returns the average time spent per frame.
@@ -96,12 +136,67 @@ impl Debug {
         self.frame_durations.average()
     }
 
+    pub(crate) fn frames_missed(&mut self, total: u64, recent_ratio: f32) {
+        self.missed_frames = total;
+        self.missed_ratio = recent_ratio;
+    }
+
+    /// Returns the amount of frames that have missed the refresh interval of
+    /// the monitor, or the [`Game::MAX_FRAME_RATE`] if it is lower.
+    ///
+    /// A steadily increasing amount of missed frames usually means that the
+    /// game is stuttering.
+    ///
+    /// [`Game::MAX_FRAME_RATE`]: trait.Game.html#associatedconstant.MAX_FRAME_RATE
+    pub fn missed_frames(&self) -> u64 {
+        self.missed_frames
+    }
+
+    pub(crate) fn ticks_dropped(&mut self, dropped: u64) {
+        self.dropped_ticks += dropped;
+    }
+
+    /// Returns the amount of ticks that have been dropped because the game
+    /// lagged behind more than the [`CatchUp::max_lag`] of its
+    /// [`Game::CATCH_UP`] policy.
+    ///
+    /// [`CatchUp::max_lag`]: struct.CatchUp.html#structfield.max_lag
+    /// [`Game::CATCH_UP`]: trait.Game.html#associatedconstant.CATCH_UP
+    pub fn dropped_ticks(&self) -> u64 {
+        self.dropped_ticks
+    }
+
+    /// Returns the frame duration at the given percentile of the last 1000
+    /// frames.
+    ///
+    /// For instance, a percentile of `99.0` returns the duration of the
+    /// frames in the slowest 1%, which is usually known as the _1% low_.
+    pub fn frame_duration_percentile(&self, percentile: f32) -> time::Duration {
+        self.frame_history.percentile(percentile)
+    }
+
+    pub(crate) fn memory_used(&mut self, usage: graphics::MemoryUsage) {
+        self.memory_usage = usage;
+    }
+
+    /// Returns an estimation of the GPU memory used by the live textures,
+    /// canvases, and buffers of your game.
+    ///
+    /// If it keeps growing, you are probably creating resources every frame.
+    pub fn memory_usage(&self) -> graphics::MemoryUsage {
+        self.memory_usage
+    }
+
+    pub(crate) fn timer_speed(&mut self, speed: f32) {
+        self.timer_speed = speed;
+    }
+
     pub(crate) fn interact_started(&mut self) {
-        self.interact_start = time::Instant::now();
+        self.interact_start = Instant::now();
     }
 
     pub(crate) fn interact_finished(&mut self) {
-        self.interact_duration = time::Instant::now() - self.interact_start;
+        self.interact_duration = Instant::now() - self.interact_start;
     }
 
     /// Returns the average time spent processing events and running
@@ -113,12 +208,12 @@ impl Debug {
     }
 
     pub(crate) fn update_started(&mut self) {
-        self.update_start = time::Instant::now();
+        self.update_start = Instant::now();
     }
 
     pub(crate) fn update_finished(&mut self) {
         self.update_durations
-            .push(time::Instant::now() - self.update_start);
+            .push(Instant::now() - self.update_start);
     }
 
     /// Returns the average time spent running [`Game::update`].
@@ -129,11 +224,11 @@ impl Debug {
     }
 
     pub(crate) fn draw_started(&mut self) {
-        self.draw_start = time::Instant::now();
+        self.draw_start = Instant::now();
     }
 
     pub(crate) fn draw_finished(&mut self) {
-        let duration = time::Instant::now() - self.draw_start;
+        let duration = Instant::now() - self.draw_start;
 
         if duration.subsec_micros() > 0 {
             self.draw_durations.push(duration);
@@ -148,11 +243,11 @@ impl Debug {
     }
 
     pub(crate) fn ui_started(&mut self) {
-        self.ui_start = time::Instant::now();
+        self.ui_start = Instant::now();
     }
 
     pub(crate) fn ui_finished(&mut self) {
-        self.ui_durations.push(time::Instant::now() - self.ui_start);
+        self.ui_durations.push(Instant::now() - self.ui_start);
     }
 
     /// Returns the average time spent rendering the [`UserInterface`].
@@ -162,18 +257,44 @@ impl Debug {
         self.ui_durations.average()
     }
 
+    pub(crate) fn ui_cached(&mut self, statistics: CacheStatistics) {
+        self.ui_cache = statistics;
+    }
+
+    /// Returns the usage of the layout cache of the [`UserInterface`] during
+    /// the last frame.
+    ///
+    /// [`UserInterface`]: ui/trait.UserInterface.html
+    pub fn ui_cache(&self) -> CacheStatistics {
+        self.ui_cache
+    }
+
+    pub(crate) fn ui_drawn(&mut self, draw_calls: u32) {
+        self.ui_draw_calls = draw_calls;
+    }
+
+    /// Returns the amount of draw calls issued to render the
+    /// [`UserInterface`] during the last frame.
+    ///
+    /// Widgets share their batches, so this amount only grows with the layers
+    /// and the different images of the [`UserInterface`].
+    ///
+    /// [`UserInterface`]: ui/trait.UserInterface.html
+    pub fn ui_draw_calls(&self) -> u32 {
+        self.ui_draw_calls
+    }
+
     pub(crate) fn toggle(&mut self) {
         self.enabled = !self.enabled;
         self.frames_until_refresh = 0;
     }
 
     pub(crate) fn debug_started(&mut self) {
-        self.debug_start = time::Instant::now();
+        self.debug_start = Instant::now();
     }
 
     pub(crate) fn debug_finished(&mut self) {
-        self.debug_durations
-            .push(time::Instant::now() - self.debug_start);
+        self.debug_durations.push(Instant::now() - self.debug_start);
     }
 
     /// Returns the average time spent running [`Game::debug`].
@@ -187,6 +308,137 @@ impl Debug {
         self.enabled
     }
 
+    /// Returns the current [`DebugSettings`].
+    ///
+    /// [`DebugSettings`]: struct.DebugSettings.html
+    pub fn settings(&self) -> &DebugSettings {
+        &self.settings
+    }
+
+    /// Returns a mutable reference to the current [`DebugSettings`], allowing
+    /// you to restyle the debug view at runtime.
+    ///
+    /// Changes are applied on the next refresh.
+    ///
+    /// [`DebugSettings`]: struct.DebugSettings.html
+    pub fn settings_mut(&mut self) -> &mut DebugSettings {
+        self.frames_until_refresh = 0;
+
+        &mut self.settings
+    }
+
+    /// Exports the performance data collected so far to the given path.
+    ///
+    /// The file contains the counters of the session together with the samples
+    /// of every time buffer, from oldest to newest. Durations are stored in
+    /// microseconds.
+    ///
+    /// The data is written as JSON if the path has a `json` extension, and as
+    /// CSV otherwise. The CSV file has a `metric,sample,value` header, and
+    /// counters leave the `sample` column empty.
+    ///
+    /// This is useful to compare performance across different versions of
+    /// your game.
+    pub fn export<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+
+        let is_json = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.eq_ignore_ascii_case("json"))
+            .unwrap_or(false);
+
+        let contents = if is_json {
+            self.to_json()
+        } else {
+            self.to_csv()
+        };
+
+        fs::write(path, contents)?;
+
+        Ok(())
+    }
+
+    // Summarizes the counters and the recent averages for crash reports
+    pub(crate) fn report(&self) -> Option<String> {
+        let mut report = String::new();
+
+        for (name, value) in self.counters().iter() {
+            let _ = writeln!(report, "{}: {}", name, value);
+        }
+
+        for (name, buffer) in self.buffers().iter() {
+            let average = buffer.average().as_micros();
+
+            let _ = writeln!(report, "{} (average): {}", name, average);
+        }
+
+        Some(report)
+    }
+
+    fn counters(&self) -> [(&'static str, u128); 7] {
+        [
+            ("load_us", self.load_duration.as_micros()),
+            ("interact_us", self.interact_duration.as_micros()),
+            ("missed_frames", u128::from(self.missed_frames)),
+            ("dropped_ticks", u128::from(self.dropped_ticks)),
+            ("texture_bytes", u128::from(self.memory_usage.textures)),
+            ("canvas_bytes", u128::from(self.memory_usage.canvases)),
+            ("buffer_bytes", u128::from(self.memory_usage.buffers)),
+        ]
+    }
+
+    fn buffers(&self) -> [(&'static str, &TimeBuffer); 5] {
+        [
+            ("frame_us", &self.frame_history),
+            ("update_us", &self.update_durations),
+            ("draw_us", &self.draw_durations),
+            ("ui_us", &self.ui_durations),
+            ("debug_us", &self.debug_durations),
+        ]
+    }
+
+    fn to_csv(&self) -> String {
+        let mut csv = String::from("metric,sample,value\n");
+
+        for (name, value) in self.counters().iter() {
+            let _ = writeln!(csv, "{},,{}", name, value);
+        }
+
+        for (name, buffer) in self.buffers().iter() {
+            for (i, duration) in buffer.iter().enumerate() {
+                let _ =
+                    writeln!(csv, "{},{},{}", name, i, duration.as_micros());
+            }
+        }
+
+        csv
+    }
+
+    fn to_json(&self) -> String {
+        let mut json = String::from("{\n");
+
+        for (name, value) in self.counters().iter() {
+            let _ = writeln!(json, "  \"{}\": {},", name, value);
+        }
+
+        let buffers = self.buffers();
+
+        for (i, (name, buffer)) in buffers.iter().enumerate() {
+            let samples: Vec<String> = buffer
+                .iter()
+                .map(|duration| duration.as_micros().to_string())
+                .collect();
+
+            let _ = write!(json, "  \"{}\": [{}]", name, samples.join(", "));
+
+            json.push_str(if i + 1 < buffers.len() { ",\n" } else { "\n" });
+        }
+
+        json.push_str("}\n");
+        json
+    }
+
     /// Draws the [`Debug`] information.
     ///
     /// [`Debug`]: struct.Debug.html
@@ -194,17 +446,28 @@ impl Debug {
         if self.frames_until_refresh <= 0 {
             self.text.clear();
             self.refresh_text();
-            self.frames_until_refresh = self.draw_rate.max(1);
+            self.refresh_panel();
+            self.refresh_graph();
+            self.frames_until_refresh = self.settings.refresh_interval.max(1);
         }
 
-        self.draw_text(frame);
+        let origin = self.origin(frame.width(), frame.height());
+        let mut target = frame.as_target();
+        let mut target =
+            target.transform(graphics::Transformation::translate(origin));
+
+        self.frame_graph.draw(&mut target);
+        self.draw_text(&mut target);
         self.frames_until_refresh -= 1;
     }
 
     const MARGIN: f32 = 20.0;
-    const ROW_HEIGHT: f32 = 25.0;
-    const TITLE_WIDTH: f32 = 150.0;
-    const SHADOW_OFFSET: f32 = 2.0;
+    const GRAPH_FRAMES: usize = 200;
+    const GRAPH_BAR_WIDTH: f32 = 2.0;
+    const GRAPH_HEIGHT: f32 = 100.0;
+
+    // 60 fps are drawn at a third of the height of the graph
+    const GRAPH_PIXELS_PER_MILLI: f32 = Self::GRAPH_HEIGHT / 50.0;
 
     fn refresh_text(&mut self) {
         let frame_duration = self.frame_durations.average();
@@ -231,55 +494,239 @@ impl Debug {
 
             self.text.push((String::from(*title), formatted_duration));
         }
+
+        let low = |percentile| {
+            let duration = self.frame_history.percentile(percentile);
+
+            (1.0 / duration.as_secs_f32().max(0.000_001)).round() as u32
+        };
+
+        let lows = format!("{} fps (1%), {} fps (0.1%)", low(99.0), low(99.9));
+
+        self.text.push((String::from("Lows:"), lows));
+
+        self.text.push((
+            String::from("Missed:"),
+            format!(
+                "{} ({:.0}% recently)",
+                self.missed_frames,
+                self.missed_ratio * 100.0
+            ),
+        ));
+
+        self.text.push((
+            String::from("Dropped:"),
+            format!("{} ticks", self.dropped_ticks),
+        ));
+
+        let memory = self.memory_usage;
+
+        self.text.push((
+            String::from("VRAM:"),
+            format!(
+                "{} ({} tex, {} canvas, {} buf)",
+                format_bytes(memory.total()),
+                format_bytes(memory.textures),
+                format_bytes(memory.canvases),
+                format_bytes(memory.buffers),
+            ),
+        ));
+
+        self.text.push((
+            String::from("UI cache:"),
+            format!(
+                "{} hits, {} misses",
+                self.ui_cache.hits, self.ui_cache.misses
+            ),
+        ));
+
+        self.text.push((
+            String::from("UI draws:"),
+            format!("{} calls", self.ui_draw_calls),
+        ));
+
+        self.text
+            .push((String::from("Speed:"), format!("{}x", self.timer_speed)));
+    }
+
+    fn row_height(&self) -> f32 {
+        self.settings.font_size * 1.25
+    }
+
+    fn title_width(&self) -> f32 {
+        self.settings.font_size * 7.5
     }
 
-    fn draw_text(&mut self, frame: &mut graphics::Frame<'_>) {
+    fn shadow_offset(&self) -> f32 {
+        self.settings.font_size / 10.0
+    }
+
+    fn refresh_panel(&mut self) {
+        let font_size = self.settings.font_size;
+        let font = &mut self.font;
+
+        let values_width = self
+            .text
+            .iter()
+            .map(|(_, value)| {
+                let (width, _) = font.measure(graphics::Text {
+                    content: value,
+                    size: font_size,
+                    ..graphics::Text::default()
+                });
+
+                width
+            })
+            .fold(0.0, f32::max);
+
+        let width = (self.title_width() + values_width)
+            .max(Self::GRAPH_FRAMES as f32 * Self::GRAPH_BAR_WIDTH);
+
+        let height = self.text.len() as f32 * self.row_height()
+            + Self::MARGIN / 2.0
+            + Self::GRAPH_HEIGHT;
+
+        self.panel_size = (width, height);
+    }
+
+    fn origin(&self, frame_width: f32, frame_height: f32) -> graphics::Vector {
+        let (width, height) = self.panel_size;
+
+        let right = (frame_width - Self::MARGIN - width).max(Self::MARGIN);
+        let bottom = (frame_height - Self::MARGIN - height).max(Self::MARGIN);
+
+        let (x, y) = match self.settings.position {
+            DebugPosition::TopLeft => (Self::MARGIN, Self::MARGIN),
+            DebugPosition::TopRight => (right, Self::MARGIN),
+            DebugPosition::BottomLeft => (Self::MARGIN, bottom),
+            DebugPosition::BottomRight => (right, bottom),
+        };
+
+        graphics::Vector::new(x, y)
+    }
+
+    fn refresh_graph(&mut self) {
+        let mut graph = graphics::Mesh::new();
+
+        if self.settings.background_opacity > 0.0 {
+            let (width, height) = self.panel_size;
+
+            graph.fill(
+                graphics::Shape::Rectangle(graphics::Rectangle {
+                    x: -Self::MARGIN / 2.0,
+                    y: -Self::MARGIN / 2.0,
+                    width: width + Self::MARGIN,
+                    height: height + Self::MARGIN,
+                }),
+                graphics::Color {
+                    a: self.settings.background_opacity.min(1.0),
+                    ..self.settings.background_color
+                },
+            );
+        }
+
+        let x = 0.0;
+        let y = self.text.len() as f32 * self.row_height() + Self::MARGIN / 2.0;
+
+        graph.fill(
+            graphics::Shape::Rectangle(graphics::Rectangle {
+                x,
+                y,
+                width: Self::GRAPH_FRAMES as f32 * Self::GRAPH_BAR_WIDTH,
+                height: Self::GRAPH_HEIGHT,
+            }),
+            graphics::Color {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.5,
+            },
+        );
+
+        let durations: Vec<time::Duration> =
+            self.frame_history.iter().collect();
+        let start = durations.len().saturating_sub(Self::GRAPH_FRAMES);
+
+        for (i, duration) in durations[start..].iter().enumerate() {
+            let millis = duration.as_secs_f32() * 1000.0;
+            let height = (millis * Self::GRAPH_PIXELS_PER_MILLI)
+                .min(Self::GRAPH_HEIGHT)
+                .max(1.0);
+
+            let color = if millis <= 17.0 {
+                graphics::Color::from_rgb(0, 200, 83)
+            } else if millis <= 34.0 {
+                graphics::Color::from_rgb(255, 214, 0)
+            } else {
+                graphics::Color::from_rgb(213, 0, 0)
+            };
+
+            graph.fill(
+                graphics::Shape::Rectangle(graphics::Rectangle {
+                    x: x + i as f32 * Self::GRAPH_BAR_WIDTH,
+                    y: y + Self::GRAPH_HEIGHT - height,
+                    width: Self::GRAPH_BAR_WIDTH,
+                    height,
+                }),
+                color,
+            );
+        }
+
+        // 60 fps target line
+        graph.stroke(
+            graphics::Shape::Polyline {
+                points: vec![
+                    graphics::Point::new(
+                        x,
+                        y + Self::GRAPH_HEIGHT
+                            - 16.667 * Self::GRAPH_PIXELS_PER_MILLI,
+                    ),
+                    graphics::Point::new(
+                        x + Self::GRAPH_FRAMES as f32 * Self::GRAPH_BAR_WIDTH,
+                        y + Self::GRAPH_HEIGHT
+                            - 16.667 * Self::GRAPH_PIXELS_PER_MILLI,
+                    ),
+                ],
+            },
+            graphics::Color::WHITE,
+            1.0,
+        );
+
+        self.frame_graph = graph;
+    }
+
+    fn draw_text(&mut self, target: &mut graphics::Target<'_>) {
+        let font_size = self.settings.font_size;
+        let row_height = self.row_height();
+        let title_width = self.title_width();
+        let shadow_offset = self.shadow_offset();
+
         for (row, (key, value)) in self.text.iter().enumerate() {
-            let y = row as f32 * Self::ROW_HEIGHT;
-
-            self.font.add(graphics::Text {
-                content: key,
-                position: graphics::Point::new(
-                    Self::MARGIN + Self::SHADOW_OFFSET,
-                    Self::MARGIN + y + Self::SHADOW_OFFSET,
-                ),
-                size: 20.0,
-                color: graphics::Color::BLACK,
-                ..graphics::Text::default()
-            });
-
-            self.font.add(graphics::Text {
-                content: key,
-                position: graphics::Point::new(Self::MARGIN, Self::MARGIN + y),
-                size: 20.0,
-                color: graphics::Color::WHITE,
-                ..graphics::Text::default()
-            });
-
-            self.font.add(graphics::Text {
-                content: value,
-                position: graphics::Point::new(
-                    Self::MARGIN + Self::TITLE_WIDTH + Self::SHADOW_OFFSET,
-                    Self::MARGIN + y + Self::SHADOW_OFFSET,
-                ),
-                size: 20.0,
-                color: graphics::Color::BLACK,
-                ..graphics::Text::default()
-            });
-
-            self.font.add(graphics::Text {
-                content: value,
-                position: graphics::Point::new(
-                    Self::MARGIN + Self::TITLE_WIDTH,
-                    Self::MARGIN + y,
-                ),
-                size: 20.0,
-                color: graphics::Color::WHITE,
-                ..graphics::Text::default()
-            });
+            let y = row as f32 * row_height;
+
+            for (content, x) in [(key, 0.0), (value, title_width)].iter() {
+                self.font.add(graphics::Text {
+                    content,
+                    position: graphics::Point::new(
+                        x + shadow_offset,
+                        y + shadow_offset,
+                    ),
+                    size: font_size,
+                    color: self.settings.shadow_color,
+                    ..graphics::Text::default()
+                });
+
+                self.font.add(graphics::Text {
+                    content,
+                    position: graphics::Point::new(*x, y),
+                    size: font_size,
+                    color: self.settings.text_color,
+                    ..graphics::Text::default()
+                });
+            }
         }
 
-        self.font.draw(&mut frame.as_target());
+        self.font.draw(target);
     }
 }
 
@@ -305,6 +752,19 @@ fn format_duration(duration: &time::Duration) -> String {
     }
 }
 
+fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+
+    if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
 impl std::fmt::Debug for Debug {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -349,4 +809,26 @@ impl TimeBuffer {
 
         sum / self.size.max(1) as u32
     }
+
+    // Iterates over the stored durations from oldest to newest
+    fn iter(&self) -> impl Iterator<Item = time::Duration> + '_ {
+        let capacity = self.contents.len();
+        let oldest = (self.head + capacity + 1 - self.size) % capacity;
+
+        (0..self.size).map(move |i| self.contents[(oldest + i) % capacity])
+    }
+
+    fn percentile(&self, percentile: f32) -> time::Duration {
+        let mut durations: Vec<time::Duration> = self.iter().collect();
+
+        if durations.is_empty() {
+            return time::Duration::from_secs(0);
+        }
+
+        durations.sort();
+
+        let rank = (percentile / 100.0 * (durations.len() - 1) as f32).round();
+
+        durations[(rank as usize).min(durations.len() - 1)]
+    }
 }
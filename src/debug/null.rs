@@ -1,19 +1,42 @@
+use super::DebugSettings;
 use crate::graphics;
+use crate::ui::core::CacheStatistics;
+use crate::Result;
+
+use std::path::Path;
+use std::time::Duration;
 
 // Null debug implementation
 #[allow(missing_debug_implementations)]
 #[allow(missing_docs)]
-pub struct Debug {}
+pub struct Debug {
+    settings: DebugSettings,
+}
 
 impl Debug {
-    pub(crate) fn new(_gpu: &mut graphics::Gpu) -> Self {
-        Self {}
+    pub(crate) fn new(
+        _gpu: &mut graphics::Gpu,
+        settings: DebugSettings,
+    ) -> Self {
+        Self { settings }
+    }
+
+    pub(crate) fn reset(&mut self, _gpu: &mut graphics::Gpu) -> Result<()> {
+        Ok(())
+    }
+
+    pub(crate) fn report(&self) -> Option<String> {
+        None
     }
 
     pub(crate) fn loading_started(&mut self) {}
     pub(crate) fn loading_finished(&mut self) {}
     pub(crate) fn frame_started(&mut self) {}
     pub(crate) fn frame_finished(&mut self) {}
+    pub(crate) fn frames_missed(&mut self, _total: u64, _recent_ratio: f32) {}
+    pub(crate) fn ticks_dropped(&mut self, _dropped: u64) {}
+    pub(crate) fn memory_used(&mut self, _usage: graphics::MemoryUsage) {}
+    pub(crate) fn timer_speed(&mut self, _speed: f32) {}
     pub(crate) fn interact_started(&mut self) {}
     pub(crate) fn interact_finished(&mut self) {}
     pub(crate) fn update_started(&mut self) {}
@@ -22,6 +45,8 @@ impl Debug {
     pub(crate) fn draw_finished(&mut self) {}
     pub(crate) fn ui_started(&mut self) {}
     pub(crate) fn ui_finished(&mut self) {}
+    pub(crate) fn ui_cached(&mut self, _statistics: CacheStatistics) {}
+    pub(crate) fn ui_drawn(&mut self, _draw_calls: u32) {}
     pub(crate) fn debug_started(&mut self) {}
     pub(crate) fn debug_finished(&mut self) {}
 
@@ -32,6 +57,31 @@ impl Debug {
         false
     }
 
+    #[allow(missing_docs)]
+    pub fn settings(&self) -> &DebugSettings {
+        &self.settings
+    }
+
+    #[allow(missing_docs)]
+    pub fn settings_mut(&mut self) -> &mut DebugSettings {
+        &mut self.settings
+    }
+
+    #[allow(missing_docs)]
+    pub fn frame_duration_percentile(&self, _percentile: f32) -> Duration {
+        Duration::from_secs(0)
+    }
+
+    #[allow(missing_docs)]
+    pub fn memory_usage(&self) -> graphics::MemoryUsage {
+        graphics::MemoryUsage::default()
+    }
+
+    #[allow(missing_docs)]
+    pub fn export<P: AsRef<Path>>(&self, _path: P) -> Result<()> {
+        Ok(())
+    }
+
     #[allow(missing_docs)]
     pub fn draw(&mut self, _frame: &mut graphics::Frame<'_>) {}
 }
@@ -0,0 +1,76 @@
+use crate::graphics::Color;
+
+/// The appearance and behavior of the built-in [`Debug`] view.
+///
+/// You can choose the initial settings with [`Game::DEBUG_SETTINGS`] and
+/// change them at runtime using [`Debug::settings_mut`].
+///
+/// [`Debug`]: struct.Debug.html
+/// [`Game::DEBUG_SETTINGS`]: trait.Game.html#associatedconstant.DEBUG_SETTINGS
+/// [`Debug::settings_mut`]: struct.Debug.html#method.settings_mut
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugSettings {
+    /// The corner of the screen where the debug view is shown.
+    pub position: DebugPosition,
+
+    /// The size of the debug text.
+    pub font_size: f32,
+
+    /// The color of the debug text.
+    pub text_color: Color,
+
+    /// The color of the shadow drawn behind the debug text.
+    pub shadow_color: Color,
+
+    /// The color of the panel behind the debug view.
+    pub background_color: Color,
+
+    /// The opacity of the panel behind the debug view, in the `[0.0, 1.0]`
+    /// interval.
+    pub background_opacity: f32,
+
+    /// The amount of frames between refreshes of the debug view.
+    pub refresh_interval: u16,
+}
+
+impl DebugSettings {
+    /// The default [`DebugSettings`].
+    ///
+    /// The debug view is shown in the top left corner, using white text with
+    /// a black shadow and no background. It refreshes every 10 frames.
+    ///
+    /// [`DebugSettings`]: struct.DebugSettings.html
+    pub const DEFAULT: DebugSettings = DebugSettings {
+        position: DebugPosition::TopLeft,
+        font_size: 20.0,
+        text_color: Color::WHITE,
+        shadow_color: Color::BLACK,
+        background_color: Color::BLACK,
+        background_opacity: 0.0,
+        refresh_interval: 10,
+    };
+}
+
+impl Default for DebugSettings {
+    fn default() -> DebugSettings {
+        DebugSettings::DEFAULT
+    }
+}
+
+/// The corner of the screen where the [`Debug`] view is shown.
+///
+/// [`Debug`]: struct.Debug.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugPosition {
+    /// The top left corner
+    TopLeft,
+
+    /// The top right corner
+    TopRight,
+
+    /// The bottom left corner
+    BottomLeft,
+
+    /// The bottom right corner
+    BottomRight,
+}
@@ -0,0 +1,75 @@
+//! Show native dialogs to your users.
+//!
+//! Dialogs are useful to prompt for file paths in editors and to show fatal
+//! errors to players without a console:
+//!
+//! ```no_run
+//! use coffee::dialog;
+//!
+//! if let Some(path) = dialog::open_file("Open level", &["*.ron"]) {
+//!     println!("Opening {}", path.display());
+//! }
+//!
+//! if dialog::confirm("Quit", "Your progress will be lost. Are you sure?") {
+//!     // ...
+//! }
+//! ```
+//!
+//! Every dialog blocks the current thread until it is closed, so the game
+//! stops drawing and updating while it is open.
+//!
+//! This module is only available when the `dialogs` feature is enabled.
+use std::path::PathBuf;
+
+use tinyfiledialogs::{MessageBoxIcon, YesNo};
+
+/// Shows a message with an error icon and waits until it is dismissed.
+pub fn alert(title: &str, message: &str) {
+    tinyfiledialogs::message_box_ok(
+        &sanitize(title),
+        &sanitize(message),
+        MessageBoxIcon::Error,
+    );
+}
+
+/// Asks a yes or no question and returns whether the answer was yes.
+pub fn confirm(title: &str, message: &str) -> bool {
+    let answer = tinyfiledialogs::message_box_yes_no(
+        &sanitize(title),
+        &sanitize(message),
+        MessageBoxIcon::Question,
+        YesNo::No,
+    );
+
+    answer == YesNo::Yes
+}
+
+/// Asks for an existing file and returns its path, unless the dialog is
+/// cancelled.
+///
+/// Only the files matching one of the given patterns, like `"*.png"`, can be
+/// chosen. Any file can be chosen when no patterns are given.
+pub fn open_file(title: &str, patterns: &[&str]) -> Option<PathBuf> {
+    let description = patterns.join(", ");
+
+    let filter = if patterns.is_empty() {
+        None
+    } else {
+        Some((patterns, description.as_str()))
+    };
+
+    tinyfiledialogs::open_file_dialog(&sanitize(title), "", filter)
+        .map(PathBuf::from)
+}
+
+/// Asks for the path where a file should be saved, starting at the given
+/// default path, and returns it unless the dialog is cancelled.
+pub fn save_file(title: &str, default_path: &str) -> Option<PathBuf> {
+    tinyfiledialogs::save_file_dialog(&sanitize(title), default_path)
+        .map(PathBuf::from)
+}
+
+// tinyfiledialogs does not support quotes in messages
+fn sanitize(text: &str) -> String {
+    text.replace(&['"', '\''][..], "`")
+}
@@ -0,0 +1,220 @@
+//! Build development tools quickly using [`egui`].
+//!
+//! An [`Egui`] context is fed with the input events of your game and drawn on
+//! top of a [`Frame`]. It is great to tweak values or inspect the state of
+//! your game while developing it, while the [`ui`] module remains the best
+//! choice for the user interface that ships with your game.
+//!
+//! The [`egui::Input`] wrapper keeps the events received during an
+//! interaction, so you can feed them to the [`Egui`] context:
+//!
+//! ```no_run
+//! use coffee::egui::{self, Egui};
+//! use coffee::graphics::{Frame, Window};
+//! use coffee::input::KeyboardAndMouse;
+//! use coffee::load::Task;
+//! use coffee::{Game, Timer};
+//!
+//! struct MyGame {
+//!     egui: Egui,
+//!     speed: f32,
+//! }
+//!
+//! impl Game for MyGame {
+//!     type Input = egui::Input<KeyboardAndMouse>;
+//!     type LoadingScreen = ();
+//!
+//!     fn load(_window: &Window) -> Task<MyGame> {
+//!         Task::succeed(|| MyGame { egui: Egui::new(), speed: 1.0 })
+//!     }
+//!
+//!     fn interact(&mut self, input: &mut Self::Input, _window: &mut Window) {
+//!         self.egui.handle_all(input.events());
+//!     }
+//!
+//!     fn draw(&mut self, frame: &mut Frame<'_>, _timer: &Timer) {
+//!         let speed = &mut self.speed;
+//!
+//!         self.egui.run(frame, |context| {
+//!             ::egui::Window::new("Tweaks").show(context, |ui| {
+//!                 let _ = ui.add(::egui::Slider::f32(speed, 0.0..=10.0));
+//!             });
+//!         });
+//!     }
+//! }
+//! ```
+//!
+//! The [`egui`] output is rasterized on the CPU and uploaded as an image every
+//! frame. This keeps it independent of the graphics backend, but it is not
+//! meant to be used in release builds.
+//!
+//! This module is only available when the `egui` feature is enabled.
+//!
+//! [`egui`]: https://github.com/emilk/egui
+//! [`Egui`]: struct.Egui.html
+//! [`Frame`]: ../graphics/struct.Frame.html
+//! [`ui`]: ../ui/index.html
+//! [`egui::Input`]: struct.Input.html
+mod event;
+mod input;
+mod rasterizer;
+
+pub use input::Input;
+
+use crate::graphics::{Frame, Image, Point, Quad};
+use crate::input::Event;
+use rasterizer::Rasterizer;
+
+use instant::Instant;
+
+/// An [`egui`] context that can be drawn on a [`Frame`].
+///
+/// [`egui`]: https://github.com/emilk/egui
+/// [`Frame`]: ../graphics/struct.Frame.html
+pub struct Egui {
+    context: ::egui::CtxRef,
+    input: ::egui::RawInput,
+    modifiers: ::egui::Modifiers,
+    cursor: ::egui::Pos2,
+    pixels_per_point: f32,
+    started_at: Instant,
+    rasterizer: Rasterizer,
+}
+
+impl Egui {
+    /// Creates a new [`Egui`] context.
+    ///
+    /// [`Egui`]: struct.Egui.html
+    pub fn new() -> Egui {
+        Egui {
+            context: ::egui::CtxRef::default(),
+            input: ::egui::RawInput::default(),
+            modifiers: ::egui::Modifiers::default(),
+            cursor: ::egui::Pos2::new(0.0, 0.0),
+            pixels_per_point: 1.0,
+            started_at: Instant::now(),
+            rasterizer: Rasterizer::new(),
+        }
+    }
+
+    /// Returns the underlying [`egui`] context.
+    ///
+    /// [`egui`]: https://github.com/emilk/egui
+    pub fn context(&self) -> &::egui::CtxRef {
+        &self.context
+    }
+
+    /// Sets the amount of physical pixels of a logical point.
+    ///
+    /// You can use [`Window::scale_factor`] to make the tools readable on
+    /// high density displays. By default, it is set to `1.0`.
+    ///
+    /// [`Window::scale_factor`]: ../graphics/struct.Window.html#method.scale_factor
+    pub fn set_pixels_per_point(&mut self, pixels_per_point: f32) {
+        self.pixels_per_point = pixels_per_point.max(0.1);
+    }
+
+    /// Returns true if [`egui`] is using the mouse, which means your game
+    /// should probably ignore it.
+    ///
+    /// [`egui`]: https://github.com/emilk/egui
+    pub fn wants_mouse(&self) -> bool {
+        self.context.wants_pointer_input()
+    }
+
+    /// Returns true if [`egui`] is using the keyboard, which means your game
+    /// should probably ignore it.
+    ///
+    /// [`egui`]: https://github.com/emilk/egui
+    pub fn wants_keyboard(&self) -> bool {
+        self.context.wants_keyboard_input()
+    }
+
+    /// Feeds an input event to the [`Egui`] context.
+    ///
+    /// [`Egui`]: struct.Egui.html
+    pub fn handle(&mut self, event: Event) {
+        event::convert(
+            event,
+            self.pixels_per_point,
+            &mut self.cursor,
+            &mut self.modifiers,
+            &mut self.input,
+        );
+    }
+
+    /// Feeds all the given input events to the [`Egui`] context.
+    ///
+    /// [`Egui`]: struct.Egui.html
+    pub fn handle_all(&mut self, events: &[Event]) {
+        for event in events {
+            self.handle(*event);
+        }
+    }
+
+    /// Builds the tools using the given function and draws them on top of
+    /// the given [`Frame`].
+    ///
+    /// [`Frame`]: ../graphics/struct.Frame.html
+    pub fn run<F>(&mut self, frame: &mut Frame<'_>, f: F)
+    where
+        F: FnOnce(&::egui::CtxRef),
+    {
+        let scale = self.pixels_per_point;
+
+        let mut input = std::mem::take(&mut self.input);
+        input.screen_rect = Some(::egui::Rect::from_min_size(
+            ::egui::Pos2::new(0.0, 0.0),
+            ::egui::Vec2::new(frame.width() / scale, frame.height() / scale),
+        ));
+        input.pixels_per_point = Some(scale);
+        input.time = Some(self.started_at.elapsed().as_secs_f64());
+        input.modifiers = self.modifiers;
+
+        self.context.begin_frame(input);
+
+        f(&self.context);
+
+        let (_output, shapes) = self.context.end_frame();
+        let meshes = self.context.tessellate(shapes);
+        let texture = self.context.texture();
+
+        self.rasterizer
+            .resize(frame.width() as usize, frame.height() as usize);
+
+        for ::egui::ClippedMesh(clip, mesh) in &meshes {
+            self.rasterizer.draw(clip, mesh, &texture, scale);
+        }
+
+        if let Some((x, y, pixels)) = self.rasterizer.take() {
+            let (width, height) = pixels.dimensions();
+
+            match Image::from_image(
+                frame.gpu(),
+                &image::DynamicImage::ImageRgba8(pixels),
+            ) {
+                Ok(image) => image.draw(
+                    Quad {
+                        position: Point::new(x as f32, y as f32),
+                        size: (width as f32, height as f32),
+                        ..Quad::default()
+                    },
+                    &mut frame.as_target(),
+                ),
+                Err(error) => log::warn!("Failed to draw egui: {}", error),
+            }
+        }
+    }
+}
+
+impl Default for Egui {
+    fn default() -> Egui {
+        Egui::new()
+    }
+}
+
+impl std::fmt::Debug for Egui {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Egui {{ pixels_per_point: {} }}", self.pixels_per_point)
+    }
+}
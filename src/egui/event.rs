@@ -0,0 +1,131 @@
+use crate::input::keyboard::{self, KeyCode};
+use crate::input::{mouse, touch, ButtonState, Event};
+
+use egui::{Modifiers, Pos2, RawInput};
+
+// The amount of points scrolled per wheel line
+const LINE_HEIGHT: f32 = 24.0;
+
+pub(super) fn convert(
+    event: Event,
+    pixels_per_point: f32,
+    cursor: &mut Pos2,
+    modifiers: &mut Modifiers,
+    input: &mut RawInput,
+) {
+    let position =
+        |x: f32, y: f32| Pos2::new(x / pixels_per_point, y / pixels_per_point);
+
+    match event {
+        Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+            *cursor = position(x, y);
+
+            input.events.push(egui::Event::PointerMoved(*cursor));
+        }
+        Event::Mouse(mouse::Event::CursorLeft) => {
+            input.events.push(egui::Event::PointerGone);
+        }
+        Event::Mouse(mouse::Event::Input { state, button }) => {
+            let button = match button {
+                mouse::Button::Left => egui::PointerButton::Primary,
+                mouse::Button::Right => egui::PointerButton::Secondary,
+                mouse::Button::Middle => egui::PointerButton::Middle,
+                mouse::Button::Other(_) => return,
+            };
+
+            input.events.push(egui::Event::PointerButton {
+                pos: *cursor,
+                button,
+                pressed: state == ButtonState::Pressed,
+                modifiers: *modifiers,
+            });
+        }
+        Event::Mouse(mouse::Event::WheelScrolled { delta_x, delta_y }) => {
+            input.scroll_delta +=
+                egui::Vec2::new(delta_x, delta_y) * LINE_HEIGHT;
+        }
+        Event::Touch(touch::Event::Started { x, y, .. }) => {
+            *cursor = position(x, y);
+
+            input.events.push(egui::Event::PointerMoved(*cursor));
+            input.events.push(egui::Event::PointerButton {
+                pos: *cursor,
+                button: egui::PointerButton::Primary,
+                pressed: true,
+                modifiers: *modifiers,
+            });
+        }
+        Event::Touch(touch::Event::Moved { x, y, .. }) => {
+            *cursor = position(x, y);
+
+            input.events.push(egui::Event::PointerMoved(*cursor));
+        }
+        Event::Touch(touch::Event::Ended { x, y, .. }) => {
+            *cursor = position(x, y);
+
+            input.events.push(egui::Event::PointerButton {
+                pos: *cursor,
+                button: egui::PointerButton::Primary,
+                pressed: false,
+                modifiers: *modifiers,
+            });
+            input.events.push(egui::Event::PointerGone);
+        }
+        Event::Touch(touch::Event::Cancelled { .. }) => {
+            input.events.push(egui::Event::PointerGone);
+        }
+        Event::Keyboard(keyboard::Event::TextEntered { character }) => {
+            if !character.is_control() && !modifiers.ctrl {
+                input.events.push(egui::Event::Text(character.to_string()));
+            }
+        }
+        Event::Keyboard(keyboard::Event::Input { state, key_code }) => {
+            let pressed = state == ButtonState::Pressed;
+
+            match key_code {
+                KeyCode::LShift | KeyCode::RShift => modifiers.shift = pressed,
+                KeyCode::LControl | KeyCode::RControl => {
+                    modifiers.ctrl = pressed;
+                    modifiers.command = pressed;
+                }
+                KeyCode::LAlt | KeyCode::RAlt => modifiers.alt = pressed,
+                _ => {}
+            }
+
+            if let Some(key) = key(key_code) {
+                input.events.push(egui::Event::Key {
+                    key,
+                    pressed,
+                    modifiers: *modifiers,
+                });
+            }
+        }
+        _ => {}
+    }
+}
+
+fn key(key_code: KeyCode) -> Option<egui::Key> {
+    Some(match key_code {
+        KeyCode::Down => egui::Key::ArrowDown,
+        KeyCode::Left => egui::Key::ArrowLeft,
+        KeyCode::Right => egui::Key::ArrowRight,
+        KeyCode::Up => egui::Key::ArrowUp,
+        KeyCode::Escape => egui::Key::Escape,
+        KeyCode::Tab => egui::Key::Tab,
+        KeyCode::Back => egui::Key::Backspace,
+        KeyCode::Return => egui::Key::Enter,
+        KeyCode::Space => egui::Key::Space,
+        KeyCode::Insert => egui::Key::Insert,
+        KeyCode::Delete => egui::Key::Delete,
+        KeyCode::Home => egui::Key::Home,
+        KeyCode::End => egui::Key::End,
+        KeyCode::PageUp => egui::Key::PageUp,
+        KeyCode::PageDown => egui::Key::PageDown,
+        KeyCode::A => egui::Key::A,
+        KeyCode::C => egui::Key::C,
+        KeyCode::V => egui::Key::V,
+        KeyCode::X => egui::Key::X,
+        KeyCode::Z => egui::Key::Z,
+        _ => return None,
+    })
+}
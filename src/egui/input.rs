@@ -0,0 +1,52 @@
+use crate::input::{self, Event};
+
+/// An [`Input`] wrapper that keeps the events of the last interaction, so
+/// they can be fed to an [`Egui`] context.
+///
+/// [`Input`]: ../input/trait.Input.html
+/// [`Egui`]: struct.Egui.html
+#[derive(Debug, Clone)]
+pub struct Input<I> {
+    inner: I,
+    events: Vec<Event>,
+}
+
+impl<I> Input<I> {
+    /// Returns the wrapped [`Input`].
+    ///
+    /// [`Input`]: ../input/trait.Input.html
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped [`Input`].
+    ///
+    /// [`Input`]: ../input/trait.Input.html
+    pub fn inner_mut(&mut self) -> &mut I {
+        &mut self.inner
+    }
+
+    /// Returns the events received since the last interaction.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+}
+
+impl<I: input::Input> input::Input for Input<I> {
+    fn new() -> Input<I> {
+        Input {
+            inner: I::new(),
+            events: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, event: Event) {
+        self.events.push(event);
+        self.inner.update(event);
+    }
+
+    fn clear(&mut self) {
+        self.events.clear();
+        self.inner.clear();
+    }
+}
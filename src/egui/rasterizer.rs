@@ -0,0 +1,226 @@
+use egui::{Mesh, Pos2, Rect, Texture, Vertex};
+
+// Draws egui meshes on the CPU, keeping track of the region that was touched
+pub(super) struct Rasterizer {
+    width: usize,
+    height: usize,
+    pixels: Vec<[f32; 4]>,
+    bounds: Option<Bounds>,
+}
+
+#[derive(Clone, Copy)]
+struct Bounds {
+    left: usize,
+    top: usize,
+    right: usize,
+    bottom: usize,
+}
+
+impl Rasterizer {
+    pub fn new() -> Rasterizer {
+        Rasterizer {
+            width: 0,
+            height: 0,
+            pixels: Vec::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn resize(&mut self, width: usize, height: usize) {
+        if self.width != width || self.height != height {
+            self.width = width;
+            self.height = height;
+            self.pixels = vec![[0.0; 4]; width * height];
+            self.bounds = None;
+        }
+    }
+
+    pub fn draw(
+        &mut self,
+        clip: &Rect,
+        mesh: &Mesh,
+        texture: &Texture,
+        pixels_per_point: f32,
+    ) {
+        let clip = Bounds {
+            left: (clip.min.x * pixels_per_point).max(0.0) as usize,
+            top: (clip.min.y * pixels_per_point).max(0.0) as usize,
+            right: ((clip.max.x * pixels_per_point).ceil() as usize)
+                .min(self.width),
+            bottom: ((clip.max.y * pixels_per_point).ceil() as usize)
+                .min(self.height),
+        };
+
+        for triangle in mesh.indices.chunks_exact(3) {
+            let vertex = |i: usize| &mesh.vertices[triangle[i] as usize];
+
+            self.triangle(
+                [vertex(0), vertex(1), vertex(2)],
+                clip,
+                texture,
+                pixels_per_point,
+            );
+        }
+    }
+
+    // Returns the touched region as an image, clearing it
+    pub fn take(&mut self) -> Option<(usize, usize, image::RgbaImage)> {
+        let bounds = self.bounds.take()?;
+
+        let width = bounds.right - bounds.left;
+        let height = bounds.bottom - bounds.top;
+
+        let mut image = image::RgbaImage::new(width as u32, height as u32);
+
+        for y in 0..height {
+            for x in 0..width {
+                let pixel = &mut self.pixels
+                    [(bounds.top + y) * self.width + bounds.left + x];
+
+                let [r, g, b, a] = *pixel;
+                *pixel = [0.0; 4];
+
+                if a > 0.0 {
+                    // Images use straight alpha
+                    let channel = |c: f32| (c / a * 255.0).min(255.0) as u8;
+
+                    image.put_pixel(
+                        x as u32,
+                        y as u32,
+                        image::Rgba([
+                            channel(r),
+                            channel(g),
+                            channel(b),
+                            (a * 255.0) as u8,
+                        ]),
+                    );
+                }
+            }
+        }
+
+        Some((bounds.left, bounds.top, image))
+    }
+
+    fn triangle(
+        &mut self,
+        vertices: [&Vertex; 3],
+        clip: Bounds,
+        texture: &Texture,
+        pixels_per_point: f32,
+    ) {
+        let position = |vertex: &Vertex| {
+            Pos2::new(
+                vertex.pos.x * pixels_per_point,
+                vertex.pos.y * pixels_per_point,
+            )
+        };
+
+        let [a, b, c] = [
+            position(vertices[0]),
+            position(vertices[1]),
+            position(vertices[2]),
+        ];
+
+        let area = edge(a, b, c);
+
+        if area.abs() < std::f32::EPSILON {
+            return;
+        }
+
+        let bounds = Bounds {
+            left: (a.x.min(b.x).min(c.x).max(0.0) as usize).max(clip.left),
+            top: (a.y.min(b.y).min(c.y).max(0.0) as usize).max(clip.top),
+            right: (a.x.max(b.x).max(c.x).ceil().max(0.0) as usize)
+                .min(clip.right),
+            bottom: (a.y.max(b.y).max(c.y).ceil().max(0.0) as usize)
+                .min(clip.bottom),
+        };
+
+        if bounds.left >= bounds.right || bounds.top >= bounds.bottom {
+            return;
+        }
+
+        let colors =
+            [color(vertices[0]), color(vertices[1]), color(vertices[2])];
+
+        let mut is_drawn = false;
+
+        for y in bounds.top..bounds.bottom {
+            for x in bounds.left..bounds.right {
+                let p = Pos2::new(x as f32 + 0.5, y as f32 + 0.5);
+
+                let w0 = edge(b, c, p) / area;
+                let w1 = edge(c, a, p) / area;
+                let w2 = 1.0 - w0 - w1;
+
+                // Allow a small overlap to avoid gaps between triangles
+                if w0 < -0.001 || w1 < -0.001 || w2 < -0.001 {
+                    continue;
+                }
+
+                let uv = vertices[0].uv.to_vec2() * w0
+                    + vertices[1].uv.to_vec2() * w1
+                    + vertices[2].uv.to_vec2() * w2;
+
+                let coverage = sample(texture, uv.x, uv.y);
+
+                let pixel = &mut self.pixels[y * self.width + x];
+                let mut source = [0.0; 4];
+
+                for (i, channel) in source.iter_mut().enumerate() {
+                    *channel = (colors[0][i] * w0
+                        + colors[1][i] * w1
+                        + colors[2][i] * w2)
+                        * coverage;
+                }
+
+                // Colors are premultiplied
+                let alpha = source[3];
+
+                for (target, source) in pixel.iter_mut().zip(&source) {
+                    *target = source + *target * (1.0 - alpha);
+                }
+
+                is_drawn = true;
+            }
+        }
+
+        if is_drawn {
+            self.bounds = Some(match self.bounds {
+                Some(current) => Bounds {
+                    left: current.left.min(bounds.left),
+                    top: current.top.min(bounds.top),
+                    right: current.right.max(bounds.right),
+                    bottom: current.bottom.max(bounds.bottom),
+                },
+                None => bounds,
+            });
+        }
+    }
+}
+
+fn edge(a: Pos2, b: Pos2, p: Pos2) -> f32 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+fn color(vertex: &Vertex) -> [f32; 4] {
+    let color = vertex.color;
+
+    [
+        f32::from(color.r()) / 255.0,
+        f32::from(color.g()) / 255.0,
+        f32::from(color.b()) / 255.0,
+        f32::from(color.a()) / 255.0,
+    ]
+}
+
+fn sample(texture: &Texture, u: f32, v: f32) -> f32 {
+    if texture.width == 0 || texture.height == 0 {
+        return 1.0;
+    }
+
+    let x = ((u * texture.width as f32) as usize).min(texture.width - 1);
+    let y = ((v * texture.height as f32) as usize).min(texture.height - 1);
+
+    f32::from(texture.pixels[y * texture.width + x]) / 255.0
+}
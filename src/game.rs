@@ -1,11 +1,26 @@
+mod background;
+mod catch_up;
+mod crash;
+mod headless;
 mod r#loop;
+mod pacing;
+mod runtime;
+mod timestep;
 
+pub use background::Background;
+pub use catch_up::CatchUp;
+pub use crash::CrashHandler;
+pub use headless::Headless;
 pub(crate) use r#loop::Loop;
+pub use runtime::Runtime;
+pub use timestep::Timestep;
 
 use crate::graphics::{CursorIcon, Frame, Window, WindowSettings};
-use crate::input::{keyboard, Input};
+use crate::input::{keyboard, Input, Recording};
 use crate::load::{LoadingScreen, Task};
-use crate::{Debug, Result, Timer};
+use crate::{Debug, DebugSettings, Error, Result, Timer};
+
+use std::time::Duration;
 
 /// The entrypoint of the engine. It describes your game logic.
 ///
@@ -51,6 +66,70 @@ pub trait Game {
     /// [`update`]: #method.update
     const TICKS_PER_SECOND: u16 = 60;
 
+    /// Defines the [`Timestep`] of the game.
+    ///
+    /// By default, it is set to [`Timestep::Fixed`].
+    ///
+    /// [`Timestep`]: enum.Timestep.html
+    /// [`Timestep::Fixed`]: enum.Timestep.html#variant.Fixed
+    const TIMESTEP: Timestep = Timestep::Fixed;
+
+    /// Defines the [`CatchUp`] policy of the game, which is applied when its
+    /// updates fall behind.
+    ///
+    /// By default, it is set to [`CatchUp::DEFAULT`], which runs at most one
+    /// tick per frame.
+    ///
+    /// [`CatchUp`]: struct.CatchUp.html
+    /// [`CatchUp::DEFAULT`]: struct.CatchUp.html#associatedconstant.DEFAULT
+    const CATCH_UP: CatchUp = CatchUp::DEFAULT;
+
+    /// Defines the maximum amount of frames that will be drawn per second.
+    ///
+    /// The game loop will sleep between frames to stay under this limit. This
+    /// is independent of vertical synchronization, which may cap the frame
+    /// rate further.
+    ///
+    /// By default, it is set to `None`, which means there is no limit.
+    const MAX_FRAME_RATE: Option<u16> = None;
+
+    /// Defines the [`Background`] behavior of the game, which is applied while
+    /// its window is not focused.
+    ///
+    /// By default, it is set to [`Background::Continue`].
+    ///
+    /// [`Background`]: enum.Background.html
+    /// [`Background::Continue`]: enum.Background.html#variant.Continue
+    const BACKGROUND_BEHAVIOR: Background = Background::Continue;
+
+    /// Defines the behavior of the audio of the game while its window is not
+    /// focused or is minimized.
+    ///
+    /// It is only available when the `audio` feature is enabled.
+    ///
+    /// By default, it is set to [`audio::Background::Continue`].
+    ///
+    /// [`audio::Background::Continue`]: audio/enum.Background.html#variant.Continue
+    #[cfg(feature = "audio")]
+    const BACKGROUND_AUDIO: crate::audio::Background =
+        crate::audio::Background::Continue;
+
+    /// Defines whether vertical synchronization should be disabled when frames
+    /// consistently miss the refresh interval of the monitor.
+    ///
+    /// Missing the refresh interval with vertical synchronization enabled
+    /// halves the frame rate, which causes noticeable stutter. Disabling it
+    /// trades this stutter for some tearing.
+    ///
+    /// This is not supported by the OpenGL backend, where vertical
+    /// synchronization cannot be changed once the window is created. In any
+    /// case, you can check the missed frames in the [`Debug`] view.
+    ///
+    /// By default, it is set to `false`.
+    ///
+    /// [`Debug`]: struct.Debug.html
+    const VSYNC_FALLBACK: bool = false;
+
     /// Defines the key that will be used to toggle the [`debug`] view. Set it to
     /// `None` if you want to disable it.
     ///
@@ -59,6 +138,98 @@ pub trait Game {
     /// [`debug`]: #method.debug
     const DEBUG_KEY: Option<keyboard::KeyCode> = Some(keyboard::KeyCode::F12);
 
+    /// Defines the initial [`DebugSettings`] of the built-in [`debug`] view,
+    /// like its position, font size, and colors.
+    ///
+    /// You can change them at runtime with [`Debug::settings_mut`].
+    ///
+    /// By default, it is set to [`DebugSettings::DEFAULT`].
+    ///
+    /// [`DebugSettings`]: struct.DebugSettings.html
+    /// [`debug`]: #method.debug
+    /// [`Debug::settings_mut`]: struct.Debug.html#method.settings_mut
+    /// [`DebugSettings::DEFAULT`]: struct.DebugSettings.html#associatedconstant.DEFAULT
+    const DEBUG_SETTINGS: DebugSettings = DebugSettings::DEFAULT;
+
+    /// Defines the [`CrashHandler`] of the game.
+    ///
+    /// When set, panics in the game loop write a crash report to disk before
+    /// the game exits.
+    ///
+    /// By default, it is set to `None`.
+    ///
+    /// [`CrashHandler`]: struct.CrashHandler.html
+    const CRASH_HANDLER: Option<CrashHandler> = None;
+
+    /// Defines the keys that will be used to save and load a checkpoint of the
+    /// game state, respectively. Set it to `None` if you want to disable them.
+    ///
+    /// Saving a checkpoint calls [`save_state`] and loading it calls
+    /// [`load_state`]. Only one checkpoint is kept at a time.
+    ///
+    /// Like the [`debug`] view, checkpoint keys are only available when
+    /// compiling with `debug_assertions` _or_ the `debug` feature enabled.
+    ///
+    /// By default, it is set to `F5` and `F9`.
+    ///
+    /// [`save_state`]: #method.save_state
+    /// [`load_state`]: #method.load_state
+    /// [`debug`]: #method.debug
+    const CHECKPOINT_KEYS: Option<(keyboard::KeyCode, keyboard::KeyCode)> =
+        Some((keyboard::KeyCode::F5, keyboard::KeyCode::F9));
+
+    /// Defines the keys that will be used to slow down and speed up the
+    /// [`Timer`], respectively. Set it to `None` if you want to disable them.
+    ///
+    /// The keys step through `0x`, `0.25x`, `0.5x`, `1x`, `2x`, and `4x`, which
+    /// makes animation and physics bugs easier to observe. They only work
+    /// while the [`debug`] view is shown, which also displays the current
+    /// speed.
+    ///
+    /// By default, it is set to `F7` and `F8`.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`debug`]: #method.debug
+    const TIME_SCALE_KEYS: Option<(keyboard::KeyCode, keyboard::KeyCode)> =
+        Some((keyboard::KeyCode::F7, keyboard::KeyCode::F8));
+
+    /// Defines the key that will be used to take a screenshot of the game.
+    /// Set it to `None` if you want to disable it.
+    ///
+    /// Screenshots are saved as timestamped PNG images in the
+    /// [`SCREENSHOT_DIRECTORY`] using [`Window::save_screenshot`]. Like the
+    /// checkpoint keys, it is only available when compiling with
+    /// `debug_assertions` _or_ the `debug` feature enabled.
+    ///
+    /// By default, it is set to `F11`.
+    ///
+    /// [`SCREENSHOT_DIRECTORY`]: #associatedconstant.SCREENSHOT_DIRECTORY
+    /// [`Window::save_screenshot`]: graphics/struct.Window.html#method.save_screenshot
+    const SCREENSHOT_KEY: Option<keyboard::KeyCode> =
+        Some(keyboard::KeyCode::F11);
+
+    /// Defines the directory where the screenshots taken with the
+    /// [`SCREENSHOT_KEY`] are saved.
+    ///
+    /// By default, it is set to `screenshots`.
+    ///
+    /// [`SCREENSHOT_KEY`]: #associatedconstant.SCREENSHOT_KEY
+    const SCREENSHOT_DIRECTORY: &'static str = "screenshots";
+
+    /// Defines the name used to remember the [`WindowGeometry`] of the
+    /// [`Game`] between runs.
+    ///
+    /// When set, the size, position, and state of the window are saved in the
+    /// configuration directory of the platform on exit and restored on
+    /// startup, overriding the [`WindowSettings`].
+    ///
+    /// By default, it is set to `None`.
+    ///
+    /// [`WindowGeometry`]: graphics/struct.WindowGeometry.html
+    /// [`Game`]: trait.Game.html
+    /// [`WindowSettings`]: graphics/struct.WindowSettings.html
+    const REMEMBER_WINDOW: Option<&'static str> = None;
+
     /// Loads the [`Game`].
     ///
     /// Use the [`load`] module to load your assets here.
@@ -124,6 +295,79 @@ pub trait Game {
     /// [`Window`]: graphics/struct.Window.html
     fn update(&mut self, _window: &Window) {}
 
+    /// Updates the [`Game`] using a variable timestep.
+    ///
+    /// This function is only called when [`TIMESTEP`] is set to
+    /// [`Timestep::Variable`], instead of [`update`]. It is called once every
+    /// frame and receives the time elapsed since the last frame.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`TIMESTEP`]: #associatedconstant.TIMESTEP
+    /// [`Timestep::Variable`]: enum.Timestep.html#variant.Variable
+    /// [`update`]: #method.update
+    fn update_variable(&mut self, _delta: Duration, _window: &Window) {}
+
+    /// Handles a raw [`winit`] event.
+    ///
+    /// This function is called for every event of the event loop before Coffee
+    /// processes it. You can use it to handle events that Coffee does not
+    /// support yet.
+    ///
+    /// It is only available when the `raw-events` feature is enabled.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`winit`]: https://docs.rs/winit/0.22
+    #[cfg(feature = "raw-events")]
+    fn on_raw_event(
+        &mut self,
+        _event: &crate::winit::event::Event<'_, ()>,
+        _window: &mut Window,
+    ) {
+    }
+
+    /// Serializes the current game state.
+    ///
+    /// Together with [`load_state`], it allows saving checkpoints of your
+    /// game. Thanks to the fixed timestep, loading a checkpoint and feeding the
+    /// same input will produce the same results, which is very useful to
+    /// debug your game logic.
+    ///
+    /// By default, it returns `None`, which disables checkpoints.
+    ///
+    /// [`load_state`]: #method.load_state
+    fn save_state(&self) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Restores a game state previously serialized by [`save_state`].
+    ///
+    /// If it fails, the game loop stops and the error is returned by [`run`].
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`save_state`]: #method.save_state
+    /// [`run`]: #method.run
+    fn load_state(&mut self, _state: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    /// Returns a checksum of the current game state.
+    ///
+    /// When the input of the game is being recorded, this is called after
+    /// every update and stored in the [`Recording`]. Then, [`replay`] compares
+    /// the stored checksums with the ones of the replay to detect desyncs.
+    ///
+    /// By default, it returns `None`, which disables the check.
+    ///
+    /// [`Recording`]: input/struct.Recording.html
+    /// [`replay`]: #method.replay
+    fn checksum(&self) -> Option<u64> {
+        None
+    }
+
     /// Defines the cursor icon of the window.
     ///
     /// By default, it returns platform-dependent default cursor.
@@ -154,21 +398,105 @@ pub trait Game {
         debug.draw(frame);
     }
 
-    /// Handles a close request from the operating system to the game window.
+    /// Handles the game being suspended.
+    ///
+    /// This function is called when the game window is minimized or when the
+    /// operating system suspends the application, which is common on mobile
+    /// platforms.
+    ///
+    /// While suspended, the game is neither updated nor drawn. Once it is
+    /// resumed, [`on_resume`] is called and the time spent suspended is
+    /// skipped.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`on_resume`]: #method.on_resume
+    fn on_suspend(&mut self) {}
+
+    /// Handles the game being resumed after [`on_suspend`] was called.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`on_suspend`]: #method.on_suspend
+    fn on_resume(&mut self) {}
+
+    /// Handles a reset of the graphics device.
+    ///
+    /// The graphics device is reset when it is lost, like when the window
+    /// surface stops providing frames for a while. The window surface and the
+    /// resources owned by Coffee are recreated automatically. However, the
+    /// resources created by the game, like an [`Image`] or a [`Font`], become
+    /// unusable and need to be loaded again here.
+    ///
+    /// Outdated surfaces, like after alt-tabbing out of exclusive fullscreen,
+    /// and frames that time out do not reset the device.
+    ///
+    /// If it fails, the game loop stops and the error is returned by [`run`].
+    ///
+    /// A lost OpenGL context cannot be recovered. In that case, the game loop
+    /// stops with [`Error::SurfaceLost`] instead.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Image`]: graphics/struct.Image.html
+    /// [`Font`]: graphics/struct.Font.html
+    /// [`run`]: #method.run
+    /// [`Error::SurfaceLost`]: enum.Error.html#variant.SurfaceLost
+    fn on_device_reset(&mut self, _window: &mut Window) -> Result<()> {
+        Ok(())
+    }
+
+    /// Handles a close request from the operating system to the game window,
+    /// or a call to [`Window::close`].
     ///
     /// This function should return true to allow the game loop to end,
     /// otherwise false.
     ///
     /// By default, it does nothing and returns true.
+    ///
+    /// [`Window::close`]: graphics/struct.Window.html#method.close
     fn on_close_request(&mut self) -> bool {
         true
     }
 
+    /// Handles the end of the game.
+    ///
+    /// This function is called once the game loop stops, right before [`run`]
+    /// returns. It happens when a close request is accepted, when the game
+    /// [`is_finished`], or when the game loop stops because of an error.
+    ///
+    /// You can use it to flush saves, close files, or persist settings.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`run`]: #method.run
+    /// [`is_finished`]: #method.is_finished
+    fn on_exit(&mut self, _window: &mut Window) {}
+
+    /// Returns a fatal error that happened in the game, if any.
+    ///
+    /// This function is polled after every update. When it returns an error,
+    /// the game loop stops and [`run`] returns it. You can wrap your own errors
+    /// in [`Error::Runtime`].
+    ///
+    /// By default, it always returns `None`.
+    ///
+    /// [`run`]: #method.run
+    /// [`Error::Runtime`]: enum.Error.html#variant.Runtime
+    fn take_error(&mut self) -> Option<Error> {
+        None
+    }
+
     /// Returns whether the game is finished or not.
     ///
     /// If this function returns true, the game will be closed gracefully.
     ///
+    /// You do not need to implement this to add a quit button to your game.
+    /// Use [`Window::close`] instead.
+    ///
     /// By default, it always returns false.
+    ///
+    /// [`Window::close`]: graphics/struct.Window.html#method.close
     fn is_finished(&self) -> bool {
         false
     }
@@ -177,12 +505,42 @@ pub trait Game {
     ///
     /// You probably want to call this in your `main` function to run your game!
     ///
+    /// It returns once the game finishes. If the game loop stops because of an
+    /// error, like losing the window surface or an error returned by
+    /// [`take_error`], the error is returned.
+    ///
     /// [`Game`]: trait.Game.html
     /// [`WindowSettings`]: graphics/struct.WindowSettings.html
+    /// [`take_error`]: #method.take_error
     fn run(window_settings: WindowSettings) -> Result<()>
     where
         Self: 'static + Sized,
     {
         <r#loop::Default as Loop<Self>>::run(window_settings)
     }
+
+    /// Loads the [`Game`] in a [`Headless`] runner and re-simulates the given
+    /// [`Recording`] tick by tick, returning the resulting [`Game`].
+    ///
+    /// An [`Error::Desync`] is returned if the [`checksum`] of the [`Game`]
+    /// diverges from the recorded one.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`Headless`]: struct.Headless.html
+    /// [`Recording`]: input/struct.Recording.html
+    /// [`Error::Desync`]: enum.Error.html#variant.Desync
+    /// [`checksum`]: #method.checksum
+    fn replay(
+        window_settings: WindowSettings,
+        recording: &Recording,
+    ) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        let mut headless = Headless::new(window_settings)?;
+
+        headless.replay(recording)?;
+
+        Ok(headless.into_game())
+    }
 }
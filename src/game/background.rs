@@ -0,0 +1,28 @@
+/// The behavior of a [`Game`] while its window is not focused.
+///
+/// [`Game`]: trait.Game.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Background {
+    /// The game keeps running as usual.
+    Continue,
+
+    /// The game keeps running, but it is drawn and updated at most the given
+    /// amount of times per second.
+    ///
+    /// Its tick rate is restored once the window is focused again.
+    Throttle(u16),
+
+    /// The game is suspended until the window is focused again.
+    ///
+    /// [`Game::on_suspend`] and [`Game::on_resume`] are called accordingly.
+    ///
+    /// [`Game::on_suspend`]: trait.Game.html#method.on_suspend
+    /// [`Game::on_resume`]: trait.Game.html#method.on_resume
+    Pause,
+}
+
+impl Default for Background {
+    fn default() -> Background {
+        Background::Continue
+    }
+}
@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+/// How the game loop catches up when the updates of a [`Game`] fall behind.
+///
+/// This only applies to a [`Timestep::Fixed`]. When a frame takes longer than
+/// a tick, the ticks that were not run pile up. A [`CatchUp`] policy decides
+/// how many of them are run during the next frames and when to give up on
+/// them.
+///
+/// Running many ticks per frame makes frames even slower, which may cause
+/// more ticks to pile up. Use [`max_lag`] to drop them before the game
+/// spirals out of control.
+///
+/// [`Game`]: trait.Game.html
+/// [`Timestep::Fixed`]: enum.Timestep.html#variant.Fixed
+/// [`CatchUp`]: struct.CatchUp.html
+/// [`max_lag`]: #structfield.max_lag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CatchUp {
+    /// The maximum amount of ticks run during a single frame.
+    ///
+    /// It is always at least `1`.
+    pub max_ticks_per_frame: u16,
+
+    /// The maximum amount of time the ticks of the game can lag behind.
+    ///
+    /// The ticks exceeding it are dropped and counted in
+    /// [`Debug::dropped_ticks`]. When `None`, ticks are never dropped.
+    ///
+    /// [`Debug::dropped_ticks`]: struct.Debug.html#method.dropped_ticks
+    pub max_lag: Option<Duration>,
+}
+
+impl CatchUp {
+    /// The default [`CatchUp`] policy, which runs at most one tick per frame
+    /// and never drops ticks.
+    ///
+    /// [`CatchUp`]: struct.CatchUp.html
+    pub const DEFAULT: CatchUp = CatchUp {
+        max_ticks_per_frame: 1,
+        max_lag: None,
+    };
+}
+
+impl Default for CatchUp {
+    fn default() -> CatchUp {
+        CatchUp::DEFAULT
+    }
+}
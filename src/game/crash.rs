@@ -0,0 +1,157 @@
+use std::backtrace::Backtrace;
+use std::fmt::Write as _;
+use std::fs;
+use std::panic;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The crash reporting behavior of a [`Game`].
+///
+/// When a panic happens in the game loop, a crash report is written to the
+/// [`directory`]. It contains the panic message, a backtrace, the graphics
+/// adapter in use, and the recent performance metrics of the [`Debug`] view.
+///
+/// The panic keeps unwinding once the report is written, so the game exits as
+/// it would otherwise.
+///
+/// [`Game`]: trait.Game.html
+/// [`directory`]: #structfield.directory
+/// [`Debug`]: struct.Debug.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrashHandler {
+    /// The directory where crash reports are written.
+    ///
+    /// It is created if it does not exist.
+    pub directory: &'static str,
+
+    /// Whether to show a native error dialog before exiting.
+    ///
+    /// It has no effect unless the `dialogs` feature is enabled.
+    pub dialog: bool,
+}
+
+impl CrashHandler {
+    /// The default [`CrashHandler`].
+    ///
+    /// It writes reports to a `crashes` directory and shows an error dialog.
+    ///
+    /// [`CrashHandler`]: struct.CrashHandler.html
+    pub const DEFAULT: CrashHandler = CrashHandler {
+        directory: "crashes",
+        dialog: true,
+    };
+}
+
+impl Default for CrashHandler {
+    fn default() -> CrashHandler {
+        CrashHandler::DEFAULT
+    }
+}
+
+// The last panic, recorded by the hook while the stack is still intact
+static PANIC: Mutex<Option<Panic>> = Mutex::new(None);
+
+struct Panic {
+    message: String,
+    location: String,
+    backtrace: Backtrace,
+}
+
+// Installs a panic hook that records panics before running the previous hook
+pub(crate) fn install() {
+    let previous = panic::take_hook();
+
+    panic::set_hook(Box::new(move |info| {
+        let payload = info.payload();
+
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| String::from(*message))
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| String::from("Box<dyn Any>"));
+
+        let location = info
+            .location()
+            .map(|location| location.to_string())
+            .unwrap_or_else(|| String::from("unknown"));
+
+        if let Ok(mut panic) = PANIC.lock() {
+            *panic = Some(Panic {
+                message,
+                location,
+                backtrace: Backtrace::force_capture(),
+            });
+        }
+
+        previous(info);
+    }));
+}
+
+// Writes a report of the last panic and shows the error dialog, if enabled
+pub(crate) fn report(
+    handler: &CrashHandler,
+    adapter: &str,
+    metrics: Option<String>,
+) {
+    let panic = match PANIC.lock().ok().and_then(|mut panic| panic.take()) {
+        Some(panic) => panic,
+        None => return,
+    };
+
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or(0);
+
+    let mut report = String::new();
+
+    let _ = writeln!(report, "Panic: {}", panic.message);
+    let _ = writeln!(report, "Location: {}", panic.location);
+    let _ = writeln!(report, "Time: {}", time);
+    let _ = writeln!(report, "Coffee: {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(
+        report,
+        "Platform: {} ({})",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+    let _ = writeln!(report, "Graphics adapter: {}", adapter);
+
+    if let Some(metrics) = metrics {
+        let _ = write!(report, "\nMetrics:\n{}", metrics);
+    }
+
+    let _ = write!(report, "\nBacktrace:\n{}\n", panic.backtrace);
+
+    let directory = Path::new(handler.directory);
+    let path = directory.join(format!("crash-{}.txt", time));
+
+    match write(directory, &path, &report) {
+        Ok(()) => log::error!("Crash report saved to {}", path.display()),
+        Err(error) => log::error!("Crash report failed: {}", error),
+    }
+
+    if handler.dialog {
+        show_dialog(&panic.message, &path);
+    }
+}
+
+fn write(directory: &Path, path: &Path, report: &str) -> std::io::Result<()> {
+    fs::create_dir_all(directory)?;
+    fs::write(path, report)
+}
+
+#[cfg(feature = "dialogs")]
+fn show_dialog(message: &str, path: &Path) {
+    let text = format!(
+        "The game has crashed: {}\n\nA crash report was saved to {}",
+        message,
+        path.display()
+    );
+
+    crate::dialog::alert("Error", &text);
+}
+
+#[cfg(not(feature = "dialogs"))]
+fn show_dialog(_message: &str, _path: &Path) {}
@@ -0,0 +1,253 @@
+use crate::graphics::{Window, WindowSettings};
+use crate::input::{self, Input, Recording};
+use crate::{Error, Game, Result, Timestep};
+
+use std::time::Duration;
+
+/// A [`Game`] stepped manually instead of by a running event loop.
+///
+/// A [`Headless`] game is only updated when you tell it to. It does not
+/// depend on real time, which makes it deterministic. You can use it to write
+/// integration tests for your game logic, to run simulations, or to reuse your
+/// [`Game`] in a dedicated server.
+///
+/// No event loop, window, or [`Gpu`] is created. Your [`Game`] receives a
+/// headless [`Window`] instead, which keeps the size of the given
+/// [`WindowSettings`] and is never drawn. Therefore, [`Game::draw`] is never
+/// called, and any [`Task`] that needs a [`Gpu`] fails to run with
+/// [`Error::GpuUnavailable`]. You can use [`Window::is_headless`] in
+/// [`Game::load`] to skip loading your graphics.
+///
+/// # Example
+/// ```
+/// # use coffee::graphics::{Frame, Window, WindowSettings};
+/// # use coffee::load::Task;
+/// # use coffee::{Game, Timer};
+/// # struct MyGame { score: u32 }
+/// # impl Game for MyGame {
+/// #     type Input = ();
+/// #     type LoadingScreen = ();
+/// #     fn load(_window: &Window) -> Task<MyGame> {
+/// #         Task::succeed(|| MyGame { score: 0 })
+/// #     }
+/// #     fn draw(&mut self, _frame: &mut Frame, _timer: &Timer) {}
+/// #     fn update(&mut self, _window: &Window) { self.score += 1; }
+/// # }
+/// use coffee::Headless;
+///
+/// let mut game = Headless::<MyGame>::new(WindowSettings {
+///     title: String::from("Test"),
+///     size: (800, 600),
+///     resizable: false,
+///     ..Default::default()
+/// })
+/// .expect("Load game");
+///
+/// game.advance(60);
+///
+/// assert_eq!(game.game().score, 60);
+/// ```
+///
+/// [`Game`]: trait.Game.html
+/// [`Headless`]: struct.Headless.html
+/// [`Gpu`]: graphics/struct.Gpu.html
+/// [`Window`]: graphics/struct.Window.html
+/// [`WindowSettings`]: graphics/struct.WindowSettings.html
+/// [`Game::draw`]: trait.Game.html#tymethod.draw
+/// [`Task`]: load/struct.Task.html
+/// [`Error::GpuUnavailable`]: enum.Error.html#variant.GpuUnavailable
+/// [`Window::is_headless`]: graphics/struct.Window.html#method.is_headless
+/// [`Game::load`]: trait.Game.html#tymethod.load
+pub struct Headless<G: Game> {
+    game: G,
+    input: G::Input,
+    window: Window,
+    ticks_per_second: u16,
+    ticks: u64,
+    is_closed: bool,
+}
+
+impl<G: Game> Headless<G> {
+    /// Loads the [`Game`] in a headless [`Window`] with the given
+    /// [`WindowSettings`].
+    ///
+    /// The loading screen of the [`Game`] is not shown.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`Window`]: graphics/struct.Window.html
+    /// [`WindowSettings`]: graphics/struct.WindowSettings.html
+    pub fn new(window_settings: WindowSettings) -> Result<Headless<G>> {
+        let window = Window::headless(&window_settings);
+
+        let game = G::load(&window).run_headless()?;
+
+        Ok(Headless {
+            game,
+            input: G::Input::new(),
+            window,
+            ticks_per_second: G::TICKS_PER_SECOND,
+            ticks: 0,
+            is_closed: false,
+        })
+    }
+
+    /// Returns a reference to the [`Game`].
+    ///
+    /// [`Game`]: trait.Game.html
+    pub fn game(&self) -> &G {
+        &self.game
+    }
+
+    /// Returns a mutable reference to the [`Game`].
+    ///
+    /// [`Game`]: trait.Game.html
+    pub fn game_mut(&mut self) -> &mut G {
+        &mut self.game
+    }
+
+    /// Returns a mutable reference to the headless [`Window`].
+    ///
+    /// [`Window`]: graphics/struct.Window.html
+    pub fn window(&mut self) -> &mut Window {
+        &mut self.window
+    }
+
+    /// Feeds an input event to the [`Game`].
+    ///
+    /// The event will be processed during the next call to [`step`].
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`step`]: #method.step
+    pub fn feed(&mut self, event: input::Event) {
+        self.input.update(event);
+    }
+
+    /// Runs a single tick of the [`Game`].
+    ///
+    /// First, the [`Game`] interacts with the fed input. Then, it is updated
+    /// once.
+    ///
+    /// Ticks have a fixed duration. When using a [`Timestep::Variable`], the
+    /// [`Game`] is updated with a delta of `1 / TICKS_PER_SECOND` seconds,
+    /// unless a different rate is set with [`Window::set_ticks_per_second`].
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`Timestep::Variable`]: enum.Timestep.html#variant.Variable
+    /// [`Window::set_ticks_per_second`]: graphics/struct.Window.html#method.set_ticks_per_second
+    pub fn step(&mut self) {
+        self.interact();
+
+        let delta =
+            Duration::from_secs(1) / u32::from(self.ticks_per_second.max(1));
+
+        self.update(delta);
+    }
+
+    fn interact(&mut self) {
+        self.game.interact(&mut self.input, &mut self.window);
+        self.input.clear();
+
+        if let Some(ticks_per_second) = self.window.take_ticks_per_second() {
+            self.ticks_per_second = ticks_per_second;
+        }
+    }
+
+    fn update(&mut self, delta: Duration) {
+        match G::TIMESTEP {
+            Timestep::Fixed => self.game.update(&self.window),
+            Timestep::Variable => {
+                self.game.update_variable(delta, &self.window)
+            }
+        }
+
+        self.ticks += 1;
+
+        if self.window.take_close_request() && self.game.on_close_request() {
+            self.is_closed = true;
+        }
+    }
+
+    /// Runs the given amount of ticks of the [`Game`], stopping early if it
+    /// finishes.
+    ///
+    /// [`Game`]: trait.Game.html
+    pub fn advance(&mut self, ticks: u32) {
+        for _ in 0..ticks {
+            if self.is_finished() {
+                break;
+            }
+
+            self.step();
+        }
+    }
+
+    /// Re-simulates the given [`Recording`] frame by frame.
+    ///
+    /// An [`Error::Desync`] is returned as soon as the [`Game::checksum`]
+    /// diverges from the recorded one.
+    ///
+    /// [`Recording`]: input/struct.Recording.html
+    /// [`Error::Desync`]: enum.Error.html#variant.Desync
+    /// [`Game::checksum`]: trait.Game.html#method.checksum
+    pub fn replay(&mut self, recording: &Recording) -> Result<()> {
+        for frame in recording.iter() {
+            for event in &frame.events {
+                self.input.update(*event);
+            }
+
+            self.interact();
+
+            for _ in 0..frame.ticks {
+                self.update(frame.delta);
+            }
+
+            // The checksum is recorded after the last tick of a frame
+            if frame.ticks > 0 {
+                if let (Some(expected), Some(found)) =
+                    (frame.checksum, self.game.checksum())
+                {
+                    if expected != found {
+                        return Err(Error::Desync {
+                            tick: self.ticks,
+                            expected,
+                            found,
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the amount of ticks that have been run.
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Returns whether the [`Game`] is finished or not.
+    ///
+    /// A [`Game`] is finished when [`Game::is_finished`] returns true or when
+    /// a [`Window::close`] request is accepted.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`Game::is_finished`]: trait.Game.html#method.is_finished
+    /// [`Window::close`]: graphics/struct.Window.html#method.close
+    pub fn is_finished(&self) -> bool {
+        self.is_closed || self.game.is_finished()
+    }
+
+    /// Consumes the [`Headless`] runner and returns the [`Game`].
+    ///
+    /// [`Headless`]: struct.Headless.html
+    /// [`Game`]: trait.Game.html
+    pub fn into_game(self) -> G {
+        self.game
+    }
+}
+
+impl<G: Game> std::fmt::Debug for Headless<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Headless {{ ticks: {} }}", self.ticks)
+    }
+}
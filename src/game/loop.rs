@@ -1,10 +1,25 @@
 use crate::debug::Debug;
+use crate::game::crash;
+use crate::game::pacing::Pacing;
 use crate::graphics::window::winit;
-use crate::graphics::{Window, WindowSettings};
-use crate::input::{self, gamepad, keyboard, mouse, window, Input};
-use crate::load::{Join, LoadingScreen, Task};
-use crate::{Result, Timer};
+use crate::graphics::window::winit::event::{
+    ElementState, Event, KeyboardInput, WindowEvent,
+};
+use crate::graphics::window::winit::event_loop::{ControlFlow, EventLoop};
+use crate::graphics::{
+    self, PresentMode, Window, WindowGeometry, WindowSettings,
+};
+use crate::input::{self, gamepad, keyboard, mouse, touch, window, Input};
+use crate::load::{hot_reload, Join, LoadingScreen, Task};
+use crate::profile;
+use crate::{Background, Error, Result, Timer, Timestep};
 use std::convert::TryInto;
+use std::panic;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use instant::Instant;
 
 pub trait Loop<Game: super::Game> {
     type Attributes;
@@ -21,137 +36,747 @@ pub trait Loop<Game: super::Game> {
         input.update(event);
     }
 
+    fn on_tick(&mut self, _delta: std::time::Duration) {}
+
     fn after_draw(
         &mut self,
         _game: &mut Game,
         _input: &mut Game::Input,
         _window: &mut Window,
+        _timer: &Timer,
         _debug: &mut Debug,
     ) {
     }
 
+    fn on_device_reset(
+        &mut self,
+        _game: &mut Game,
+        _window: &mut Window,
+    ) -> Result<()> {
+        Ok(())
+    }
+
     fn run(window_settings: WindowSettings) -> Result<()>
     where
         Self: 'static + Sized,
         Game: 'static,
         Game::Input: 'static,
     {
+        let (event_loop, engine) =
+            graphics::window::open(window_settings, Engine::<Game, Self>::new)?;
+
+        run_event_loop(event_loop, engine)
+    }
+}
+
+#[cfg(not(any(
+    target_arch = "wasm32",
+    target_os = "android",
+    target_os = "ios"
+)))]
+fn run_event_loop<Game, L>(
+    mut event_loop: EventLoop<()>,
+    mut engine: Engine<Game, L>,
+) -> Result<()>
+where
+    Game: super::Game,
+    L: Loop<Game>,
+{
+    use crate::graphics::window::winit::platform::desktop::EventLoopExtDesktop;
+
+    event_loop.run_return(|event, _, control_flow| {
+        engine.handle(event);
+
+        *control_flow = engine.control_flow();
+    });
+
+    engine.exit()
+}
+
+// The browser and mobile platforms drive the event loop, which never returns.
+// In the browser, `ControlFlow::Poll` is backed by `requestAnimationFrame`.
+#[cfg(any(target_arch = "wasm32", target_os = "android", target_os = "ios"))]
+fn run_event_loop<Game, L>(
+    event_loop: EventLoop<()>,
+    engine: Engine<Game, L>,
+) -> Result<()>
+where
+    Game: 'static + super::Game,
+    L: 'static + Loop<Game>,
+{
+    let mut engine = Some(engine);
+
+    event_loop.run(move |event, _, control_flow| {
+        let flow = match &mut engine {
+            Some(running) => {
+                running.handle(event);
+                running.control_flow()
+            }
+            None => ControlFlow::Exit,
+        };
+
+        if flow == ControlFlow::Exit {
+            if let Some(Err(error)) = engine.take().map(Engine::exit) {
+                log::error!("The game stopped because of an error: {}", error);
+            }
+        }
+
+        *control_flow = flow;
+    })
+}
+
+pub(crate) struct Engine<Game: super::Game, L: Loop<Game>> {
+    game: Game,
+    game_loop: L,
+    window: Window,
+    input: Game::Input,
+    gamepads: Option<gamepad::Tracker>,
+    debug: Debug,
+    timer: Timer,
+    ticks_per_second: u16,
+    lifecycle: Lifecycle,
+    limiter: Option<FrameLimiter>,
+    throttle: Option<FrameLimiter>,
+    pacing: Pacing,
+    vsync: bool,
+    checkpoint: Option<Vec<u8>>,
+    failure: Option<Error>,
+    is_finished: bool,
+}
+
+impl<Game: super::Game, L: Loop<Game>> Engine<Game, L> {
+    pub fn new(
+        window_settings: WindowSettings,
+        event_loop: &winit::event_loop::EventLoop<()>,
+    ) -> Result<Self> {
+        if Game::CRASH_HANDLER.is_some() {
+            crash::install();
+        }
+
         // Window creation
-        let event_loop = winit::event_loop::EventLoop::new();
-        let mut window = Window::new(window_settings, &event_loop)?;
-        let mut debug = Debug::new(window.gpu());
+        let geometry = Game::REMEMBER_WINDOW.and_then(WindowGeometry::load);
+
+        let window_settings = match geometry {
+            Some(geometry) => geometry.apply(window_settings),
+            None => window_settings,
+        };
+
+        let mut window = Window::new(window_settings, event_loop)?;
+
+        if let Some(position) = geometry.and_then(|geometry| geometry.position)
+        {
+            window.set_position(position);
+        }
+
+        let mut debug = Debug::new(window.gpu(), Game::DEBUG_SETTINGS);
 
         // Loading
+        log::info!("Loading game");
         debug.loading_started();
         let (mut game, configuration) = {
             let mut loading_screen = Game::LoadingScreen::new(window.gpu())?;
 
             loading_screen.run(
-                (Game::load(&window), Self::load(&window)).join(),
+                (Game::load(&window), L::load(&window)).join(),
                 &mut window,
             )?
         };
 
-        let mut game_loop = Self::new(configuration, &mut game, &mut window);
-        let mut input = Game::Input::new();
-        let mut gamepads = gamepad::Tracker::new();
+        let game_loop = L::new(configuration, &mut game, &mut window);
+        let input = Game::Input::new();
+        let gamepads = gamepad::Tracker::new();
         debug.loading_finished();
+        log::info!("Game loaded");
 
         let mut timer = Timer::new(Game::TICKS_PER_SECOND);
+        let throttle = match Game::BACKGROUND_BEHAVIOR {
+            Background::Throttle(frame_rate) => {
+                Some(FrameLimiter::new(frame_rate))
+            }
+            _ => None,
+        };
+        let pacing = Pacing::new(
+            window.refresh_rate().unwrap_or(60),
+            Game::MAX_FRAME_RATE,
+        );
 
         // Initialization
         debug.frame_started();
         timer.update();
 
-        event_loop.run(move |event, _, control_flow| match event {
-            winit::event::Event::NewEvents(_) => {
-                debug.interact_started();
-            }
-            winit::event::Event::MainEventsCleared => {
-                if let Some(tracker) = &mut gamepads {
-                    while let Some((id, event, time)) = tracker.next_event() {
-                        game_loop.on_input(
-                            &mut input,
-                            input::Event::Gamepad { id, event, time },
-                        );
-                    }
+        Ok(Engine {
+            game,
+            game_loop,
+            window,
+            input,
+            gamepads,
+            debug,
+            timer,
+            ticks_per_second: Game::TICKS_PER_SECOND,
+            lifecycle: Lifecycle::new(),
+            limiter: Game::MAX_FRAME_RATE.map(FrameLimiter::new),
+            throttle,
+            pacing,
+            vsync: true,
+            checkpoint: None,
+            failure: None,
+            is_finished: false,
+        })
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    pub fn game_mut(&mut self) -> &mut Game {
+        &mut self.game
+    }
+
+    pub fn window(&mut self) -> &mut Window {
+        &mut self.window
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+
+    pub fn control_flow(&self) -> ControlFlow {
+        if self.is_finished {
+            ControlFlow::Exit
+        } else if self.lifecycle.is_paused() {
+            // Nothing is drawn while paused, so we wait for events instead of
+            // spinning
+            ControlFlow::Wait
+        } else {
+            ControlFlow::Poll
+        }
+    }
+
+    pub fn handle(&mut self, event: Event<'_, ()>) {
+        let handler = match Game::CRASH_HANDLER {
+            Some(handler) => handler,
+            None => return self.process(event),
+        };
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            self.process(event);
+        }));
+
+        if let Err(payload) = result {
+            crash::report(
+                &handler,
+                self.window.gpu().adapter(),
+                self.debug.report(),
+            );
+
+            panic::resume_unwind(payload);
+        }
+    }
+
+    fn process(&mut self, event: Event<'_, ()>) {
+        #[cfg(feature = "raw-events")]
+        self.game.on_raw_event(&event, &mut self.window);
+
+        if self.is_finished {
+            return;
+        }
+
+        let window_id = self.window.id();
+
+        match event {
+            Event::NewEvents(_) => {
+                self.debug.interact_started();
+            }
+            Event::Suspended => {
+                log::info!("Game suspended");
+
+                self.lifecycle.suspended = true;
+                self.sync_lifecycle();
+            }
+            Event::Resumed => {
+                log::info!("Game resumed");
+
+                // Android destroys the native window of suspended apps
+                if cfg!(target_os = "android") && self.lifecycle.suspended {
+                    self.window.recreate_surface();
                 }
 
-                game.interact(&mut input, &mut window);
-                input.clear();
-                debug.interact_finished();
+                self.lifecycle.suspended = false;
+                self.sync_lifecycle();
+            }
+            Event::MainEventsCleared => {
+                self.update();
+            }
+            Event::RedrawRequested(id) if id == window_id => {
+                self.draw();
+            }
+            Event::WindowEvent {
+                window_id: id,
+                event,
+            } if id == window_id => {
+                self.on_window_event(event);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn update(&mut self) {
+        if self.is_finished || self.lifecycle.is_paused() {
+            return;
+        }
+
+        if let Some(tracker) = &mut self.gamepads {
+            while let Some((id, event, time)) = tracker.next_event() {
+                let event = input::Event::Gamepad { id, event, time };
+
+                record(&mut self.window, event);
+                self.game_loop.on_input(&mut self.input, event);
+            }
+        }
 
-                if timer.tick() {
-                    debug.update_started();
-                    game.update(&window);
-                    debug.update_finished();
+        hot_reload::reload(self.window.gpu());
+
+        self.game.interact(&mut self.input, &mut self.window);
+        self.input.clear();
+        self.debug.interact_finished();
+
+        if let Some(ticks_per_second) = self.window.take_ticks_per_second() {
+            self.ticks_per_second = ticks_per_second;
+            self.sync_tick_rate();
+        }
+
+        let max_ticks = match Game::TIMESTEP {
+            Timestep::Fixed => {
+                let catch_up = Game::CATCH_UP;
+
+                if let Some(max_lag) = catch_up.max_lag {
+                    self.debug.ticks_dropped(self.timer.drop_lag(max_lag));
                 }
 
-                window.request_redraw();
+                catch_up.max_ticks_per_frame.max(1)
+            }
+            Timestep::Variable => 1,
+        };
 
-                if game.is_finished() {
-                    *control_flow = winit::event_loop::ControlFlow::Exit;
+        let mut ticks = 0;
+
+        while ticks < max_ticks {
+            let ticked = match Game::TIMESTEP {
+                Timestep::Fixed => self.timer.tick(),
+                Timestep::Variable => self.timer.tick_variable(),
+            };
+
+            if !ticked {
+                break;
+            }
+
+            profile::scope!("update");
+
+            self.debug.update_started();
+
+            match Game::TIMESTEP {
+                Timestep::Fixed => self.game.update(&self.window),
+                Timestep::Variable => {
+                    self.game.update_variable(self.timer.delta(), &self.window)
                 }
             }
-            winit::event::Event::RedrawRequested { .. } => {
-                debug.draw_started();
-                game.draw(&mut window.frame(), &timer);
-                debug.draw_finished();
-
-                game_loop.after_draw(
-                    &mut game,
-                    &mut input,
-                    &mut window,
-                    &mut debug,
-                );
-
-                if debug.is_enabled() {
-                    debug.debug_started();
-                    game.debug(&input, &mut window.frame(), &mut debug);
-                    debug.debug_finished();
+
+            self.debug.update_finished();
+
+            self.game_loop.on_tick(match Game::TIMESTEP {
+                Timestep::Fixed => {
+                    self.timer.tick_duration().unwrap_or_default()
                 }
+                Timestep::Variable => self.timer.delta(),
+            });
+
+            ticks += 1;
+        }
+
+        if let Some(recording) = self.window.recording_mut() {
+            let checksum = if ticks > 0 {
+                self.game.checksum()
+            } else {
+                None
+            };
+
+            recording.finish_frame(ticks, self.timer.delta(), checksum);
+        }
+
+        self.window.request_redraw();
+
+        if let Some(error) = self.game.take_error() {
+            self.failure = Some(error);
+            self.is_finished = true;
+        } else if self.game.is_finished() {
+            self.is_finished = true;
+        } else if self.window.take_close_request() {
+            self.is_finished = self.game.on_close_request();
+        }
+    }
+
+    pub fn draw(&mut self) {
+        if self.is_finished || self.lifecycle.is_paused() {
+            return;
+        }
+
+        self.window.begin_capture();
+
+        {
+            profile::scope!("draw");
+
+            self.debug.draw_started();
+            self.game.draw(&mut self.window.frame(), &self.timer);
+            self.debug.draw_finished();
+        }
+
+        self.game_loop.after_draw(
+            &mut self.game,
+            &mut self.input,
+            &mut self.window,
+            &self.timer,
+            &mut self.debug,
+        );
+
+        self.window.end_capture();
+
+        if self.debug.is_enabled() {
+            self.debug.debug_started();
+            self.game.debug(
+                &self.input,
+                &mut self.window.frame(),
+                &mut self.debug,
+            );
+            self.debug.debug_finished();
+        }
+
+        let presented = match self.window.swap_buffers() {
+            Err(Error::SurfaceLost) => self.reset_device(),
+            result => result,
+        };
+
+        if let Err(error) = presented {
+            self.failure = Some(error);
+            self.is_finished = true;
+            return;
+        }
+
+        match (&mut self.throttle, &mut self.limiter) {
+            (Some(throttle), _) if !self.lifecycle.focused => {
+                throttle.wait();
+                self.pacing.reset();
+            }
+            (_, Some(limiter)) => {
+                limiter.wait();
+                self.pacing.frame_presented();
+            }
+            _ => {
+                self.pacing.frame_presented();
+            }
+        }
+
+        self.debug
+            .frames_missed(self.pacing.missed(), self.pacing.recent_ratio());
+        self.debug.memory_used(self.window.gpu().memory_usage());
+        self.debug.timer_speed(self.timer.speed());
+
+        if Game::VSYNC_FALLBACK && self.vsync && self.pacing.is_struggling() {
+            // We only try once, as the backend may not support it
+            self.vsync = false;
 
-                window.swap_buffers();
-                debug.frame_finished();
+            let _ = self.window.set_present_mode(PresentMode::Immediate);
+        }
+
+        self.debug.frame_finished();
+        profile::finish_frame();
+
+        self.debug.frame_started();
+        self.window.request_redraw();
+        self.timer.update();
+    }
 
-                debug.frame_started();
-                window.request_redraw();
-                timer.update();
+    fn reset_device(&mut self) -> Result<()> {
+        log::warn!("Graphics device lost, resetting it");
+
+        self.window.reset_device()?;
+        self.debug.reset(self.window.gpu())?;
+        self.game_loop
+            .on_device_reset(&mut self.game, &mut self.window)?;
+        self.game.on_device_reset(&mut self.window)?;
+
+        log::info!("Graphics device reset");
+
+        Ok(())
+    }
+
+    pub fn exit(mut self) -> Result<()> {
+        log::info!("Game loop finished");
+
+        if let Some(name) = Game::REMEMBER_WINDOW {
+            if let Err(error) = self.window.geometry().save(name) {
+                log::warn!("Window geometry could not be saved: {}", error);
             }
-            winit::event::Event::WindowEvent { event, .. } => match event {
-                winit::event::WindowEvent::CloseRequested => {
-                    if game.on_close_request() {
-                        *control_flow = winit::event_loop::ControlFlow::Exit;
-                    }
+        }
+
+        self.game.on_exit(&mut self.window);
+
+        match self.failure {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    fn on_window_event(&mut self, event: WindowEvent<'_>) {
+        match event {
+            WindowEvent::CloseRequested => {
+                log::debug!("Window close requested");
+
+                if self.game.on_close_request() {
+                    self.is_finished = true;
                 }
-                winit::event::WindowEvent::Resized(logical_size) => {
-                    window.resize(logical_size);
+            }
+            WindowEvent::ScaleFactorChanged { new_inner_size, .. } => {
+                self.window.resize(*new_inner_size);
+            }
+            WindowEvent::Resized(physical_size) => {
+                log::debug!("Window resized to {:?}", physical_size);
+
+                self.lifecycle.minimized =
+                    physical_size.width == 0 || physical_size.height == 0;
+
+                if !self.lifecycle.minimized {
+                    self.window.resize(physical_size);
                 }
-                _ => {
-                    match event {
-                        winit::event::WindowEvent::KeyboardInput {
-                            input:
-                                winit::event::KeyboardInput {
-                                    virtual_keycode,
-                                    state: winit::event::ElementState::Released,
-                                    ..
-                                },
-                            ..
-                        } if Game::DEBUG_KEY.is_some() => {
-                            if virtual_keycode == Game::DEBUG_KEY {
-                                debug.toggle();
-                            }
+
+                self.sync_lifecycle();
+            }
+            _ => {
+                match event {
+                    WindowEvent::KeyboardInput {
+                        input:
+                            KeyboardInput {
+                                virtual_keycode: Some(key_code),
+                                state: ElementState::Released,
+                                ..
+                            },
+                        ..
+                    } => {
+                        if Game::DEBUG_KEY == Some(key_code) {
+                            self.debug.toggle();
                         }
-                        _ => {}
+
+                        self.on_checkpoint_key(key_code);
+                        self.on_time_scale_key(key_code);
+                        self.on_screenshot_key(key_code);
                     }
+                    WindowEvent::Focused(focused) => {
+                        self.lifecycle.focused = focused;
+                        self.sync_lifecycle();
+                    }
+                    _ => {}
+                }
 
-                    if let Some(input_event) = try_into_input_event(event) {
-                        game_loop.on_input(&mut input, input_event);
+                if let Some(input_event) = try_into_input_event(event) {
+                    record(&mut self.window, input_event);
+                    self.game_loop.on_input(&mut self.input, input_event);
+                }
+            }
+        }
+    }
+
+    fn on_checkpoint_key(&mut self, key_code: keyboard::KeyCode) {
+        if !cfg!(any(debug_assertions, feature = "debug")) {
+            return;
+        }
+
+        match Game::CHECKPOINT_KEYS {
+            Some((save, _)) if key_code == save => {
+                if let Some(state) = self.game.save_state() {
+                    self.checkpoint = Some(state);
+                }
+            }
+            Some((_, load)) if key_code == load => {
+                if let Some(state) = &self.checkpoint {
+                    if let Err(error) = self.game.load_state(state) {
+                        self.failure = Some(error);
+                        self.is_finished = true;
                     }
+
+                    self.timer.reset();
                 }
-            },
+            }
             _ => {}
-        });
+        }
+    }
+
+    fn on_time_scale_key(&mut self, key_code: keyboard::KeyCode) {
+        const SPEEDS: [f32; 6] = [0.0, 0.25, 0.5, 1.0, 2.0, 4.0];
+
+        if !self.debug.is_enabled() {
+            return;
+        }
+
+        let speed = self.timer.speed();
+
+        let new_speed = match Game::TIME_SCALE_KEYS {
+            Some((slower, _)) if key_code == slower => {
+                SPEEDS.iter().rev().find(|&&step| step < speed)
+            }
+            Some((_, faster)) if key_code == faster => {
+                SPEEDS.iter().find(|&&step| step > speed)
+            }
+            _ => None,
+        };
+
+        if let Some(new_speed) = new_speed {
+            self.timer.set_speed(*new_speed);
+        }
+    }
+
+    fn on_screenshot_key(&mut self, key_code: keyboard::KeyCode) {
+        if !cfg!(any(debug_assertions, feature = "debug"))
+            || Game::SCREENSHOT_KEY != Some(key_code)
+        {
+            return;
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        let filename = format!(
+            "screenshot-{}-{:03}.png",
+            timestamp.as_secs(),
+            timestamp.subsec_millis()
+        );
+
+        self.window.save_screenshot(
+            Path::new(Game::SCREENSHOT_DIRECTORY).join(filename),
+        );
+    }
+
+    fn sync_lifecycle(&mut self) {
+        self.lifecycle.sync(&mut self.game, &mut self.timer);
+        self.pacing.reset();
+        self.sync_tick_rate();
+
+        #[cfg(feature = "audio")]
+        self.window.audio().set_background(
+            if self.lifecycle.is_in_background() {
+                Some(Game::BACKGROUND_AUDIO)
+            } else {
+                None
+            },
+        );
+    }
+
+    // A throttled game is also updated less often while in the background
+    fn sync_tick_rate(&mut self) {
+        let ticks_per_second = match Game::BACKGROUND_BEHAVIOR {
+            Background::Throttle(rate) if !self.lifecycle.focused => {
+                self.ticks_per_second.min(rate)
+            }
+            _ => self.ticks_per_second,
+        };
+
+        self.timer.set_ticks_per_second(ticks_per_second);
+    }
+}
+
+fn record(window: &mut Window, event: input::Event) {
+    if let Some(recording) = window.recording_mut() {
+        recording.record(event);
+    }
+}
+
+struct Lifecycle {
+    suspended: bool,
+    minimized: bool,
+    focused: bool,
+    paused: bool,
+}
+
+impl Lifecycle {
+    fn new() -> Lifecycle {
+        Lifecycle {
+            suspended: false,
+            minimized: false,
+            focused: true,
+            paused: false,
+        }
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    #[cfg(feature = "audio")]
+    fn is_in_background(&self) -> bool {
+        self.suspended || self.minimized || !self.focused
+    }
+
+    fn sync<Game: super::Game>(&mut self, game: &mut Game, timer: &mut Timer) {
+        let paused = self.suspended
+            || self.minimized
+            || (!self.focused
+                && Game::BACKGROUND_BEHAVIOR == Background::Pause);
+
+        if paused == self.paused {
+            return;
+        }
+
+        self.paused = paused;
+
+        if paused {
+            game.on_suspend();
+        } else {
+            game.on_resume();
+            timer.reset();
+        }
+    }
+}
+
+struct FrameLimiter {
+    frame_duration: Duration,
+    next_frame: Instant,
+}
+
+impl FrameLimiter {
+    // Sleeping is not precise, so we spin during the last part of the wait
+    const SPIN_DURATION: Duration = Duration::from_millis(1);
+
+    fn new(max_frame_rate: u16) -> FrameLimiter {
+        FrameLimiter {
+            frame_duration: Duration::from_secs(1)
+                / u32::from(max_frame_rate.max(1)),
+            next_frame: Instant::now(),
+        }
+    }
+
+    fn wait(&mut self) {
+        let now = Instant::now();
+
+        // If we fell behind, we start counting again from now instead of
+        // drawing frames as fast as possible to catch up
+        if now > self.next_frame + self.frame_duration {
+            self.next_frame = now + self.frame_duration;
+            return;
+        }
+
+        // The browser paces frames for us and we cannot block its thread
+        if cfg!(target_arch = "wasm32") {
+            return;
+        }
+
+        if self.next_frame > now + Self::SPIN_DURATION {
+            thread::sleep(self.next_frame - now - Self::SPIN_DURATION);
+        }
+
+        while Instant::now() < self.next_frame {
+            std::hint::spin_loop();
+        }
+
+        self.next_frame += self.frame_duration;
     }
 }
 
@@ -159,9 +784,9 @@ fn try_into_input_event(
     event: winit::event::WindowEvent<'_>,
 ) -> Option<input::Event> {
     match event {
-        winit::event::WindowEvent::KeyboardInput {
+        WindowEvent::KeyboardInput {
             input:
-                winit::event::KeyboardInput {
+                KeyboardInput {
                     state,
                     virtual_keycode: Some(key_code),
                     ..
@@ -171,15 +796,15 @@ fn try_into_input_event(
             state,
             key_code,
         })),
-        winit::event::WindowEvent::ReceivedCharacter(codepoint) => {
+        WindowEvent::ReceivedCharacter(codepoint) => {
             Some(input::Event::Keyboard(keyboard::Event::TextEntered {
                 character: codepoint,
             }))
         }
-        winit::event::WindowEvent::MouseInput { state, button, .. } => {
+        WindowEvent::MouseInput { state, button, .. } => {
             Some(input::Event::Mouse(mouse::Event::Input { state, button }))
         }
-        winit::event::WindowEvent::MouseWheel { delta, .. } => match delta {
+        WindowEvent::MouseWheel { delta, .. } => match delta {
             winit::event::MouseScrollDelta::LineDelta(x, y) => {
                 Some(input::Event::Mouse(mouse::Event::WheelScrolled {
                     delta_x: x,
@@ -188,24 +813,47 @@ fn try_into_input_event(
             }
             _ => None,
         },
-        winit::event::WindowEvent::CursorMoved { position, .. } => {
+        WindowEvent::CursorMoved { position, .. } => {
             Some(input::Event::Mouse(mouse::Event::CursorMoved {
                 x: position.x as f32,
                 y: position.y as f32,
             }))
         }
-        winit::event::WindowEvent::CursorEntered { .. } => {
+        WindowEvent::CursorEntered { .. } => {
             Some(input::Event::Mouse(mouse::Event::CursorEntered))
         }
-        winit::event::WindowEvent::CursorLeft { .. } => {
+        WindowEvent::CursorLeft { .. } => {
             Some(input::Event::Mouse(mouse::Event::CursorLeft))
         }
-        winit::event::WindowEvent::Focused(focus) => Some(if focus == true {
+        WindowEvent::Touch(winit::event::Touch {
+            phase,
+            location,
+            id,
+            ..
+        }) => {
+            let (x, y) = (location.x as f32, location.y as f32);
+
+            Some(input::Event::Touch(match phase {
+                winit::event::TouchPhase::Started => {
+                    touch::Event::Started { id, x, y }
+                }
+                winit::event::TouchPhase::Moved => {
+                    touch::Event::Moved { id, x, y }
+                }
+                winit::event::TouchPhase::Ended => {
+                    touch::Event::Ended { id, x, y }
+                }
+                winit::event::TouchPhase::Cancelled => {
+                    touch::Event::Cancelled { id }
+                }
+            }))
+        }
+        WindowEvent::Focused(focus) => Some(if focus == true {
             input::Event::Window(window::Event::Focused)
         } else {
             input::Event::Window(window::Event::Unfocused)
         }),
-        winit::event::WindowEvent::Moved(position) => {
+        WindowEvent::Moved(position) => {
             Some(input::Event::Window(window::Event::Moved {
                 x: position.x as f32,
                 y: position.y as f32,
@@ -240,6 +888,7 @@ where
         game: &mut Game,
         _input: &mut Game::Input,
         window: &mut Window,
+        _timer: &Timer,
         _debug: &mut Debug,
     ) {
         window.update_cursor(game.cursor_icon().try_into().ok());
@@ -0,0 +1,85 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use instant::Instant;
+
+// Tracks how often presented frames miss their expected interval
+pub(crate) struct Pacing {
+    expected: Duration,
+    last_present: Option<Instant>,
+    recent: VecDeque<bool>,
+    recent_missed: usize,
+    missed: u64,
+}
+
+impl Pacing {
+    const WINDOW: usize = 120;
+
+    // A frame is missed when it takes 50% longer than expected
+    const TOLERANCE: f32 = 1.5;
+
+    // We consider pacing broken when 1 in 5 recent frames are missed
+    const STRUGGLING_RATIO: f32 = 0.2;
+
+    pub fn new(refresh_rate: u16, max_frame_rate: Option<u16>) -> Pacing {
+        let frame_rate = match max_frame_rate {
+            Some(max) => max.min(refresh_rate),
+            None => refresh_rate,
+        };
+
+        Pacing {
+            expected: Duration::from_secs(1) / u32::from(frame_rate.max(1)),
+            last_present: None,
+            recent: VecDeque::with_capacity(Self::WINDOW),
+            recent_missed: 0,
+            missed: 0,
+        }
+    }
+
+    pub fn frame_presented(&mut self) {
+        let now = Instant::now();
+
+        if let Some(last_present) = self.last_present {
+            let missed =
+                now - last_present > self.expected.mul_f32(Self::TOLERANCE);
+
+            if self.recent.len() == Self::WINDOW {
+                if let Some(true) = self.recent.pop_front() {
+                    self.recent_missed -= 1;
+                }
+            }
+
+            self.recent.push_back(missed);
+
+            if missed {
+                self.recent_missed += 1;
+                self.missed += 1;
+            }
+        }
+
+        self.last_present = Some(now);
+    }
+
+    // Forgets the last frame, used when the game loop stops drawing for a
+    // while on purpose
+    pub fn reset(&mut self) {
+        self.last_present = None;
+    }
+
+    pub fn missed(&self) -> u64 {
+        self.missed
+    }
+
+    pub fn recent_ratio(&self) -> f32 {
+        if self.recent.is_empty() {
+            0.0
+        } else {
+            self.recent_missed as f32 / self.recent.len() as f32
+        }
+    }
+
+    pub fn is_struggling(&self) -> bool {
+        self.recent.len() == Self::WINDOW
+            && self.recent_ratio() >= Self::STRUGGLING_RATIO
+    }
+}
@@ -0,0 +1,177 @@
+use crate::game::r#loop::{self, Engine};
+use crate::graphics::window::winit;
+use crate::graphics::{Window, WindowSettings};
+use crate::{Game, Result};
+
+/// A [`Game`] driven by an event loop that you own.
+///
+/// [`Game::run`] creates and owns the event loop of your game. However, if
+/// you are embedding your game inside an editor or a launcher that already
+/// owns a [`winit`] event loop, you can use a [`Runtime`] instead.
+///
+/// A [`Runtime`] creates its own window in your event loop and loads the
+/// [`Game`] in it. Then, you need to forward the events of your event loop to
+/// it using [`handle`]. Events that belong to other windows are ignored.
+///
+/// Your event loop must use the same version of [`winit`] as Coffee. Enable
+/// the `raw-events` feature to access it through `coffee::winit`. If your
+/// event loop uses custom user events, convert the events with
+/// `Event::map_nonuser_event` before forwarding them.
+///
+/// # Example
+/// ```no_run
+/// # use coffee::graphics::{Frame, Window, WindowSettings};
+/// # use coffee::load::Task;
+/// # use coffee::{Game, Timer};
+/// # use winit::event_loop::ControlFlow;
+/// # use winit::platform::desktop::EventLoopExtDesktop;
+/// # struct MyGame;
+/// # impl Game for MyGame {
+/// #     type Input = ();
+/// #     type LoadingScreen = ();
+/// #     fn load(_window: &Window) -> Task<MyGame> {
+/// #         Task::succeed(|| MyGame)
+/// #     }
+/// #     fn draw(&mut self, _frame: &mut Frame, _timer: &Timer) {}
+/// # }
+/// use coffee::Runtime;
+///
+/// let mut event_loop = winit::event_loop::EventLoop::new();
+///
+/// let mut runtime = Runtime::<MyGame>::new(
+///     WindowSettings {
+///         title: String::from("Embedded game"),
+///         size: (800, 600),
+///         ..Default::default()
+///     },
+///     &event_loop,
+/// )
+/// .expect("Load game");
+///
+/// event_loop.run_return(|event, _, control_flow| {
+///     // Handle your own events here...
+///
+///     runtime.handle(event);
+///
+///     if runtime.is_finished() {
+///         *control_flow = ControlFlow::Exit;
+///     } else {
+///         *control_flow = ControlFlow::Poll;
+///     }
+/// });
+///
+/// runtime.exit().expect("Exit game");
+/// ```
+///
+/// [`Game`]: trait.Game.html
+/// [`Game::run`]: trait.Game.html#method.run
+/// [`winit`]: https://docs.rs/winit/0.22
+/// [`Runtime`]: struct.Runtime.html
+/// [`handle`]: #method.handle
+pub struct Runtime<G: 'static + Game> {
+    engine: Engine<G, r#loop::Default>,
+}
+
+impl<G: 'static + Game> Runtime<G> {
+    /// Creates a new window with the given [`WindowSettings`] in the provided
+    /// event loop, and loads the [`Game`] in it.
+    ///
+    /// The loading screen of the [`Game`] is shown while loading. This blocks
+    /// until the [`Game`] is loaded.
+    ///
+    /// [`WindowSettings`]: graphics/struct.WindowSettings.html
+    /// [`Game`]: trait.Game.html
+    pub fn new(
+        window_settings: WindowSettings,
+        event_loop: &winit::event_loop::EventLoop<()>,
+    ) -> Result<Runtime<G>> {
+        Ok(Runtime {
+            engine: Engine::new(window_settings, event_loop)?,
+        })
+    }
+
+    /// Processes an event of the event loop.
+    ///
+    /// The [`Game`] is updated on `MainEventsCleared` and drawn on
+    /// `RedrawRequested`. Therefore, you do not need to call [`update`] or
+    /// [`draw`] when you forward all your events.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`update`]: #method.update
+    /// [`draw`]: #method.draw
+    pub fn handle(&mut self, event: winit::event::Event<'_, ()>) {
+        self.engine.handle(event);
+    }
+
+    /// Runs a single iteration of the game loop, processing input and
+    /// updating the [`Game`] if its timer ticks.
+    ///
+    /// [`Game`]: trait.Game.html
+    pub fn update(&mut self) {
+        self.engine.update();
+    }
+
+    /// Draws a frame of the [`Game`] and presents it.
+    ///
+    /// [`Game`]: trait.Game.html
+    pub fn draw(&mut self) {
+        self.engine.draw();
+    }
+
+    /// Returns the control flow that the [`Game`] would use if it owned the
+    /// event loop.
+    ///
+    /// [`Game`]: trait.Game.html
+    pub fn control_flow(&self) -> winit::event_loop::ControlFlow {
+        self.engine.control_flow()
+    }
+
+    /// Returns a reference to the [`Game`].
+    ///
+    /// [`Game`]: trait.Game.html
+    pub fn game(&self) -> &G {
+        self.engine.game()
+    }
+
+    /// Returns a mutable reference to the [`Game`].
+    ///
+    /// [`Game`]: trait.Game.html
+    pub fn game_mut(&mut self) -> &mut G {
+        self.engine.game_mut()
+    }
+
+    /// Returns a mutable reference to the [`Window`] of the [`Game`].
+    ///
+    /// [`Window`]: graphics/struct.Window.html
+    /// [`Game`]: trait.Game.html
+    pub fn window(&mut self) -> &mut Window {
+        self.engine.window()
+    }
+
+    /// Returns whether the [`Game`] is finished or not.
+    ///
+    /// Once finished, the [`Runtime`] ignores any further events and you
+    /// should call [`exit`].
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`Runtime`]: struct.Runtime.html
+    /// [`exit`]: #method.exit
+    pub fn is_finished(&self) -> bool {
+        self.engine.is_finished()
+    }
+
+    /// Exits the [`Game`], calling [`Game::on_exit`] and returning the error
+    /// that stopped it, if any.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`Game::on_exit`]: trait.Game.html#method.on_exit
+    pub fn exit(self) -> Result<()> {
+        self.engine.exit()
+    }
+}
+
+impl<G: 'static + Game> std::fmt::Debug for Runtime<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Runtime {{ finished: {} }}", self.engine.is_finished())
+    }
+}
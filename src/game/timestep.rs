@@ -0,0 +1,29 @@
+/// The timestep of a [`Game`].
+///
+/// [`Game`]: trait.Game.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timestep {
+    /// The game is updated [`Game::TICKS_PER_SECOND`] times every second
+    /// using [`Game::update`], independently of the frame rate.
+    ///
+    /// This makes the game logic deterministic.
+    ///
+    /// [`Game::TICKS_PER_SECOND`]: trait.Game.html#associatedconstant.TICKS_PER_SECOND
+    /// [`Game::update`]: trait.Game.html#method.update
+    Fixed,
+
+    /// The game is updated once every frame using [`Game::update_variable`],
+    /// which receives the time elapsed since the last frame.
+    ///
+    /// This can be simpler for games that do not need deterministic logic,
+    /// like visual novels or tools.
+    ///
+    /// [`Game::update_variable`]: trait.Game.html#method.update_variable
+    Variable,
+}
+
+impl Default for Timestep {
+    fn default() -> Timestep {
+        Timestep::Fixed
+    }
+}
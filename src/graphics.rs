@@ -85,6 +85,11 @@
 //! [`Game::draw`]: ../trait.Game.html#tymethod.draw
 //! [`Game::load`]: ../trait.Game.html#tymethod.load
 
+#[cfg(all(feature = "opengl", target_os = "android"))]
+compile_error!(
+    "The `opengl` backend does not support Android, use `vulkan` instead"
+);
+
 #[cfg(feature = "opengl")]
 mod backend_gfx;
 #[cfg(feature = "opengl")]
@@ -105,40 +110,68 @@ mod backend_wgpu;
 ))]
 use backend_wgpu as gpu;
 
+mod atlas;
 mod batch;
 mod canvas;
 mod color;
+mod crt;
 mod font;
 mod image;
+mod memory;
 mod mesh;
+mod multi_batch;
+mod palette;
 mod point;
 mod quad;
 mod rectangle;
+mod shader;
 mod shape;
 mod sprite;
 mod target;
 mod text;
+mod trail;
 mod transformation;
 mod vector;
 
 pub mod texture_array;
 pub(crate) mod window;
 
+#[cfg(feature = "debug-physics")]
+pub mod debug_physics;
+
+#[cfg(feature = "json")]
+mod aseprite;
+
+#[cfg(feature = "json")]
+pub use aseprite::Aseprite;
+
 pub use self::image::Image;
+pub use atlas::Atlas;
 pub use batch::Batch;
 pub use canvas::Canvas;
 pub use color::Color;
+pub use crt::Crt;
 pub use font::Font;
 pub use gpu::Gpu;
+pub use memory::MemoryUsage;
 pub use mesh::Mesh;
+pub use multi_batch::MultiBatch;
+pub use palette::Palette;
 pub use point::Point;
-pub use quad::{IntoQuad, Quad};
+pub use quad::{IntoQuad, Packing, Quad};
 pub use rectangle::Rectangle;
+pub use shader::{Shader, ShaderKind, Uniform};
 pub use shape::Shape;
 pub use sprite::Sprite;
 pub use target::Target;
 pub use text::{HorizontalAlignment, Text, VerticalAlignment};
 pub use texture_array::TextureArray;
+pub use trail::Trail;
 pub use transformation::Transformation;
 pub use vector::Vector;
-pub use window::{CursorIcon, Frame, Settings as WindowSettings, Window};
+#[cfg(feature = "clipboard")]
+pub use window::Clipboard;
+pub use window::{
+    CursorIcon, Frame, Geometry as WindowGeometry, LinuxBackend, Orientation,
+    PresentMode, Settings as WindowSettings, Window,
+};
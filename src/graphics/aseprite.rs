@@ -0,0 +1,321 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::anim::{Animation, Frame, Repeat};
+use crate::assets;
+use crate::graphics::{Batch, Image, Rectangle};
+use crate::load::Task;
+use crate::{Error, Result};
+
+/// A spritesheet exported from [Aseprite].
+///
+/// An [`Aseprite`] sheet is loaded from the JSON data file exported together
+/// with its image. Both the `Hash` and `Array` layouts are supported. The
+/// frame durations, tags, and slices authored in Aseprite are kept, so you can
+/// obtain ready-to-play [`Animation`] values from it.
+///
+/// This is only available when the `json` feature is enabled.
+///
+/// ```
+/// # #[cfg(feature = "json")]
+/// # {
+/// use coffee::graphics::Aseprite;
+/// use coffee::load::Task;
+///
+/// let load_player: Task<Aseprite> = Aseprite::load("player.json");
+/// # }
+/// ```
+///
+/// [Aseprite]: https://www.aseprite.org
+/// [`Aseprite`]: struct.Aseprite.html
+/// [`Animation`]: ../anim/struct.Animation.html
+#[derive(Debug, Clone)]
+pub struct Aseprite {
+    image: Image,
+    sheet: Sheet,
+}
+
+#[derive(Debug, Clone)]
+struct Sheet {
+    image: PathBuf,
+    frames: Vec<Frame>,
+    tags: Vec<Tag>,
+    slices: Vec<Slice>,
+}
+
+#[derive(Debug, Clone)]
+struct Tag {
+    name: String,
+    from: usize,
+    to: usize,
+    direction: Direction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Forward,
+    Reverse,
+    PingPong,
+    PingPongReverse,
+}
+
+#[derive(Debug, Clone)]
+struct Slice {
+    name: String,
+    keys: Vec<(usize, Rectangle<u16>)>,
+}
+
+impl Aseprite {
+    /// Creates a [`Task`] that loads an [`Aseprite`] sheet from the JSON data
+    /// file in the given path.
+    ///
+    /// The image of the sheet is loaded from the path stored in the data file,
+    /// relative to the data file itself.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Aseprite`]: struct.Aseprite.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<Aseprite> {
+        let path = path.into();
+
+        Task::offload(move || Sheet::read(&path)).and_then(|sheet| {
+            Image::load(sheet.image.clone())
+                .map(move |image| Aseprite { image, sheet })
+        })
+    }
+
+    /// Returns the [`Image`] of the [`Aseprite`] sheet.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`Aseprite`]: struct.Aseprite.html
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Creates a new [`Batch`] using the [`Image`] of the [`Aseprite`] sheet.
+    ///
+    /// [`Batch`]: struct.Batch.html
+    /// [`Image`]: struct.Image.html
+    /// [`Aseprite`]: struct.Aseprite.html
+    pub fn batch(&self) -> Batch {
+        Batch::new(self.image.clone())
+    }
+
+    /// Returns all the frames of the [`Aseprite`] sheet, in order.
+    ///
+    /// [`Aseprite`]: struct.Aseprite.html
+    pub fn frames(&self) -> &[Frame] {
+        &self.sheet.frames
+    }
+
+    /// Returns the names of the tags of the [`Aseprite`] sheet.
+    ///
+    /// [`Aseprite`]: struct.Aseprite.html
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        self.sheet.tags.iter().map(|tag| tag.name.as_str())
+    }
+
+    /// Returns a looping [`Animation`] of every frame of the [`Aseprite`]
+    /// sheet.
+    ///
+    /// [`Animation`]: ../anim/struct.Animation.html
+    /// [`Aseprite`]: struct.Aseprite.html
+    pub fn animation(&self) -> Animation {
+        Animation::new(self.sheet.frames.clone())
+    }
+
+    /// Returns a looping [`Animation`] of the frames of the tag with the given
+    /// name, following its direction.
+    ///
+    /// Returns `None` if the [`Aseprite`] sheet has no such tag.
+    ///
+    /// [`Animation`]: ../anim/struct.Animation.html
+    /// [`Aseprite`]: struct.Aseprite.html
+    pub fn tag(&self, name: &str) -> Option<Animation> {
+        let tag = self.sheet.tags.iter().find(|tag| tag.name == name)?;
+
+        let mut frames: Vec<Frame> = self
+            .sheet
+            .frames
+            .iter()
+            .skip(tag.from)
+            .take((tag.to + 1).saturating_sub(tag.from))
+            .copied()
+            .collect();
+
+        let repeat = match tag.direction {
+            Direction::Forward => Repeat::Loop,
+            Direction::Reverse => {
+                frames.reverse();
+                Repeat::Loop
+            }
+            Direction::PingPong => Repeat::PingPong,
+            Direction::PingPongReverse => {
+                frames.reverse();
+                Repeat::PingPong
+            }
+        };
+
+        Some(Animation::new(frames).repeat(repeat))
+    }
+
+    /// Returns the bounds of the slice with the given name at the given frame.
+    ///
+    /// Returns `None` if the [`Aseprite`] sheet has no such slice or if it
+    /// does not exist yet at the given frame.
+    ///
+    /// [`Aseprite`]: struct.Aseprite.html
+    pub fn slice(&self, name: &str, frame: usize) -> Option<Rectangle<u16>> {
+        let slice =
+            self.sheet.slices.iter().find(|slice| slice.name == name)?;
+
+        // A key applies from its frame until the next key
+        slice
+            .keys
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= frame)
+            .map(|(_, bounds)| *bounds)
+    }
+}
+
+impl Sheet {
+    fn read(path: &Path) -> Result<Sheet> {
+        let read = || -> Result<Sheet> {
+            let bytes = assets::read(path)?;
+
+            let data: Value = serde_json::from_slice(&bytes)
+                .map_err(|error| Error::Deserialization(Box::new(error)))?;
+
+            Sheet::parse(&data, path.parent().unwrap_or(Path::new("")))
+                .map_err(|error| Error::Deserialization(error.into()))
+        };
+
+        read().map_err(|error| Error::Asset {
+            path: path.to_path_buf(),
+            source: Box::new(error),
+        })
+    }
+
+    fn parse(
+        data: &Value,
+        directory: &Path,
+    ) -> std::result::Result<Sheet, String> {
+        let meta = &data["meta"];
+
+        let image = meta["image"]
+            .as_str()
+            .ok_or_else(|| String::from("missing meta.image"))?;
+
+        let frames: Vec<&Value> = match &data["frames"] {
+            Value::Array(frames) => frames.iter().collect(),
+            Value::Object(frames) => {
+                let mut frames: Vec<(&String, &Value)> =
+                    frames.iter().collect();
+
+                // Object keys are sorted alphabetically, so we sort them again
+                // by the frame number in their name ("sheet 10.aseprite")
+                frames.sort_by_key(|(name, _)| frame_number(name));
+                frames.into_iter().map(|(_, frame)| frame).collect()
+            }
+            _ => Vec::new(),
+        };
+
+        let frames = frames
+            .into_iter()
+            .map(|frame| {
+                Ok(Frame {
+                    source: rectangle(&frame["frame"])?,
+                    duration: Duration::from_millis(
+                        frame["duration"].as_u64().unwrap_or(100),
+                    ),
+                })
+            })
+            .collect::<std::result::Result<Vec<_>, String>>()?;
+
+        let tags = array(&meta["frameTags"])
+            .map(|tag| {
+                Ok(Tag {
+                    name: string(&tag["name"])?,
+                    from: index(&tag["from"])?,
+                    to: index(&tag["to"])?,
+                    direction: match tag["direction"].as_str() {
+                        Some("reverse") => Direction::Reverse,
+                        Some("pingpong") => Direction::PingPong,
+                        Some("pingpong_reverse") => Direction::PingPongReverse,
+                        _ => Direction::Forward,
+                    },
+                })
+            })
+            .collect::<std::result::Result<_, String>>()?;
+
+        let slices = array(&meta["slices"])
+            .map(|slice| {
+                Ok(Slice {
+                    name: string(&slice["name"])?,
+                    keys: array(&slice["keys"])
+                        .map(|key| {
+                            Ok((
+                                index(&key["frame"])?,
+                                rectangle(&key["bounds"])?,
+                            ))
+                        })
+                        .collect::<std::result::Result<_, String>>()?,
+                })
+            })
+            .collect::<std::result::Result<_, String>>()?;
+
+        Ok(Sheet {
+            image: directory.join(image),
+            frames,
+            tags,
+            slices,
+        })
+    }
+}
+
+fn frame_number(name: &str) -> Option<usize> {
+    name.rsplit(|c: char| !c.is_ascii_digit())
+        .find(|digits| !digits.is_empty())
+        .and_then(|digits| digits.parse().ok())
+}
+
+fn array(value: &Value) -> impl Iterator<Item = &Value> {
+    value
+        .as_array()
+        .map(|values| values.iter())
+        .into_iter()
+        .flatten()
+}
+
+fn string(value: &Value) -> std::result::Result<String, String> {
+    value
+        .as_str()
+        .map(String::from)
+        .ok_or_else(|| format!("expected a string, found {}", value))
+}
+
+fn index(value: &Value) -> std::result::Result<usize, String> {
+    value
+        .as_u64()
+        .map(|index| index as usize)
+        .ok_or_else(|| format!("expected a frame index, found {}", value))
+}
+
+fn rectangle(value: &Value) -> std::result::Result<Rectangle<u16>, String> {
+    let coordinate = |key| {
+        value[key]
+            .as_u64()
+            .filter(|coordinate| *coordinate <= u64::from(u16::MAX))
+            .map(|coordinate| coordinate as u16)
+            .ok_or_else(|| format!("expected a rectangle, found {}", value))
+    };
+
+    Ok(Rectangle {
+        x: coordinate("x")?,
+        y: coordinate("y")?,
+        width: coordinate("w")?,
+        height: coordinate("h")?,
+    })
+}
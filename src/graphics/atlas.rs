@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::assets;
+use crate::graphics::{Batch, Image, Rectangle};
+use crate::load::Task;
+use crate::{Error, Result};
+
+/// An [`Image`] packed at load time from a folder of smaller images.
+///
+/// An [`Atlas`] lets you draw many different sprites with a single [`Batch`]
+/// without preparing a spritesheet with an external tool. Every packed image
+/// is named after its path, relative to the folder of the pattern and without
+/// extension, and its name gives you the `source` of a [`Sprite`]:
+///
+/// ```
+/// use coffee::graphics::{Atlas, Point, Sprite};
+/// use coffee::load::Task;
+///
+/// let load_atlas: Task<Atlas> = Atlas::load_dir("sprites/**/*.png");
+///
+/// # fn goblin(atlas: &Atlas) -> Option<Sprite> {
+/// // Given "sprites/enemies/goblin.png"
+/// let goblin = Sprite {
+///     source: atlas.get("enemies/goblin")?,
+///     position: Point::new(100.0, 100.0),
+///     ..Sprite::default()
+/// };
+/// # Some(goblin)
+/// # }
+/// ```
+///
+/// The packing is meant for small projects. For big spritesheets, consider
+/// a [`TextureArray`] or an external tool.
+///
+/// [`Image`]: struct.Image.html
+/// [`Atlas`]: struct.Atlas.html
+/// [`Batch`]: struct.Batch.html
+/// [`Sprite`]: struct.Sprite.html
+/// [`TextureArray`]: texture_array/struct.TextureArray.html
+#[derive(Debug, Clone)]
+pub struct Atlas {
+    image: Image,
+    sources: HashMap<String, Rectangle<u16>>,
+}
+
+impl Atlas {
+    // Keeps the edges of an image from bleeding into its neighbors
+    const SPACING: u32 = 1;
+
+    /// Creates a [`Task`] that packs all the images matching the given glob
+    /// pattern into an [`Atlas`].
+    ///
+    /// The images are decoded and packed on a background thread. The paths
+    /// are matched using [`assets::glob`].
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Atlas`]: struct.Atlas.html
+    /// [`assets::glob`]: ../assets/fn.glob.html
+    pub fn load_dir(pattern: &str) -> Task<Atlas> {
+        let pattern = String::from(pattern);
+
+        Task::offload_then(
+            move || pack(&pattern),
+            |gpu, (image, sources)| {
+                let image = Image::from_image(
+                    gpu,
+                    &image::DynamicImage::ImageRgba8(image),
+                )?;
+
+                Ok(Atlas { image, sources })
+            },
+        )
+    }
+
+    /// Returns the [`Image`] of the [`Atlas`].
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`Atlas`]: struct.Atlas.html
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Creates a new [`Batch`] using the [`Image`] of the [`Atlas`].
+    ///
+    /// [`Batch`]: struct.Batch.html
+    /// [`Image`]: struct.Image.html
+    /// [`Atlas`]: struct.Atlas.html
+    pub fn batch(&self) -> Batch {
+        Batch::new(self.image.clone())
+    }
+
+    /// Returns the region of the [`Atlas`] containing the image with the given
+    /// name, if any.
+    ///
+    /// [`Atlas`]: struct.Atlas.html
+    pub fn get(&self, name: &str) -> Option<Rectangle<u16>> {
+        self.sources.get(name).copied()
+    }
+
+    /// Returns the region of every image in the [`Atlas`] by name.
+    ///
+    /// [`Atlas`]: struct.Atlas.html
+    pub fn sources(&self) -> &HashMap<String, Rectangle<u16>> {
+        &self.sources
+    }
+}
+
+fn pack(
+    pattern: &str,
+) -> Result<(image::RgbaImage, HashMap<String, Rectangle<u16>>)> {
+    let paths = assets::glob(pattern).map_err(|error| Error::Asset {
+        path: PathBuf::from(pattern),
+        source: Box::new(error.into()),
+    })?;
+
+    let base = assets::glob::Pattern::new(pattern).base();
+
+    let mut images = paths
+        .iter()
+        .map(|path| Ok((name(&base, path), Image::decode(path)?.to_rgba())))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Taller images first, so every shelf wastes less space
+    images.sort_by(|(_, a), (_, b)| b.height().cmp(&a.height()));
+
+    let area: u32 = images
+        .iter()
+        .map(|(_, image)| {
+            (image.width() + Atlas::SPACING) * (image.height() + Atlas::SPACING)
+        })
+        .sum();
+
+    let widest = images
+        .iter()
+        .map(|(_, image)| image.width())
+        .max()
+        .unwrap_or(1);
+
+    let width = ((area as f32).sqrt().ceil() as u32)
+        .next_power_of_two()
+        .max(widest);
+
+    let mut positions = Vec::with_capacity(images.len());
+    let (mut x, mut y, mut shelf_height) = (0, 0, 0);
+
+    for (_, image) in &images {
+        if x + image.width() > width {
+            x = 0;
+            y += shelf_height + Atlas::SPACING;
+            shelf_height = 0;
+        }
+
+        positions.push((x, y));
+
+        x += image.width() + Atlas::SPACING;
+        shelf_height = shelf_height.max(image.height());
+    }
+
+    let mut atlas = image::RgbaImage::new(width, (y + shelf_height).max(1));
+    let mut sources = HashMap::with_capacity(images.len());
+
+    for ((name, image), (x, y)) in images.into_iter().zip(positions) {
+        image::imageops::overlay(&mut atlas, &image, x, y);
+
+        let _ = sources.insert(
+            name,
+            Rectangle {
+                x: x as u16,
+                y: y as u16,
+                width: image.width() as u16,
+                height: image.height() as u16,
+            },
+        );
+    }
+
+    Ok((atlas, sources))
+}
+
+fn name(base: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(base).unwrap_or(path).with_extension("");
+
+    relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
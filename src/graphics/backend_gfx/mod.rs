@@ -1,12 +1,15 @@
 mod font;
 mod format;
+mod program;
 mod quad;
 mod surface;
 pub mod texture;
 mod triangle;
 mod types;
+mod validation;
 
 pub use font::Font;
+pub use program::Program;
 pub use quad::Quad;
 pub use surface::Surface;
 pub use texture::Texture;
@@ -16,8 +19,11 @@ pub use types::TargetView;
 use gfx::{self, Device};
 use gfx_device_gl as gl;
 
-use crate::graphics::{Color, Transformation};
-use crate::Result;
+use crate::graphics::memory;
+use crate::graphics::{
+    Color, Crt, MemoryUsage, PresentMode, ShaderKind, Transformation,
+};
+use crate::{Error, Result};
 
 /// A link between your game and a graphics processor.
 ///
@@ -36,27 +42,58 @@ pub struct Gpu {
     encoder: gfx::Encoder<gl::Resources, gl::CommandBuffer>,
     triangle_pipeline: triangle::Pipeline,
     quad_pipeline: quad::Pipeline,
+    memory: memory::Tracker,
+    adapter: String,
 }
 
 impl Gpu {
     pub(super) fn for_window(
         builder: winit::window::WindowBuilder,
         events_loop: &winit::event_loop::EventLoop<()>,
+        present_mode: PresentMode,
+        validation: bool,
     ) -> Result<(Gpu, Surface)> {
-        let (surface, device, mut factory) =
-            Surface::new(builder, events_loop)?;
+        let (surface, mut device, mut factory) =
+            Surface::new(builder, events_loop, present_mode, validation)?;
+
+        if validation {
+            validation::enable(&mut device);
+        }
+
+        let info = device.get_info();
+
+        log::info!(
+            "Using OpenGL {:?} on {} ({})",
+            info.version,
+            info.platform_name.renderer,
+            info.platform_name.vendor
+        );
+
+        let adapter = format!(
+            "{} ({}, OpenGL {:?})",
+            info.platform_name.renderer,
+            info.platform_name.vendor,
+            info.version
+        );
 
         let mut encoder: gfx::Encoder<gl::Resources, gl::CommandBuffer> =
             factory.create_command_buffer().into();
 
+        let memory = memory::Tracker::new();
+
         let triangle_pipeline = triangle::Pipeline::new(
             &mut factory,
+            &memory,
             &mut encoder,
-            surface.target(),
-        );
+            surface.view(),
+        )?;
 
-        let quad_pipeline =
-            quad::Pipeline::new(&mut factory, &mut encoder, surface.target());
+        let quad_pipeline = quad::Pipeline::new(
+            &mut factory,
+            &memory,
+            &mut encoder,
+            surface.view(),
+        )?;
 
         Ok((
             Gpu {
@@ -65,6 +102,8 @@ impl Gpu {
                 encoder,
                 triangle_pipeline,
                 quad_pipeline,
+                memory,
+                adapter,
             },
             surface,
         ))
@@ -88,18 +127,36 @@ impl Gpu {
         self.device.cleanup();
     }
 
+    pub(super) fn reset(&mut self, _surface: &mut Surface) -> Result<()> {
+        // glutin cannot create a new OpenGL context for an existing window,
+        // so a lost context cannot be recovered
+        Err(Error::SurfaceLost)
+    }
+
+    pub(crate) fn adapter(&self) -> &str {
+        &self.adapter
+    }
+
+    /// Returns an estimation of the GPU memory used by the live resources
+    /// created with this [`Gpu`].
+    ///
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.memory.usage()
+    }
+
     pub(super) fn upload_texture(
         &mut self,
         image: &image::DynamicImage,
     ) -> Texture {
-        Texture::new(&mut self.factory, image)
+        Texture::new(&mut self.factory, &self.memory, image)
     }
 
     pub(super) fn upload_texture_array(
         &mut self,
         layers: &[image::DynamicImage],
     ) -> Texture {
-        Texture::new_array(&mut self.factory, layers)
+        Texture::new_array(&mut self.factory, &self.memory, layers)
     }
 
     pub(super) fn create_drawable_texture(
@@ -107,7 +164,7 @@ impl Gpu {
         width: u16,
         height: u16,
     ) -> texture::Drawable {
-        texture::Drawable::new(&mut self.factory, width, height)
+        texture::Drawable::new(&mut self.factory, &self.memory, width, height)
     }
 
     pub(super) fn read_drawable_texture_pixels(
@@ -119,6 +176,15 @@ impl Gpu {
         drawable.read_pixels(&mut self.device, &mut self.factory)
     }
 
+    pub(super) fn create_program(
+        &mut self,
+        kind: ShaderKind,
+        glsl: &str,
+        _spirv: &[u8],
+    ) -> Result<Program> {
+        Program::new(&mut self.factory, kind, glsl)
+    }
+
     pub(super) fn upload_font(&mut self, bytes: &'static [u8]) -> Font {
         Font::from_bytes(&mut self.factory, bytes)
     }
@@ -132,7 +198,9 @@ impl Gpu {
     ) {
         self.triangle_pipeline.draw(
             &mut self.factory,
+            &self.memory,
             &mut self.encoder,
+            triangle::Mode::Regular,
             vertices,
             indices,
             transformation,
@@ -140,6 +208,29 @@ impl Gpu {
         );
     }
 
+    pub(super) fn draw_shader_triangles(
+        &mut self,
+        program: &Program,
+        uniforms: &[[f32; 4]; 8],
+        vertices: &[Vertex],
+        indices: &[u32],
+        view: &TargetView,
+        transformation: &Transformation,
+    ) {
+        if let Program::Mesh(shader) = program {
+            self.triangle_pipeline.draw(
+                &mut self.factory,
+                &self.memory,
+                &mut self.encoder,
+                triangle::Mode::Custom(shader, uniforms.into()),
+                vertices,
+                indices,
+                transformation,
+                view,
+            );
+        }
+    }
+
     pub(super) fn draw_texture_quads(
         &mut self,
         texture: &Texture,
@@ -151,12 +242,73 @@ impl Gpu {
 
         self.quad_pipeline.draw_textured(
             &mut self.encoder,
+            quad::Mode::Regular,
+            instances,
+            transformation,
+            view,
+        );
+    }
+
+    pub(super) fn draw_palette_quads(
+        &mut self,
+        texture: &Texture,
+        palette: &Texture,
+        instances: &[Quad],
+        view: &TargetView,
+        transformation: &Transformation,
+    ) {
+        self.quad_pipeline.bind_texture(texture);
+
+        self.quad_pipeline.draw_textured(
+            &mut self.encoder,
+            quad::Mode::Palette(palette),
             instances,
             transformation,
             view,
         );
     }
 
+    pub(super) fn draw_shader_quads(
+        &mut self,
+        texture: &Texture,
+        program: &Program,
+        uniforms: &[[f32; 4]; 8],
+        instances: &[Quad],
+        view: &TargetView,
+        transformation: &Transformation,
+    ) {
+        if let Program::Quad(shader) = program {
+            self.quad_pipeline.bind_texture(texture);
+
+            self.quad_pipeline.draw_textured(
+                &mut self.encoder,
+                quad::Mode::Custom(shader, uniforms.into()),
+                instances,
+                transformation,
+                view,
+            );
+        }
+    }
+
+    pub(super) fn draw_crt_quad(
+        &mut self,
+        texture: &Texture,
+        instance: Quad,
+        crt: &Crt,
+        view: &TargetView,
+        transformation: &Transformation,
+    ) {
+        self.quad_pipeline.bind_texture(texture);
+
+        self.quad_pipeline.draw_textured(
+            &mut self.encoder,
+            quad::Mode::Crt(quad::Crt::new(crt, texture, &instance)),
+            &[instance],
+            transformation,
+            view,
+        );
+    }
+
     pub(super) fn draw_font(
         &mut self,
         font: &mut Font,
@@ -0,0 +1,62 @@
+use gfx::*;
+use gfx_device_gl as gl;
+
+use super::{quad, triangle};
+use crate::graphics::ShaderKind;
+use crate::Result;
+
+gfx_defines! {
+    constant Uniforms {
+        uniform0: [f32; 4] = "u_Uniform0",
+        uniform1: [f32; 4] = "u_Uniform1",
+        uniform2: [f32; 4] = "u_Uniform2",
+        uniform3: [f32; 4] = "u_Uniform3",
+        uniform4: [f32; 4] = "u_Uniform4",
+        uniform5: [f32; 4] = "u_Uniform5",
+        uniform6: [f32; 4] = "u_Uniform6",
+        uniform7: [f32; 4] = "u_Uniform7",
+    }
+}
+
+// A fragment shader provided by the user, linked with the vertex shader of
+// the pipeline it replaces
+pub enum Program {
+    Quad(quad::Shader<quad::custom_pipe::Meta>),
+    Mesh(triangle::Shader<triangle::custom_pipe::Meta>),
+}
+
+impl Program {
+    pub fn new(
+        factory: &mut gl::Factory,
+        kind: ShaderKind,
+        glsl: &str,
+    ) -> Result<Program> {
+        Ok(match kind {
+            ShaderKind::Quad => Program::Quad(quad::Shader::new(
+                factory,
+                glsl.as_bytes(),
+                quad::custom_init(),
+            )?),
+            ShaderKind::Mesh => Program::Mesh(triangle::Shader::new(
+                factory,
+                glsl.as_bytes(),
+                triangle::custom_init(),
+            )?),
+        })
+    }
+}
+
+impl From<&[[f32; 4]; 8]> for Uniforms {
+    fn from(uniforms: &[[f32; 4]; 8]) -> Uniforms {
+        Uniforms {
+            uniform0: uniforms[0],
+            uniform1: uniforms[1],
+            uniform2: uniforms[2],
+            uniform3: uniforms[3],
+            uniform4: uniforms[4],
+            uniform5: uniforms[5],
+            uniform6: uniforms[6],
+            uniform7: uniforms[7],
+        }
+    }
+}
@@ -1,10 +1,15 @@
+use std::mem;
+
 use gfx::traits::FactoryExt;
 use gfx::{self, *};
 use gfx_device_gl as gl;
 
 use super::format;
+use super::program::Uniforms;
 use super::texture::Texture;
+use crate::graphics::memory::{self, Allocation, Resource};
 use crate::graphics::{self, Transformation};
+use crate::{Error, Result};
 
 const MAX_INSTANCES: u32 = 100_000;
 const QUAD_INDICES: [u16; 6] = [0, 1, 2, 0, 2, 3];
@@ -34,12 +39,22 @@ gfx_defines! {
         translation: [f32; 2] = "a_Translation",
         scale: [f32; 2] = "a_Scale",
         layer: u32 = "t_Layer",
+        color: [f32; 4] = "a_Color",
     }
 
     constant Globals {
         mvp: [[f32; 4]; 4] = "u_MVP",
     }
 
+    constant Crt {
+        source: [f32; 4] = "u_Source",
+        resolution: [f32; 2] = "u_Resolution",
+        scanlines: f32 = "u_Scanlines",
+        curvature: f32 = "u_Curvature",
+        pixel_grid: f32 = "u_PixelGrid",
+        blur: f32 = "u_Blur",
+    }
+
     pipeline pipe {
         vertices: gfx::VertexBuffer<Vertex> = (),
         texture: gfx::TextureSampler<[f32; 4]> = "t_Texture",
@@ -53,21 +68,81 @@ gfx_defines! {
                Some(gfx::preset::blend::ALPHA)
           ),
     }
+
+    pipeline palette_pipe {
+        vertices: gfx::VertexBuffer<Vertex> = (),
+        texture: gfx::TextureSampler<[f32; 4]> = "t_Texture",
+        palette: gfx::TextureSampler<[f32; 4]> = "t_Palette",
+        globals: gfx::ConstantBuffer<Globals> = "Globals",
+        instances: gfx::InstanceBuffer<Quad> = (),
+        out: gfx::RawRenderTarget =
+          (
+              "Target0",
+               format::COLOR,
+               gfx::state::ColorMask::all(),
+               Some(gfx::preset::blend::ALPHA)
+          ),
+    }
+
+    pipeline custom_pipe {
+        vertices: gfx::VertexBuffer<Vertex> = (),
+        texture: gfx::TextureSampler<[f32; 4]> = "t_Texture",
+        globals: gfx::ConstantBuffer<Globals> = "Globals",
+        uniforms: gfx::ConstantBuffer<Uniforms> = "Uniforms",
+        instances: gfx::InstanceBuffer<Quad> = (),
+        out: gfx::RawRenderTarget =
+          (
+              "Target0",
+               format::COLOR,
+               gfx::state::ColorMask::all(),
+               Some(gfx::preset::blend::ALPHA)
+          ),
+    }
+
+    pipeline crt_pipe {
+        vertices: gfx::VertexBuffer<Vertex> = (),
+        texture: gfx::TextureSampler<[f32; 4]> = "t_Texture",
+        globals: gfx::ConstantBuffer<Globals> = "Globals",
+        crt: gfx::ConstantBuffer<Crt> = "Crt",
+        instances: gfx::InstanceBuffer<Quad> = (),
+        out: gfx::RawRenderTarget =
+          (
+              "Target0",
+               format::COLOR,
+               gfx::state::ColorMask::all(),
+               Some(gfx::preset::blend::ALPHA)
+          ),
+    }
+}
+
+// The fragment shader used to draw some quads
+pub enum Mode<'a> {
+    Regular,
+    Palette(&'a Texture),
+    Crt(Crt),
+    Custom(&'a Shader<custom_pipe::Meta>, Uniforms),
 }
 
 pub struct Pipeline {
     slice: gfx::Slice<gl::Resources>,
     data: pipe::Data<gl::Resources>,
-    shader: Shader,
+    shader: Shader<pipe::Meta>,
+    palette_data: palette_pipe::Data<gl::Resources>,
+    palette_shader: Shader<palette_pipe::Meta>,
+    crt_data: crt_pipe::Data<gl::Resources>,
+    crt_shader: Shader<crt_pipe::Meta>,
+    custom_data: custom_pipe::Data<gl::Resources>,
     globals: Globals,
+    _allocation: Allocation,
 }
 
 impl Pipeline {
     pub fn new(
         factory: &mut gl::Factory,
+        memory: &memory::Tracker,
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
         target: &gfx::handle::RawRenderTargetView<gl::Resources>,
-    ) -> Pipeline {
+    ) -> Result<Pipeline> {
         // Create point buffer
         let instances = factory
             .create_buffer(
@@ -88,6 +163,7 @@ impl Pipeline {
 
         let texture = Texture::new(
             factory,
+            memory,
             &image::DynamicImage::ImageRgba8(image::ImageBuffer::from_pixel(
                 1,
                 1,
@@ -97,12 +173,40 @@ impl Pipeline {
 
         let data = pipe::Data {
             vertices: quads.clone(),
-            texture: (texture.view().clone(), sampler),
+            texture: (texture.view().clone(), sampler.clone()),
             globals: factory.create_constant_buffer(1),
             instances,
             out: target.clone(),
         };
 
+        // The other pipelines share the buffers of the regular one
+        let palette_data = palette_pipe::Data {
+            vertices: quads.clone(),
+            texture: (texture.view().clone(), sampler.clone()),
+            palette: (texture.view().clone(), sampler.clone()),
+            globals: data.globals.clone(),
+            instances: data.instances.clone(),
+            out: target.clone(),
+        };
+
+        let custom_data = custom_pipe::Data {
+            vertices: quads.clone(),
+            texture: (texture.view().clone(), sampler.clone()),
+            globals: data.globals.clone(),
+            uniforms: factory.create_constant_buffer(1),
+            instances: data.instances.clone(),
+            out: target.clone(),
+        };
+
+        let crt_data = crt_pipe::Data {
+            vertices: quads,
+            texture: (texture.view().clone(), sampler),
+            globals: data.globals.clone(),
+            crt: factory.create_constant_buffer(1),
+            instances: data.instances.clone(),
+            out: target.clone(),
+        };
+
         let init = pipe::Init {
             out: (
                 "Target0",
@@ -113,7 +217,37 @@ impl Pipeline {
             ..pipe::new()
         };
 
-        let shader = Shader::new(factory, init);
+        let shader =
+            Shader::new(factory, include_bytes!("shader/quad.frag"), init)?;
+
+        let palette_init = palette_pipe::Init {
+            out: (
+                "Target0",
+                format::COLOR,
+                gfx::state::ColorMask::all(),
+                Some(gfx::preset::blend::ALPHA),
+            ),
+            ..palette_pipe::new()
+        };
+
+        let palette_shader = Shader::new(
+            factory,
+            include_bytes!("shader/palette.frag"),
+            palette_init,
+        )?;
+
+        let crt_init = crt_pipe::Init {
+            out: (
+                "Target0",
+                format::COLOR,
+                gfx::state::ColorMask::all(),
+                Some(gfx::preset::blend::ALPHA),
+            ),
+            ..crt_pipe::new()
+        };
+
+        let crt_shader =
+            Shader::new(factory, include_bytes!("shader/crt.frag"), crt_init)?;
 
         let globals = Globals {
             mvp: Transformation::identity().into(),
@@ -123,12 +257,25 @@ impl Pipeline {
             .update_buffer(&data.globals, &[globals], 0)
             .expect("Globals initialization");
 
-        Pipeline {
+        let allocation = memory.allocate(
+            Resource::Buffer,
+            (mem::size_of::<Quad>() * MAX_INSTANCES as usize
+                + mem::size_of_val(&QUAD_VERTS)
+                + mem::size_of_val(&QUAD_INDICES)) as u64,
+        );
+
+        Ok(Pipeline {
             slice,
             data,
             shader,
+            palette_data,
+            palette_shader,
+            crt_data,
+            crt_shader,
+            custom_data,
             globals,
-        }
+            _allocation: allocation,
+        })
     }
 
     pub fn bind_texture(&mut self, texture: &Texture) {
@@ -138,6 +285,7 @@ impl Pipeline {
     pub fn draw_textured(
         &mut self,
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        mode: Mode<'_>,
         instances: &[Quad],
         transformation: &Transformation,
         view: &gfx::handle::RawRenderTargetView<gl::Resources>,
@@ -155,6 +303,31 @@ impl Pipeline {
 
         self.data.out = view.clone();
 
+        match &mode {
+            Mode::Regular => {}
+            Mode::Palette(palette) => {
+                self.palette_data.texture.0 = self.data.texture.0.clone();
+                self.palette_data.palette.0 = palette.view().clone();
+                self.palette_data.out = view.clone();
+            }
+            Mode::Crt(crt) => {
+                encoder
+                    .update_buffer(&self.crt_data.crt, &[*crt], 0)
+                    .expect("CRT settings upload");
+
+                self.crt_data.texture.0 = self.data.texture.0.clone();
+                self.crt_data.out = view.clone();
+            }
+            Mode::Custom(_, uniforms) => {
+                encoder
+                    .update_buffer(&self.custom_data.uniforms, &[*uniforms], 0)
+                    .expect("Uniforms upload");
+
+                self.custom_data.texture.0 = self.data.texture.0.clone();
+                self.custom_data.out = view.clone();
+            }
+        }
+
         let mut i = 0;
         let total = instances.len();
 
@@ -167,25 +340,59 @@ impl Pipeline {
 
             self.slice.instances = Some((end as u32 - i as u32, 0));
 
-            encoder.draw(&self.slice, &self.shader.state, &self.data);
+            match &mode {
+                Mode::Regular => {
+                    encoder.draw(&self.slice, &self.shader.state, &self.data);
+                }
+                Mode::Palette(_) => {
+                    encoder.draw(
+                        &self.slice,
+                        &self.palette_shader.state,
+                        &self.palette_data,
+                    );
+                }
+                Mode::Crt(_) => {
+                    encoder.draw(
+                        &self.slice,
+                        &self.crt_shader.state,
+                        &self.crt_data,
+                    );
+                }
+                Mode::Custom(shader, _) => {
+                    encoder.draw(&self.slice, &shader.state, &self.custom_data);
+                }
+            }
 
             i += MAX_INSTANCES as usize;
         }
     }
 }
 
-pub struct Shader {
-    state: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
+pub fn custom_init() -> custom_pipe::Init<'static> {
+    custom_pipe::Init {
+        out: (
+            "Target0",
+            format::COLOR,
+            gfx::state::ColorMask::all(),
+            Some(gfx::preset::blend::ALPHA),
+        ),
+        ..custom_pipe::new()
+    }
+}
+
+pub struct Shader<M> {
+    state: gfx::pso::PipelineState<gl::Resources, M>,
 }
 
-impl Shader {
-    pub fn new(factory: &mut gl::Factory, init: pipe::Init<'_>) -> Shader {
+impl<M> Shader<M> {
+    pub fn new<I: gfx::pso::PipelineInit<Meta = M>>(
+        factory: &mut gl::Factory,
+        fragment: &[u8],
+        init: I,
+    ) -> Result<Shader<M>> {
         let set = factory
-            .create_shader_set(
-                include_bytes!("shader/quad.vert"),
-                include_bytes!("shader/quad.frag"),
-            )
-            .expect("Shader set creation");
+            .create_shader_set(include_bytes!("shader/quad.vert"), fragment)
+            .map_err(|error| Error::ShaderCompilation(error.to_string()))?;
 
         let rasterizer = gfx::state::Rasterizer {
             front_face: gfx::state::FrontFace::CounterClockwise,
@@ -202,9 +409,9 @@ impl Shader {
                 rasterizer,
                 init,
             )
-            .expect("Pipeline state creation");
+            .map_err(|error| Error::ShaderCompilation(error.to_string()))?;
 
-        Shader { state }
+        Ok(Shader { state })
     }
 }
 
@@ -218,7 +425,24 @@ impl From<graphics::Quad> for Quad {
             src: [source.x, source.y, source.width, source.height],
             translation: [position.x, position.y],
             scale: [width, height],
-            layer: 0,
+            layer: quad.packing.flags(),
+            color: [1.0; 4],
+        }
+    }
+}
+
+impl Crt {
+    pub fn new(crt: &graphics::Crt, texture: &Texture, quad: &Quad) -> Crt {
+        Crt {
+            source: quad.src,
+            resolution: [
+                f32::from(texture.width()),
+                f32::from(texture.height()),
+            ],
+            scanlines: crt.scanlines,
+            curvature: crt.curvature,
+            pixel_grid: crt.pixel_grid,
+            blur: crt.blur,
         }
     }
 }
@@ -0,0 +1,58 @@
+#version 150 core
+
+in vec2 v_Uv;
+flat in uint v_Layer;
+in vec4 v_Color;
+
+uniform sampler2DArray t_Texture;
+
+layout (std140) uniform Crt {
+    vec4 u_Source;
+    vec2 u_Resolution;
+    float u_Scanlines;
+    float u_Curvature;
+    float u_PixelGrid;
+    float u_Blur;
+};
+
+out vec4 Target0;
+
+const float PI = 3.14159265;
+
+vec4 sample_source(vec2 uv) {
+    return texture(t_Texture, vec3(uv, v_Layer));
+}
+
+void main() {
+    // Bulge the screen around its center
+    vec2 screen = (v_Uv - u_Source.xy) / u_Source.zw * 2.0 - 1.0;
+    screen += screen * screen.yx * screen.yx * u_Curvature;
+
+    if (abs(screen.x) > 1.0 || abs(screen.y) > 1.0) {
+        Target0 = vec4(0.0, 0.0, 0.0, 1.0);
+        return;
+    }
+
+    vec2 uv = (screen * 0.5 + 0.5) * u_Source.zw + u_Source.xy;
+    vec4 color = sample_source(uv);
+
+    // Bleed colors horizontally, like an NTSC signal
+    if (u_Blur > 0.0) {
+        vec2 offset = vec2(u_Blur / u_Resolution.x, 0.0);
+
+        color = color * 0.5
+            + sample_source(uv - offset) * 0.25
+            + sample_source(uv + offset) * 0.25;
+    }
+
+    // Darken the gaps between the rows of the source
+    float scanline = abs(sin(uv.y * u_Resolution.y * PI));
+    color.rgb *= mix(1.0, scanline, u_Scanlines);
+
+    // Split every pixel of the screen into red, green, and blue stripes
+    vec3 mask = vec3(1.0 - u_PixelGrid);
+    mask[int(gl_FragCoord.x) % 3] = 1.0;
+    color.rgb *= mask;
+
+    Target0 = color * v_Color;
+}
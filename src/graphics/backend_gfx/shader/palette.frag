@@ -0,0 +1,33 @@
+#version 150 core
+
+uniform sampler2DArray t_Texture;
+uniform sampler2DArray t_Palette;
+flat in uint v_Layer;
+in vec2 v_Uv;
+in vec4 v_Color;
+
+out vec4 Target0;
+
+layout (std140) uniform Globals {
+    mat4 u_MVP;
+};
+
+// Textures are stored in sRGB, so the index needs to be encoded back
+float srgb(float linear) {
+    if (linear <= 0.0031308) {
+        return linear * 12.92;
+    }
+
+    return 1.055 * pow(linear, 1.0 / 2.4) - 0.055;
+}
+
+void main() {
+    vec4 color = texture(t_Texture, vec3(v_Uv, v_Layer));
+
+    int size = textureSize(t_Palette, 0).x;
+    int index = min(int(srgb(color.r) * 255.0 + 0.5), size - 1);
+
+    vec4 palette = texelFetch(t_Palette, ivec3(index, 0, 0), 0);
+
+    Target0 = vec4(palette.rgb, palette.a * color.a) * v_Color;
+}
@@ -3,6 +3,7 @@
 uniform sampler2DArray t_Texture;
 flat in uint v_Layer;
 in vec2 v_Uv;
+in vec4 v_Color;
 
 out vec4 Target0;
 
@@ -11,5 +12,5 @@ layout (std140) uniform Globals {
 };
 
 void main() {
-    Target0 = texture(t_Texture, vec3(v_Uv, v_Layer));
+    Target0 = texture(t_Texture, vec3(v_Uv, v_Layer)) * v_Color;
 }
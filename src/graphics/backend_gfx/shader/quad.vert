@@ -6,6 +6,7 @@ in vec4 a_Src;
 in vec2 a_Scale;
 in vec2 a_Translation;
 in uint t_Layer;
+in vec4 a_Color;
 
 layout (std140) uniform Globals {
     mat4 u_MVP;
@@ -13,10 +14,28 @@ layout (std140) uniform Globals {
 
 out vec2 v_Uv;
 flat out uint v_Layer;
+out vec4 v_Color;
 
 void main() {
-    v_Uv = a_Pos * a_Src.zw + a_Src.xy;
-    v_Layer = t_Layer;
+    // The packing flags are stored next to the layer
+    uint flags = t_Layer >> 16;
+    vec2 uv = a_Pos;
+
+    if ((flags & 2u) != 0u) {
+        uv.x = 1.0 - uv.x;
+    }
+
+    if ((flags & 4u) != 0u) {
+        uv.y = 1.0 - uv.y;
+    }
+
+    if ((flags & 1u) != 0u) {
+        uv = vec2(1.0 - uv.y, uv.x);
+    }
+
+    v_Uv = uv * a_Src.zw + a_Src.xy;
+    v_Layer = t_Layer & 0xFFFFu;
+    v_Color = a_Color;
 
     mat4 instance_transform = mat4(
         vec4(a_Scale.x, 0.0, 0.0, 0.0),
@@ -1,25 +1,36 @@
 use gfx_device_gl as gl;
 
 use super::{format, Gpu, TargetView};
+use crate::graphics::PresentMode;
 use crate::{Error, Result};
 
 pub struct Surface {
     context: glutin::WindowedContext<glutin::PossiblyCurrent>,
     target: TargetView,
+    present_mode: PresentMode,
 }
 
 impl Surface {
     pub(super) fn new(
         builder: winit::window::WindowBuilder,
         event_loop: &winit::event_loop::EventLoop<()>,
+        present_mode: PresentMode,
+        validation: bool,
     ) -> Result<(Self, gl::Device, gl::Factory)> {
+        // The swap interval of a context can only be 0 or 1 with glutin
+        let present_mode = match present_mode {
+            PresentMode::Immediate => PresentMode::Immediate,
+            _ => PresentMode::Fifo,
+        };
+
         let gl_builder = glutin::ContextBuilder::new()
             .with_gl(glutin::GlRequest::Latest)
             .with_gl_profile(glutin::GlProfile::Core)
             .with_multisampling(0)
             // 24 color bits, 8 alpha bits
             .with_pixel_format(24, 8)
-            .with_vsync(true);
+            .with_vsync(present_mode == PresentMode::Fifo)
+            .with_gl_debug_flag(validation);
 
         let (context, device, factory, target, _depth) = init_raw(
             builder,
@@ -27,17 +38,33 @@ impl Surface {
             &event_loop,
             format::COLOR,
             format::DEPTH,
-        )
-        .map_err(|error| Error::WindowCreation(error.to_string()))?;
-
-        Ok((Self { context, target }, device, factory))
+        )?;
+
+        log::info!(
+            "Window created with size {:?}",
+            context.window().inner_size()
+        );
+
+        Ok((
+            Self {
+                context,
+                target,
+                present_mode,
+            },
+            device,
+            factory,
+        ))
     }
 
     pub fn window(&self) -> &winit::window::Window {
         self.context.window()
     }
 
-    pub fn target(&self) -> &TargetView {
+    pub fn target(&mut self, _gpu: &mut Gpu) -> &TargetView {
+        &self.target
+    }
+
+    pub(super) fn view(&self) -> &TargetView {
         &self.target
     }
 
@@ -60,14 +87,45 @@ impl Surface {
         }
     }
 
+    pub fn recreate(&mut self, gpu: &mut Gpu) {
+        // The OpenGL context keeps its surface on desktop platforms, we only
+        // need to make sure it matches the size of the window. On Android, the
+        // surface is destroyed together with the native window and `glutin`
+        // cannot recreate it, which is why this backend is not supported there.
+        let size = self.context.window().inner_size();
+        self.resize(gpu, size);
+    }
+
+    pub fn present_mode(&self) -> PresentMode {
+        self.present_mode
+    }
+
+    pub fn present_modes(&self) -> Vec<PresentMode> {
+        // The swap interval of an OpenGL context cannot be changed once it is
+        // created
+        vec![self.present_mode]
+    }
+
+    pub fn set_present_mode(
+        &mut self,
+        _gpu: &mut Gpu,
+        present_mode: PresentMode,
+    ) -> bool {
+        present_mode == self.present_mode
+    }
+
     pub fn request_redraw(&mut self) {
         self.context.window().request_redraw();
     }
 
-    pub fn swap_buffers(&mut self, gpu: &mut Gpu) {
+    pub fn swap_buffers(&mut self, gpu: &mut Gpu) -> Result<()> {
         gpu.flush();
-        self.context.swap_buffers().expect("Buffer swap");
+
+        self.context.swap_buffers().map_err(context_error)?;
+
         gpu.cleanup();
+
+        Ok(())
     }
 }
 
@@ -85,7 +143,7 @@ fn init_raw(
         gfx::handle::RawRenderTargetView<gl::Resources>,
         gfx::handle::RawDepthStencilView<gl::Resources>,
     ),
-    glutin::CreationError,
+    Error,
 > {
     let window = {
         let color_total_bits = color_format.0.get_total_bits();
@@ -98,11 +156,12 @@ fn init_raw(
             .with_stencil_buffer(stencil_bits)
             .with_pixel_format(color_total_bits - alpha_bits, alpha_bits)
             .with_srgb(color_format.1 == gfx::format::ChannelType::Srgb)
-            .build_windowed(window, events_loop)?
+            .build_windowed(window, events_loop)
+            .map_err(|error| Error::WindowCreation(error.to_string()))?
     };
 
     let (window, device, factory, color_view, ds_view) =
-        init_existing_raw(window, color_format, ds_format);
+        init_existing_raw(window, color_format, ds_format)?;
 
     Ok((window, device, factory, color_view, ds_view))
 }
@@ -111,15 +170,16 @@ fn init_existing_raw(
     window: glutin::WindowedContext<glutin::NotCurrent>,
     color_format: gfx::format::Format,
     ds_format: gfx::format::Format,
-) -> (
+) -> Result<(
     glutin::WindowedContext<glutin::PossiblyCurrent>,
     gl::Device,
     gl::Factory,
     gfx::handle::RawRenderTargetView<gl::Resources>,
     gfx::handle::RawDepthStencilView<gl::Resources>,
-) {
+)> {
     #[allow(unsafe_code)]
-    let window = unsafe { window.make_current().unwrap() };
+    let window = unsafe { window.make_current() }
+        .map_err(|(_, error)| context_error(error))?;
 
     let (device, factory) = gl::create(|s| {
         window.get_proc_address(s) as *const std::os::raw::c_void
@@ -131,7 +191,14 @@ fn init_existing_raw(
         gl::create_main_targets_raw(dim, color_format.0, ds_format.0);
 
     // done
-    (window, device, factory, color_view, ds_view)
+    Ok((window, device, factory, color_view, ds_view))
+}
+
+fn context_error(error: glutin::ContextError) -> Error {
+    match error {
+        glutin::ContextError::ContextLost => Error::SurfaceLost,
+        error => Error::Surface(error.to_string()),
+    }
 }
 
 pub fn update_views_raw(
@@ -8,6 +8,7 @@ use gfx_device_gl as gl;
 
 use super::format::{Channel, Surface};
 use super::types::{RawTexture, ShaderResource, TargetView};
+use crate::graphics::memory::{self, Allocation, Resource};
 use crate::graphics::vector::Vector;
 use crate::graphics::Transformation;
 
@@ -18,17 +19,21 @@ pub struct Texture {
     width: u16,
     height: u16,
     layers: u16,
+    _allocation: Allocation,
 }
 
 impl Texture {
     pub(super) fn new(
         factory: &mut gl::Factory,
+        memory: &memory::Tracker,
         image: &image::DynamicImage,
     ) -> Texture {
         let rgba = image.to_rgba();
         let width = rgba.width() as u16;
         let height = rgba.height() as u16;
 
+        log::debug!("Uploading texture: {}x{}", width, height);
+
         let (raw, view) = create_texture_array(
             factory,
             width,
@@ -44,17 +49,27 @@ impl Texture {
             width,
             height,
             layers: 1,
+            _allocation: memory
+                .allocate(Resource::Texture, texture_bytes(width, height, 1)),
         }
     }
 
     pub(super) fn new_array(
         factory: &mut gl::Factory,
+        memory: &memory::Tracker,
         layers: &[image::DynamicImage],
     ) -> Texture {
         let first_layer = &layers[0].to_rgba();
         let width = first_layer.width() as u16;
         let height = first_layer.height() as u16;
 
+        log::debug!(
+            "Uploading texture array: {}x{} with {} layers",
+            width,
+            height,
+            layers.len()
+        );
+
         let rgba: Vec<Vec<u8>> =
             layers.iter().map(|i| i.to_rgba().into_raw()).collect();
 
@@ -75,6 +90,10 @@ impl Texture {
             width,
             height,
             layers: layers.len() as u16,
+            _allocation: memory.allocate(
+                Resource::Texture,
+                texture_bytes(width, height, layers.len()),
+            ),
         }
     }
 
@@ -102,7 +121,14 @@ pub struct Drawable {
 }
 
 impl Drawable {
-    pub fn new(factory: &mut gl::Factory, width: u16, height: u16) -> Drawable {
+    pub fn new(
+        factory: &mut gl::Factory,
+        memory: &memory::Tracker,
+        width: u16,
+        height: u16,
+    ) -> Drawable {
+        log::debug!("Creating drawable texture: {}x{}", width, height);
+
         let (raw, view) = create_texture_array(
             factory,
             width,
@@ -119,6 +145,8 @@ impl Drawable {
             width,
             height,
             layers: 1,
+            _allocation: memory
+                .allocate(Resource::Canvas, texture_bytes(width, height, 1)),
         };
 
         let render_desc = gfx::texture::RenderDesc {
@@ -195,6 +223,10 @@ impl Drawable {
 }
 
 // Helpers
+fn texture_bytes(width: u16, height: u16, layers: usize) -> u64 {
+    u64::from(width) * u64::from(height) * layers as u64 * 4
+}
+
 fn create_texture_array(
     factory: &mut gl::Factory,
     width: u16,
@@ -3,7 +3,10 @@ use gfx::{self, *};
 use gfx_device_gl as gl;
 
 use super::format;
+use super::program::Uniforms;
+use crate::graphics::memory::{self, Allocation, Resource};
 use crate::graphics::Transformation;
+use crate::{Error, Result};
 
 gfx_defines! {
     vertex Vertex {
@@ -26,13 +29,34 @@ gfx_defines! {
                Some(gfx::preset::blend::ALPHA)
           ),
     }
+
+    pipeline custom_pipe {
+        vertices: gfx::VertexBuffer<Vertex> = (),
+        globals: gfx::ConstantBuffer<Globals> = "Globals",
+        uniforms: gfx::ConstantBuffer<Uniforms> = "Uniforms",
+        out: gfx::RawRenderTarget =
+          (
+              "Target0",
+               format::COLOR,
+               gfx::state::ColorMask::all(),
+               Some(gfx::preset::blend::ALPHA)
+          ),
+    }
+}
+
+// The fragment shader used to draw some triangles
+pub enum Mode<'a> {
+    Regular,
+    Custom(&'a Shader<custom_pipe::Meta>, Uniforms),
 }
 
 pub struct Pipeline {
     data: pipe::Data<gl::Resources>,
     indices: gfx::handle::Buffer<gl::Resources, u32>,
-    shader: Shader,
+    shader: Shader<pipe::Meta>,
+    custom_data: custom_pipe::Data<gl::Resources>,
     globals: Globals,
+    _allocation: Allocation,
 }
 
 impl Pipeline {
@@ -40,9 +64,10 @@ impl Pipeline {
 
     pub fn new(
         factory: &mut gl::Factory,
+        memory: &memory::Tracker,
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
         target: &gfx::handle::RawRenderTargetView<gl::Resources>,
-    ) -> Pipeline {
+    ) -> Result<Pipeline> {
         let vertices = factory
             .create_buffer(
                 Self::INITIAL_BUFFER_SIZE,
@@ -67,6 +92,13 @@ impl Pipeline {
             out: target.clone(),
         };
 
+        let custom_data = custom_pipe::Data {
+            vertices: data.vertices.clone(),
+            globals: data.globals.clone(),
+            uniforms: factory.create_constant_buffer(1),
+            out: target.clone(),
+        };
+
         let init = pipe::Init {
             out: (
                 "Target0",
@@ -77,7 +109,8 @@ impl Pipeline {
             ..pipe::new()
         };
 
-        let shader = Shader::new(factory, init);
+        let shader =
+            Shader::new(factory, include_bytes!("shader/triangle.frag"), init)?;
 
         let globals = Globals {
             mvp: Transformation::identity().into(),
@@ -87,18 +120,25 @@ impl Pipeline {
             .update_buffer(&data.globals, &[globals], 0)
             .expect("Globals initialization");
 
-        Pipeline {
+        let allocation = memory
+            .allocate(Resource::Buffer, buffer_bytes(&data.vertices, &indices));
+
+        Ok(Pipeline {
             data,
             indices,
             shader,
+            custom_data,
             globals,
-        }
+            _allocation: allocation,
+        })
     }
 
     pub fn draw(
         &mut self,
         factory: &mut gl::Factory,
+        memory: &memory::Tracker,
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        mode: Mode<'_>,
         vertices: &[Vertex],
         indices: &[u32],
         transformation: &Transformation,
@@ -138,6 +178,9 @@ impl Pipeline {
                 )
                 .expect("Index buffer creation");
 
+            self._allocation = memory
+                .allocate(Resource::Buffer, buffer_bytes(&vertices, &indices));
+
             self.data.vertices = vertices;
             self.indices = indices;
         }
@@ -158,22 +201,57 @@ impl Pipeline {
             buffer: gfx::IndexBuffer::Index32(self.indices.clone()),
         };
 
-        encoder.draw(&slice, &self.shader.state, &self.data);
+        match mode {
+            Mode::Regular => {
+                encoder.draw(&slice, &self.shader.state, &self.data);
+            }
+            Mode::Custom(shader, uniforms) => {
+                encoder
+                    .update_buffer(&self.custom_data.uniforms, &[uniforms], 0)
+                    .expect("Uniforms upload");
+
+                // The vertex buffer may have been reallocated
+                self.custom_data.vertices = self.data.vertices.clone();
+                self.custom_data.out = view.clone();
+
+                encoder.draw(&slice, &shader.state, &self.custom_data);
+            }
+        }
+    }
+}
+
+fn buffer_bytes(
+    vertices: &gfx::handle::Buffer<gl::Resources, Vertex>,
+    indices: &gfx::handle::Buffer<gl::Resources, u32>,
+) -> u64 {
+    (vertices.get_info().size + indices.get_info().size) as u64
+}
+
+pub fn custom_init() -> custom_pipe::Init<'static> {
+    custom_pipe::Init {
+        out: (
+            "Target0",
+            format::COLOR,
+            gfx::state::ColorMask::all(),
+            Some(gfx::preset::blend::ALPHA),
+        ),
+        ..custom_pipe::new()
     }
 }
 
-pub struct Shader {
-    state: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
+pub struct Shader<M> {
+    state: gfx::pso::PipelineState<gl::Resources, M>,
 }
 
-impl Shader {
-    pub fn new(factory: &mut gl::Factory, init: pipe::Init<'_>) -> Shader {
+impl<M> Shader<M> {
+    pub fn new<I: gfx::pso::PipelineInit<Meta = M>>(
+        factory: &mut gl::Factory,
+        fragment: &[u8],
+        init: I,
+    ) -> Result<Shader<M>> {
         let set = factory
-            .create_shader_set(
-                include_bytes!("shader/triangle.vert"),
-                include_bytes!("shader/triangle.frag"),
-            )
-            .expect("Shader set creation");
+            .create_shader_set(include_bytes!("shader/triangle.vert"), fragment)
+            .map_err(|error| Error::ShaderCompilation(error.to_string()))?;
 
         let rasterizer = gfx::state::Rasterizer {
             front_face: gfx::state::FrontFace::CounterClockwise,
@@ -190,9 +268,9 @@ impl Shader {
                 rasterizer,
                 init,
             )
-            .expect("Pipeline state creation");
+            .map_err(|error| Error::ShaderCompilation(error.to_string()))?;
 
-        Shader { state }
+        Ok(Shader { state })
     }
 }
 
@@ -0,0 +1,55 @@
+use std::os::raw::c_void;
+
+use gfx_device_gl as gl;
+
+use gl::gl::types::{GLchar, GLenum, GLsizei, GLuint};
+
+// Logs the messages of the debug context using the `log` crate
+pub fn enable(device: &mut gl::Device) {
+    #[allow(unsafe_code)]
+    unsafe {
+        device.with_gl(|raw| {
+            if !raw.DebugMessageCallback.is_loaded() {
+                log::warn!("The OpenGL driver does not support debug output");
+                return;
+            }
+
+            raw.Enable(gl::gl::DEBUG_OUTPUT);
+
+            // Messages are logged right after the call that caused them
+            raw.Enable(gl::gl::DEBUG_OUTPUT_SYNCHRONOUS);
+            raw.DebugMessageCallback(Some(log_message), std::ptr::null());
+        });
+    }
+
+    log::info!("GPU validation enabled");
+}
+
+extern "system" fn log_message(
+    _source: GLenum,
+    _kind: GLenum,
+    id: GLuint,
+    severity: GLenum,
+    length: GLsizei,
+    message: *const GLchar,
+    _user_param: *mut c_void,
+) {
+    let level = match severity {
+        gl::gl::DEBUG_SEVERITY_HIGH => log::Level::Error,
+        gl::gl::DEBUG_SEVERITY_MEDIUM => log::Level::Warn,
+        gl::gl::DEBUG_SEVERITY_LOW => log::Level::Info,
+        _ => log::Level::Debug,
+    };
+
+    #[allow(unsafe_code)]
+    let message = unsafe {
+        std::slice::from_raw_parts(message as *const u8, length.max(0) as usize)
+    };
+
+    log::log!(
+        level,
+        "OpenGL message {}: {}",
+        id,
+        String::from_utf8_lossy(message)
+    );
+}
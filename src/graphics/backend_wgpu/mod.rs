@@ -1,4 +1,5 @@
 mod font;
+mod program;
 mod quad;
 mod surface;
 pub mod texture;
@@ -6,13 +7,17 @@ mod triangle;
 mod types;
 
 pub use font::Font;
+pub use program::Program;
 pub use quad::Quad;
 pub use surface::Surface;
 pub use texture::Texture;
 pub use triangle::Vertex;
 pub use types::TargetView;
 
-use crate::graphics::{Color, Transformation};
+use crate::graphics::memory;
+use crate::graphics::{
+    Color, Crt, MemoryUsage, PresentMode, ShaderKind, Transformation,
+};
 use crate::{Error, Result};
 
 #[allow(missing_debug_implementations)]
@@ -23,44 +28,36 @@ pub struct Gpu {
     quad_pipeline: quad::Pipeline,
     triangle_pipeline: triangle::Pipeline,
     encoder: wgpu::CommandEncoder,
+    memory: memory::Tracker,
+    adapter: String,
 }
 
 impl Gpu {
     pub(super) fn for_window(
         builder: winit::window::WindowBuilder,
         event_loop: &winit::event_loop::EventLoop<()>,
+        present_mode: PresentMode,
+        validation: bool,
     ) -> Result<(Gpu, Surface)> {
+        if validation {
+            enable_validation();
+        }
+
         let window = builder
             .build(event_loop)
             .map_err(|error| Error::WindowCreation(error.to_string()))?;
 
-        let (mut device, queue) = futures::executor::block_on(async {
-            let adapter = wgpu::Adapter::request(
-                &wgpu::RequestAdapterOptions {
-                    power_preference: wgpu::PowerPreference::HighPerformance,
-                    compatible_surface: None,
-                },
-                wgpu::BackendBit::all(),
-            )
-            .await
-            .expect("Request adapter");
-
-            let (device, queue) = adapter
-                .request_device(&wgpu::DeviceDescriptor {
-                    extensions: wgpu::Extensions {
-                        anisotropic_filtering: false,
-                    },
-                    limits: wgpu::Limits::default(),
-                })
-                .await;
-
-            (device, queue)
-        });
+        log::info!("Window created with size {:?}", window.inner_size());
 
-        let surface = Surface::new(window, &device);
+        let surface = wgpu::Surface::create(&window);
+        let (mut device, queue, info) = request_device(&surface)?;
 
-        let quad_pipeline = quad::Pipeline::new(&mut device);
-        let triangle_pipeline = triangle::Pipeline::new(&mut device);
+        let surface =
+            Surface::new(window, surface, &device, info.backend, present_mode);
+
+        let memory = memory::Tracker::new();
+        let quad_pipeline = quad::Pipeline::new(&mut device, &memory)?;
+        let triangle_pipeline = triangle::Pipeline::new(&mut device, &memory)?;
 
         let encoder =
             device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
@@ -74,11 +71,46 @@ impl Gpu {
                 quad_pipeline,
                 triangle_pipeline,
                 encoder,
+                memory,
+                adapter: adapter_name(&info),
             },
             surface,
         ))
     }
 
+    // Replaces the device and recreates the engine pipelines and the surface.
+    // Resources created with the old device become unusable.
+    pub(super) fn reset(&mut self, surface: &mut Surface) -> Result<()> {
+        let (mut device, queue, info) = request_device(surface.raw())?;
+
+        self.quad_pipeline = quad::Pipeline::new(&mut device, &self.memory)?;
+        self.triangle_pipeline =
+            triangle::Pipeline::new(&mut device, &self.memory)?;
+        self.encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("coffee::backend encoder"),
+            });
+        self.device = device;
+        self.queue = queue;
+        self.adapter = adapter_name(&info);
+
+        surface.reset(self, info.backend);
+
+        Ok(())
+    }
+
+    pub(crate) fn adapter(&self) -> &str {
+        &self.adapter
+    }
+
+    /// Returns an estimation of the GPU memory used by the live resources
+    /// created with this [`Gpu`].
+    ///
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.memory.usage()
+    }
+
     pub(super) fn clear(&mut self, view: &TargetView, color: Color) {
         let [r, g, b, a] = color.into_linear();
 
@@ -103,7 +135,13 @@ impl Gpu {
         &mut self,
         image: &image::DynamicImage,
     ) -> Texture {
-        Texture::new(&mut self.device, &self.queue, &self.quad_pipeline, image)
+        Texture::new(
+            &mut self.device,
+            &self.queue,
+            &self.quad_pipeline,
+            &self.memory,
+            image,
+        )
     }
 
     pub(super) fn upload_texture_array(
@@ -114,6 +152,7 @@ impl Gpu {
             &mut self.device,
             &self.queue,
             &self.quad_pipeline,
+            &self.memory,
             layers,
         )
     }
@@ -127,6 +166,7 @@ impl Gpu {
             &mut self.device,
             &self.queue,
             &self.quad_pipeline,
+            &self.memory,
             width,
             height,
         )
@@ -147,6 +187,22 @@ impl Gpu {
         drawable.read_pixels(&mut self.device, &self.queue, encoder)
     }
 
+    pub(super) fn create_program(
+        &mut self,
+        kind: ShaderKind,
+        _glsl: &str,
+        spirv: &[u8],
+    ) -> Result<Program> {
+        Ok(match kind {
+            ShaderKind::Quad => Program::Quad(
+                self.quad_pipeline.create_custom(&self.device, spirv)?,
+            ),
+            ShaderKind::Mesh => Program::Mesh(
+                self.triangle_pipeline.create_custom(&self.device, spirv)?,
+            ),
+        })
+    }
+
     pub(super) fn upload_font(&mut self, bytes: &'static [u8]) -> Font {
         Font::from_bytes(&mut self.device, bytes)
     }
@@ -160,7 +216,9 @@ impl Gpu {
     ) {
         self.triangle_pipeline.draw(
             &mut self.device,
+            &self.memory,
             &mut self.encoder,
+            triangle::Mode::Regular,
             vertices,
             indices,
             transformation,
@@ -168,6 +226,29 @@ impl Gpu {
         );
     }
 
+    pub(super) fn draw_shader_triangles(
+        &mut self,
+        program: &Program,
+        uniforms: &[[f32; 4]; 8],
+        vertices: &[Vertex],
+        indices: &[u32],
+        view: &TargetView,
+        transformation: &Transformation,
+    ) {
+        if let Program::Mesh(pipeline) = program {
+            self.triangle_pipeline.draw(
+                &mut self.device,
+                &self.memory,
+                &mut self.encoder,
+                triangle::Mode::Custom(pipeline, uniforms),
+                vertices,
+                indices,
+                transformation,
+                view,
+            );
+        }
+    }
+
     pub(super) fn draw_texture_quads(
         &mut self,
         texture: &Texture,
@@ -179,12 +260,73 @@ impl Gpu {
             &mut self.device,
             &mut self.encoder,
             texture.binding(),
+            quad::Mode::Regular,
+            instances,
+            transformation,
+            view,
+        );
+    }
+
+    pub(super) fn draw_palette_quads(
+        &mut self,
+        texture: &Texture,
+        palette: &Texture,
+        instances: &[Quad],
+        view: &TargetView,
+        transformation: &Transformation,
+    ) {
+        self.quad_pipeline.draw_textured(
+            &mut self.device,
+            &mut self.encoder,
+            texture.binding(),
+            quad::Mode::Palette(palette.binding()),
             instances,
             transformation,
             view,
         );
     }
 
+    pub(super) fn draw_shader_quads(
+        &mut self,
+        texture: &Texture,
+        program: &Program,
+        uniforms: &[[f32; 4]; 8],
+        instances: &[Quad],
+        view: &TargetView,
+        transformation: &Transformation,
+    ) {
+        if let Program::Quad(pipeline) = program {
+            self.quad_pipeline.draw_textured(
+                &mut self.device,
+                &mut self.encoder,
+                texture.binding(),
+                quad::Mode::Custom(pipeline, uniforms),
+                instances,
+                transformation,
+                view,
+            );
+        }
+    }
+
+    pub(super) fn draw_crt_quad(
+        &mut self,
+        texture: &Texture,
+        instance: Quad,
+        crt: &Crt,
+        view: &TargetView,
+        transformation: &Transformation,
+    ) {
+        self.quad_pipeline.draw_textured(
+            &mut self.device,
+            &mut self.encoder,
+            texture.binding(),
+            quad::Mode::Crt(quad::Crt::new(crt, texture, &instance)),
+            &[instance],
+            transformation,
+            view,
+        );
+    }
+
     pub(super) fn draw_font(
         &mut self,
         font: &mut Font,
@@ -194,3 +336,50 @@ impl Gpu {
         font.draw(&mut self.device, &mut self.encoder, target, transformation);
     }
 }
+
+fn request_device(
+    surface: &wgpu::Surface,
+) -> Result<(wgpu::Device, wgpu::Queue, wgpu::AdapterInfo)> {
+    futures::executor::block_on(async {
+        let adapter = wgpu::Adapter::request(
+            &wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                compatible_surface: Some(surface),
+            },
+            wgpu::BackendBit::all(),
+        )
+        .await
+        .ok_or(Error::NoAdapterFound)?;
+
+        let info = adapter.get_info();
+
+        log::info!("Using graphics adapter: {:?}", info);
+
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor {
+                extensions: wgpu::Extensions {
+                    anisotropic_filtering: false,
+                },
+                limits: wgpu::Limits::default(),
+            })
+            .await;
+
+        Ok((device, queue, info))
+    })
+}
+
+fn adapter_name(info: &wgpu::AdapterInfo) -> String {
+    format!("{} ({:?})", info.name, info.backend)
+}
+
+// wgpu does not expose its validation, but the drivers pick up these
+// variables when the instance is created
+fn enable_validation() {
+    #[cfg(feature = "vulkan")]
+    std::env::set_var("VK_INSTANCE_LAYERS", "VK_LAYER_KHRONOS_validation");
+
+    #[cfg(feature = "metal")]
+    std::env::set_var("METAL_DEVICE_WRAPPER_TYPE", "1");
+
+    log::info!("GPU validation enabled");
+}
@@ -0,0 +1,6 @@
+// A fragment shader provided by the user, linked with the vertex shader of
+// the pipeline it replaces
+pub enum Program {
+    Quad(wgpu::RenderPipeline),
+    Mesh(wgpu::RenderPipeline),
+}
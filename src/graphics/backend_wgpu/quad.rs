@@ -1,20 +1,43 @@
 use std::mem;
 
+use super::texture::Texture;
+use crate::graphics::memory::{self, Allocation, Resource};
 use crate::graphics::{self, Transformation};
+use crate::{Error, Result};
 use zerocopy::AsBytes;
 
+// The fragment shader used to draw some quads
+pub enum Mode<'a> {
+    Regular,
+    Palette(&'a TextureBinding),
+    Crt(Crt),
+    Custom(&'a wgpu::RenderPipeline, &'a [[f32; 4]; 8]),
+}
+
 pub struct Pipeline {
     pipeline: wgpu::RenderPipeline,
+    palette_pipeline: wgpu::RenderPipeline,
+    crt_pipeline: wgpu::RenderPipeline,
     transform: wgpu::Buffer,
+    crt: wgpu::Buffer,
+    uniforms: wgpu::Buffer,
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
     instances: wgpu::Buffer,
     constants: wgpu::BindGroup,
+    crt_constants: wgpu::BindGroup,
+    uniform_constants: wgpu::BindGroup,
     texture_layout: wgpu::BindGroupLayout,
+    custom_layout: wgpu::PipelineLayout,
+    vertex_module: wgpu::ShaderModule,
+    _allocation: Allocation,
 }
 
 impl Pipeline {
-    pub fn new(device: &mut wgpu::Device) -> Pipeline {
+    pub fn new(
+        device: &mut wgpu::Device,
+        memory: &memory::Tracker,
+    ) -> Result<Pipeline> {
         let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
             address_mode_u: wgpu::AddressMode::ClampToEdge,
             address_mode_v: wgpu::AddressMode::ClampToEdge,
@@ -89,97 +112,127 @@ impl Pipeline {
                 bind_group_layouts: &[&constant_layout, &texture_layout],
             });
 
-        let vs = include_bytes!("shader/quad.vert.spv");
-        let vs_module = device.create_shader_module(
-            &wgpu::read_spirv(std::io::Cursor::new(&vs[..]))
-                .expect("Read quad vertex shader as SPIR-V"),
-        );
+        let palette_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                bind_group_layouts: &[
+                    &constant_layout,
+                    &texture_layout,
+                    &texture_layout,
+                ],
+            });
 
-        let fs = include_bytes!("shader/quad.frag.spv");
-        let fs_module = device.create_shader_module(
-            &wgpu::read_spirv(std::io::Cursor::new(&fs[..]))
-                .expect("Read quad fragment shader as SPIR-V"),
-        );
+        let crt_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("coffee::backend::quad crt"),
+                bindings: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::UniformBuffer { dynamic: false },
+                }],
+            });
 
-        let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                layout: &layout,
-                vertex_stage: wgpu::ProgrammableStageDescriptor {
-                    module: &vs_module,
-                    entry_point: "main",
-                },
-                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
-                    module: &fs_module,
-                    entry_point: "main",
-                }),
-                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
-                    front_face: wgpu::FrontFace::Cw,
-                    cull_mode: wgpu::CullMode::None,
-                    depth_bias: 0,
-                    depth_bias_slope_scale: 0.0,
-                    depth_bias_clamp: 0.0,
-                }),
-                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
-                color_states: &[wgpu::ColorStateDescriptor {
-                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
+        let crt_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("coffee::backend::quad crt"),
+            size: mem::size_of::<Crt>() as u64,
+            usage: wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
+        });
+
+        let crt_bind_group =
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("coffee::backend::quad crt"),
+                layout: &crt_layout,
+                bindings: &[wgpu::Binding {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer {
+                        buffer: &crt_buffer,
+                        range: 0..mem::size_of::<Crt>() as u64,
                     },
-                    alpha_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
+                }],
+            });
+
+        let crt_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                bind_group_layouts: &[
+                    &constant_layout,
+                    &texture_layout,
+                    &crt_layout,
+                ],
+            });
+
+        let uniform_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("coffee::backend::quad uniforms"),
+                bindings: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::UniformBuffer { dynamic: false },
+                }],
+            });
+
+        let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("coffee::backend::quad uniforms"),
+            size: mem::size_of::<[[f32; 4]; 8]>() as u64,
+            usage: wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
+        });
+
+        let uniform_bind_group =
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("coffee::backend::quad uniforms"),
+                layout: &uniform_layout,
+                bindings: &[wgpu::Binding {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer {
+                        buffer: &uniform_buffer,
+                        range: 0..mem::size_of::<[[f32; 4]; 8]>() as u64,
                     },
-                    write_mask: wgpu::ColorWrite::ALL,
                 }],
-                depth_stencil_state: None,
-                vertex_state: wgpu::VertexStateDescriptor {
-                    index_format: wgpu::IndexFormat::Uint16,
-                    vertex_buffers: &[
-                        wgpu::VertexBufferDescriptor {
-                            stride: mem::size_of::<Vertex>() as u64,
-                            step_mode: wgpu::InputStepMode::Vertex,
-                            attributes: &[wgpu::VertexAttributeDescriptor {
-                                shader_location: 0,
-                                format: wgpu::VertexFormat::Float2,
-                                offset: 0,
-                            }],
-                        },
-                        wgpu::VertexBufferDescriptor {
-                            stride: mem::size_of::<Quad>() as u64,
-                            step_mode: wgpu::InputStepMode::Instance,
-                            attributes: &[
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 1,
-                                    format: wgpu::VertexFormat::Float4,
-                                    offset: 0,
-                                },
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 2,
-                                    format: wgpu::VertexFormat::Float2,
-                                    offset: 4 * 4,
-                                },
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 3,
-                                    format: wgpu::VertexFormat::Float2,
-                                    offset: 4 * (4 + 2),
-                                },
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 4,
-                                    format: wgpu::VertexFormat::Uint,
-                                    offset: 4 * (4 + 2 + 2),
-                                },
-                            ],
-                        },
-                    ],
-                },
-                sample_count: 1,
-                sample_mask: !0,
-                alpha_to_coverage_enabled: false,
             });
 
+        let custom_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                bind_group_layouts: &[
+                    &constant_layout,
+                    &texture_layout,
+                    &uniform_layout,
+                ],
+            });
+
+        let vs_module = create_shader_module(
+            device,
+            include_bytes!("shader/quad.vert.spv"),
+        )?;
+
+        let fs_module = create_shader_module(
+            device,
+            include_bytes!("shader/quad.frag.spv"),
+        )?;
+
+        let palette_module = create_shader_module(
+            device,
+            include_bytes!("shader/palette.frag.spv"),
+        )?;
+
+        let crt_module = create_shader_module(
+            device,
+            include_bytes!("shader/crt.frag.spv"),
+        )?;
+
+        let pipeline = create_pipeline(device, &layout, &vs_module, &fs_module);
+
+        let palette_pipeline = create_pipeline(
+            device,
+            &palette_layout,
+            &vs_module,
+            &palette_module,
+        );
+
+        let crt_pipeline = create_pipeline(
+            device,
+            &crt_pipeline_layout,
+            &vs_module,
+            &crt_module,
+        );
+
         let vertices = device.create_buffer_with_data(
             QUAD_VERTS.as_bytes(),
             wgpu::BufferUsage::VERTEX,
@@ -196,15 +249,46 @@ impl Pipeline {
             usage: wgpu::BufferUsage::VERTEX | wgpu::BufferUsage::COPY_DST,
         });
 
-        Pipeline {
+        let allocation = memory.allocate(
+            Resource::Buffer,
+            (mem::size_of::<Quad>() * Quad::MAX
+                + mem::size_of_val(&QUAD_VERTS)
+                + mem::size_of_val(&QUAD_INDICES)) as u64,
+        );
+
+        Ok(Pipeline {
             pipeline,
+            palette_pipeline,
+            crt_pipeline,
             transform: transform_buffer,
+            crt: crt_buffer,
+            uniforms: uniform_buffer,
             vertices,
             indices,
             instances,
             constants: constant_bind_group,
+            crt_constants: crt_bind_group,
+            uniform_constants: uniform_bind_group,
             texture_layout,
-        }
+            custom_layout,
+            vertex_module: vs_module,
+            _allocation: allocation,
+        })
+    }
+
+    pub fn create_custom(
+        &self,
+        device: &wgpu::Device,
+        fragment: &[u8],
+    ) -> Result<wgpu::RenderPipeline> {
+        let module = create_shader_module(device, fragment)?;
+
+        Ok(create_pipeline(
+            device,
+            &self.custom_layout,
+            &self.vertex_module,
+            &module,
+        ))
     }
 
     pub fn create_texture_binding(
@@ -229,6 +313,7 @@ impl Pipeline {
         device: &mut wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         texture: &TextureBinding,
+        mode: Mode<'_>,
         instances: &[Quad],
         transformation: &Transformation,
         target: &wgpu::TextureView,
@@ -248,6 +333,36 @@ impl Pipeline {
             16 * 4,
         );
 
+        if let Mode::Crt(crt) = &mode {
+            let crt_buffer = device.create_buffer_with_data(
+                crt.as_bytes(),
+                wgpu::BufferUsage::COPY_SRC,
+            );
+
+            encoder.copy_buffer_to_buffer(
+                &crt_buffer,
+                0,
+                &self.crt,
+                0,
+                mem::size_of::<Crt>() as u64,
+            );
+        }
+
+        if let Mode::Custom(_, uniforms) = &mode {
+            let uniform_buffer = device.create_buffer_with_data(
+                uniforms.as_bytes(),
+                wgpu::BufferUsage::COPY_SRC,
+            );
+
+            encoder.copy_buffer_to_buffer(
+                &uniform_buffer,
+                0,
+                &self.uniforms,
+                0,
+                mem::size_of::<[[f32; 4]; 8]>() as u64,
+            );
+        }
+
         let mut i = 0;
         let total = instances.len();
 
@@ -288,9 +403,41 @@ impl Pipeline {
                         depth_stencil_attachment: None,
                     });
 
-                render_pass.set_pipeline(&self.pipeline);
+                match &mode {
+                    Mode::Regular => {
+                        render_pass.set_pipeline(&self.pipeline);
+                    }
+                    Mode::Palette(_) => {
+                        render_pass.set_pipeline(&self.palette_pipeline);
+                    }
+                    Mode::Crt(_) => {
+                        render_pass.set_pipeline(&self.crt_pipeline);
+                    }
+                    Mode::Custom(pipeline, _) => {
+                        render_pass.set_pipeline(pipeline);
+                    }
+                }
+
                 render_pass.set_bind_group(0, &self.constants, &[]);
                 render_pass.set_bind_group(1, &texture.0, &[]);
+
+                match &mode {
+                    Mode::Regular => {}
+                    Mode::Palette(palette) => {
+                        render_pass.set_bind_group(2, &palette.0, &[]);
+                    }
+                    Mode::Crt(_) => {
+                        render_pass.set_bind_group(2, &self.crt_constants, &[]);
+                    }
+                    Mode::Custom(_, _) => {
+                        render_pass.set_bind_group(
+                            2,
+                            &self.uniform_constants,
+                            &[],
+                        );
+                    }
+                }
+
                 render_pass.set_index_buffer(&self.indices, 0, 0);
                 render_pass.set_vertex_buffer(0, &self.vertices, 0, 0);
                 render_pass.set_vertex_buffer(1, &self.instances, 0, 0);
@@ -307,6 +454,106 @@ impl Pipeline {
     }
 }
 
+fn create_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    vertex: &wgpu::ShaderModule,
+    fragment: &wgpu::ShaderModule,
+) -> wgpu::RenderPipeline {
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        layout,
+        vertex_stage: wgpu::ProgrammableStageDescriptor {
+            module: vertex,
+            entry_point: "main",
+        },
+        fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+            module: fragment,
+            entry_point: "main",
+        }),
+        rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+            front_face: wgpu::FrontFace::Cw,
+            cull_mode: wgpu::CullMode::None,
+            depth_bias: 0,
+            depth_bias_slope_scale: 0.0,
+            depth_bias_clamp: 0.0,
+        }),
+        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+        color_states: &[wgpu::ColorStateDescriptor {
+            format: wgpu::TextureFormat::Bgra8UnormSrgb,
+            color_blend: wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::SrcAlpha,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+            alpha_blend: wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+            write_mask: wgpu::ColorWrite::ALL,
+        }],
+        depth_stencil_state: None,
+        vertex_state: wgpu::VertexStateDescriptor {
+            index_format: wgpu::IndexFormat::Uint16,
+            vertex_buffers: &[
+                wgpu::VertexBufferDescriptor {
+                    stride: mem::size_of::<Vertex>() as u64,
+                    step_mode: wgpu::InputStepMode::Vertex,
+                    attributes: &[wgpu::VertexAttributeDescriptor {
+                        shader_location: 0,
+                        format: wgpu::VertexFormat::Float2,
+                        offset: 0,
+                    }],
+                },
+                wgpu::VertexBufferDescriptor {
+                    stride: mem::size_of::<Quad>() as u64,
+                    step_mode: wgpu::InputStepMode::Instance,
+                    attributes: &[
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 1,
+                            format: wgpu::VertexFormat::Float4,
+                            offset: 0,
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 2,
+                            format: wgpu::VertexFormat::Float2,
+                            offset: 4 * 4,
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 3,
+                            format: wgpu::VertexFormat::Float2,
+                            offset: 4 * (4 + 2),
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 4,
+                            format: wgpu::VertexFormat::Uint,
+                            offset: 4 * (4 + 2 + 2),
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 5,
+                            format: wgpu::VertexFormat::Float4,
+                            offset: 4 * (4 + 2 + 2 + 1),
+                        },
+                    ],
+                },
+            ],
+        },
+        sample_count: 1,
+        sample_mask: !0,
+        alpha_to_coverage_enabled: false,
+    })
+}
+
+fn create_shader_module(
+    device: &wgpu::Device,
+    bytes: &[u8],
+) -> Result<wgpu::ShaderModule> {
+    let spirv = wgpu::read_spirv(std::io::Cursor::new(bytes))
+        .map_err(|error| Error::ShaderCompilation(error.to_string()))?;
+
+    Ok(device.create_shader_module(&spirv))
+}
+
 #[derive(Clone, Copy, AsBytes)]
 #[repr(C)]
 pub struct Vertex {
@@ -337,6 +584,7 @@ pub struct Quad {
     scale: [f32; 2],
     translation: [f32; 2],
     pub layer: u32,
+    pub color: [f32; 4],
 }
 
 impl Quad {
@@ -353,7 +601,37 @@ impl From<graphics::Quad> for Quad {
             source: [source.x, source.y, source.width, source.height],
             translation: [position.x, position.y],
             scale: [width, height],
-            layer: 0,
+            layer: quad.packing.flags(),
+            color: [1.0; 4],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, AsBytes)]
+#[repr(C)]
+pub struct Crt {
+    source: [f32; 4],
+    resolution: [f32; 2],
+    scanlines: f32,
+    curvature: f32,
+    pixel_grid: f32,
+    blur: f32,
+    _padding: [f32; 2],
+}
+
+impl Crt {
+    pub fn new(crt: &graphics::Crt, texture: &Texture, quad: &Quad) -> Crt {
+        Crt {
+            source: quad.source,
+            resolution: [
+                f32::from(texture.width()),
+                f32::from(texture.height()),
+            ],
+            scanlines: crt.scanlines,
+            curvature: crt.curvature,
+            pixel_grid: crt.pixel_grid,
+            blur: crt.blur,
+            _padding: [0.0; 2],
         }
     }
 }
@@ -0,0 +1,31 @@
+#version 450
+
+layout(location = 0) in vec2 v_Uv;
+layout(location = 1) flat in uint v_Layer;
+layout(location = 2) in vec4 v_Color;
+
+layout(set = 0, binding = 1) uniform sampler u_Sampler;
+layout(set = 1, binding = 0) uniform texture2DArray u_Texture;
+layout(set = 2, binding = 0) uniform texture2DArray u_Palette;
+
+layout(location = 0) out vec4 o_Target;
+
+// Textures are stored in sRGB, so the index needs to be encoded back
+float srgb(float linear) {
+    if (linear <= 0.0031308) {
+        return linear * 12.92;
+    }
+
+    return 1.055 * pow(linear, 1.0 / 2.4) - 0.055;
+}
+
+void main() {
+    vec4 color = texture(sampler2DArray(u_Texture, u_Sampler), vec3(v_Uv, v_Layer));
+
+    int size = textureSize(sampler2DArray(u_Palette, u_Sampler), 0).x;
+    int index = min(int(srgb(color.r) * 255.0 + 0.5), size - 1);
+
+    vec4 palette = texelFetch(sampler2DArray(u_Palette, u_Sampler), ivec3(index, 0, 0), 0);
+
+    o_Target = vec4(palette.rgb, palette.a * color.a) * v_Color;
+}
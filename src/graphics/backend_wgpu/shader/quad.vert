@@ -5,6 +5,7 @@ layout(location = 1) in vec4 a_Src;
 layout(location = 2) in vec2 a_Scale;
 layout(location = 3) in vec2 a_Translation;
 layout(location = 4) in uint t_Layer;
+layout(location = 5) in vec4 a_Color;
 
 layout (set = 0, binding = 0) uniform Globals {
     mat4 u_Transform;
@@ -12,10 +13,28 @@ layout (set = 0, binding = 0) uniform Globals {
 
 layout(location = 0) out vec2 v_Uv;
 layout(location = 1) flat out uint v_Layer;
+layout(location = 2) out vec4 v_Color;
 
 void main() {
-    v_Uv = a_Pos * a_Src.zw + a_Src.xy;
-    v_Layer = t_Layer;
+    // The packing flags are stored next to the layer
+    uint flags = t_Layer >> 16;
+    vec2 uv = a_Pos;
+
+    if ((flags & 2u) != 0u) {
+        uv.x = 1.0 - uv.x;
+    }
+
+    if ((flags & 4u) != 0u) {
+        uv.y = 1.0 - uv.y;
+    }
+
+    if ((flags & 1u) != 0u) {
+        uv = vec2(1.0 - uv.y, uv.x);
+    }
+
+    v_Uv = uv * a_Src.zw + a_Src.xy;
+    v_Layer = t_Layer & 0xFFFFu;
+    v_Color = a_Color;
 
     mat4 a_Transform = mat4(
         vec4(a_Scale.x, 0.0, 0.0, 0.0),
@@ -1,29 +1,44 @@
-use super::{Gpu, TargetView};
+use super::{texture, Gpu, TargetView};
+use crate::graphics::PresentMode;
+use crate::{Error, Result};
 
 pub struct Surface {
     window: winit::window::Window,
     surface: wgpu::Surface,
     swap_chain: wgpu::SwapChain,
     extent: wgpu::Extent3d,
+    present_mode: PresentMode,
+    present_modes: Vec<PresentMode>,
     output: Option<wgpu::SwapChainOutput>,
+    skipped: Option<texture::Drawable>,
+    skipped_frames: u32,
 }
 
 impl Surface {
     pub fn new(
         window: winit::window::Window,
+        surface: wgpu::Surface,
         device: &wgpu::Device,
+        backend: wgpu::Backend,
+        present_mode: PresentMode,
     ) -> Surface {
-        let surface = wgpu::Surface::create(&window);
         let size = window.inner_size();
+        let present_modes = supported_modes(backend);
+        let present_mode = choose_mode(&present_modes, present_mode);
 
-        let (swap_chain, extent) = new_swap_chain(device, &surface, size);
+        let (swap_chain, extent) =
+            new_swap_chain(device, &surface, size, present_mode);
 
         Surface {
             window,
             surface,
             swap_chain,
             extent,
+            present_mode,
+            present_modes,
             output: None,
+            skipped: None,
+            skipped_frames: 0,
         }
     }
 
@@ -31,17 +46,54 @@ impl Surface {
         &self.window
     }
 
-    pub fn target(&mut self) -> &TargetView {
-        if self.output.is_none() {
-            let output = self
-                .swap_chain
-                .get_next_texture()
-                .expect("Get next texture");
+    pub(super) fn raw(&self) -> &wgpu::Surface {
+        &self.surface
+    }
+
+    pub fn target(&mut self, gpu: &mut Gpu) -> &TargetView {
+        if self.output.is_none() && self.skipped.is_none() {
+            self.output = self.next_output(gpu);
+        }
+
+        match (&self.output, &self.skipped) {
+            (Some(output), _) => &output.view,
+            (None, Some(skipped)) => skipped.target(),
+            (None, None) => unreachable!(),
+        }
+    }
+
+    fn next_output(&mut self, gpu: &mut Gpu) -> Option<wgpu::SwapChainOutput> {
+        // The swap chain becomes outdated when the window is resized behind
+        // our back or loses exclusive fullscreen
+        let size = self.window.inner_size();
+        let is_visible = size.width > 0 && size.height > 0;
+
+        if is_visible
+            && (size.width != self.extent.width
+                || size.height != self.extent.height)
+        {
+            log::warn!("Swap chain outdated, recreating it");
+            self.resize(gpu, size);
+        }
+
+        // wgpu only reports timeouts here, which are usually transient
+        for _ in 0..ACQUIRE_ATTEMPTS {
+            if let Ok(output) = self.swap_chain.get_next_texture() {
+                self.skipped_frames = 0;
 
-            self.output = Some(output);
+                return Some(output);
+            }
         }
 
-        &self.output.as_ref().unwrap().view
+        // The frame is drawn off-screen and dropped when presenting it
+        log::warn!("Timed out waiting for the next frame, skipping it");
+        self.skipped_frames += 1;
+        self.skipped = Some(gpu.create_drawable_texture(
+            self.extent.width.max(1) as u16,
+            self.extent.height.max(1) as u16,
+        ));
+
+        None
     }
 
     pub fn resize(
@@ -50,14 +102,60 @@ impl Surface {
         size: winit::dpi::PhysicalSize<u32>,
     ) {
         let (swap_chain, extent) =
-            new_swap_chain(&gpu.device, &self.surface, size);
+            new_swap_chain(&gpu.device, &self.surface, size, self.present_mode);
 
         self.swap_chain = swap_chain;
         self.extent = extent;
         self.output = None;
     }
 
-    pub fn swap_buffers(&mut self, gpu: &mut Gpu) {
+    pub fn recreate(&mut self, gpu: &mut Gpu) {
+        self.surface = wgpu::Surface::create(&self.window);
+        self.skipped = None;
+        self.skipped_frames = 0;
+
+        let size = self.window.inner_size();
+        self.resize(gpu, size);
+    }
+
+    // The new device may present to the window using a different backend
+    pub(super) fn reset(&mut self, gpu: &mut Gpu, backend: wgpu::Backend) {
+        self.present_modes = supported_modes(backend);
+        self.present_mode = choose_mode(&self.present_modes, self.present_mode);
+
+        self.recreate(gpu);
+    }
+
+    pub fn present_mode(&self) -> PresentMode {
+        self.present_mode
+    }
+
+    pub fn present_modes(&self) -> Vec<PresentMode> {
+        self.present_modes.clone()
+    }
+
+    pub fn set_present_mode(
+        &mut self,
+        gpu: &mut Gpu,
+        present_mode: PresentMode,
+    ) -> bool {
+        if !self.present_modes.contains(&present_mode) {
+            return false;
+        }
+
+        self.present_mode = present_mode;
+
+        let size = winit::dpi::PhysicalSize {
+            width: self.extent.width,
+            height: self.extent.height,
+        };
+
+        self.resize(gpu, size);
+
+        true
+    }
+
+    pub fn swap_buffers(&mut self, gpu: &mut Gpu) -> Result<()> {
         let new_encoder = gpu.device.create_command_encoder(
             &wgpu::CommandEncoderDescriptor {
                 label: Some("coffee::backend::surface blit"),
@@ -71,6 +169,19 @@ impl Surface {
         gpu.queue.submit(&[encoder.finish()]);
 
         self.output = None;
+
+        // A surface that keeps timing out is not coming back, so we report
+        // it as lost and let the game loop reset the device
+        if self.skipped.take().is_some()
+            && self.skipped_frames >= MAX_SKIPPED_FRAMES
+        {
+            log::warn!("Surface lost");
+            self.skipped_frames = 0;
+
+            return Err(Error::SurfaceLost);
+        }
+
+        Ok(())
     }
 
     pub fn request_redraw(&mut self) {
@@ -78,11 +189,57 @@ impl Surface {
     }
 }
 
+// Times we try to acquire a frame before skipping it
+const ACQUIRE_ATTEMPTS: u32 = 2;
+
+// Consecutive skipped frames before the surface is considered lost
+const MAX_SKIPPED_FRAMES: u32 = 60;
+
+// wgpu does not expose the capabilities of a surface yet, so we derive its
+// present modes from the backend of the adapter presenting to it. Fifo is
+// always available, and Vulkan drivers fall back to it silently.
+fn supported_modes(backend: wgpu::Backend) -> Vec<PresentMode> {
+    match backend {
+        wgpu::Backend::Vulkan => vec![
+            PresentMode::Fifo,
+            PresentMode::Mailbox,
+            PresentMode::Immediate,
+        ],
+        wgpu::Backend::Metal if cfg!(target_os = "macos") => {
+            vec![PresentMode::Fifo, PresentMode::Immediate]
+        }
+        wgpu::Backend::Dx12 => vec![PresentMode::Fifo, PresentMode::Immediate],
+        _ => vec![PresentMode::Fifo],
+    }
+}
+
+fn choose_mode(
+    present_modes: &[PresentMode],
+    present_mode: PresentMode,
+) -> PresentMode {
+    if present_modes.contains(&present_mode) {
+        present_mode
+    } else {
+        log::warn!("{:?} is not supported, using Fifo", present_mode);
+
+        PresentMode::Fifo
+    }
+}
+
 fn new_swap_chain(
     device: &wgpu::Device,
     surface: &wgpu::Surface,
     size: winit::dpi::PhysicalSize<u32>,
+    present_mode: PresentMode,
 ) -> (wgpu::SwapChain, wgpu::Extent3d) {
+    let present_mode = match present_mode {
+        PresentMode::Mailbox => wgpu::PresentMode::Mailbox,
+        PresentMode::Immediate => wgpu::PresentMode::Immediate,
+        PresentMode::Fifo | PresentMode::AdaptiveVsync => {
+            wgpu::PresentMode::Fifo
+        }
+    };
+
     let swap_chain = device.create_swap_chain(
         surface,
         &wgpu::SwapChainDescriptor {
@@ -90,7 +247,7 @@ fn new_swap_chain(
             format: wgpu::TextureFormat::Bgra8UnormSrgb,
             width: size.width,
             height: size.height,
-            present_mode: wgpu::PresentMode::Mailbox,
+            present_mode,
         },
     );
 
@@ -3,6 +3,7 @@ use std::rc::Rc;
 
 use super::types::TargetView;
 use crate::graphics::gpu::quad::{self, Pipeline};
+use crate::graphics::memory::{self, Allocation, Resource};
 use crate::graphics::Transformation;
 
 #[derive(Clone)]
@@ -13,6 +14,7 @@ pub struct Texture {
     width: u16,
     height: u16,
     layers: u16,
+    _allocation: Allocation,
 }
 
 impl fmt::Debug for Texture {
@@ -30,12 +32,15 @@ impl Texture {
         device: &mut wgpu::Device,
         queue: &wgpu::Queue,
         pipeline: &Pipeline,
+        memory: &memory::Tracker,
         image: &image::DynamicImage,
     ) -> Texture {
         let bgra = image.to_bgra();
         let width = bgra.width() as u16;
         let height = bgra.height() as u16;
 
+        log::debug!("Uploading texture: {}x{}", width, height);
+
         let (texture, view, binding) = create_texture_array(
             device,
             queue,
@@ -53,6 +58,8 @@ impl Texture {
             width,
             height,
             layers: 1,
+            _allocation: memory
+                .allocate(Resource::Texture, texture_bytes(width, height, 1)),
         }
     }
 
@@ -60,12 +67,20 @@ impl Texture {
         device: &mut wgpu::Device,
         queue: &wgpu::Queue,
         pipeline: &Pipeline,
+        memory: &memory::Tracker,
         layers: &[image::DynamicImage],
     ) -> Texture {
         let first_layer = &layers[0].to_bgra();
         let width = first_layer.width() as u16;
         let height = first_layer.height() as u16;
 
+        log::debug!(
+            "Uploading texture array: {}x{} with {} layers",
+            width,
+            height,
+            layers.len()
+        );
+
         let bgra: Vec<Vec<u8>> =
             layers.iter().map(|i| i.to_bgra().into_raw()).collect();
 
@@ -88,6 +103,10 @@ impl Texture {
             width,
             height,
             layers: layers.len() as u16,
+            _allocation: memory.allocate(
+                Resource::Texture,
+                texture_bytes(width, height, layers.len()),
+            ),
         }
     }
 
@@ -118,9 +137,12 @@ impl Drawable {
         device: &mut wgpu::Device,
         queue: &wgpu::Queue,
         pipeline: &Pipeline,
+        memory: &memory::Tracker,
         width: u16,
         height: u16,
     ) -> Drawable {
+        log::debug!("Creating drawable texture: {}x{}", width, height);
+
         let (texture, view, binding) = create_texture_array(
             device,
             queue,
@@ -140,6 +162,8 @@ impl Drawable {
             width,
             height,
             layers: 1,
+            _allocation: memory
+                .allocate(Resource::Canvas, texture_bytes(width, height, 1)),
         };
 
         Drawable { texture }
@@ -161,7 +185,12 @@ impl Drawable {
     ) -> image::DynamicImage {
         let texture = self.texture();
 
-        let buffer_size = 4 * texture.width() as u64 * texture.height() as u64;
+        // Copied rows must be aligned to 256 bytes on some backends
+        let row_bytes = 4 * u32::from(texture.width());
+        let padded_row_bytes = (row_bytes + 255) / 256 * 256;
+
+        let buffer_size =
+            u64::from(padded_row_bytes) * u64::from(texture.height());
 
         let buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("coffee::backend::texture pixels"),
@@ -181,7 +210,7 @@ impl Drawable {
             wgpu::BufferCopyView {
                 buffer: &buffer,
                 offset: 0,
-                bytes_per_row: 4 * u32::from(texture.width()),
+                bytes_per_row: padded_row_bytes,
                 rows_per_image: u32::from(texture.height()),
             },
             wgpu::Extent3d {
@@ -198,7 +227,12 @@ impl Drawable {
         let result = block_on(buffer.map_read(0, buffer_size));
 
         let bgra = match result {
-            Ok(mapping) => mapping.as_slice().to_vec(),
+            Ok(mapping) => mapping
+                .as_slice()
+                .chunks(padded_row_bytes as usize)
+                .flat_map(|row| &row[..row_bytes as usize])
+                .copied()
+                .collect(),
             Err(_) => vec![],
         };
 
@@ -218,6 +252,10 @@ impl Drawable {
 }
 
 // Helpers
+fn texture_bytes(width: u16, height: u16, layers: usize) -> u64 {
+    u64::from(width) * u64::from(height) * layers as u64 * 4
+}
+
 fn create_texture_array(
     device: &mut wgpu::Device,
     queue: &wgpu::Queue,
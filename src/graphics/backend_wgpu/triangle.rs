@@ -1,21 +1,37 @@
 use std::mem;
 
+use crate::graphics::memory::{self, Allocation, Resource};
 use crate::graphics::Transformation;
+use crate::{Error, Result};
 use zerocopy::AsBytes;
 
+// The fragment shader used to draw some triangles
+pub enum Mode<'a> {
+    Regular,
+    Custom(&'a wgpu::RenderPipeline, &'a [[f32; 4]; 8]),
+}
+
 pub struct Pipeline {
     pipeline: wgpu::RenderPipeline,
     transform: wgpu::Buffer,
     constants: wgpu::BindGroup,
+    uniforms: wgpu::Buffer,
+    uniform_constants: wgpu::BindGroup,
+    custom_layout: wgpu::PipelineLayout,
+    vertex_module: wgpu::ShaderModule,
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
     buffer_size: u32,
+    _allocation: Allocation,
 }
 
 impl Pipeline {
     const INITIAL_BUFFER_SIZE: u32 = 100_000;
 
-    pub fn new(device: &mut wgpu::Device) -> Pipeline {
+    pub fn new(
+        device: &mut wgpu::Device,
+        memory: &memory::Tracker,
+    ) -> Result<Pipeline> {
         let transform_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 label: Some("coffee::backend::triangle transform"),
@@ -51,75 +67,53 @@ impl Pipeline {
                 bind_group_layouts: &[&transform_layout],
             });
 
+        let uniform_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("coffee::backend::triangle uniforms"),
+                bindings: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::UniformBuffer { dynamic: false },
+                }],
+            });
+
+        let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("coffee::backend::triangle uniforms"),
+            size: mem::size_of::<[[f32; 4]; 8]>() as u64,
+            usage: wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
+        });
+
+        let uniform_bind_group =
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("coffee::backend::triangle uniforms"),
+                layout: &uniform_layout,
+                bindings: &[wgpu::Binding {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer {
+                        buffer: &uniform_buffer,
+                        range: 0..mem::size_of::<[[f32; 4]; 8]>() as u64,
+                    },
+                }],
+            });
+
+        let custom_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                bind_group_layouts: &[&transform_layout, &uniform_layout],
+            });
+
         let vs = include_bytes!("shader/triangle.vert.spv");
         let vs_module = device.create_shader_module(
             &wgpu::read_spirv(std::io::Cursor::new(&vs[..]))
-                .expect("Read triangle vertex shader as SPIR-V"),
+                .map_err(|error| Error::ShaderCompilation(error.to_string()))?,
         );
 
         let fs = include_bytes!("shader/triangle.frag.spv");
         let fs_module = device.create_shader_module(
             &wgpu::read_spirv(std::io::Cursor::new(&fs[..]))
-                .expect("Read triangle fragment shader as SPIR-V"),
+                .map_err(|error| Error::ShaderCompilation(error.to_string()))?,
         );
 
-        let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                layout: &layout,
-                vertex_stage: wgpu::ProgrammableStageDescriptor {
-                    module: &vs_module,
-                    entry_point: "main",
-                },
-                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
-                    module: &fs_module,
-                    entry_point: "main",
-                }),
-                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
-                    front_face: wgpu::FrontFace::Ccw,
-                    cull_mode: wgpu::CullMode::None,
-                    depth_bias: 0,
-                    depth_bias_slope_scale: 0.0,
-                    depth_bias_clamp: 0.0,
-                }),
-                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
-                color_states: &[wgpu::ColorStateDescriptor {
-                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    alpha_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    write_mask: wgpu::ColorWrite::ALL,
-                }],
-                depth_stencil_state: None,
-                vertex_state: wgpu::VertexStateDescriptor {
-                    index_format: wgpu::IndexFormat::Uint32,
-                    vertex_buffers: &[wgpu::VertexBufferDescriptor {
-                        stride: mem::size_of::<Vertex>() as u64,
-                        step_mode: wgpu::InputStepMode::Vertex,
-                        attributes: &[
-                            wgpu::VertexAttributeDescriptor {
-                                shader_location: 0,
-                                format: wgpu::VertexFormat::Float2,
-                                offset: 0,
-                            },
-                            wgpu::VertexAttributeDescriptor {
-                                shader_location: 1,
-                                format: wgpu::VertexFormat::Float4,
-                                offset: 4 * 2,
-                            },
-                        ],
-                    }],
-                },
-                sample_count: 1,
-                sample_mask: !0,
-                alpha_to_coverage_enabled: false,
-            });
+        let pipeline = create_pipeline(device, &layout, &vs_module, &fs_module);
 
         let vertices = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("coffee::backend::triangle vertices"),
@@ -135,20 +129,48 @@ impl Pipeline {
             usage: wgpu::BufferUsage::INDEX | wgpu::BufferUsage::COPY_DST,
         });
 
-        Pipeline {
+        Ok(Pipeline {
             pipeline,
             transform: transform_buffer,
             constants: constant_bind_group,
+            uniforms: uniform_buffer,
+            uniform_constants: uniform_bind_group,
+            custom_layout,
+            vertex_module: vs_module,
             vertices,
             indices,
             buffer_size: Self::INITIAL_BUFFER_SIZE,
-        }
+            _allocation: memory.allocate(
+                Resource::Buffer,
+                buffer_bytes(Self::INITIAL_BUFFER_SIZE),
+            ),
+        })
+    }
+
+    pub fn create_custom(
+        &self,
+        device: &wgpu::Device,
+        fragment: &[u8],
+    ) -> Result<wgpu::RenderPipeline> {
+        let module = device.create_shader_module(
+            &wgpu::read_spirv(std::io::Cursor::new(fragment))
+                .map_err(|error| Error::ShaderCompilation(error.to_string()))?,
+        );
+
+        Ok(create_pipeline(
+            device,
+            &self.custom_layout,
+            &self.vertex_module,
+            &module,
+        ))
     }
 
     pub fn draw(
         &mut self,
         device: &mut wgpu::Device,
+        memory: &memory::Tracker,
         encoder: &mut wgpu::CommandEncoder,
+        mode: Mode<'_>,
         vertices: &[Vertex],
         indices: &[u32],
         transformation: &Transformation,
@@ -173,6 +195,21 @@ impl Pipeline {
             16 * 4,
         );
 
+        if let Mode::Custom(_, uniforms) = &mode {
+            let uniform_buffer = device.create_buffer_with_data(
+                uniforms.as_bytes(),
+                wgpu::BufferUsage::COPY_SRC,
+            );
+
+            encoder.copy_buffer_to_buffer(
+                &uniform_buffer,
+                0,
+                &self.uniforms,
+                0,
+                mem::size_of::<[[f32; 4]; 8]>() as u64,
+            );
+        }
+
         if self.buffer_size < vertices.len() as u32
             || self.buffer_size < indices.len() as u32
         {
@@ -191,6 +228,8 @@ impl Pipeline {
             });
 
             self.buffer_size = new_size;
+            self._allocation =
+                memory.allocate(Resource::Buffer, buffer_bytes(new_size));
         }
 
         let vertex_buffer = device.create_buffer_with_data(
@@ -239,7 +278,16 @@ impl Pipeline {
                     depth_stencil_attachment: None,
                 });
 
-            render_pass.set_pipeline(&self.pipeline);
+            match &mode {
+                Mode::Regular => {
+                    render_pass.set_pipeline(&self.pipeline);
+                }
+                Mode::Custom(pipeline, _) => {
+                    render_pass.set_pipeline(pipeline);
+                    render_pass.set_bind_group(1, &self.uniform_constants, &[]);
+                }
+            }
+
             render_pass.set_bind_group(0, &self.constants, &[]);
             render_pass.set_index_buffer(&self.indices, 0, 0);
             render_pass.set_vertex_buffer(0, &self.vertices, 0, 0);
@@ -249,6 +297,74 @@ impl Pipeline {
     }
 }
 
+fn create_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    vertex: &wgpu::ShaderModule,
+    fragment: &wgpu::ShaderModule,
+) -> wgpu::RenderPipeline {
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        layout,
+        vertex_stage: wgpu::ProgrammableStageDescriptor {
+            module: vertex,
+            entry_point: "main",
+        },
+        fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+            module: fragment,
+            entry_point: "main",
+        }),
+        rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+            front_face: wgpu::FrontFace::Ccw,
+            cull_mode: wgpu::CullMode::None,
+            depth_bias: 0,
+            depth_bias_slope_scale: 0.0,
+            depth_bias_clamp: 0.0,
+        }),
+        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+        color_states: &[wgpu::ColorStateDescriptor {
+            format: wgpu::TextureFormat::Bgra8UnormSrgb,
+            color_blend: wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::SrcAlpha,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+            alpha_blend: wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+            write_mask: wgpu::ColorWrite::ALL,
+        }],
+        depth_stencil_state: None,
+        vertex_state: wgpu::VertexStateDescriptor {
+            index_format: wgpu::IndexFormat::Uint32,
+            vertex_buffers: &[wgpu::VertexBufferDescriptor {
+                stride: mem::size_of::<Vertex>() as u64,
+                step_mode: wgpu::InputStepMode::Vertex,
+                attributes: &[
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 0,
+                        format: wgpu::VertexFormat::Float2,
+                        offset: 0,
+                    },
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 1,
+                        format: wgpu::VertexFormat::Float4,
+                        offset: 4 * 2,
+                    },
+                ],
+            }],
+        },
+        sample_count: 1,
+        sample_mask: !0,
+        alpha_to_coverage_enabled: false,
+    })
+}
+
+fn buffer_bytes(size: u32) -> u64 {
+    (mem::size_of::<Vertex>() + mem::size_of::<u32>()) as u64 * u64::from(size)
+}
+
 #[derive(Debug, Clone, Copy, AsBytes)]
 #[repr(C)]
 pub struct Vertex {
@@ -1,7 +1,10 @@
 use rayon::prelude::*;
+use std::rc::Rc;
 
 use crate::graphics::gpu;
-use crate::graphics::{Image, IntoQuad, Target};
+use crate::graphics::{
+    Color, Image, IntoQuad, Palette, Shader, ShaderKind, Target,
+};
 
 /// A collection of quads that will be drawn all at once using the same
 /// [`Image`].
@@ -42,12 +45,75 @@ impl Batch {
         self.instances.push(instance);
     }
 
+    // Adds a quad multiplied by the given color
+    pub(crate) fn add_tinted<Q: IntoQuad>(&mut self, quad: Q, color: Color) {
+        let mut instance =
+            gpu::Quad::from(quad.into_quad(self.x_unit, self.y_unit));
+
+        instance.color = color.into_linear();
+
+        self.instances.push(instance);
+    }
+
     /// Draws the [`Batch`] on the given [`Target`].
     ///
     /// [`Batch`]: struct.Batch.html
     /// [`Target`]: struct.Target.html
     pub fn draw(&self, target: &mut Target<'_>) {
-        target.draw_texture_quads(&self.image.texture, &self.instances[..]);
+        target.draw_texture_quads(
+            &self.image.texture.borrow(),
+            &self.instances[..],
+        );
+    }
+
+    /// Draws the [`Batch`] on the given [`Target`] using the colors of a
+    /// [`Palette`].
+    ///
+    /// The red channel of the [`Image`] selects a color of the [`Palette`].
+    ///
+    /// [`Batch`]: struct.Batch.html
+    /// [`Target`]: struct.Target.html
+    /// [`Palette`]: struct.Palette.html
+    /// [`Image`]: struct.Image.html
+    pub fn draw_with_palette(
+        &self,
+        palette: &Palette,
+        target: &mut Target<'_>,
+    ) {
+        target.draw_palette_quads(
+            &self.image.texture.borrow(),
+            &palette.image.texture.borrow(),
+            &self.instances[..],
+        );
+    }
+
+    /// Draws the [`Batch`] on the given [`Target`] using a custom [`Shader`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`Shader`] is not a [`ShaderKind::Quad`].
+    ///
+    /// [`Batch`]: struct.Batch.html
+    /// [`Target`]: struct.Target.html
+    /// [`Shader`]: struct.Shader.html
+    /// [`ShaderKind::Quad`]: enum.ShaderKind.html#variant.Quad
+    pub fn draw_with_shader(&self, shader: &Shader, target: &mut Target<'_>) {
+        assert_eq!(shader.kind(), ShaderKind::Quad, "A quad shader is needed");
+
+        target.draw_shader_quads(
+            &self.image.texture.borrow(),
+            shader,
+            &self.instances[..],
+        );
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.instances.is_empty()
+    }
+
+    // Returns true if the batch draws the given image
+    pub(crate) fn has_image(&self, image: &Image) -> bool {
+        Rc::ptr_eq(&self.image.texture, &image.texture)
     }
 
     /// Clears the [`Batch`] contents.
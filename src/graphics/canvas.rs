@@ -1,5 +1,7 @@
 use crate::graphics::gpu::{self, texture, Gpu};
-use crate::graphics::{IntoQuad, Target};
+use crate::graphics::{
+    Crt, IntoQuad, Shader, ShaderKind, Target, Transformation,
+};
 use crate::load::Task;
 use crate::Result;
 
@@ -7,7 +9,15 @@ use crate::Result;
 ///
 /// It can be used both as a [`Target`] and as a resource.
 ///
+/// A [`Canvas`] uses the same coordinate system as a [`Frame`]: the origin
+/// `(0, 0)` is its top-left corner and the y axis points downwards, in pixels.
+/// Every graphics backend follows this convention, so a [`Canvas`] is drawn
+/// the right way up and [`read_pixels`] starts with its top row.
+///
 /// [`Target`]: struct.Target.html
+/// [`Canvas`]: struct.Canvas.html
+/// [`Frame`]: struct.Frame.html
+/// [`read_pixels`]: #method.read_pixels
 #[derive(Clone)]
 pub struct Canvas {
     drawable: texture::Drawable,
@@ -61,6 +71,39 @@ impl Canvas {
         )
     }
 
+    /// Views the [`Canvas`] as a [`Target`] with the given [`Transformation`]
+    /// applied, like a camera.
+    ///
+    /// This is equivalent to calling [`Target::transform`] on the result of
+    /// [`as_target`].
+    ///
+    /// ```
+    /// use coffee::graphics::{Canvas, Gpu, Transformation, Vector};
+    ///
+    /// fn draw_scene(canvas: &mut Canvas, gpu: &mut Gpu, camera: Vector) {
+    ///     let mut target = canvas.as_target_with(
+    ///         gpu,
+    ///         Transformation::scale(2.0) * Transformation::translate(-camera),
+    ///     );
+    ///
+    ///     // Draw the scene on `target` here
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Target`]: struct.Target.html
+    /// [`Transformation`]: struct.Transformation.html
+    /// [`Target::transform`]: struct.Target.html#method.transform
+    /// [`as_target`]: #method.as_target
+    pub fn as_target_with<'a>(
+        &'a mut self,
+        gpu: &'a mut Gpu,
+        transformation: Transformation,
+    ) -> Target<'a> {
+        self.as_target(gpu).transformed(transformation)
+    }
+
     /// Renders the [`Canvas`] on the given [`Target`].
     ///
     /// [`Canvas`]: struct.Canvas.html
@@ -75,7 +118,60 @@ impl Canvas {
         );
     }
 
-    /// Reads the pixels of the [`Canvas`].
+    /// Renders the [`Canvas`] on the given [`Target`] through a [`Crt`]
+    /// effect.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Target`]: struct.Target.html
+    /// [`Crt`]: struct.Crt.html
+    pub fn draw_with_crt<Q: IntoQuad>(
+        &self,
+        quad: Q,
+        crt: &Crt,
+        target: &mut Target<'_>,
+    ) {
+        target.draw_crt_quad(
+            &self.drawable.texture(),
+            gpu::Quad::from(quad.into_quad(
+                1.0 / self.width() as f32,
+                1.0 / self.height() as f32,
+            )),
+            crt,
+        );
+    }
+
+    /// Renders the [`Canvas`] on the given [`Target`] using a custom
+    /// [`Shader`].
+    ///
+    /// This is useful to apply a post-processing effect to a whole scene.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`Shader`] is not a [`ShaderKind::Quad`].
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Target`]: struct.Target.html
+    /// [`Shader`]: struct.Shader.html
+    /// [`ShaderKind::Quad`]: enum.ShaderKind.html#variant.Quad
+    pub fn draw_with_shader<Q: IntoQuad>(
+        &self,
+        quad: Q,
+        shader: &Shader,
+        target: &mut Target<'_>,
+    ) {
+        assert_eq!(shader.kind(), ShaderKind::Quad, "A quad shader is needed");
+
+        target.draw_shader_quads(
+            &self.drawable.texture(),
+            shader,
+            &[gpu::Quad::from(quad.into_quad(
+                1.0 / self.width() as f32,
+                1.0 / self.height() as f32,
+            ))],
+        );
+    }
+
+    /// Reads the pixels of the [`Canvas`], starting with its top row.
     ///
     /// _Note:_ This is a very slow operation.
     ///
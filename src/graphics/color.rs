@@ -1,5 +1,6 @@
 /// An RGBA color in the sRGB color space.
 #[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color {
     /// Red component.
     pub r: f32,
@@ -55,6 +56,16 @@ impl Color {
         a: 1.0,
     };
 
+    /// Transparent color.
+    ///
+    /// It is useful to clear the frame of a transparent window.
+    pub const TRANSPARENT: Self = Self {
+        r: 0.0,
+        g: 0.0,
+        b: 0.0,
+        a: 0.0,
+    };
+
     /// Creates a new [`Color`] from components in the [0, 1.0] range.
     ///
     /// [`Color`]: struct.Color.html
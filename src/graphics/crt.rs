@@ -0,0 +1,49 @@
+/// The settings of a retro effect that emulates a CRT screen.
+///
+/// Draw your game on a [`Canvas`] and then use [`Canvas::draw_with_crt`] to
+/// show it through the effect. The effect works best with pixel art, when the
+/// [`Canvas`] is drawn scaled up several times.
+///
+/// [`Canvas`]: struct.Canvas.html
+/// [`Canvas::draw_with_crt`]: struct.Canvas.html#method.draw_with_crt
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Crt {
+    /// How much to darken the gaps between the rows of pixels, from `0.0` to
+    /// `1.0`.
+    pub scanlines: f32,
+
+    /// How much the screen bulges. `0.0` keeps it flat.
+    pub curvature: f32,
+
+    /// The strength of the red, green, and blue stripes of the screen, from
+    /// `0.0` to `1.0`.
+    pub pixel_grid: f32,
+
+    /// The distance, in pixels of the [`Canvas`], that colors bleed
+    /// horizontally, like in an NTSC signal. `0.0` disables the blur.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    pub blur: f32,
+}
+
+impl Crt {
+    /// The default [`Crt`] settings.
+    ///
+    /// It has noticeable scanlines, a slight curvature, and a subtle pixel
+    /// grid. The blur is disabled.
+    ///
+    /// [`Crt`]: struct.Crt.html
+    pub const DEFAULT: Crt = Crt {
+        scanlines: 0.5,
+        curvature: 0.1,
+        pixel_grid: 0.2,
+        blur: 0.0,
+    };
+}
+
+impl Default for Crt {
+    fn default() -> Crt {
+        Crt::DEFAULT
+    }
+}
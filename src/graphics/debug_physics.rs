@@ -0,0 +1,212 @@
+//! Draw the colliders of your physics engine to debug them.
+//!
+//! A [`Collider`] describes the shape and position of a collider in any
+//! physics engine that interoperates with [`mint`], like [rapier] and
+//! [parry]. Its outline can be added to a [`Mesh`] every frame:
+//!
+//! ```
+//! use coffee::graphics::debug_physics::{self, Collider};
+//! use coffee::graphics::{Color, Frame, Point, Vector};
+//!
+//! fn draw(frame: &mut Frame<'_>) {
+//!     let colliders = [
+//!         Collider::ball(Point::new(100.0, 100.0), 0.0, 20.0),
+//!         Collider::cuboid(
+//!             Point::new(200.0, 100.0),
+//!             0.5,
+//!             Vector::new(30.0, 10.0),
+//!         ),
+//!     ];
+//!
+//!     let mesh = debug_physics::mesh(&colliders, Color::GREEN, 1.0);
+//!
+//!     mesh.draw(&mut frame.as_target());
+//! }
+//! ```
+//!
+//! This module is only available when the `debug-physics` feature is enabled.
+//!
+//! [`Collider`]: struct.Collider.html
+//! [`mint`]: https://docs.rs/mint
+//! [rapier]: https://rapier.rs
+//! [parry]: https://parry.rs
+//! [`Mesh`]: ../struct.Mesh.html
+use crate::graphics::{Color, Mesh, Point, Shape, Vector};
+
+/// The shape and position of a collider.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Collider {
+    position: Point,
+    rotation: f32,
+    shape: Kind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Kind {
+    Ball { radius: f32 },
+    Cuboid { half_extents: Vector },
+    Polyline { vertices: Vec<Point> },
+}
+
+impl Collider {
+    /// Creates a ball [`Collider`] with the given position, rotation in
+    /// radians, and radius.
+    ///
+    /// [`Collider`]: struct.Collider.html
+    pub fn ball(
+        position: impl Into<mint::Point2<f32>>,
+        rotation: f32,
+        radius: f32,
+    ) -> Collider {
+        Collider {
+            position: point(position),
+            rotation,
+            shape: Kind::Ball { radius },
+        }
+    }
+
+    /// Creates a cuboid [`Collider`] with the given position, rotation in
+    /// radians, and half extents.
+    ///
+    /// [`Collider`]: struct.Collider.html
+    pub fn cuboid(
+        position: impl Into<mint::Point2<f32>>,
+        rotation: f32,
+        half_extents: impl Into<mint::Vector2<f32>>,
+    ) -> Collider {
+        let half_extents = half_extents.into();
+
+        Collider {
+            position: point(position),
+            rotation,
+            shape: Kind::Cuboid {
+                half_extents: Vector::new(half_extents.x, half_extents.y),
+            },
+        }
+    }
+
+    /// Creates a polyline [`Collider`] with the given position, rotation in
+    /// radians, and vertices relative to its position.
+    ///
+    /// [`Collider`]: struct.Collider.html
+    pub fn polyline<P: Into<mint::Point2<f32>>>(
+        position: impl Into<mint::Point2<f32>>,
+        rotation: f32,
+        vertices: impl IntoIterator<Item = P>,
+    ) -> Collider {
+        Collider {
+            position: point(position),
+            rotation,
+            shape: Kind::Polyline {
+                vertices: vertices.into_iter().map(point).collect(),
+            },
+        }
+    }
+
+    /// Returns the position of the [`Collider`].
+    ///
+    /// [`Collider`]: struct.Collider.html
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    /// Returns the rotation of the [`Collider`], in radians.
+    ///
+    /// [`Collider`]: struct.Collider.html
+    pub fn rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    // Transforms a point relative to the collider into world coordinates
+    fn transform(&self, point: Vector) -> Point {
+        let (sin, cos) = self.rotation.sin_cos();
+
+        self.position
+            + Vector::new(
+                point.x * cos - point.y * sin,
+                point.x * sin + point.y * cos,
+            )
+    }
+}
+
+/// Adds the outline of a [`Collider`] to the given [`Mesh`].
+///
+/// Balls also show a line from their center to their edge, which makes their
+/// rotation visible.
+///
+/// [`Collider`]: struct.Collider.html
+/// [`Mesh`]: ../struct.Mesh.html
+pub fn stroke(mesh: &mut Mesh, collider: &Collider, color: Color, width: f32) {
+    match &collider.shape {
+        Kind::Ball { radius } => {
+            mesh.stroke(
+                Shape::Circle {
+                    center: collider.position,
+                    radius: *radius,
+                },
+                color,
+                width,
+            );
+
+            mesh.stroke(
+                Shape::Polyline {
+                    points: vec![
+                        collider.position,
+                        collider.transform(Vector::new(*radius, 0.0)),
+                    ],
+                },
+                color,
+                width,
+            );
+        }
+        Kind::Cuboid { half_extents } => {
+            let corners = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)];
+
+            let mut points: Vec<Point> = corners
+                .iter()
+                .map(|(x, y)| {
+                    collider.transform(Vector::new(
+                        half_extents.x * x,
+                        half_extents.y * y,
+                    ))
+                })
+                .collect();
+
+            // Close the outline
+            points.push(points[0]);
+
+            mesh.stroke(Shape::Polyline { points }, color, width);
+        }
+        Kind::Polyline { vertices } => {
+            let points = vertices
+                .iter()
+                .map(|vertex| collider.transform(vertex.coords))
+                .collect();
+
+            mesh.stroke(Shape::Polyline { points }, color, width);
+        }
+    }
+}
+
+/// Creates a [`Mesh`] with the outlines of the given colliders.
+///
+/// [`Mesh`]: ../struct.Mesh.html
+pub fn mesh<'a>(
+    colliders: impl IntoIterator<Item = &'a Collider>,
+    color: Color,
+    width: f32,
+) -> Mesh {
+    let mut mesh = Mesh::new();
+
+    for collider in colliders {
+        stroke(&mut mesh, collider, color, width);
+    }
+
+    mesh
+}
+
+fn point(point: impl Into<mint::Point2<f32>>) -> Point {
+    let point = point.into();
+
+    Point::new(point.x, point.y)
+}
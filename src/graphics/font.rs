@@ -1,11 +1,51 @@
+mod path;
+
+use crate::assets;
 use crate::graphics::gpu;
-use crate::graphics::{Gpu, Target, Text};
-use crate::load::Task;
-use crate::Result;
+use crate::graphics::{
+    Color, Gpu, HorizontalAlignment, Point, Shape, Target, Text,
+    Transformation, Vector, VerticalAlignment,
+};
+use crate::load::{hot_reload, Task};
+use crate::{Error, Result};
+
+use path::Path;
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
 
 /// A collection of text with the same font.
+///
+/// Fonts loaded from a path can be [hot reloaded] during development.
+///
+/// [hot reloaded]: ../load/hot_reload/index.html
 #[allow(missing_debug_implementations)]
-pub struct Font(gpu::Font);
+pub struct Font {
+    pub(crate) raw: Rc<RefCell<gpu::Font>>,
+    glyphs: Vec<Glyph>,
+    labels: Vec<Label>,
+}
+
+// A glyph placed along a path
+struct Glyph {
+    content: String,
+    size: f32,
+    color: Color,
+    offset: Vector,
+    transformation: Transformation,
+}
+
+// Text placed in world coordinates
+struct Label {
+    content: String,
+    position: Point,
+    bounds: (f32, f32),
+    size: f32,
+    color: Color,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+}
 
 impl Font {
     pub(crate) const DEFAULT: &'static [u8] =
@@ -15,7 +55,11 @@ impl Font {
     ///
     /// [`Font`]: struct.Font.html
     pub fn from_bytes(gpu: &mut Gpu, bytes: &'static [u8]) -> Result<Font> {
-        Ok(Font(gpu.upload_font(bytes)))
+        Ok(Font {
+            raw: Rc::new(RefCell::new(gpu.upload_font(bytes))),
+            glyphs: Vec::new(),
+            labels: Vec::new(),
+        })
     }
 
     /// Creates a [`Task`] that loads a [`Font`] from raw data.
@@ -26,19 +70,174 @@ impl Font {
         Task::using_gpu(move |gpu| Font::from_bytes(gpu, bytes))
     }
 
+    /// Creates a [`Task`] that loads a [`Font`] from the given path.
+    ///
+    /// The font data is kept in memory until your game exits.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Font`]: struct.Font.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<Font> {
+        let path = path.into();
+        let p = path.clone();
+
+        Task::offload_then(
+            move || Font::read(&p),
+            move |gpu, bytes| {
+                let font = Font::from_bytes(gpu, bytes)?;
+
+                hot_reload::watch_font(path, &font.raw);
+
+                Ok(font)
+            },
+        )
+    }
+
+    // The glyph brushes of the graphics backends borrow the font data for as
+    // long as they live
+    pub(crate) fn read(path: &std::path::Path) -> Result<&'static [u8]> {
+        let bytes = assets::read(path).map_err(|error| Error::Asset {
+            path: path.to_path_buf(),
+            source: Box::new(error.into()),
+        })?;
+
+        Ok(Box::leak(bytes.into_boxed_slice()))
+    }
+
     /// Adds [`Text`] to this [`Font`].
     ///
     /// [`Text`]: struct.Text.html
     /// [`Font`]: struct.Font.html
     pub fn add(&mut self, text: Text<'_>) {
-        self.0.add(text)
+        self.raw.borrow_mut().add(text)
+    }
+
+    /// Adds [`Text`] in world coordinates to this [`Font`].
+    ///
+    /// Unlike [`add`], the text is rendered at its final size on the screen
+    /// when the [`Font`] is drawn on a scaled [`Target`]. Therefore, it stays
+    /// crisp with any camera zoom. This is useful for damage numbers,
+    /// nameplates, and other labels that move with the world.
+    ///
+    /// The `position`, `bounds`, and `size` of the [`Text`] are in world
+    /// units.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Font`]: struct.Font.html
+    /// [`add`]: #method.add
+    /// [`Target`]: struct.Target.html
+    pub fn add_world(&mut self, text: Text<'_>) {
+        self.labels.push(Label {
+            content: String::from(text.content),
+            position: text.position,
+            bounds: text.bounds,
+            size: text.size,
+            color: text.color,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+        });
+    }
+
+    /// Adds [`Text`] to this [`Font`], placing its glyphs along the outline of
+    /// the given [`Shape`].
+    ///
+    /// Each glyph is rotated to follow the outline. Circles and ellipses are
+    /// followed clockwise, starting at their top, and rectangles clockwise,
+    /// starting at their top-left corner.
+    ///
+    /// The text is placed relative to the start of the outline, according to
+    /// its alignment:
+    ///
+    ///   * The horizontal alignment defines whether the text starts, is
+    ///     centered, or ends at the start of closed outlines. For polylines,
+    ///     it defines whether the text starts at the first point, is centered
+    ///     in the polyline, or ends at the last point.
+    ///   * The vertical alignment defines whether the glyphs hang below, are
+    ///     centered on, or stand on the outline.
+    ///   * The `position` moves the text along the outline, in its `x`
+    ///     coordinate, and away from it, in its `y` coordinate.
+    ///
+    /// The bounds of the [`Text`] are ignored. Every glyph is drawn separately,
+    /// so you should keep the text short.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Font`]: struct.Font.html
+    /// [`Shape`]: enum.Shape.html
+    pub fn add_on_path(&mut self, text: Text<'_>, shape: &Shape) {
+        let path = Path::new(shape);
+
+        let size = text.size;
+        let measure = |font: &mut Font, content: &str| {
+            font.measure(Text {
+                content,
+                size,
+                ..Text::default()
+            })
+            .0
+        };
+
+        // The advance of each glyph is the difference between the widths of
+        // the text before and after it
+        let mut advances = Vec::new();
+        let mut previous = 0.0;
+
+        for (i, character) in text.content.char_indices() {
+            let width =
+                measure(self, &text.content[..i + character.len_utf8()]);
+
+            advances.push((character, previous, width - previous));
+            previous = width;
+        }
+
+        let anchor = if path.is_closed() {
+            0.0
+        } else {
+            match text.horizontal_alignment {
+                HorizontalAlignment::Left => 0.0,
+                HorizontalAlignment::Center => path.length() / 2.0,
+                HorizontalAlignment::Right => path.length(),
+            }
+        };
+
+        let start = anchor + text.position.x
+            - match text.horizontal_alignment {
+                HorizontalAlignment::Left => 0.0,
+                HorizontalAlignment::Center => previous / 2.0,
+                HorizontalAlignment::Right => previous,
+            };
+
+        let y = text.position.y
+            - match text.vertical_alignment {
+                VerticalAlignment::Top => 0.0,
+                VerticalAlignment::Center => size / 2.0,
+                VerticalAlignment::Bottom => size,
+            };
+
+        for (character, x, advance) in advances {
+            if character.is_whitespace() {
+                continue;
+            }
+
+            let (point, angle) = match path.at(start + x + advance / 2.0) {
+                Some(placement) => placement,
+                None => return,
+            };
+
+            self.glyphs.push(Glyph {
+                content: character.to_string(),
+                size,
+                color: text.color,
+                offset: Vector::new(-advance / 2.0, y),
+                transformation: Transformation::translate(point.coords)
+                    * Transformation::rotate(angle),
+            });
+        }
     }
 
     /// Computes the layout bounds of the given [`Text`].
     ///
     /// [`Text`]: struct.Text.html
     pub fn measure(&mut self, text: Text<'_>) -> (f32, f32) {
-        self.0.measure(text)
+        self.raw.borrow_mut().measure(text)
     }
 
     /// Renders and flushes all the text added to this [`Font`].
@@ -46,6 +245,47 @@ impl Font {
     /// [`Font`]: struct.Font.html
     #[inline]
     pub fn draw(&mut self, target: &mut Target<'_>) {
-        target.draw_font(&mut self.0)
+        let mut raw = self.raw.borrow_mut();
+
+        target.draw_font(&mut raw);
+
+        for glyph in self.glyphs.drain(..) {
+            raw.add(Text {
+                content: &glyph.content,
+                position: Point::new(0.0, 0.0) + glyph.offset,
+                size: glyph.size,
+                color: glyph.color,
+                ..Text::default()
+            });
+
+            target.transform(glyph.transformation).draw_font(&mut raw);
+        }
+
+        let scale = target.scale();
+
+        if self.labels.is_empty() || scale == 0.0 {
+            self.labels.clear();
+            return;
+        }
+
+        // Labels are laid out at their size on the screen and scaled back
+        for label in self.labels.drain(..) {
+            raw.add(Text {
+                content: &label.content,
+                position: Point::new(
+                    label.position.x * scale,
+                    label.position.y * scale,
+                ),
+                bounds: (label.bounds.0 * scale, label.bounds.1 * scale),
+                size: label.size * scale,
+                color: label.color,
+                horizontal_alignment: label.horizontal_alignment,
+                vertical_alignment: label.vertical_alignment,
+            });
+        }
+
+        target
+            .transform(Transformation::scale(1.0 / scale))
+            .draw_font(&mut raw);
     }
 }
@@ -0,0 +1,174 @@
+use std::f32::consts::PI;
+
+use crate::graphics::{Point, Rectangle, Shape, Vector};
+
+// A shape flattened into line segments, measured by arc length
+pub struct Path {
+    points: Vec<Point>,
+    distances: Vec<f32>,
+    is_closed: bool,
+}
+
+impl Path {
+    pub fn new(shape: &Shape) -> Path {
+        let (mut points, is_closed) = match shape {
+            Shape::Rectangle(rectangle) => (
+                vec![
+                    Point::new(rectangle.x, rectangle.y),
+                    Point::new(rectangle.x + rectangle.width, rectangle.y),
+                    Point::new(
+                        rectangle.x + rectangle.width,
+                        rectangle.y + rectangle.height,
+                    ),
+                    Point::new(rectangle.x, rectangle.y + rectangle.height),
+                ],
+                true,
+            ),
+            Shape::RoundedRectangle { bounds, radius } => {
+                (rounded_rectangle(*bounds, *radius), true)
+            }
+            Shape::Circle { center, radius } => {
+                (ellipse(*center, *radius, *radius, 0.0), true)
+            }
+            Shape::Ellipse {
+                center,
+                horizontal_radius,
+                vertical_radius,
+                rotation,
+            } => (
+                ellipse(
+                    *center,
+                    *horizontal_radius,
+                    *vertical_radius,
+                    *rotation,
+                ),
+                true,
+            ),
+            Shape::Polyline { points } => (points.clone(), false),
+        };
+
+        if is_closed {
+            if let Some(first) = points.first().copied() {
+                points.push(first);
+            }
+        }
+
+        let mut distances = Vec::with_capacity(points.len());
+        let mut total = 0.0;
+
+        for (i, point) in points.iter().enumerate() {
+            if i > 0 {
+                total += (*point - points[i - 1]).norm();
+            }
+
+            distances.push(total);
+        }
+
+        Path {
+            points,
+            distances,
+            is_closed,
+        }
+    }
+
+    pub fn length(&self) -> f32 {
+        self.distances.last().copied().unwrap_or(0.0)
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.is_closed
+    }
+
+    // Returns the point at the given distance along the path and the angle of
+    // the path there. Open paths are extended along their ends.
+    pub fn at(&self, distance: f32) -> Option<(Point, f32)> {
+        if self.points.len() < 2 {
+            return None;
+        }
+
+        let length = self.length();
+
+        let distance = if self.is_closed && length > 0.0 {
+            distance.rem_euclid(length)
+        } else {
+            distance
+        };
+
+        let segment = self
+            .distances
+            .iter()
+            .skip(1)
+            .position(|end| distance <= *end)
+            .unwrap_or(self.points.len() - 2);
+
+        let start = self.points[segment];
+        let direction: Vector = self.points[segment + 1] - start;
+        let segment_length = direction.norm();
+
+        if segment_length == 0.0 {
+            return Some((start, 0.0));
+        }
+
+        let offset = distance - self.distances[segment];
+
+        Some((
+            start + direction * (offset / segment_length),
+            direction.y.atan2(direction.x),
+        ))
+    }
+}
+
+// Samples an ellipse clockwise, starting at its top
+fn ellipse(
+    center: Point,
+    horizontal_radius: f32,
+    vertical_radius: f32,
+    rotation: f32,
+) -> Vec<Point> {
+    let segments = (horizontal_radius.max(vertical_radius).ceil() as usize)
+        .max(32)
+        .min(256);
+
+    let (sin, cos) = rotation.sin_cos();
+
+    (0..segments)
+        .map(|i| {
+            let angle = -PI / 2.0 + 2.0 * PI * i as f32 / segments as f32;
+            let x = horizontal_radius * angle.cos();
+            let y = vertical_radius * angle.sin();
+
+            center + Vector::new(x * cos - y * sin, x * sin + y * cos)
+        })
+        .collect()
+}
+
+fn rounded_rectangle(bounds: Rectangle<f32>, radius: f32) -> Vec<Point> {
+    let radius = radius.max(0.0).min(bounds.width.min(bounds.height) / 2.0);
+    let segments = (radius.ceil() as usize).max(8).min(64);
+
+    // The centers of the corners, clockwise from the top left one
+    let corners = [
+        Point::new(bounds.x + radius, bounds.y + radius),
+        Point::new(bounds.x + bounds.width - radius, bounds.y + radius),
+        Point::new(
+            bounds.x + bounds.width - radius,
+            bounds.y + bounds.height - radius,
+        ),
+        Point::new(bounds.x + radius, bounds.y + bounds.height - radius),
+    ];
+
+    corners
+        .iter()
+        .enumerate()
+        .flat_map(|(i, center)| {
+            let start = PI + PI / 2.0 * i as f32;
+
+            (0..=segments).map(move |j| {
+                let angle = start + PI / 2.0 * j as f32 / segments as f32;
+
+                *center
+                    + Vector::new(radius * angle.cos(), radius * angle.sin())
+            })
+        })
+        .collect()
+}
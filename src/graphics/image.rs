@@ -1,11 +1,16 @@
-use std::fs::File;
-use std::io::Read;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
 
+use crate::assets;
 use crate::graphics::gpu::{self, Texture};
-use crate::graphics::{Color, Gpu, IntoQuad, Target};
-use crate::load::Task;
-use crate::Result;
+use crate::graphics::{
+    Color, Gpu, IntoQuad, Palette, Shader, ShaderKind, Target,
+};
+use crate::load::{hot_reload, Task};
+use crate::{Error, Result};
 
 /// A loaded image.
 ///
@@ -14,10 +19,13 @@ use crate::Result;
 /// Cloning an [`Image`] is cheap, it only clones a handle. It does not
 /// create a new copy of the image on the GPU.
 ///
+/// Images loaded from a path can be [hot reloaded] during development.
+///
 /// [`Image`]: struct.Image.html
+/// [hot reloaded]: ../load/hot_reload/index.html
 #[derive(Clone)]
 pub struct Image {
-    pub(super) texture: Texture,
+    pub(crate) texture: Rc<RefCell<Texture>>,
 }
 
 impl Image {
@@ -25,24 +33,109 @@ impl Image {
     ///
     /// [`Image`]: struct.Image.html
     pub fn new<P: AsRef<Path>>(gpu: &mut Gpu, path: P) -> Result<Image> {
-        let image = {
-            let mut buf = Vec::new();
-            let mut reader = File::open(path)?;
-            let _ = reader.read_to_end(&mut buf)?;
-            image::load_from_memory(&buf)?
-        };
+        let image = Image::decode(&path)?;
+        let image = Image::from_image(gpu, &image)?;
 
-        Image::from_image(gpu, &image)
+        hot_reload::watch_image(path.as_ref().to_path_buf(), &image.texture);
+
+        Ok(image)
     }
 
     /// Creates a [`Task`] that loads an [`Image`] from the given path.
     ///
+    /// The image is decoded on a background thread. Therefore, multiple images
+    /// can be decoded concurrently using [`JoinParallel`].
+    ///
     /// [`Task`]: ../load/struct.Task.html
     /// [`Image`]: struct.Image.html
+    /// [`JoinParallel`]: ../load/trait.JoinParallel.html
     pub fn load<P: Into<PathBuf>>(path: P) -> Task<Image> {
-        let p = path.into();
+        let path = path.into();
+        let p = path.clone();
+
+        Task::offload_then(
+            move || Image::decode(&p),
+            move |gpu, image| {
+                let image = Image::from_image(gpu, &image)?;
+
+                hot_reload::watch_image(path, &image.texture);
+
+                Ok(image)
+            },
+        )
+    }
+
+    /// Creates a [`Task`] that loads all the images matching the given glob
+    /// pattern.
+    ///
+    /// The images are decoded concurrently on background threads. The paths
+    /// are matched using [`assets::glob`].
+    ///
+    /// ```
+    /// use coffee::graphics::Image;
+    ///
+    /// let load_sprites = Image::load_dir("sprites/**/*.png");
+    /// ```
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`assets::glob`]: ../assets/fn.glob.html
+    pub fn load_dir(pattern: &str) -> Task<HashMap<PathBuf, Image>> {
+        match assets::glob(pattern) {
+            Ok(paths) => Task::parallel(
+                paths
+                    .into_iter()
+                    .map(|path| {
+                        Image::load(path.clone())
+                            .map(move |image| (path, image))
+                    })
+                    .collect(),
+            )
+            .map(|images| images.into_iter().collect()),
+            Err(error) => {
+                let path = PathBuf::from(pattern);
 
-        Task::using_gpu(move |gpu| Image::new(gpu, &p))
+                Task::new(move || {
+                    Err(Error::Asset {
+                        path,
+                        source: Box::new(error.into()),
+                    })
+                })
+            }
+        }
+    }
+
+    /// Loads an [`Image`] from the given encoded bytes.
+    ///
+    /// The format of the image is guessed from its contents. This is useful
+    /// to load images embedded with `include_bytes!` or obtained from an
+    /// archive.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn from_bytes(gpu: &mut Gpu, bytes: &[u8]) -> Result<Image> {
+        let image = image::load_from_memory(bytes)?;
+
+        Image::from_image(gpu, &image)
+    }
+
+    /// Creates a [`Task`] that loads an [`Image`] from the given encoded bytes.
+    ///
+    /// The bytes can either be borrowed statically, like the ones obtained with
+    /// `include_bytes!`, or owned, like a `Vec<u8>` downloaded from the
+    /// network.
+    ///
+    /// The image is decoded on a background thread.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Image`]: struct.Image.html
+    pub fn load_from_bytes<B: Into<Cow<'static, [u8]>>>(
+        bytes: B,
+    ) -> Task<Image> {
+        let bytes = bytes.into();
+
+        Task::offload_then(
+            move || Ok(image::load_from_memory(&bytes)?),
+            |gpu, image| Image::from_image(gpu, &image),
+        )
     }
 
     /// Creates an [`Image`] from a [`DynamicImage`] of the [`image` crate].
@@ -56,7 +149,9 @@ impl Image {
     ) -> Result<Image> {
         let texture = gpu.upload_texture(&image);
 
-        Ok(Image { texture })
+        Ok(Image {
+            texture: Rc::new(RefCell::new(texture)),
+        })
     }
 
     /// Creates an [`Image`] representing a color palette.
@@ -82,18 +177,33 @@ impl Image {
         )
     }
 
+    pub(crate) fn decode<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<image::DynamicImage> {
+        let decode = || -> Result<image::DynamicImage> {
+            let bytes = assets::read(&path)?;
+
+            Ok(image::load_from_memory(&bytes)?)
+        };
+
+        decode().map_err(|error| Error::Asset {
+            path: path.as_ref().to_path_buf(),
+            source: Box::new(error),
+        })
+    }
+
     /// Returns the width of the [`Image`].
     ///
     /// [`Image`]: struct.Image.html
     pub fn width(&self) -> u16 {
-        self.texture.width()
+        self.texture.borrow().width()
     }
 
     /// Returns the height of the [`Image`].
     ///
     /// [`Image`]: struct.Image.html
     pub fn height(&self) -> u16 {
-        self.texture.height()
+        self.texture.borrow().height()
     }
 
     /// Draws the [`Image`] on the given [`Target`].
@@ -103,7 +213,61 @@ impl Image {
     #[inline]
     pub fn draw<Q: IntoQuad>(&self, quad: Q, target: &mut Target<'_>) {
         target.draw_texture_quads(
-            &self.texture,
+            &self.texture.borrow(),
+            &[gpu::Quad::from(quad.into_quad(
+                1.0 / self.width() as f32,
+                1.0 / self.height() as f32,
+            ))],
+        );
+    }
+
+    /// Draws the [`Image`] on the given [`Target`] using the colors of a
+    /// [`Palette`].
+    ///
+    /// The red channel of the [`Image`] selects a color of the [`Palette`].
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`Target`]: struct.Target.html
+    /// [`Palette`]: struct.Palette.html
+    #[inline]
+    pub fn draw_with_palette<Q: IntoQuad>(
+        &self,
+        quad: Q,
+        palette: &Palette,
+        target: &mut Target<'_>,
+    ) {
+        target.draw_palette_quads(
+            &self.texture.borrow(),
+            &palette.image.texture.borrow(),
+            &[gpu::Quad::from(quad.into_quad(
+                1.0 / self.width() as f32,
+                1.0 / self.height() as f32,
+            ))],
+        );
+    }
+
+    /// Draws the [`Image`] on the given [`Target`] using a custom [`Shader`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`Shader`] is not a [`ShaderKind::Quad`].
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`Target`]: struct.Target.html
+    /// [`Shader`]: struct.Shader.html
+    /// [`ShaderKind::Quad`]: enum.ShaderKind.html#variant.Quad
+    #[inline]
+    pub fn draw_with_shader<Q: IntoQuad>(
+        &self,
+        quad: Q,
+        shader: &Shader,
+        target: &mut Target<'_>,
+    ) {
+        assert_eq!(shader.kind(), ShaderKind::Quad, "A quad shader is needed");
+
+        target.draw_shader_quads(
+            &self.texture.borrow(),
+            shader,
             &[gpu::Quad::from(quad.into_quad(
                 1.0 / self.width() as f32,
                 1.0 / self.height() as f32,
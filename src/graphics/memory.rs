@@ -0,0 +1,94 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// An estimation of the GPU memory used by the live resources of a [`Gpu`].
+///
+/// It only accounts for the resources created by Coffee. Memory used by the
+/// driver or the glyph caches of a [`Font`] is not included.
+///
+/// [`Gpu`]: struct.Gpu.html
+/// [`Font`]: struct.Font.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryUsage {
+    /// Bytes used by images and texture arrays
+    pub textures: u64,
+
+    /// Bytes used by canvases
+    pub canvases: u64,
+
+    /// Bytes used by vertex, index, and instance buffers
+    pub buffers: u64,
+}
+
+impl MemoryUsage {
+    /// Returns the total amount of bytes used.
+    pub fn total(&self) -> u64 {
+        self.textures + self.canvases + self.buffers
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Resource {
+    Texture,
+    Canvas,
+    Buffer,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Tracker {
+    usage: Rc<Cell<MemoryUsage>>,
+}
+
+impl Tracker {
+    pub fn new() -> Tracker {
+        Tracker::default()
+    }
+
+    pub fn allocate(&self, resource: Resource, bytes: u64) -> Allocation {
+        self.update(resource, |used| used + bytes);
+
+        Allocation(Rc::new(Inner {
+            tracker: self.clone(),
+            resource,
+            bytes,
+        }))
+    }
+
+    pub fn usage(&self) -> MemoryUsage {
+        self.usage.get()
+    }
+
+    fn update(&self, resource: Resource, f: impl FnOnce(u64) -> u64) {
+        let mut usage = self.usage.get();
+
+        let used = match resource {
+            Resource::Texture => &mut usage.textures,
+            Resource::Canvas => &mut usage.canvases,
+            Resource::Buffer => &mut usage.buffers,
+        };
+
+        *used = f(*used);
+
+        self.usage.set(usage);
+    }
+}
+
+// Releases its bytes from the tracker once the last clone is dropped
+#[derive(Debug, Clone)]
+pub(crate) struct Allocation(Rc<Inner>);
+
+#[derive(Debug)]
+struct Inner {
+    tracker: Tracker,
+    resource: Resource,
+    bytes: u64,
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        let bytes = self.bytes;
+
+        self.tracker
+            .update(self.resource, |used| used.saturating_sub(bytes));
+    }
+}
@@ -1,7 +1,17 @@
-use crate::graphics::{gpu, Color, Rectangle, Shape, Target};
+use crate::graphics::{
+    gpu, Color, Rectangle, Shader, ShaderKind, Shape, Target,
+};
 
 use lyon_tessellation as lyon;
 
+#[cfg(feature = "svg")]
+mod svg;
+
+#[cfg(feature = "svg")]
+use crate::{assets, Error, Result};
+#[cfg(feature = "svg")]
+use std::path::Path;
+
 /// A set of shapes that can be drawn.
 ///
 /// # Tolerance
@@ -47,6 +57,36 @@ impl Mesh {
         }
     }
 
+    /// Creates a new [`Mesh`] from the SVG file in the given path, using the
+    /// given tolerance.
+    ///
+    /// The fill and stroke of every path in the file are tessellated using
+    /// their solid color. Gradients and patterns are not supported, and the
+    /// paths using them are skipped.
+    ///
+    /// This is only available when the `svg` feature is enabled.
+    ///
+    /// [`Mesh`]: struct.Mesh.html
+    #[cfg(feature = "svg")]
+    pub fn from_svg<P: AsRef<Path>>(path: P, tolerance: f32) -> Result<Mesh> {
+        let load = || -> Result<Mesh> {
+            let bytes = assets::read(&path)?;
+
+            let tree = usvg::Tree::from_data(&bytes, &usvg::Options::default())
+                .map_err(|error| Error::Svg(error.to_string()))?;
+
+            let mut mesh = Mesh::new_with_tolerance(tolerance);
+            svg::tessellate(&mut mesh, &tree);
+
+            Ok(mesh)
+        };
+
+        load().map_err(|error| Error::Asset {
+            path: path.as_ref().to_path_buf(),
+            source: Box::new(error),
+        })
+    }
+
     /// Returns true if the [`Mesh`] is empty.
     ///
     /// [`Mesh`]: struct.Mesh.html
@@ -79,6 +119,20 @@ impl Mesh {
                 )
                 .expect("Fill rectangle");
             }
+            Shape::RoundedRectangle { bounds, radius } => {
+                let _ = lyon::basic_shapes::fill_rounded_rectangle(
+                    &lyon::math::rect(
+                        bounds.x,
+                        bounds.y,
+                        bounds.width,
+                        bounds.height,
+                    ),
+                    &lyon::basic_shapes::BorderRadii::new_all_same(radius),
+                    &Self::fill_options(self.tolerance),
+                    &mut builder,
+                )
+                .expect("Fill rounded rectangle");
+            }
             Shape::Circle { center, radius } => {
                 let _ = lyon::basic_shapes::fill_circle(
                     lyon::math::point(center.x, center.y),
@@ -142,6 +196,20 @@ impl Mesh {
                 )
                 .expect("Stroke rectangle");
             }
+            Shape::RoundedRectangle { bounds, radius } => {
+                let _ = lyon::basic_shapes::stroke_rounded_rectangle(
+                    &lyon::math::rect(
+                        bounds.x,
+                        bounds.y,
+                        bounds.width,
+                        bounds.height,
+                    ),
+                    &lyon::basic_shapes::BorderRadii::new_all_same(radius),
+                    &Self::stroke_options(self.tolerance, width),
+                    &mut builder,
+                )
+                .expect("Stroke rounded rectangle");
+            }
             Shape::Circle { center, radius } => {
                 let _ = lyon::basic_shapes::stroke_circle(
                     lyon::math::point(center.x, center.y),
@@ -180,6 +248,48 @@ impl Mesh {
         }
     }
 
+    /// Adds a filled [lyon] path to the [`Mesh`].
+    ///
+    /// This is useful if you already build your paths with lyon. The path
+    /// needs to come from the same version of lyon used by Coffee, `0.13`.
+    ///
+    /// [lyon]: https://docs.rs/lyon
+    /// [`Mesh`]: struct.Mesh.html
+    pub fn fill_path(&mut self, path: &lyon::path::Path, color: Color) {
+        let mut builder = lyon::BuffersBuilder::new(
+            &mut self.buffers,
+            WithColor(color.into_linear()),
+        );
+
+        if let Err(error) = lyon::FillTessellator::new().tessellate_path(
+            path.iter(),
+            &Self::fill_options(self.tolerance),
+            &mut builder,
+        ) {
+            log::warn!("Path failed to fill: {:?}", error);
+        }
+    }
+
+    /// Adds the stroke of a [lyon] path to the [`Mesh`].
+    ///
+    /// This is useful if you already build your paths with lyon. The path
+    /// needs to come from the same version of lyon used by Coffee, `0.13`.
+    ///
+    /// [lyon]: https://docs.rs/lyon
+    /// [`Mesh`]: struct.Mesh.html
+    pub fn stroke_path(
+        &mut self,
+        path: &lyon::path::Path,
+        color: Color,
+        width: f32,
+    ) {
+        self.stroke_path_with(
+            path,
+            color,
+            &Self::stroke_options(self.tolerance, width),
+        );
+    }
+
     /// Draws the [`Mesh`] on the given [`Target`].
     ///
     /// [`Mesh`]: struct.Mesh.html
@@ -188,6 +298,46 @@ impl Mesh {
         target.draw_triangles(&self.buffers.vertices, &self.buffers.indices);
     }
 
+    /// Draws the [`Mesh`] on the given [`Target`] using a custom [`Shader`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`Shader`] is not a [`ShaderKind::Mesh`].
+    ///
+    /// [`Mesh`]: struct.Mesh.html
+    /// [`Target`]: struct.Target.html
+    /// [`Shader`]: struct.Shader.html
+    /// [`ShaderKind::Mesh`]: enum.ShaderKind.html#variant.Mesh
+    pub fn draw_with_shader(&self, shader: &Shader, target: &mut Target<'_>) {
+        assert_eq!(shader.kind(), ShaderKind::Mesh, "A mesh shader is needed");
+
+        target.draw_shader_triangles(
+            shader,
+            &self.buffers.vertices,
+            &self.buffers.indices,
+        );
+    }
+
+    fn stroke_path_with(
+        &mut self,
+        path: &lyon::path::Path,
+        color: Color,
+        options: &lyon::StrokeOptions,
+    ) {
+        let mut builder = lyon::BuffersBuilder::new(
+            &mut self.buffers,
+            WithColor(color.into_linear()),
+        );
+
+        if let Err(error) = lyon::StrokeTessellator::new().tessellate_path(
+            path.iter(),
+            options,
+            &mut builder,
+        ) {
+            log::warn!("Path failed to stroke: {:?}", error);
+        }
+    }
+
     fn fill_options(tolerance: f32) -> lyon::FillOptions {
         lyon::FillOptions::DEFAULT
             .with_tolerance(tolerance)
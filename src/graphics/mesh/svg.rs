@@ -0,0 +1,117 @@
+use super::Mesh;
+use crate::graphics::Color;
+
+use lyon::path::builder::{FlatPathBuilder, PathBuilder};
+use lyon_tessellation as lyon;
+use usvg::NodeExt;
+
+// Tessellates the visible paths of an SVG tree into the given mesh
+pub(super) fn tessellate(mesh: &mut Mesh, tree: &usvg::Tree) {
+    for node in tree.root().descendants() {
+        if let usvg::NodeKind::Path(ref path) = *node.borrow() {
+            if path.visibility != usvg::Visibility::Visible {
+                continue;
+            }
+
+            let transform = node.abs_transform();
+            let outline = build_path(&path.data, &transform);
+
+            if let Some(fill) = &path.fill {
+                if let Some(color) = color(&fill.paint, fill.opacity.value()) {
+                    mesh.fill_path(&outline, color);
+                }
+            }
+
+            if let Some(stroke) = &path.stroke {
+                if let Some(color) =
+                    color(&stroke.paint, stroke.opacity.value())
+                {
+                    let width = stroke.width.value() * scale(&transform);
+
+                    let options =
+                        Mesh::stroke_options(mesh.tolerance, width as f32)
+                            .with_line_cap(line_cap(stroke.linecap))
+                            .with_line_join(line_join(stroke.linejoin));
+
+                    mesh.stroke_path_with(&outline, color, &options);
+                }
+            }
+        }
+    }
+}
+
+fn build_path(
+    data: &usvg::PathData,
+    transform: &usvg::Transform,
+) -> lyon::path::Path {
+    let point = |x: f64, y: f64| {
+        let (x, y) = transform.apply(x, y);
+
+        lyon::math::point(x as f32, y as f32)
+    };
+
+    let mut builder = lyon::path::Path::builder();
+
+    for segment in data.iter() {
+        match *segment {
+            usvg::PathSegment::MoveTo { x, y } => {
+                builder.move_to(point(x, y));
+            }
+            usvg::PathSegment::LineTo { x, y } => {
+                builder.line_to(point(x, y));
+            }
+            usvg::PathSegment::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => {
+                builder.cubic_bezier_to(
+                    point(x1, y1),
+                    point(x2, y2),
+                    point(x, y),
+                );
+            }
+            usvg::PathSegment::ClosePath => {
+                builder.close();
+            }
+        }
+    }
+
+    builder.build()
+}
+
+fn color(paint: &usvg::Paint, opacity: f64) -> Option<Color> {
+    match paint {
+        usvg::Paint::Color(color) => Some(Color {
+            a: opacity as f32,
+            ..Color::from_rgb(color.red, color.green, color.blue)
+        }),
+        usvg::Paint::Link(_) => None,
+    }
+}
+
+// Approximates the scale of a transform to obtain stroke widths
+fn scale(transform: &usvg::Transform) -> f64 {
+    (transform.a * transform.d - transform.b * transform.c)
+        .abs()
+        .sqrt()
+}
+
+fn line_cap(line_cap: usvg::LineCap) -> lyon::LineCap {
+    match line_cap {
+        usvg::LineCap::Butt => lyon::LineCap::Butt,
+        usvg::LineCap::Round => lyon::LineCap::Round,
+        usvg::LineCap::Square => lyon::LineCap::Square,
+    }
+}
+
+fn line_join(line_join: usvg::LineJoin) -> lyon::LineJoin {
+    match line_join {
+        usvg::LineJoin::Miter => lyon::LineJoin::Miter,
+        usvg::LineJoin::Round => lyon::LineJoin::Round,
+        usvg::LineJoin::Bevel => lyon::LineJoin::Bevel,
+    }
+}
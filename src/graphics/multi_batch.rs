@@ -0,0 +1,87 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::graphics::gpu::Texture;
+use crate::graphics::{Batch, Image, IntoQuad, Target};
+
+/// A collection of quads that use different images, drawn with one draw call
+/// per [`Image`].
+///
+/// Quads are grouped by [`Image`] in the order each [`Image`] was first added.
+/// Therefore, quads that use different images are not necessarily drawn in
+/// the order they were added. If you need a specific order across images,
+/// use different batches instead.
+///
+/// [`Image`]: struct.Image.html
+#[derive(Default)]
+pub struct MultiBatch {
+    batches: Vec<Batch>,
+    indices: HashMap<*const RefCell<Texture>, usize>,
+}
+
+impl MultiBatch {
+    /// Creates a new empty [`MultiBatch`].
+    ///
+    /// [`MultiBatch`]: struct.MultiBatch.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a quad that uses the given [`Image`] to the [`MultiBatch`].
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`MultiBatch`]: struct.MultiBatch.html
+    #[inline]
+    pub fn add<Q: IntoQuad>(&mut self, image: &Image, quad: Q) {
+        let batches = &mut self.batches;
+
+        let index = *self
+            .indices
+            .entry(Rc::as_ptr(&image.texture))
+            .or_insert_with(|| {
+                batches.push(Batch::new(image.clone()));
+                batches.len() - 1
+            });
+
+        self.batches[index].add(quad);
+    }
+
+    /// Returns the amount of draw calls needed to draw the [`MultiBatch`].
+    ///
+    /// [`MultiBatch`]: struct.MultiBatch.html
+    pub fn draw_calls(&self) -> usize {
+        self.batches
+            .iter()
+            .filter(|batch| !batch.is_empty())
+            .count()
+    }
+
+    /// Draws the [`MultiBatch`] on the given [`Target`].
+    ///
+    /// [`MultiBatch`]: struct.MultiBatch.html
+    /// [`Target`]: struct.Target.html
+    pub fn draw(&self, target: &mut Target<'_>) {
+        for batch in self.batches.iter().filter(|batch| !batch.is_empty()) {
+            batch.draw(target);
+        }
+    }
+
+    /// Clears the [`MultiBatch`] contents.
+    ///
+    /// The images added so far are kept, so the memory of their quads can be
+    /// reused. Create a new [`MultiBatch`] to release them.
+    ///
+    /// [`MultiBatch`]: struct.MultiBatch.html
+    pub fn clear(&mut self) {
+        for batch in self.batches.iter_mut() {
+            batch.clear();
+        }
+    }
+}
+
+impl std::fmt::Debug for MultiBatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MultiBatch {{ images: {} }}", self.batches.len())
+    }
+}
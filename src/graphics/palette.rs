@@ -0,0 +1,64 @@
+use crate::graphics::{Color, Gpu, Image};
+use crate::Result;
+
+/// A list of colors used to draw images with indexed colors.
+///
+/// When an [`Image`] or a [`Batch`] is drawn with a [`Palette`], the red
+/// channel of each pixel is used as an index into the [`Palette`] and the
+/// pixel is drawn with the color found there. The alpha of the pixel is kept,
+/// multiplied by the alpha of the [`Palette`] color.
+///
+/// This lets you reuse the same spritesheet for different team colors or to
+/// flash a sprite when it takes damage, by drawing it with another
+/// [`Palette`]. Indices out of bounds use the last color of the [`Palette`].
+///
+/// Cloning a [`Palette`] is cheap, it only clones a handle.
+///
+/// [`Image`]: struct.Image.html
+/// [`Batch`]: struct.Batch.html
+/// [`Palette`]: struct.Palette.html
+#[derive(Clone)]
+pub struct Palette {
+    pub(crate) image: Image,
+}
+
+impl Palette {
+    /// The maximum amount of colors in a [`Palette`].
+    ///
+    /// [`Palette`]: struct.Palette.html
+    pub const MAX_COLORS: usize = 256;
+
+    /// Creates a new [`Palette`] with the given colors.
+    ///
+    /// The first [`MAX_COLORS`] colors are used, as an index is stored in a
+    /// single byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` is empty.
+    ///
+    /// [`Palette`]: struct.Palette.html
+    /// [`MAX_COLORS`]: #associatedconstant.MAX_COLORS
+    pub fn new(gpu: &mut Gpu, colors: &[Color]) -> Result<Palette> {
+        assert!(!colors.is_empty(), "A palette needs at least one color");
+
+        let colors = &colors[..colors.len().min(Self::MAX_COLORS)];
+
+        Ok(Palette {
+            image: Image::from_colors(gpu, colors)?,
+        })
+    }
+
+    /// Returns the amount of colors in the [`Palette`].
+    ///
+    /// [`Palette`]: struct.Palette.html
+    pub fn size(&self) -> usize {
+        usize::from(self.image.width())
+    }
+}
+
+impl std::fmt::Debug for Palette {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Palette {{ colors: {} }}", self.size())
+    }
+}
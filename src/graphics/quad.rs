@@ -13,6 +13,11 @@ pub struct Quad {
 
     /// The size of the quad.
     pub size: (f32, f32),
+
+    /// How the [`source`] region is stored in the resource.
+    ///
+    /// [`source`]: #structfield.source
+    pub packing: Packing,
 }
 
 impl Default for Quad {
@@ -26,10 +31,60 @@ impl Default for Quad {
             },
             position: Point::new(0.0, 0.0),
             size: (1.0, 1.0),
+            packing: Packing::NONE,
         }
     }
 }
 
+/// How the pixels of a region are stored in a resource.
+///
+/// Texture packers may rotate or flip the images of an atlas to save space.
+/// Draw their regions with the same [`Packing`] to show them as they were.
+///
+/// Flips are applied to the region once rotated back.
+///
+/// [`Packing`]: struct.Packing.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Packing {
+    /// Whether the region is stored rotated 90 degrees clockwise, like the
+    /// `rotated` frames of [TexturePacker].
+    ///
+    /// [TexturePacker]: https://www.codeandweb.com/texturepacker
+    pub rotated: bool,
+
+    /// Whether the region is stored mirrored horizontally.
+    pub flip_horizontally: bool,
+
+    /// Whether the region is stored mirrored vertically.
+    pub flip_vertically: bool,
+}
+
+impl Packing {
+    /// A region stored as it is drawn.
+    pub const NONE: Packing = Packing {
+        rotated: false,
+        flip_horizontally: false,
+        flip_vertically: false,
+    };
+
+    /// A region stored rotated 90 degrees clockwise.
+    pub const ROTATED: Packing = Packing {
+        rotated: true,
+        flip_horizontally: false,
+        flip_vertically: false,
+    };
+
+    // The flags read by the quad shader, next to the texture layer
+    pub(crate) fn flags(self) -> u32 {
+        let flags = u32::from(self.rotated)
+            | u32::from(self.flip_horizontally) << 1
+            | u32::from(self.flip_vertically) << 2;
+
+        flags << 16
+    }
+}
+
 /// Turn a type into a quad.
 ///
 /// Most methods accept generic types that can be turned into quads. This allows
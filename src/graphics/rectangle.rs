@@ -2,6 +2,7 @@ use crate::graphics::Point;
 
 /// A generic rectangle.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rectangle<T> {
     /// X coordinate of the top-left corner.
     pub x: T,
@@ -0,0 +1,365 @@
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::assets;
+use crate::graphics::gpu::{self, Gpu};
+use crate::graphics::{Color, Point, Vector};
+use crate::load::{hot_reload, Task};
+use crate::{Error, Result};
+
+/// A custom fragment shader.
+///
+/// A [`Shader`] replaces the fragment shader used to draw an [`Image`], a
+/// [`Batch`], a [`Canvas`], or a [`Mesh`], depending on its [`ShaderKind`].
+/// Use it to implement effects like outlines, dissolves, or color grading.
+///
+/// As every graphics backend consumes a different language, you need to
+/// provide the source of the shader in GLSL, for OpenGL, and compiled to
+/// SPIR-V, for Vulkan, Metal, and DirectX. The SPIR-V binary can be obtained
+/// with a tool like `glslangValidator`.
+///
+/// # Inputs
+/// A quad shader receives the texture coordinates, the layer, and the color of
+/// the quad. In GLSL:
+///
+/// ```glsl
+/// #version 150 core
+///
+/// uniform sampler2DArray t_Texture;
+/// flat in uint v_Layer;
+/// in vec2 v_Uv;
+/// in vec4 v_Color;
+///
+/// out vec4 Target0;
+/// ```
+///
+/// And in the source compiled to SPIR-V:
+///
+/// ```glsl
+/// #version 450
+///
+/// layout(location = 0) in vec2 v_Uv;
+/// layout(location = 1) flat in uint v_Layer;
+/// layout(location = 2) in vec4 v_Color;
+///
+/// layout(set = 0, binding = 1) uniform sampler u_Sampler;
+/// layout(set = 1, binding = 0) uniform texture2DArray u_Texture;
+///
+/// layout(location = 0) out vec4 o_Target;
+/// ```
+///
+/// A mesh shader only receives the color of the vertices, as `v_Color` at
+/// `location = 0`.
+///
+/// # Uniforms
+/// A [`Shader`] declares the names of up to [`MAX_UNIFORMS`] uniforms when it
+/// is created, and their values are set by name with [`set_uniform`]. Each
+/// uniform is a `vec4` in a `Uniforms` block, in the order they are declared.
+/// The members of the block are named after their position:
+///
+/// ```glsl
+/// layout (std140) uniform Uniforms {
+///     vec4 u_Uniform0; // The first declared uniform, like "time"
+///     vec4 u_Uniform1; // The second one, like "tint"
+/// };
+/// ```
+///
+/// In SPIR-V, the block is bound to `binding = 0` of `set = 2` for quad
+/// shaders and of `set = 1` for mesh shaders.
+///
+/// ```no_run
+/// # use coffee::graphics::{Color, Gpu, Shader, ShaderKind};
+/// # use coffee::Result;
+/// # fn load(gpu: &mut Gpu, glsl: &str, spirv: &[u8]) -> Result<()> {
+/// let mut shader =
+///     Shader::new(gpu, ShaderKind::Quad, glsl, spirv, &["time", "tint"])?;
+///
+/// shader.set_uniform("time", 1.5)?;
+/// shader.set_uniform("tint", Color::WHITE)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Cloning a [`Shader`] is cheap, it only clones a handle and the values of
+/// its uniforms.
+///
+/// Shaders loaded from files can be [hot reloaded] during development.
+///
+/// [`Shader`]: struct.Shader.html
+/// [`Image`]: struct.Image.html
+/// [`Batch`]: struct.Batch.html
+/// [`Canvas`]: struct.Canvas.html
+/// [`Mesh`]: struct.Mesh.html
+/// [`ShaderKind`]: enum.ShaderKind.html
+/// [`MAX_UNIFORMS`]: #associatedconstant.MAX_UNIFORMS
+/// [`set_uniform`]: #method.set_uniform
+/// [hot reloaded]: ../load/hot_reload/index.html
+#[derive(Clone)]
+pub struct Shader {
+    pub(crate) program: Rc<RefCell<gpu::Program>>,
+    pub(crate) uniforms: [[f32; 4]; Shader::MAX_UNIFORMS],
+    uniform_names: Rc<Vec<String>>,
+    kind: ShaderKind,
+}
+
+impl Shader {
+    /// The maximum amount of uniforms of a [`Shader`].
+    ///
+    /// [`Shader`]: struct.Shader.html
+    pub const MAX_UNIFORMS: usize = 8;
+
+    /// Creates a new [`Shader`] of the given [`ShaderKind`] from its GLSL
+    /// source, its SPIR-V binary, and the names of its uniforms.
+    ///
+    /// Only the source used by the current graphics backend is compiled.
+    ///
+    /// It fails with [`Error::Uniform`] if there are more than
+    /// [`MAX_UNIFORMS`] uniforms or if a name is repeated.
+    ///
+    /// [`Shader`]: struct.Shader.html
+    /// [`ShaderKind`]: enum.ShaderKind.html
+    /// [`Error::Uniform`]: ../enum.Error.html#variant.Uniform
+    /// [`MAX_UNIFORMS`]: #associatedconstant.MAX_UNIFORMS
+    pub fn new(
+        gpu: &mut Gpu,
+        kind: ShaderKind,
+        glsl: &str,
+        spirv: &[u8],
+        uniforms: &[&str],
+    ) -> Result<Shader> {
+        let uniform_names = uniform_names(uniforms)?;
+
+        Ok(Shader {
+            program: Rc::new(RefCell::new(
+                gpu.create_program(kind, glsl, spirv)?,
+            )),
+            uniforms: [[0.0; 4]; Self::MAX_UNIFORMS],
+            uniform_names: Rc::new(uniform_names),
+            kind,
+        })
+    }
+
+    /// Creates a [`Task`] that loads a [`Shader`] of the given [`ShaderKind`]
+    /// from the given GLSL and SPIR-V files, with the given uniforms.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Shader`]: struct.Shader.html
+    /// [`ShaderKind`]: enum.ShaderKind.html
+    pub fn load<G: Into<PathBuf>, S: Into<PathBuf>>(
+        kind: ShaderKind,
+        glsl: G,
+        spirv: S,
+        uniforms: &[&str],
+    ) -> Task<Shader> {
+        let glsl = glsl.into();
+        let spirv = spirv.into();
+        let uniforms: Vec<String> =
+            uniforms.iter().map(|name| String::from(*name)).collect();
+
+        Task::using_gpu(move |gpu| {
+            let uniforms: Vec<&str> =
+                uniforms.iter().map(String::as_str).collect();
+
+            let shader =
+                Shader::from_files(gpu, kind, &glsl, &spirv, &uniforms)?;
+
+            hot_reload::watch_shader(glsl, spirv, kind, &shader.program);
+
+            Ok(shader)
+        })
+    }
+
+    pub(crate) fn from_files(
+        gpu: &mut Gpu,
+        kind: ShaderKind,
+        glsl: &Path,
+        spirv: &Path,
+        uniforms: &[&str],
+    ) -> Result<Shader> {
+        let source =
+            String::from_utf8(read(glsl)?).map_err(|error| Error::Asset {
+                path: glsl.to_path_buf(),
+                source: Box::new(Error::Deserialization(Box::new(error))),
+            })?;
+
+        let binary = read(spirv)?;
+
+        Shader::new(gpu, kind, &source, &binary, uniforms)
+    }
+
+    /// Returns the [`ShaderKind`] of the [`Shader`].
+    ///
+    /// [`ShaderKind`]: enum.ShaderKind.html
+    /// [`Shader`]: struct.Shader.html
+    pub fn kind(&self) -> ShaderKind {
+        self.kind
+    }
+
+    /// Sets the value of the uniform with the given name.
+    ///
+    /// It fails with [`Error::Uniform`] if the [`Shader`] was not created
+    /// with a uniform of that name. In that case, no value changes.
+    ///
+    /// [`Error::Uniform`]: ../enum.Error.html#variant.Uniform
+    /// [`Shader`]: struct.Shader.html
+    pub fn set_uniform<U: Uniform>(
+        &mut self,
+        name: &str,
+        value: U,
+    ) -> Result<()> {
+        let index = self
+            .uniform_names
+            .iter()
+            .position(|uniform| uniform == name)
+            .ok_or_else(|| {
+                Error::Uniform(format!("Unknown uniform \"{}\"", name))
+            })?;
+
+        self.uniforms[index] = value.to_vec4();
+
+        Ok(())
+    }
+
+    /// Returns the names of the uniforms of the [`Shader`], in order.
+    ///
+    /// [`Shader`]: struct.Shader.html
+    pub fn uniforms(&self) -> &[String] {
+        &self.uniform_names
+    }
+}
+
+impl std::fmt::Debug for Shader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Shader {{ kind: {:?} }}", self.kind)
+    }
+}
+
+fn uniform_names(uniforms: &[&str]) -> Result<Vec<String>> {
+    if uniforms.len() > Shader::MAX_UNIFORMS {
+        return Err(Error::Uniform(format!(
+            "A shader can have at most {} uniforms, found {}",
+            Shader::MAX_UNIFORMS,
+            uniforms.len()
+        )));
+    }
+
+    let mut names: Vec<String> = Vec::with_capacity(uniforms.len());
+
+    for name in uniforms {
+        if names.iter().any(|declared| declared == name) {
+            return Err(Error::Uniform(format!(
+                "Uniform \"{}\" is declared twice",
+                name
+            )));
+        }
+
+        names.push(String::from(*name));
+    }
+
+    Ok(names)
+}
+
+fn read(path: &Path) -> Result<Vec<u8>> {
+    assets::read(path).map_err(|error| Error::Asset {
+        path: path.to_path_buf(),
+        source: Box::new(error.into()),
+    })
+}
+
+/// The kind of draw a [`Shader`] replaces.
+///
+/// [`Shader`]: struct.Shader.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShaderKind {
+    /// Draws of an [`Image`], a [`Batch`], or a [`Canvas`].
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`Batch`]: struct.Batch.html
+    /// [`Canvas`]: struct.Canvas.html
+    Quad,
+
+    /// Draws of a [`Mesh`].
+    ///
+    /// [`Mesh`]: struct.Mesh.html
+    Mesh,
+}
+
+/// A value that fits in a uniform slot of a [`Shader`].
+///
+/// Every value is stored as a `vec4`. The unused components are set to `0.0`.
+///
+/// [`Shader`]: struct.Shader.html
+pub trait Uniform {
+    /// Returns the components of the value.
+    fn to_vec4(&self) -> [f32; 4];
+}
+
+impl Uniform for f32 {
+    fn to_vec4(&self) -> [f32; 4] {
+        [*self, 0.0, 0.0, 0.0]
+    }
+}
+
+impl Uniform for [f32; 2] {
+    fn to_vec4(&self) -> [f32; 4] {
+        [self[0], self[1], 0.0, 0.0]
+    }
+}
+
+impl Uniform for [f32; 3] {
+    fn to_vec4(&self) -> [f32; 4] {
+        [self[0], self[1], self[2], 0.0]
+    }
+}
+
+impl Uniform for [f32; 4] {
+    fn to_vec4(&self) -> [f32; 4] {
+        *self
+    }
+}
+
+impl Uniform for Point {
+    fn to_vec4(&self) -> [f32; 4] {
+        [self.x, self.y, 0.0, 0.0]
+    }
+}
+
+impl Uniform for Vector {
+    fn to_vec4(&self) -> [f32; 4] {
+        [self.x, self.y, 0.0, 0.0]
+    }
+}
+
+/// A [`Color`] is stored in linear space, like the colors of the vertices.
+///
+/// [`Color`]: struct.Color.html
+impl Uniform for Color {
+    fn to_vec4(&self) -> [f32; 4] {
+        self.into_linear()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn declares_uniforms_in_order() {
+        let names = uniform_names(&["time", "tint"]).expect("Valid uniforms");
+
+        assert_eq!(names, vec![String::from("time"), String::from("tint")]);
+    }
+
+    #[test]
+    fn rejects_too_many_uniforms() {
+        let names = ["a", "b", "c", "d", "e", "f", "g", "h", "i"];
+
+        assert!(uniform_names(&names).is_err());
+    }
+
+    #[test]
+    fn rejects_repeated_uniforms() {
+        assert!(uniform_names(&["time", "time"]).is_err());
+    }
+}
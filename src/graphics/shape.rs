@@ -6,6 +6,15 @@ pub enum Shape {
     /// A rectangle
     Rectangle(Rectangle<f32>),
 
+    /// A rectangle with rounded corners
+    RoundedRectangle {
+        /// The bounds of the rectangle
+        bounds: Rectangle<f32>,
+
+        /// The radius of the corners
+        radius: f32,
+    },
+
     /// A circle
     Circle {
         /// The center of the circle
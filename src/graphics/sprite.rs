@@ -1,4 +1,4 @@
-use crate::graphics::{IntoQuad, Point, Quad, Rectangle};
+use crate::graphics::{IntoQuad, Packing, Point, Quad, Rectangle};
 
 /// A quad describing the portion of a resource in absolute coordinates.
 ///
@@ -8,9 +8,13 @@ use crate::graphics::{IntoQuad, Point, Quad, Rectangle};
 /// [`Quad`]: struct.Quad.html
 /// [`Sprite`]: struct.Sprite.html
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Sprite {
     /// The portion of a resource that contains the sprite, in absolute
     /// coordinates.
+    ///
+    /// If the sprite is stored rotated, these are the bounds of the rotated
+    /// pixels.
     pub source: Rectangle<u16>,
 
     /// The position where the sprite should be drawn.
@@ -18,6 +22,12 @@ pub struct Sprite {
 
     /// The scale to apply to the sprite.
     pub scale: (f32, f32),
+
+    /// How the [`source`] is stored in the resource.
+    ///
+    /// [`source`]: #structfield.source
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub packing: Packing,
 }
 
 impl Default for Sprite {
@@ -32,12 +42,19 @@ impl Default for Sprite {
             },
             position: Point::new(0.0, 0.0),
             scale: (1.0, 1.0),
+            packing: Packing::NONE,
         }
     }
 }
 
 impl IntoQuad for Sprite {
     fn into_quad(self, x_unit: f32, y_unit: f32) -> Quad {
+        let (width, height) = if self.packing.rotated {
+            (self.source.height, self.source.width)
+        } else {
+            (self.source.width, self.source.height)
+        };
+
         Quad {
             source: Rectangle {
                 x: self.source.x as f32 * x_unit,
@@ -46,10 +63,8 @@ impl IntoQuad for Sprite {
                 height: self.source.height as f32 * y_unit,
             },
             position: self.position,
-            size: (
-                self.source.width as f32 * self.scale.0,
-                self.source.height as f32 * self.scale.1,
-            ),
+            size: (width as f32 * self.scale.0, height as f32 * self.scale.1),
+            packing: self.packing,
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::graphics::gpu::{self, Font, Gpu, TargetView, Texture, Vertex};
-use crate::graphics::{Color, Transformation};
+use crate::graphics::{Color, Crt, Shader, Transformation};
 
 /// A rendering target.
 ///
@@ -17,6 +17,7 @@ pub struct Target<'a> {
     gpu: &'a mut Gpu,
     view: &'a TargetView,
     transformation: Transformation,
+    scale: f32,
 }
 
 impl<'a> Target<'a> {
@@ -30,6 +31,7 @@ impl<'a> Target<'a> {
             gpu,
             view,
             transformation: Transformation::orthographic(width, height),
+            scale: 1.0,
         }
     }
 
@@ -45,6 +47,16 @@ impl<'a> Target<'a> {
         target
     }
 
+    // Applies the given transformation, like `transform`, to an owned target
+    pub(super) fn transformed(
+        mut self,
+        transformation: Transformation,
+    ) -> Self {
+        self.transformation = self.transformation * transformation;
+        self.scale *= transformation.scale_factor();
+        self
+    }
+
     /// Creates a new [`Target`] applying the given transformation.
     ///
     /// This is equivalent to multiplying the current [`Target`] transform by
@@ -84,6 +96,7 @@ impl<'a> Target<'a> {
             gpu: self.gpu,
             view: self.view,
             transformation: self.transformation * transformation,
+            scale: self.scale * transformation.scale_factor(),
         }
     }
 
@@ -108,6 +121,22 @@ impl<'a> Target<'a> {
         );
     }
 
+    pub(super) fn draw_shader_triangles(
+        &mut self,
+        shader: &Shader,
+        vertices: &[Vertex],
+        indices: &[u32],
+    ) {
+        self.gpu.draw_shader_triangles(
+            &shader.program.borrow(),
+            &shader.uniforms,
+            vertices,
+            indices,
+            &self.view,
+            &self.transformation,
+        );
+    }
+
     pub(super) fn draw_texture_quads(
         &mut self,
         texture: &Texture,
@@ -121,6 +150,57 @@ impl<'a> Target<'a> {
         );
     }
 
+    pub(super) fn draw_palette_quads(
+        &mut self,
+        texture: &Texture,
+        palette: &Texture,
+        instances: &[gpu::Quad],
+    ) {
+        self.gpu.draw_palette_quads(
+            texture,
+            palette,
+            instances,
+            &self.view,
+            &self.transformation,
+        );
+    }
+
+    pub(super) fn draw_shader_quads(
+        &mut self,
+        texture: &Texture,
+        shader: &Shader,
+        instances: &[gpu::Quad],
+    ) {
+        self.gpu.draw_shader_quads(
+            texture,
+            &shader.program.borrow(),
+            &shader.uniforms,
+            instances,
+            &self.view,
+            &self.transformation,
+        );
+    }
+
+    pub(super) fn draw_crt_quad(
+        &mut self,
+        texture: &Texture,
+        instance: gpu::Quad,
+        crt: &Crt,
+    ) {
+        self.gpu.draw_crt_quad(
+            texture,
+            instance,
+            crt,
+            &self.view,
+            &self.transformation,
+        );
+    }
+
+    // The scale applied by the transformations of the target
+    pub(super) fn scale(&self) -> f32 {
+        self.scale
+    }
+
     pub(in crate::graphics) fn draw_font(&mut self, font: &mut Font) {
         self.gpu.draw_font(font, &self.view, self.transformation);
     }
@@ -7,8 +7,10 @@ pub use batch::Batch;
 pub use builder::Builder;
 pub use loader::{Indices, Key, Loader};
 
+use std::collections::HashMap;
 use std::fmt;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use crate::graphics::gpu::Texture;
 
@@ -32,6 +34,22 @@ pub struct TextureArray {
     texture: Texture,
     x_unit: f32,
     y_unit: f32,
+    names: Arc<HashMap<String, Index>>,
+}
+
+impl TextureArray {
+    /// Returns the [`Index`] of the texture added with the given name, if
+    /// any.
+    ///
+    /// Textures are named when added with [`Builder::add_named`] or
+    /// [`Loader::add_named`].
+    ///
+    /// [`Index`]: struct.Index.html
+    /// [`Builder::add_named`]: struct.Builder.html#method.add_named
+    /// [`Loader::add_named`]: struct.Loader.html#method.add_named
+    pub fn get(&self, name: &str) -> Option<Index> {
+        self.names.get(name).copied()
+    }
 }
 
 /// An index that identifies a texture in a [`TextureArray`].
@@ -63,6 +81,16 @@ pub enum Error {
 
     /// A provided image did not fit in a texture array layer.
     ImageIsTooBig(PathBuf),
+
+    /// A provided image, loaded from bytes, did not fit in a texture array
+    /// layer.
+    ImageBytesAreTooBig {
+        /// The width of the image
+        width: u32,
+
+        /// The height of the image
+        height: u32,
+    },
 }
 
 impl fmt::Display for Error {
@@ -72,6 +100,9 @@ impl fmt::Display for Error {
             Error::ImageIsTooBig(path) => {
                 write!(f, "Image is too big: {}", path.display())
             }
+            Error::ImageBytesAreTooBig { width, height } => {
+                write!(f, "Image is too big: {}x{}", width, height)
+            }
         }
     }
 }
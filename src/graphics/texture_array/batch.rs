@@ -37,7 +37,7 @@ impl Batch {
 
         let mut instance = gpu::Quad::from(quad);
 
-        instance.layer = index.layer.into();
+        instance.layer |= u32::from(index.layer);
 
         self.instances.push(instance);
     }
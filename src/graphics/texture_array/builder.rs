@@ -1,9 +1,9 @@
-use std::fs::File;
-use std::io::Read;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use super::{Index, Offset, TextureArray};
+use crate::assets;
 use crate::graphics::Gpu;
 use crate::{Error, Result};
 
@@ -16,6 +16,7 @@ pub struct Builder {
     height: u32,
     layers: Vec<Layer>,
     current: Layer,
+    names: HashMap<String, Index>,
 }
 
 impl Builder {
@@ -29,6 +30,7 @@ impl Builder {
             height: height as u32,
             layers: Vec::new(),
             current: Layer::new(width, height),
+            names: HashMap::new(),
         }
     }
 
@@ -47,18 +49,101 @@ impl Builder {
     /// [`TextureArray`]: struct.TextureArray.html
     /// [`Builder`]: struct.Builder.html
     pub fn add<P: AsRef<Path>>(&mut self, path: P) -> Result<Index> {
-        let img = {
-            let mut buf = Vec::new();
-            let mut reader = File::open(&path)?;
-            let _ = reader.read_to_end(&mut buf)?;
-            let rgba = image::load_from_memory(&buf)?.to_rgba();
-            Arc::new(rgba)
+        let decode = || -> Result<image::RgbaImage> {
+            let bytes = assets::read(&path)?;
+
+            Ok(image::load_from_memory(&bytes)?.to_rgba())
         };
 
+        let img = Arc::new(decode().map_err(|error| Error::Asset {
+            path: PathBuf::from(path.as_ref()),
+            source: Box::new(error),
+        })?);
+
+        self.add_rgba(img, || {
+            super::Error::ImageIsTooBig(PathBuf::from(path.as_ref()))
+        })
+    }
+
+    /// Loads a new image from the given path and adds it to the produced
+    /// [`TextureArray`] with a name.
+    ///
+    /// The [`Index`] of the image can be obtained later with
+    /// [`TextureArray::get`]. Adding another image with the same name
+    /// replaces the previous one in the lookup, but not in the array.
+    ///
+    /// [`TextureArray`]: struct.TextureArray.html
+    /// [`Index`]: struct.Index.html
+    /// [`TextureArray::get`]: struct.TextureArray.html#method.get
+    pub fn add_named<P: AsRef<Path>>(
+        &mut self,
+        name: &str,
+        path: P,
+    ) -> Result<Index> {
+        let index = self.add(path)?;
+
+        let _ = self.names.insert(String::from(name), index);
+
+        Ok(index)
+    }
+
+    /// Adds all the images matching the given glob pattern to the produced
+    /// [`TextureArray`].
+    ///
+    /// The paths are matched using [`assets::glob`]. It returns the [`Index`]
+    /// of every added image by path.
+    ///
+    /// [`TextureArray`]: struct.TextureArray.html
+    /// [`assets::glob`]: ../../assets/fn.glob.html
+    /// [`Index`]: struct.Index.html
+    pub fn add_dir(
+        &mut self,
+        pattern: &str,
+    ) -> Result<HashMap<PathBuf, Index>> {
+        let paths = assets::glob(pattern).map_err(|error| Error::Asset {
+            path: PathBuf::from(pattern),
+            source: Box::new(error.into()),
+        })?;
+
+        let mut indices = HashMap::with_capacity(paths.len());
+
+        for path in paths {
+            let index = self.add(&path)?;
+
+            let _ = indices.insert(path, index);
+        }
+
+        Ok(indices)
+    }
+
+    /// Decodes a new image from the given bytes and adds it to the produced
+    /// [`TextureArray`].
+    ///
+    /// The format of the image is guessed from its contents. Images are
+    /// placed following the same strategy as in [`add`].
+    ///
+    /// [`TextureArray`]: struct.TextureArray.html
+    /// [`add`]: #method.add
+    pub fn add_bytes(&mut self, bytes: &[u8]) -> Result<Index> {
+        let img = Arc::new(image::load_from_memory(bytes)?.to_rgba());
+        let (width, height) = (img.width(), img.height());
+
+        self.add_rgba(img, || super::Error::ImageBytesAreTooBig {
+            width,
+            height,
+        })
+    }
+
+    fn add_rgba<F>(
+        &mut self,
+        img: Arc<image::RgbaImage>,
+        too_big: F,
+    ) -> Result<Index>
+    where
+        F: FnOnce() -> super::Error,
+    {
         if img.width() > self.width || img.height() > self.height {
-            Err(Error::TextureArray(super::Error::ImageIsTooBig(
-                PathBuf::from(path.as_ref()),
-            )))
+            Err(Error::TextureArray(too_big()))
         } else {
             let offset = self.current.add(img.clone());
 
@@ -107,6 +192,7 @@ impl Builder {
             texture,
             x_unit: 1.0 / self.width as f32,
             y_unit: 1.0 / self.height as f32,
+            names: Arc::new(self.names.clone()),
         }
     }
 }
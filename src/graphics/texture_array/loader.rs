@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::VecDeque;
 use std::path::PathBuf;
 
@@ -56,7 +57,14 @@ use crate::{Error, Result};
 pub struct Loader {
     width: u16,
     height: u16,
-    paths: Vec<PathBuf>,
+    sources: Vec<Source>,
+    names: Vec<(String, Key)>,
+}
+
+#[derive(Debug, Clone)]
+enum Source {
+    Path(PathBuf),
+    Bytes(Cow<'static, [u8]>),
 }
 
 impl Loader {
@@ -69,7 +77,8 @@ impl Loader {
         Loader {
             width,
             height,
-            paths: Vec::new(),
+            sources: Vec::new(),
+            names: Vec::new(),
         }
     }
 
@@ -80,8 +89,36 @@ impl Loader {
     /// [`Key`]: struct.Key.html
     /// [`Index`]: struct.Index.html
     pub fn add<P: Into<PathBuf>>(&mut self, path: P) -> Key {
-        self.paths.push(path.into());
-        Key(self.paths.len() - 1)
+        self.sources.push(Source::Path(path.into()));
+        Key(self.sources.len() - 1)
+    }
+
+    /// Queues an image to be added to the produced [`TextureArray`] with a
+    /// name, and obtain a [`Key`] to its [`Index`].
+    ///
+    /// The [`Index`] can also be obtained with [`TextureArray::get`] once
+    /// loaded.
+    ///
+    /// [`TextureArray`]: struct.TextureArray.html
+    /// [`Key`]: struct.Key.html
+    /// [`Index`]: struct.Index.html
+    /// [`TextureArray::get`]: struct.TextureArray.html#method.get
+    pub fn add_named<P: Into<PathBuf>>(&mut self, name: &str, path: P) -> Key {
+        let key = self.add(path);
+
+        self.names.push((String::from(name), key));
+        key
+    }
+
+    /// Queues an image to be decoded from the given bytes and added to the
+    /// produced [`TextureArray`], and obtain a [`Key`] to its [`Index`].
+    ///
+    /// [`TextureArray`]: struct.TextureArray.html
+    /// [`Key`]: struct.Key.html
+    /// [`Index`]: struct.Index.html
+    pub fn add_bytes<B: Into<Cow<'static, [u8]>>>(&mut self, bytes: B) -> Key {
+        self.sources.push(Source::Bytes(bytes.into()));
+        Key(self.sources.len() - 1)
     }
 
     /// Finishes the [`Loader`] definition and obtain a [`Task`] that produces
@@ -95,22 +132,37 @@ impl Loader {
     where
         F: 'static + Fn(TextureArray, Indices) -> Result<T>,
     {
-        let total_work = self.paths.len() as u32 + 1;
+        let total_work = self.sources.len() as u32 + 1;
 
         Task::sequence(total_work, move |task| {
             let mut builder = Builder::new(self.width, self.height);
-            let mut work_todo = VecDeque::from(self.paths.clone());
+            let mut work_todo = VecDeque::from(self.sources.clone());
             let mut indices = Vec::new();
 
             while let Some(next) = work_todo.pop_front() {
-                let index = builder.add(next)?;
+                let key = Key(indices.len());
+
+                let name = self
+                    .names
+                    .iter()
+                    .find(|(_, named_key)| *named_key == key)
+                    .map(|(name, _)| name);
+
+                let index = match (next, name) {
+                    (Source::Path(path), Some(name)) => {
+                        builder.add_named(name, path)?
+                    }
+                    (Source::Path(path), None) => builder.add(path)?,
+                    (Source::Bytes(bytes), _) => builder.add_bytes(&bytes)?,
+                };
+
                 indices.push(index);
 
                 task.notify_progress(1);
             }
 
             let result =
-                on_completion(builder.build(task.gpu()), Indices(indices))?;
+                on_completion(builder.build(task.gpu()?), Indices(indices))?;
 
             task.notify_progress(1);
 
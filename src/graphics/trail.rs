@@ -0,0 +1,102 @@
+use std::collections::VecDeque;
+
+use crate::graphics::{Batch, Color, Point, Sprite};
+
+/// The recent positions of a sprite, drawn as fading copies behind it.
+///
+/// Record a position every tick, in [`Game::update`], and add the trail to a
+/// [`Batch`] when drawing. As positions are sampled on ticks instead of
+/// frames, the trail looks the same with any frame rate.
+///
+/// ```
+/// use coffee::graphics::{Batch, Color, Point, Sprite, Trail};
+///
+/// fn update(trail: &mut Trail, position: Point) {
+///     trail.push(position);
+/// }
+///
+/// fn draw(trail: &Trail, sprite: &Sprite, batch: &mut Batch) {
+///     trail.add_to(batch, sprite, Color::WHITE);
+///     batch.add(sprite.clone());
+/// }
+/// ```
+///
+/// [`Game::update`]: ../trait.Game.html#method.update
+/// [`Batch`]: struct.Batch.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trail {
+    positions: VecDeque<Point>,
+    length: usize,
+}
+
+impl Trail {
+    /// Creates a new empty [`Trail`] that keeps the given amount of
+    /// positions.
+    ///
+    /// [`Trail`]: struct.Trail.html
+    pub fn new(length: usize) -> Trail {
+        Trail {
+            positions: VecDeque::with_capacity(length + 1),
+            length,
+        }
+    }
+
+    /// Records a new position in the [`Trail`], forgetting the oldest one if
+    /// it is full.
+    ///
+    /// [`Trail`]: struct.Trail.html
+    pub fn push(&mut self, position: Point) {
+        self.positions.push_back(position);
+
+        while self.positions.len() > self.length {
+            let _ = self.positions.pop_front();
+        }
+    }
+
+    /// Forgets all the positions of the [`Trail`].
+    ///
+    /// This is useful when a sprite teleports.
+    ///
+    /// [`Trail`]: struct.Trail.html
+    pub fn clear(&mut self) {
+        self.positions.clear();
+    }
+
+    /// Returns the recorded positions of the [`Trail`], from the oldest to
+    /// the newest.
+    ///
+    /// [`Trail`]: struct.Trail.html
+    pub fn positions(&self) -> impl Iterator<Item = Point> + '_ {
+        self.positions.iter().copied()
+    }
+
+    /// Adds a copy of the [`Sprite`] at every position of the [`Trail`] to
+    /// the given [`Batch`].
+    ///
+    /// The copies are tinted with the given [`Color`]. Their opacity fades
+    /// from the alpha of the [`Color`], for the newest position, towards
+    /// zero, for the oldest one. The `position` of the [`Sprite`] is ignored.
+    ///
+    /// [`Sprite`]: struct.Sprite.html
+    /// [`Trail`]: struct.Trail.html
+    /// [`Batch`]: struct.Batch.html
+    /// [`Color`]: struct.Color.html
+    pub fn add_to(&self, batch: &mut Batch, sprite: &Sprite, color: Color) {
+        let total = self.positions.len() as f32;
+
+        for (i, position) in self.positions.iter().enumerate() {
+            let opacity = (i + 1) as f32 / (total + 1.0);
+
+            batch.add_tinted(
+                Sprite {
+                    position: *position,
+                    ..sprite.clone()
+                },
+                Color {
+                    a: color.a * opacity,
+                    ..color
+                },
+            );
+        }
+    }
+}
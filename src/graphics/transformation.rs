@@ -63,6 +63,13 @@ impl Transformation {
     pub fn rotate(rotation: f32) -> Transformation {
         Transformation(Matrix3::new_rotation(rotation))
     }
+
+    // The factor by which areas are scaled, as a uniform scale
+    pub(crate) fn scale_factor(&self) -> f32 {
+        let m = &self.0;
+
+        (m[(0, 0)] * m[(1, 1)] - m[(0, 1)] * m[(1, 0)]).abs().sqrt()
+    }
 }
 
 impl Mul for Transformation {
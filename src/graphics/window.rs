@@ -1,28 +1,59 @@
+#[cfg(feature = "clipboard")]
+mod clipboard;
 mod cursor_icon;
 mod frame;
+mod geometry;
+mod linux_backend;
+mod orientation;
+mod present_mode;
 mod settings;
 
+#[cfg(not(feature = "raw-events"))]
 pub(crate) use winit;
 
+#[cfg(feature = "raw-events")]
+pub use winit;
+
+#[cfg(feature = "clipboard")]
+pub use clipboard::Clipboard;
 pub use cursor_icon::CursorIcon;
 pub use frame::Frame;
+pub use geometry::Geometry;
+pub use linux_backend::LinuxBackend;
+pub use orientation::Orientation;
+pub use present_mode::PresentMode;
 pub use settings::Settings;
 
+pub(crate) use linux_backend::open;
+
 use crate::graphics::gpu::{self, Gpu};
+use crate::graphics::{Canvas, Point, PresentMode, Quad, Target};
+use crate::input::Recording;
 use crate::Result;
 
+use std::fs;
+use std::path::{Path, PathBuf};
+
 /// An open window.
 ///
 /// It is provided as an argument in some methods in [`Game`].
 ///
 /// [`Game`]: ../trait.Game.html
 pub struct Window {
-    gpu: Gpu,
-    surface: gpu::Surface,
+    display: Option<Display>,
     width: f32,
     height: f32,
     is_fullscreen: bool,
     cursor_icon: Option<winit::window::CursorIcon>,
+    recording: Option<Recording>,
+    close_requested: bool,
+    ticks_per_second: Option<u16>,
+    screenshot: Option<PathBuf>,
+    capture: Option<Canvas>,
+    #[cfg(feature = "audio")]
+    audio: crate::audio::Mixer,
+    #[cfg(feature = "clipboard")]
+    clipboard: Clipboard,
 }
 
 impl Window {
@@ -30,28 +61,87 @@ impl Window {
         settings: Settings,
         event_loop: &winit::event_loop::EventLoop<()>,
     ) -> Result<Window> {
-        let (width, height) = settings.size;
-        let is_fullscreen = settings.fullscreen;
+        let present_mode = settings.present_mode.unwrap_or_default();
+        let validation = settings.gpu_validation;
+
+        let (gpu, surface) = Gpu::for_window(
+            settings.clone().into_builder(event_loop),
+            event_loop,
+            present_mode,
+            validation,
+        )?;
+
+        #[cfg(target_arch = "wasm32")]
+        attach_canvas(surface.window());
+
+        Ok(Window::build(&settings, Some(Display { gpu, surface })))
+    }
 
-        let (gpu, surface) =
-            Gpu::for_window(settings.into_builder(event_loop), event_loop)?;
+    // A window of a `Headless` game. It is never shown nor drawn.
+    pub(crate) fn headless(settings: &Settings) -> Window {
+        Window::build(settings, None)
+    }
+
+    fn build(settings: &Settings, display: Option<Display>) -> Window {
+        let (width, height) = settings.size;
 
-        Ok(Window {
-            is_fullscreen,
-            gpu,
-            surface,
+        Window {
+            display,
+            is_fullscreen: settings.fullscreen,
             width: width as f32,
             height: height as f32,
             cursor_icon: Some(winit::window::CursorIcon::Default),
-        })
+            recording: None,
+            close_requested: false,
+            ticks_per_second: None,
+            screenshot: None,
+            capture: None,
+            #[cfg(feature = "audio")]
+            audio: crate::audio::Mixer::new(),
+            #[cfg(feature = "clipboard")]
+            clipboard: Clipboard::new(),
+        }
     }
 
     /// Returns the [`Gpu`] linked to the [`Window`].
     ///
+    /// # Panics
+    /// The [`Window`] of a [`Headless`] game has no [`Gpu`]. Calling this
+    /// method on it will panic.
+    ///
     /// [`Gpu`]: struct.Gpu.html
     /// [`Window`]: struct.Window.html
+    /// [`Headless`]: ../struct.Headless.html
     pub fn gpu(&mut self) -> &mut Gpu {
-        &mut self.gpu
+        &mut self.display_mut().gpu
+    }
+
+    /// Returns true if the [`Window`] belongs to a [`Headless`] game.
+    ///
+    /// A headless [`Window`] is never shown and it has no [`Gpu`].
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`Headless`]: ../struct.Headless.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn is_headless(&self) -> bool {
+        self.display.is_none()
+    }
+
+    /// Returns the audio [`Mixer`] linked to the [`Window`].
+    ///
+    /// [`Mixer`]: ../audio/struct.Mixer.html
+    /// [`Window`]: struct.Window.html
+    #[cfg(feature = "audio")]
+    pub fn audio(&self) -> &crate::audio::Mixer {
+        &self.audio
+    }
+
+    /// Returns the [`Clipboard`] of the system.
+    ///
+    /// [`Clipboard`]: struct.Clipboard.html
+    #[cfg(feature = "clipboard")]
+    pub fn clipboard(&mut self) -> &mut Clipboard {
+        &mut self.clipboard
     }
 
     pub(crate) fn frame(&mut self) -> Frame<'_> {
@@ -62,20 +152,83 @@ impl Window {
     ///
     /// [`Window`]: struct.Window.html
     pub fn toggle_fullscreen(&mut self) {
-        let window = self.surface.window();
+        if let Some(display) = &self.display {
+            let window = display.surface.window();
 
-        let monitor = if self.is_fullscreen {
-            None
-        } else {
-            Some(window.primary_monitor())
-        };
+            let monitor = if self.is_fullscreen {
+                None
+            } else {
+                Some(window.primary_monitor())
+            };
 
-        window
-            .set_fullscreen(monitor.map(winit::window::Fullscreen::Borderless));
+            window.set_fullscreen(
+                monitor.map(winit::window::Fullscreen::Borderless),
+            );
+        }
 
         self.is_fullscreen = !self.is_fullscreen;
     }
 
+    /// Returns the current [`Geometry`] of the [`Window`].
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    /// [`Window`]: struct.Window.html
+    pub fn geometry(&self) -> Geometry {
+        let size = (self.width as u32, self.height as u32);
+
+        let window = match &self.display {
+            Some(display) => display.surface.window(),
+            None => {
+                return Geometry {
+                    position: None,
+                    size,
+                    maximized: false,
+                    fullscreen: self.is_fullscreen,
+                }
+            }
+        };
+
+        let outer_size = window.outer_size();
+
+        // winit cannot tell us whether the window is maximized, so we assume
+        // it is when it covers the width of its monitor
+        let maximized = !self.is_fullscreen
+            && outer_size.width >= window.current_monitor().size().width;
+
+        Geometry {
+            position: window
+                .outer_position()
+                .ok()
+                .map(|position| (position.x, position.y)),
+            size,
+            maximized,
+            fullscreen: self.is_fullscreen,
+        }
+    }
+
+    pub(crate) fn set_position(&mut self, (x, y): (i32, i32)) {
+        self.display_mut()
+            .surface
+            .window()
+            .set_outer_position(winit::dpi::PhysicalPosition { x, y });
+    }
+
+    /// Returns the scale factor of the [`Window`].
+    ///
+    /// It is the ratio between physical pixels and logical pixels, which is
+    /// greater than `1.0` on high density displays, like Retina screens. The
+    /// size of the [`Window`] and input positions are always measured in
+    /// physical pixels.
+    ///
+    /// The scale factor of a headless [`Window`] is always `1.0`.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn scale_factor(&self) -> f64 {
+        self.display
+            .as_ref()
+            .map_or(1.0, |display| display.surface.window().scale_factor())
+    }
+
     /// Returns the width of the [`Window`].
     ///
     /// [`Window`]: struct.Window.html
@@ -90,35 +243,268 @@ impl Window {
         self.height
     }
 
-    pub(crate) fn swap_buffers(&mut self) {
-        self.surface.swap_buffers(&mut self.gpu);
+    /// Returns the current [`PresentMode`] of the [`Window`].
+    ///
+    /// [`PresentMode`]: enum.PresentMode.html
+    /// [`Window`]: struct.Window.html
+    pub fn present_mode(&self) -> PresentMode {
+        self.display
+            .as_ref()
+            .map(|display| display.surface.present_mode())
+            .unwrap_or_default()
+    }
+
+    /// Returns the present modes supported by the [`Window`].
+    ///
+    /// With OpenGL, the present mode cannot be changed once the [`Window`] is
+    /// created. Therefore, only the current one is returned. Otherwise, they
+    /// depend on the graphics backend presenting to the [`Window`].
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn present_modes(&self) -> Vec<PresentMode> {
+        self.display
+            .as_ref()
+            .map(|display| display.surface.present_modes())
+            .unwrap_or_default()
+    }
+
+    /// Changes the [`PresentMode`] of the [`Window`].
+    ///
+    /// It returns false if the [`PresentMode`] is not supported. In that case,
+    /// the current one is kept.
+    ///
+    /// [`PresentMode`]: enum.PresentMode.html
+    /// [`Window`]: struct.Window.html
+    pub fn set_present_mode(&mut self, present_mode: PresentMode) -> bool {
+        match &mut self.display {
+            Some(Display { gpu, surface }) => {
+                surface.set_present_mode(gpu, present_mode)
+            }
+            None => false,
+        }
+    }
+
+    /// Requests the [`Window`] to close.
+    ///
+    /// The request is handled at the end of the current frame, exactly like a
+    /// close request from the operating system. Therefore, it goes through
+    /// [`Game::on_close_request`] first.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`Game::on_close_request`]: ../trait.Game.html#method.on_close_request
+    pub fn close(&mut self) {
+        self.close_requested = true;
+    }
+
+    pub(crate) fn take_close_request(&mut self) -> bool {
+        std::mem::replace(&mut self.close_requested, false)
+    }
+
+    /// Changes how many times per second the [`Game`] is updated.
+    ///
+    /// The new rate is applied to the [`Timer`] of the [`Game`] once
+    /// [`Game::interact`] returns, using [`Timer::set_ticks_per_second`].
+    ///
+    /// [`Game`]: ../trait.Game.html
+    /// [`Timer`]: ../struct.Timer.html
+    /// [`Game::interact`]: ../trait.Game.html#method.interact
+    /// [`Timer::set_ticks_per_second`]: ../struct.Timer.html#method.set_ticks_per_second
+    pub fn set_ticks_per_second(&mut self, ticks_per_second: u16) {
+        self.ticks_per_second = Some(ticks_per_second);
+    }
+
+    pub(crate) fn take_ticks_per_second(&mut self) -> Option<u16> {
+        self.ticks_per_second.take()
+    }
+
+    /// Starts recording the input of the [`Window`].
+    ///
+    /// Recording starts on the next frame. Any previous recording is
+    /// discarded.
+    ///
+    /// A [`Recording`] can only be replayed from a freshly loaded game. Thus,
+    /// you will normally want to start recording as soon as your game loads.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`Recording`]: ../input/struct.Recording.html
+    pub fn start_recording(&mut self) {
+        self.recording = Some(Recording::new());
+    }
+
+    /// Stops recording the input of the [`Window`] and returns the
+    /// [`Recording`], if any.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`Recording`]: ../input/struct.Recording.html
+    pub fn stop_recording(&mut self) -> Option<Recording> {
+        self.recording.take()
+    }
+
+    /// Returns true if the input of the [`Window`] is being recorded.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Saves a screenshot of the next frame of the [`Window`] to the given
+    /// path as a PNG image.
+    ///
+    /// The frame is captured once your game and its user interface are drawn.
+    /// Therefore, the [`debug`] view is not included. Any error while saving
+    /// the image is logged.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`debug`]: ../trait.Game.html#method.debug
+    pub fn save_screenshot<P: Into<PathBuf>>(&mut self, path: P) {
+        self.screenshot = Some(path.into());
+    }
+
+    // Redirects the next frame to a canvas if a screenshot was requested
+    pub(crate) fn begin_capture(&mut self) {
+        if self.screenshot.is_none() || self.capture.is_some() {
+            return;
+        }
+
+        let (width, height) = (self.width as u16, self.height as u16);
+
+        match Canvas::new(self.gpu(), width, height) {
+            Ok(canvas) => {
+                self.capture = Some(canvas);
+            }
+            Err(error) => {
+                self.screenshot = None;
+
+                log::warn!("Screenshot failed: {}", error);
+            }
+        }
+    }
+
+    // Saves the captured frame and draws it on the window surface
+    pub(crate) fn end_capture(&mut self) {
+        let canvas = match self.capture.take() {
+            Some(canvas) => canvas,
+            None => return,
+        };
+
+        let (width, height) = (self.width, self.height);
+        let Display { gpu, surface } = self.display_mut();
+
+        let image = canvas.read_pixels(gpu);
+        let view = surface.target(gpu);
+
+        canvas.draw(
+            Quad {
+                position: Point::new(0.0, 0.0),
+                size: (width, height),
+                ..Quad::default()
+            },
+            &mut Target::new(gpu, view, width, height),
+        );
+
+        if let Some(path) = self.screenshot.take() {
+            match save_image(&image, &path) {
+                Ok(()) => log::info!("Screenshot saved to {}", path.display()),
+                Err(error) => log::warn!("Screenshot failed: {}", error),
+            }
+        }
+    }
+
+    pub(crate) fn recording_mut(&mut self) -> Option<&mut Recording> {
+        self.recording.as_mut()
+    }
+
+    pub(crate) fn refresh_rate(&self) -> Option<u16> {
+        let monitor = self.display.as_ref()?.surface.window().current_monitor();
+        let size = monitor.size();
+
+        monitor
+            .video_modes()
+            .filter(|mode| mode.size() == size)
+            .map(|mode| mode.refresh_rate())
+            .max()
+    }
+
+    pub(crate) fn id(&self) -> winit::window::WindowId {
+        self.display().surface.window().id()
+    }
+
+    pub(crate) fn swap_buffers(&mut self) -> Result<()> {
+        let Display { gpu, surface } = self.display_mut();
+
+        surface.swap_buffers(gpu)
     }
 
     pub(crate) fn request_redraw(&mut self) {
-        self.surface.request_redraw();
+        self.display_mut().surface.request_redraw();
     }
 
     pub(crate) fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
-        self.surface.resize(&mut self.gpu, new_size);
+        let Display { gpu, surface } = self.display_mut();
+
+        surface.resize(gpu, new_size);
 
         self.width = new_size.width as f32;
         self.height = new_size.height as f32;
     }
 
+    pub(crate) fn recreate_surface(&mut self) {
+        let Display { gpu, surface } = self.display_mut();
+
+        surface.recreate(gpu);
+
+        let size = surface.window().inner_size();
+
+        self.width = size.width as f32;
+        self.height = size.height as f32;
+    }
+
+    // Replaces the graphics device after losing it, recreating the surface
+    pub(crate) fn reset_device(&mut self) -> Result<()> {
+        let Display { gpu, surface } = self.display_mut();
+
+        gpu.reset(surface)?;
+
+        let size = surface.window().inner_size();
+
+        self.width = size.width as f32;
+        self.height = size.height as f32;
+
+        Ok(())
+    }
+
     pub(crate) fn update_cursor(
         &mut self,
         new_cursor: Option<winit::window::CursorIcon>,
     ) {
         if self.cursor_icon != new_cursor {
+            let window = self.display_mut().surface.window();
+
             if let Some(cursor_icon) = new_cursor {
-                self.surface.window().set_cursor_icon(cursor_icon);
+                window.set_cursor_icon(cursor_icon);
             }
-            self.surface
-                .window()
-                .set_cursor_visible(new_cursor.is_some());
+            window.set_cursor_visible(new_cursor.is_some());
             self.cursor_icon = new_cursor;
         }
     }
+
+    fn display(&self) -> &Display {
+        self.display
+            .as_ref()
+            .expect("A headless window has no display")
+    }
+
+    fn display_mut(&mut self) -> &mut Display {
+        self.display
+            .as_mut()
+            .expect("A headless window has no display")
+    }
+}
+
+// The GPU and the surface of a window that is shown
+struct Display {
+    gpu: Gpu,
+    surface: gpu::Surface,
 }
 
 impl std::fmt::Debug for Window {
@@ -130,3 +516,34 @@ impl std::fmt::Debug for Window {
         )
     }
 }
+
+fn save_image(image: &image::DynamicImage, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    image.save(path)?;
+
+    Ok(())
+}
+
+// Adds the canvas of the window to the body of the web page
+#[cfg(target_arch = "wasm32")]
+fn attach_canvas(window: &winit::window::Window) {
+    use winit::platform::web::WindowExtWebSys;
+
+    let body = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.body());
+
+    match body {
+        Some(body) => {
+            let canvas = web_sys::Element::from(window.canvas());
+
+            if body.append_child(&canvas).is_err() {
+                log::warn!("The game canvas could not be added to the page");
+            }
+        }
+        None => log::warn!("No document body to add the game canvas to"),
+    }
+}
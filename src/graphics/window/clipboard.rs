@@ -0,0 +1,100 @@
+use std::borrow::Cow;
+
+use crate::{Error, Result};
+
+/// The clipboard of the system.
+///
+/// It can hold text or an image. You can obtain it with
+/// [`Window::clipboard`].
+///
+/// This is only available when the `clipboard` feature is enabled.
+///
+/// [`Window::clipboard`]: struct.Window.html#method.clipboard
+pub struct Clipboard {
+    // Connecting to the clipboard is slow on some platforms, so it is only
+    // done once it is needed
+    connection: Option<arboard::Clipboard>,
+}
+
+impl Clipboard {
+    pub(crate) fn new() -> Clipboard {
+        Clipboard { connection: None }
+    }
+
+    /// Reads the text of the [`Clipboard`].
+    ///
+    /// [`Clipboard`]: struct.Clipboard.html
+    pub fn read_text(&mut self) -> Result<String> {
+        self.connection()?.get_text().map_err(into_error)
+    }
+
+    /// Writes the given text to the [`Clipboard`].
+    ///
+    /// [`Clipboard`]: struct.Clipboard.html
+    pub fn write_text(&mut self, text: &str) -> Result<()> {
+        self.connection()?
+            .set_text(text.to_owned())
+            .map_err(into_error)
+    }
+
+    /// Reads the image of the [`Clipboard`].
+    ///
+    /// You can draw it by creating an [`Image`] with [`Image::from_image`].
+    ///
+    /// [`Clipboard`]: struct.Clipboard.html
+    /// [`Image`]: struct.Image.html
+    /// [`Image::from_image`]: struct.Image.html#method.from_image
+    pub fn read_image(&mut self) -> Result<image::DynamicImage> {
+        let data = self.connection()?.get_image().map_err(into_error)?;
+
+        image::RgbaImage::from_raw(
+            data.width as u32,
+            data.height as u32,
+            data.bytes.into_owned(),
+        )
+        .map(image::DynamicImage::ImageRgba8)
+        .ok_or_else(|| {
+            Error::Clipboard(String::from("The image has an invalid size"))
+        })
+    }
+
+    /// Writes the given image to the [`Clipboard`].
+    ///
+    /// An [`Image`] lives on the GPU and cannot be read back, but you can
+    /// draw it on a [`Canvas`] and use [`Canvas::read_pixels`].
+    ///
+    /// [`Clipboard`]: struct.Clipboard.html
+    /// [`Image`]: struct.Image.html
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Canvas::read_pixels`]: struct.Canvas.html#method.read_pixels
+    pub fn write_image(&mut self, image: &image::DynamicImage) -> Result<()> {
+        let image = image.to_rgba();
+
+        let data = arboard::ImageData {
+            width: image.width() as usize,
+            height: image.height() as usize,
+            bytes: Cow::Owned(image.into_raw()),
+        };
+
+        self.connection()?.set_image(data).map_err(into_error)
+    }
+
+    fn connection(&mut self) -> Result<&mut arboard::Clipboard> {
+        if self.connection.is_none() {
+            self.connection =
+                Some(arboard::Clipboard::new().map_err(into_error)?);
+        }
+
+        Ok(self.connection.as_mut().expect("Clipboard connection"))
+    }
+}
+
+impl std::fmt::Debug for Clipboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Clipboard")
+    }
+}
+
+fn into_error(error: arboard::Error) -> Error {
+    Error::Clipboard(error.to_string())
+}
@@ -14,6 +14,22 @@ pub enum CursorIcon {
     Hidden,
     /// Indicates something is to be moved.
     Move,
+    /// Indicates something can be grabbed.
+    Grab,
+    /// Indicates something is being grabbed.
+    Grabbing,
+    /// Indicates the game is busy, but can still be used.
+    Progress,
+    /// Indicates the game is busy and cannot be used.
+    Wait,
+    /// Indicates text that can be selected or edited.
+    Text,
+    /// Indicates something can be resized horizontally.
+    ResizeHorizontal,
+    /// Indicates something can be resized vertically.
+    ResizeVertical,
+    /// Indicates an action is not allowed.
+    NotAllowed,
 }
 
 impl Default for CursorIcon {
@@ -34,6 +50,18 @@ impl TryFrom<CursorIcon> for winit::window::CursorIcon {
             CursorIcon::Hand => Ok(winit::window::CursorIcon::Hand),
             CursorIcon::Hidden => Err(()),
             CursorIcon::Move => Ok(winit::window::CursorIcon::Move),
+            CursorIcon::Grab => Ok(winit::window::CursorIcon::Grab),
+            CursorIcon::Grabbing => Ok(winit::window::CursorIcon::Grabbing),
+            CursorIcon::Progress => Ok(winit::window::CursorIcon::Progress),
+            CursorIcon::Wait => Ok(winit::window::CursorIcon::Wait),
+            CursorIcon::Text => Ok(winit::window::CursorIcon::Text),
+            CursorIcon::ResizeHorizontal => {
+                Ok(winit::window::CursorIcon::EwResize)
+            }
+            CursorIcon::ResizeVertical => {
+                Ok(winit::window::CursorIcon::NsResize)
+            }
+            CursorIcon::NotAllowed => Ok(winit::window::CursorIcon::NotAllowed),
         }
     }
 }
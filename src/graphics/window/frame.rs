@@ -1,4 +1,4 @@
-use super::Window;
+use super::{Display, Window};
 
 use crate::graphics::{Color, Gpu, Target};
 
@@ -49,14 +49,21 @@ impl<'a> Frame<'a> {
     /// [`Target`]: struct.Target.html
     pub fn as_target(&mut self) -> Target<'_> {
         let Window {
-            surface,
-            gpu,
+            display,
             width,
             height,
+            capture,
             ..
         } = &mut self.window;
 
-        let view = surface.target();
+        let Display { gpu, surface } =
+            display.as_mut().expect("A headless window has no display");
+
+        if let Some(canvas) = capture {
+            return canvas.as_target(gpu);
+        }
+
+        let view = surface.target(gpu);
 
         Target::new(gpu, view, *width, *height)
     }
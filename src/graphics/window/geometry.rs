@@ -0,0 +1,149 @@
+use super::Settings;
+use crate::Result;
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// The size, position, and state of a window.
+///
+/// You can obtain it with [`Window::geometry`]. Use
+/// [`Game::REMEMBER_WINDOW`] to save it on exit and restore it on startup
+/// automatically.
+///
+/// [`Window::geometry`]: struct.Window.html#method.geometry
+/// [`Game::REMEMBER_WINDOW`]: ../trait.Game.html#associatedconstant.REMEMBER_WINDOW
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Geometry {
+    /// The position of the top-left corner of the window on the desktop, in
+    /// physical pixels, if known.
+    pub position: Option<(i32, i32)>,
+
+    /// The size of the window, in physical pixels.
+    pub size: (u32, u32),
+
+    /// Whether the window is maximized.
+    pub maximized: bool,
+
+    /// Whether the window is in fullscreen mode.
+    pub fullscreen: bool,
+}
+
+impl Geometry {
+    /// Loads the [`Geometry`] saved with the given name, if any.
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    pub fn load(name: &str) -> Option<Geometry> {
+        let contents = fs::read_to_string(path(name)?).ok()?;
+
+        let mut geometry = Geometry {
+            position: None,
+            size: (0, 0),
+            maximized: false,
+            fullscreen: false,
+        };
+
+        for line in contents.lines() {
+            let mut parts = line.split('=').map(str::trim);
+
+            match (parts.next(), parts.next()) {
+                (Some("position"), Some(value)) => {
+                    geometry.position = pair(value);
+                }
+                (Some("size"), Some(value)) => {
+                    geometry.size = pair(value)?;
+                }
+                (Some("maximized"), Some(value)) => {
+                    geometry.maximized = value.parse().ok()?;
+                }
+                (Some("fullscreen"), Some(value)) => {
+                    geometry.fullscreen = value.parse().ok()?;
+                }
+                _ => {}
+            }
+        }
+
+        if geometry.size.0 == 0 || geometry.size.1 == 0 {
+            return None;
+        }
+
+        Some(geometry)
+    }
+
+    /// Saves the [`Geometry`] with the given name in the configuration
+    /// directory of the platform.
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    pub fn save(&self, name: &str) -> Result<()> {
+        let path = path(name).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "Configuration directory not found",
+            )
+        })?;
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        let mut contents = String::new();
+
+        if let Some((x, y)) = self.position {
+            contents.push_str(&format!("position = {} {}\n", x, y));
+        }
+
+        contents.push_str(&format!(
+            "size = {} {}\nmaximized = {}\nfullscreen = {}\n",
+            self.size.0, self.size.1, self.maximized, self.fullscreen
+        ));
+
+        fs::write(path, contents)?;
+
+        Ok(())
+    }
+
+    /// Applies the size and state of the [`Geometry`] to the given
+    /// [`WindowSettings`].
+    ///
+    /// The position can only be restored once the window is open.
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    /// [`WindowSettings`]: struct.WindowSettings.html
+    pub fn apply(&self, settings: Settings) -> Settings {
+        Settings {
+            size: self.size,
+            maximized: self.maximized,
+            fullscreen: self.fullscreen,
+            ..settings
+        }
+    }
+}
+
+fn pair<T: std::str::FromStr>(value: &str) -> Option<(T, T)> {
+    let mut numbers = value.split_whitespace().map(str::parse);
+
+    match (numbers.next(), numbers.next()) {
+        (Some(Ok(a)), Some(Ok(b))) => Some((a, b)),
+        _ => None,
+    }
+}
+
+fn path(name: &str) -> Option<PathBuf> {
+    Some(config_directory()?.join(name).join("window"))
+}
+
+fn config_directory() -> Option<PathBuf> {
+    if cfg!(target_os = "windows") {
+        env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        env::var_os("HOME")
+            .map(|home| PathBuf::from(home).join("Library/Application Support"))
+    } else {
+        env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| {
+                env::var_os("HOME")
+                    .map(|home| PathBuf::from(home).join(".config"))
+            })
+    }
+}
@@ -0,0 +1,166 @@
+use super::{winit, Settings};
+use crate::{Error, Result};
+
+use winit::event_loop::EventLoop;
+
+/// The windowing system used on Linux and BSD.
+///
+/// It is ignored on any other platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LinuxBackend {
+    /// Wayland is used when available, X11 otherwise.
+    ///
+    /// If the window cannot be created using Wayland, it will be created
+    /// using X11 or XWayland instead.
+    Auto,
+
+    /// Always use X11, or XWayland on Wayland compositors.
+    X11,
+
+    /// Always use Wayland.
+    ///
+    /// Opening a window fails with an [`Error::WindowCreation`] if no Wayland
+    /// compositor is running.
+    ///
+    /// [`Error::WindowCreation`]: ../enum.Error.html#variant.WindowCreation
+    Wayland,
+}
+
+impl Default for LinuxBackend {
+    fn default() -> LinuxBackend {
+        LinuxBackend::Auto
+    }
+}
+
+/// Creates an event loop for the given [`Settings`] and opens a window with
+/// it, falling back to X11 if Wayland fails.
+///
+/// [`Settings`]: struct.Settings.html
+pub(crate) fn open<T, F>(
+    settings: Settings,
+    build: F,
+) -> Result<(EventLoop<()>, T)>
+where
+    F: Fn(Settings, &EventLoop<()>) -> Result<T>,
+{
+    let backend = settings.platform;
+    let event_loop = new_event_loop(backend)?;
+    let is_wayland = is_wayland(&event_loop);
+
+    log::debug!(
+        "Opening window with the {:?} backend (Wayland: {})",
+        backend,
+        is_wayland
+    );
+
+    match build(settings.clone(), &event_loop) {
+        Err(error @ Error::WindowCreation(_))
+        | Err(error @ Error::Surface(_))
+        | Err(error @ Error::SurfaceLost)
+            if backend == LinuxBackend::Auto && is_wayland =>
+        {
+            log::warn!(
+                "Wayland window creation failed, falling back to X11: {}",
+                error
+            );
+
+            drop(event_loop);
+
+            let event_loop = new_event_loop(LinuxBackend::X11)?;
+            let value = build(settings, &event_loop)?;
+
+            Ok((event_loop, value))
+        }
+        result => result.map(|value| (event_loop, value)),
+    }
+}
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn new_event_loop(backend: LinuxBackend) -> Result<EventLoop<()>> {
+    use winit::platform::unix::EventLoopExtUnix;
+
+    Ok(match backend {
+        LinuxBackend::Auto => EventLoop::new(),
+        LinuxBackend::X11 => EventLoop::new_x11().unwrap_or_else(|error| {
+            log::warn!("X11 is not available: {:?}", error);
+
+            EventLoop::new()
+        }),
+        // `new_wayland` panics when it cannot connect to a compositor, so we
+        // look for its socket first
+        LinuxBackend::Wayland if has_wayland_socket() => {
+            EventLoop::new_wayland()
+        }
+        LinuxBackend::Wayland => {
+            return Err(Error::WindowCreation(String::from(
+                "Wayland is not available",
+            )));
+        }
+    })
+}
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn has_wayland_socket() -> bool {
+    use std::env;
+    use std::path::PathBuf;
+
+    let display = env::var_os("WAYLAND_DISPLAY")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("wayland-0"));
+
+    if display.is_absolute() {
+        return display.exists();
+    }
+
+    env::var_os("XDG_RUNTIME_DIR")
+        .map(|directory| PathBuf::from(directory).join(display).exists())
+        .unwrap_or(false)
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+fn new_event_loop(_backend: LinuxBackend) -> Result<EventLoop<()>> {
+    Ok(EventLoop::new())
+}
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn is_wayland(event_loop: &EventLoop<()>) -> bool {
+    use winit::platform::unix::EventLoopWindowTargetExtUnix;
+
+    event_loop.is_wayland()
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+fn is_wayland(_event_loop: &EventLoop<()>) -> bool {
+    false
+}
@@ -0,0 +1,10 @@
+/// The orientation of the screen on mobile devices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Orientation {
+    /// The screen is wider than it is tall.
+    Landscape,
+
+    /// The screen is taller than it is wide.
+    Portrait,
+}
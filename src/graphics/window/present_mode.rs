@@ -0,0 +1,38 @@
+/// The way frames are presented on a [`Window`].
+///
+/// Use [`Window::present_modes`] to know which ones are supported.
+///
+/// [`Window`]: struct.Window.html
+/// [`Window::present_modes`]: struct.Window.html#method.present_modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PresentMode {
+    /// Frames are queued and presented on vertical blanks.
+    ///
+    /// There is no tearing, but drawing blocks when the queue is full.
+    Fifo,
+
+    /// Frames are presented on vertical blanks, replacing the queued frame
+    /// with the newest one.
+    ///
+    /// There is no tearing and drawing never blocks, but frames may be
+    /// dropped.
+    Mailbox,
+
+    /// Frames are presented immediately.
+    ///
+    /// It has the lowest latency, but it may cause tearing.
+    Immediate,
+
+    /// Frames are presented on vertical blanks, unless they arrive late.
+    ///
+    /// Late frames are presented immediately, trading stutter for some
+    /// tearing.
+    AdaptiveVsync,
+}
+
+impl Default for PresentMode {
+    fn default() -> PresentMode {
+        PresentMode::Mailbox
+    }
+}
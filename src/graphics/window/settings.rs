@@ -1,7 +1,23 @@
 use super::winit;
+use super::{LinuxBackend, Orientation, PresentMode};
 
 /// A window configuration.
+///
+/// You can use [`Default::default`] to only set the fields you care about:
+///
+/// ```
+/// use coffee::graphics::WindowSettings;
+///
+/// let settings = WindowSettings {
+///     title: String::from("A caffeinated game"),
+///     resizable: false,
+///     ..Default::default()
+/// };
+/// ```
+///
+/// [`Default::default`]: #impl-Default
 #[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Settings {
     /// A title for the window.
     pub title: String,
@@ -17,6 +33,74 @@ pub struct Settings {
 
     /// Defines whether or not the window should start maximized.
     pub maximized: bool,
+
+    /// Locks the screen to the given [`Orientation`] on mobile devices.
+    ///
+    /// `None` allows any orientation. It is only applied on iOS. On Android,
+    /// the orientation needs to be set in the manifest of the app.
+    ///
+    /// [`Orientation`]: enum.Orientation.html
+    pub orientation: Option<Orientation>,
+
+    /// The [`LinuxBackend`] used to create the window on Linux and BSD.
+    ///
+    /// [`LinuxBackend`]: enum.LinuxBackend.html
+    pub platform: LinuxBackend,
+
+    /// Defines whether or not the graphics driver should validate the
+    /// commands of the game.
+    ///
+    /// With OpenGL, it creates a debug context and logs its messages using the
+    /// `log` crate. With Vulkan and Metal, it enables the validation layers of
+    /// the driver. It has no effect with Direct3D.
+    ///
+    /// Validation is slow, so you should only enable it while developing.
+    pub gpu_validation: bool,
+
+    /// Defines whether or not the window should be transparent.
+    ///
+    /// The alpha channel of a transparent window is used to blend it with the
+    /// desktop, so you can clear its frame with [`Color::TRANSPARENT`] and
+    /// only draw what is needed. This is useful to build streaming widgets
+    /// and other overlays that are shown on top of the desktop.
+    ///
+    /// Whether transparency is honored depends on the platform and its
+    /// compositor.
+    ///
+    /// A transparent window still receives all the input over its area, even
+    /// where nothing is drawn. Input pass-through is not supported: `winit`
+    /// 0.22 cannot make a window ignore the cursor, so click-through overlays
+    /// cannot be built with Coffee.
+    ///
+    /// [`Color::TRANSPARENT`]: struct.Color.html#associatedconstant.TRANSPARENT
+    pub transparent: bool,
+
+    /// The [`PresentMode`] of the window.
+    ///
+    /// `None` uses [`PresentMode::Mailbox`] when supported, and
+    /// [`PresentMode::Fifo`] otherwise.
+    ///
+    /// [`PresentMode`]: enum.PresentMode.html
+    /// [`PresentMode::Mailbox`]: enum.PresentMode.html#variant.Mailbox
+    /// [`PresentMode::Fifo`]: enum.PresentMode.html#variant.Fifo
+    pub present_mode: Option<PresentMode>,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            title: String::from("Coffee"),
+            size: (1280, 1024),
+            resizable: true,
+            fullscreen: false,
+            maximized: false,
+            orientation: None,
+            platform: LinuxBackend::default(),
+            gpu_validation: false,
+            transparent: false,
+            present_mode: None,
+        }
+    }
 }
 
 impl Settings {
@@ -30,7 +114,7 @@ impl Settings {
             None
         };
 
-        winit::window::WindowBuilder::new()
+        let builder = winit::window::WindowBuilder::new()
             .with_title(self.title)
             .with_inner_size(winit::dpi::PhysicalSize {
                 width: self.size.0,
@@ -39,5 +123,21 @@ impl Settings {
             .with_resizable(self.resizable)
             .with_fullscreen(monitor.map(winit::window::Fullscreen::Borderless))
             .with_maximized(self.maximized)
+            .with_transparent(self.transparent);
+
+        #[cfg(target_os = "ios")]
+        let builder = {
+            use winit::platform::ios::{
+                ValidOrientations, WindowBuilderExtIOS,
+            };
+
+            builder.with_valid_orientations(match self.orientation {
+                None => ValidOrientations::LandscapeAndPortrait,
+                Some(Orientation::Landscape) => ValidOrientations::Landscape,
+                Some(Orientation::Portrait) => ValidOrientations::Portrait,
+            })
+        };
+
+        builder
     }
 }
@@ -0,0 +1,427 @@
+//! Translate the text of your game with [Fluent].
+//!
+//! A [`Localization`] contains the messages of every language of your game.
+//! Once installed, its messages can be translated with [`translate`] or used
+//! directly in a user interface with [`ui::Text::localized`]:
+//!
+//! ```
+//! use coffee::i18n::{self, Localization};
+//! use coffee::ui::Text;
+//!
+//! # fn main() -> coffee::Result<()> {
+//! let mut localization = Localization::new("en-US")?;
+//! localization.add("en-US", "menu-start = Start game")?;
+//! localization.add("es-ES", "menu-start = Empezar partida")?;
+//!
+//! i18n::install(localization);
+//!
+//! let _start = Text::localized("menu-start");
+//!
+//! i18n::set_language("es-ES")?;
+//! assert_eq!(i18n::translate("menu-start"), "Empezar partida");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Switching the language takes effect immediately: the user interface
+//! computes its layout again on the next frame. As a [`graphics::Text`]
+//! borrows its contents, use [`translate`] to obtain them.
+//!
+//! The installed [`Localization`] belongs to the current thread, which should
+//! be the one running your [`Game`].
+//!
+//! This module is only available when the `i18n` feature is enabled.
+//!
+//! [Fluent]: https://projectfluent.org
+//! [`Localization`]: struct.Localization.html
+//! [`translate`]: fn.translate.html
+//! [`ui::Text::localized`]: ../ui/struct.Text.html#method.localized
+//! [`graphics::Text`]: ../graphics/struct.Text.html
+//! [`Game`]: ../trait.Game.html
+use std::cell::{Cell, RefCell};
+use std::path::Path;
+
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use unic_langid::LanguageIdentifier;
+
+use crate::{assets, Error, Result};
+
+/// The messages of a game in different languages.
+///
+/// Messages are written in the [Fluent syntax]. When a message is missing in
+/// the current language, the one of the fallback language is used instead.
+///
+/// [Fluent syntax]: https://projectfluent.org/fluent/guide/
+pub struct Localization {
+    bundles: Vec<(LanguageIdentifier, FluentBundle<FluentResource>)>,
+    fallback: LanguageIdentifier,
+    language: LanguageIdentifier,
+}
+
+impl std::fmt::Debug for Localization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let languages: Vec<_> =
+            self.bundles.iter().map(|(language, _)| language).collect();
+
+        f.debug_struct("Localization")
+            .field("languages", &languages)
+            .field("fallback", &self.fallback)
+            .field("language", &self.language)
+            .finish()
+    }
+}
+
+impl Localization {
+    /// Creates an empty [`Localization`] with the given fallback language,
+    /// like `"en-US"`.
+    ///
+    /// The fallback language is also the current one at first.
+    ///
+    /// [`Localization`]: struct.Localization.html
+    pub fn new(fallback: &str) -> Result<Localization> {
+        let fallback = parse_language(fallback)?;
+
+        Ok(Localization {
+            bundles: Vec::new(),
+            language: fallback.clone(),
+            fallback,
+        })
+    }
+
+    /// Adds the messages of the given Fluent source to a language of the
+    /// [`Localization`].
+    ///
+    /// [`Localization`]: struct.Localization.html
+    pub fn add(&mut self, language: &str, source: &str) -> Result<()> {
+        let language = parse_language(language)?;
+
+        let resource = FluentResource::try_new(String::from(source))
+            .map_err(|(_, errors)| invalid("Fluent syntax", &errors))?;
+
+        let bundle = match self
+            .bundles
+            .iter()
+            .position(|(bundle_language, _)| *bundle_language == language)
+        {
+            Some(i) => &mut self.bundles[i].1,
+            None => {
+                let mut bundle = FluentBundle::new(vec![language.clone()]);
+
+                // Isolation marks would be drawn by the font
+                bundle.set_use_isolating(false);
+
+                self.bundles.push((language, bundle));
+                &mut self.bundles.last_mut().expect("Added bundle").1
+            }
+        };
+
+        bundle
+            .add_resource(resource)
+            .map_err(|errors| invalid("Fluent messages", &errors))
+    }
+
+    /// Adds the messages of the given Fluent file to a language of the
+    /// [`Localization`].
+    ///
+    /// [`Localization`]: struct.Localization.html
+    pub fn add_file<P: AsRef<Path>>(
+        &mut self,
+        language: &str,
+        path: P,
+    ) -> Result<()> {
+        let path = path.as_ref();
+
+        let read = || -> Result<String> {
+            String::from_utf8(assets::read(path)?)
+                .map_err(|error| Error::Deserialization(Box::new(error)))
+        };
+
+        read()
+            .and_then(|source| self.add(language, &source))
+            .map_err(|error| Error::Asset {
+                path: path.to_owned(),
+                source: Box::new(error),
+            })
+    }
+
+    /// Returns the current language of the [`Localization`].
+    ///
+    /// [`Localization`]: struct.Localization.html
+    pub fn language(&self) -> String {
+        self.language.to_string()
+    }
+
+    /// Changes the current language of the [`Localization`].
+    ///
+    /// [`Localization`]: struct.Localization.html
+    pub fn set_language(&mut self, language: &str) -> Result<()> {
+        self.language = parse_language(language)?;
+
+        Ok(())
+    }
+
+    /// Translates the message with the given identifier.
+    ///
+    /// The identifier itself is returned when the message does not exist.
+    pub fn translate(&self, id: &str) -> String {
+        self.translate_with(id, &[])
+    }
+
+    /// Translates the message with the given identifier, using the given
+    /// arguments for its variables.
+    ///
+    /// The identifier itself is returned when the message does not exist.
+    pub fn translate_with(
+        &self,
+        id: &str,
+        arguments: &[(&str, Value)],
+    ) -> String {
+        let mut args = FluentArgs::new();
+
+        for (name, value) in arguments {
+            args.set(*name, FluentValue::from(value));
+        }
+
+        let message = [&self.language, &self.fallback]
+            .iter()
+            .filter_map(|language| self.bundle(language))
+            .find_map(|bundle| {
+                let pattern = bundle.get_message(id)?.value()?;
+                let mut errors = Vec::new();
+
+                let message =
+                    bundle.format_pattern(pattern, Some(&args), &mut errors);
+
+                for error in errors {
+                    log::warn!("Message \"{}\" is malformed: {:?}", id, error);
+                }
+
+                Some(message.into_owned())
+            });
+
+        message.unwrap_or_else(|| {
+            log::warn!("Message \"{}\" is missing", id);
+
+            String::from(id)
+        })
+    }
+
+    fn bundle(
+        &self,
+        language: &LanguageIdentifier,
+    ) -> Option<&FluentBundle<FluentResource>> {
+        self.bundles
+            .iter()
+            .find(|(bundle_language, _)| bundle_language == language)
+            .map(|(_, bundle)| bundle)
+    }
+}
+
+/// The value of a variable in a message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Some text.
+    Text(String),
+
+    /// A number, which selects the plural form of a message.
+    Number(f64),
+}
+
+impl From<&str> for Value {
+    fn from(text: &str) -> Value {
+        Value::Text(String::from(text))
+    }
+}
+
+impl From<String> for Value {
+    fn from(text: String) -> Value {
+        Value::Text(text)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(number: f64) -> Value {
+        Value::Number(number)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(number: i64) -> Value {
+        Value::Number(number as f64)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(number: u32) -> Value {
+        Value::Number(f64::from(number))
+    }
+}
+
+impl<'a> From<&'a Value> for FluentValue<'a> {
+    fn from(value: &'a Value) -> FluentValue<'a> {
+        match value {
+            Value::Text(text) => FluentValue::from(text.as_str()),
+            Value::Number(number) => FluentValue::from(*number),
+        }
+    }
+}
+
+thread_local! {
+    static LOCALIZATION: RefCell<Option<Localization>> = RefCell::new(None);
+    static REVISION: Cell<u64> = Cell::new(0);
+}
+
+/// Installs the [`Localization`] used by [`translate`] in the current thread,
+/// replacing the previous one.
+///
+/// [`Localization`]: struct.Localization.html
+/// [`translate`]: fn.translate.html
+pub fn install(localization: Localization) {
+    LOCALIZATION.with(|current| *current.borrow_mut() = Some(localization));
+
+    invalidate();
+}
+
+/// Returns the current language of the installed [`Localization`], if any.
+///
+/// [`Localization`]: struct.Localization.html
+pub fn language() -> Option<String> {
+    LOCALIZATION
+        .with(|current| current.borrow().as_ref().map(Localization::language))
+}
+
+/// Changes the current language of the installed [`Localization`].
+///
+/// Every user interface computes its layout again on the next frame.
+///
+/// # Panics
+/// It panics if no [`Localization`] is installed.
+///
+/// [`Localization`]: struct.Localization.html
+pub fn set_language(language: &str) -> Result<()> {
+    LOCALIZATION.with(|current| {
+        current
+            .borrow_mut()
+            .as_mut()
+            .expect("Install a localization before setting its language")
+            .set_language(language)
+    })?;
+
+    invalidate();
+
+    Ok(())
+}
+
+/// Translates the message with the given identifier using the installed
+/// [`Localization`].
+///
+/// The identifier itself is returned when the message does not exist or no
+/// [`Localization`] is installed.
+///
+/// [`Localization`]: struct.Localization.html
+pub fn translate(id: &str) -> String {
+    translate_with(id, &[])
+}
+
+/// Translates the message with the given identifier using the installed
+/// [`Localization`] and the given arguments.
+///
+/// [`Localization`]: struct.Localization.html
+pub fn translate_with(id: &str, arguments: &[(&str, Value)]) -> String {
+    LOCALIZATION.with(|current| match current.borrow().as_ref() {
+        Some(localization) => localization.translate_with(id, arguments),
+        None => String::from(id),
+    })
+}
+
+// Changes every time the translations may change
+pub(crate) fn revision() -> u64 {
+    REVISION.with(Cell::get)
+}
+
+fn invalidate() {
+    REVISION.with(|revision| revision.set(revision.get().wrapping_add(1)));
+}
+
+fn parse_language(language: &str) -> Result<LanguageIdentifier> {
+    language.parse().map_err(|error| {
+        Error::Deserialization(
+            format!("Invalid language \"{}\": {}", language, error).into(),
+        )
+    })
+}
+
+fn invalid<E: std::fmt::Debug>(kind: &str, errors: &[E]) -> Error {
+    Error::Deserialization(format!("Invalid {}: {:?}", kind, errors).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn localization() -> Localization {
+        let mut localization =
+            Localization::new("en-US").expect("Create localization");
+
+        localization
+            .add(
+                "en-US",
+                "start = Start game\n\
+                 quit = Quit\n\
+                 coins = { $count ->\n    \
+                     [one] One coin\n   \
+                    *[other] { $count } coins\n\
+                 }\n",
+            )
+            .expect("Add English");
+
+        localization
+            .add("es-ES", "start = Empezar partida\n")
+            .expect("Add Spanish");
+
+        localization
+    }
+
+    #[test]
+    fn translates_to_the_current_language() {
+        let mut localization = localization();
+
+        assert_eq!(localization.translate("start"), "Start game");
+
+        localization.set_language("es-ES").expect("Set language");
+
+        assert_eq!(localization.language(), "es-ES");
+        assert_eq!(localization.translate("start"), "Empezar partida");
+    }
+
+    #[test]
+    fn falls_back_to_the_fallback_language() {
+        let mut localization = localization();
+
+        localization.set_language("es-ES").expect("Set language");
+
+        assert_eq!(localization.translate("quit"), "Quit");
+        assert_eq!(localization.translate("missing"), "missing");
+    }
+
+    #[test]
+    fn selects_plural_forms() {
+        let localization = localization();
+
+        let coins = |count: u32| {
+            localization.translate_with("coins", &[("count", count.into())])
+        };
+
+        assert_eq!(coins(1), "One coin");
+        assert_eq!(coins(3), "3 coins");
+    }
+
+    #[test]
+    fn rejects_invalid_sources() {
+        let mut localization = localization();
+
+        assert!(Localization::new("not a language!").is_err());
+        assert!(localization.add("en-US", "start = Again\n").is_err());
+        assert!(localization.add("en-US", "= Nothing\n").is_err());
+    }
+}
@@ -3,16 +3,20 @@
 pub mod gamepad;
 pub mod keyboard;
 pub mod mouse;
+pub mod touch;
 pub mod window;
 
 mod event;
 mod keyboard_and_mouse;
+mod recording;
 
 pub use crate::graphics::window::winit::event::ElementState as ButtonState;
 pub use event::Event;
+pub use gamepad::Gamepads;
 pub use keyboard::Keyboard;
 pub use keyboard_and_mouse::KeyboardAndMouse;
 pub use mouse::Mouse;
+pub use recording::Recording;
 
 /// The input of your [`Game`].
 ///
@@ -1,4 +1,4 @@
-use crate::input::{gamepad, keyboard, mouse, window};
+use crate::input::{gamepad, keyboard, mouse, touch, window};
 
 use std::time::SystemTime;
 
@@ -20,6 +20,9 @@ pub enum Event {
     /// A mouse event
     Mouse(mouse::Event),
 
+    /// A touch event
+    Touch(touch::Event),
+
     /// A gamepad event
     Gamepad {
         /// The gamepad identifier
@@ -7,7 +7,11 @@ pub use event::Event;
 pub use gilrs::Axis;
 pub use gilrs::Button;
 
+use super::{Event as InputEvent, Input};
+use crate::graphics::Vector;
+
 use gilrs::Gilrs;
+use std::collections::{HashMap, HashSet};
 use std::convert::TryInto;
 use std::time::SystemTime;
 
@@ -15,6 +19,155 @@ use std::time::SystemTime;
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub struct Id(gilrs::GamepadId);
 
+/// A simple gamepad input tracker.
+///
+/// It keeps track of the buttons and axes of every connected gamepad. The
+/// sticks are available as vectors with a radial dead zone, which removes
+/// the drift of worn out sticks without a jump in value at its edge.
+///
+/// You can use this as your [`Game::Input`] directly!
+///
+/// [`Game::Input`]: ../../trait.Game.html#associatedtype.Input
+#[derive(Debug, Clone)]
+pub struct Gamepads {
+    gamepads: HashMap<Id, State>,
+    dead_zone: f32,
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    axes: HashMap<Axis, f32>,
+    pressed_buttons: HashSet<Button>,
+    released_buttons: HashSet<Button>,
+}
+
+impl Gamepads {
+    /// The default dead zone of the sticks.
+    pub const DEFAULT_DEAD_ZONE: f32 = 0.15;
+
+    /// Returns the identifiers of the connected gamepads.
+    pub fn ids(&self) -> impl Iterator<Item = Id> + '_ {
+        self.gamepads.keys().copied()
+    }
+
+    /// Returns true if the given button of a gamepad is currently pressed.
+    pub fn is_button_pressed(&self, id: Id, button: Button) -> bool {
+        self.gamepads
+            .get(&id)
+            .map(|state| state.pressed_buttons.contains(&button))
+            .unwrap_or(false)
+    }
+
+    /// Returns true if the given button of a gamepad was released during the
+    /// last interaction.
+    pub fn was_button_released(&self, id: Id, button: Button) -> bool {
+        self.gamepads
+            .get(&id)
+            .map(|state| state.released_buttons.contains(&button))
+            .unwrap_or(false)
+    }
+
+    /// Returns the raw value of the given axis of a gamepad, from `-1.0` to
+    /// `1.0`.
+    pub fn axis(&self, id: Id, axis: Axis) -> f32 {
+        self.gamepads
+            .get(&id)
+            .and_then(|state| state.axes.get(&axis))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the direction of the left stick of a gamepad.
+    ///
+    /// The length of the vector goes from `0.0`, inside the dead zone, to
+    /// `1.0`. As in the coordinates of the screen, the Y axis points down.
+    pub fn left_stick(&self, id: Id) -> Vector {
+        self.stick(id, Axis::LeftStickX, Axis::LeftStickY)
+    }
+
+    /// Returns the direction of the right stick of a gamepad.
+    ///
+    /// The length of the vector goes from `0.0`, inside the dead zone, to
+    /// `1.0`. As in the coordinates of the screen, the Y axis points down.
+    pub fn right_stick(&self, id: Id) -> Vector {
+        self.stick(id, Axis::RightStickX, Axis::RightStickY)
+    }
+
+    /// Returns the dead zone of the sticks.
+    pub fn dead_zone(&self) -> f32 {
+        self.dead_zone
+    }
+
+    /// Sets the dead zone of the sticks, from `0.0` to `1.0`.
+    ///
+    /// By default, it is [`DEFAULT_DEAD_ZONE`].
+    ///
+    /// [`DEFAULT_DEAD_ZONE`]: #associatedconstant.DEFAULT_DEAD_ZONE
+    pub fn set_dead_zone(&mut self, dead_zone: f32) {
+        self.dead_zone = dead_zone.max(0.0).min(1.0);
+    }
+
+    fn stick(&self, id: Id, x: Axis, y: Axis) -> Vector {
+        // Sticks point up with positive values
+        let raw = Vector::new(self.axis(id, x), -self.axis(id, y));
+        let tilt = raw.norm();
+
+        if tilt <= self.dead_zone || self.dead_zone >= 1.0 {
+            return Vector::new(0.0, 0.0);
+        }
+
+        // Rescale the tilt so it starts at zero on the edge of the dead zone
+        let scaled = (tilt.min(1.0) - self.dead_zone) / (1.0 - self.dead_zone);
+
+        raw * (scaled / tilt)
+    }
+}
+
+impl Input for Gamepads {
+    fn new() -> Gamepads {
+        Gamepads {
+            gamepads: HashMap::new(),
+            dead_zone: Self::DEFAULT_DEAD_ZONE,
+        }
+    }
+
+    fn update(&mut self, event: InputEvent) {
+        match event {
+            InputEvent::Gamepad { id, event, .. } => match event {
+                Event::Disconnected => {
+                    let _ = self.gamepads.remove(&id);
+                }
+                Event::Connected => {
+                    let _ = self.gamepads.entry(id).or_default();
+                }
+                Event::ButtonPressed(button) => {
+                    let state = self.gamepads.entry(id).or_default();
+                    let _ = state.pressed_buttons.insert(button);
+                }
+                Event::ButtonReleased(button) => {
+                    let state = self.gamepads.entry(id).or_default();
+                    let _ = state.pressed_buttons.remove(&button);
+                    let _ = state.released_buttons.insert(button);
+                }
+                Event::ButtonChanged(_, _) => {}
+                Event::AxisChanged(axis, value) => {
+                    let state = self.gamepads.entry(id).or_default();
+                    let _ = state.axes.insert(axis, value);
+                }
+            },
+            _ => {
+                // Ignore other events...
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        for state in self.gamepads.values_mut() {
+            state.released_buttons.clear();
+        }
+    }
+}
+
 pub(crate) struct Tracker {
     context: Gilrs,
 }
@@ -59,6 +59,9 @@ impl Input for Keyboard {
                 }
                 Event::TextEntered { .. } => {}
             },
+            InputEvent::Touch { .. } => {
+                // Ignore touch events...
+            }
             InputEvent::Gamepad { .. } => {
                 // Ignore gamepad events...
             }
@@ -131,6 +131,9 @@ impl Input for Mouse {
             InputEvent::Keyboard { .. } => {
                 // Ignore keyboard events...
             }
+            InputEvent::Touch { .. } => {
+                // Ignore touch events...
+            }
             InputEvent::Gamepad { .. } => {
                 // Ignore gamepad events...
             }
@@ -0,0 +1,93 @@
+use crate::input::Event;
+
+use std::time::Duration;
+
+/// A recording of the input of a game session.
+///
+/// It stores the input events received during every frame and how many times
+/// the game was updated. Thanks to the fixed timestep, this is enough to
+/// re-simulate a session tick by tick using [`Headless::replay`].
+///
+/// You can record a session using [`Window::start_recording`] and
+/// [`Window::stop_recording`].
+///
+/// [`Headless::replay`]: ../struct.Headless.html#method.replay
+/// [`Window::start_recording`]: ../graphics/struct.Window.html#method.start_recording
+/// [`Window::stop_recording`]: ../graphics/struct.Window.html#method.stop_recording
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Recording {
+    frames: Vec<Frame>,
+    pending: Vec<Event>,
+    is_started: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Frame {
+    pub events: Vec<Event>,
+    pub ticks: u16,
+    pub delta: Duration,
+    pub checksum: Option<u64>,
+}
+
+impl Recording {
+    /// Creates a new empty [`Recording`].
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn new() -> Recording {
+        Recording::default()
+    }
+
+    /// Returns the amount of frames in the [`Recording`].
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn frames(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns the amount of ticks in the [`Recording`].
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn ticks(&self) -> usize {
+        self.frames
+            .iter()
+            .map(|frame| usize::from(frame.ticks))
+            .sum()
+    }
+
+    /// Returns true if the [`Recording`] contains no frames.
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub(crate) fn record(&mut self, event: Event) {
+        self.pending.push(event);
+    }
+
+    pub(crate) fn finish_frame(
+        &mut self,
+        ticks: u16,
+        delta: Duration,
+        checksum: Option<u64>,
+    ) {
+        // Recording may start in the middle of a frame. We only keep whole
+        // frames.
+        if !self.is_started {
+            self.is_started = true;
+            self.pending.clear();
+            return;
+        }
+
+        self.frames.push(Frame {
+            events: std::mem::replace(&mut self.pending, Vec::new()),
+            ticks,
+            delta,
+            checksum,
+        });
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &Frame> {
+        self.frames.iter()
+    }
+}
@@ -0,0 +1,5 @@
+//! Listen to touch events.
+
+mod event;
+
+pub use event::Event;
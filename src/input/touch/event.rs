@@ -0,0 +1,48 @@
+/// A touch event.
+///
+/// Every finger touching the screen has a unique identifier that stays the
+/// same until the touch ends or is cancelled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// A finger started touching the screen.
+    Started {
+        /// The identifier of the touch
+        id: u64,
+
+        /// The X coordinate of the touch position
+        x: f32,
+
+        /// The Y coordinate of the touch position
+        y: f32,
+    },
+
+    /// A finger was moved.
+    Moved {
+        /// The identifier of the touch
+        id: u64,
+
+        /// The X coordinate of the touch position
+        x: f32,
+
+        /// The Y coordinate of the touch position
+        y: f32,
+    },
+
+    /// A finger was lifted from the screen.
+    Ended {
+        /// The identifier of the touch
+        id: u64,
+
+        /// The X coordinate of the touch position
+        x: f32,
+
+        /// The Y coordinate of the touch position
+        y: f32,
+    },
+
+    /// The system cancelled the touch.
+    Cancelled {
+        /// The identifier of the touch
+        id: u64,
+    },
+}
@@ -0,0 +1,44 @@
+//! Load levels made with [LDtk], a modern 2D level editor.
+//!
+//! A [`Project`] contains every [`Level`] of an LDtk project. The tiles of
+//! each [`Layer`], including auto-tiles, are ready to draw as a [`Tilemap`],
+//! and the fields of every [`Entity`] can be deserialized into your own types.
+//!
+//! ```
+//! use coffee::ldtk::Project;
+//! use coffee::load::Task;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Door {
+//!     destination: String,
+//!     locked: bool,
+//! }
+//!
+//! let load_world: Task<Project> = Project::load("world.ldtk");
+//!
+//! fn doors(project: &Project) -> coffee::Result<Vec<Door>> {
+//!     let level = project.level("Entrance").expect("Entrance level");
+//!
+//!     level.entities("Door").map(|entity| entity.fields()).collect()
+//! }
+//! ```
+//!
+//! This module is only available when the `json` feature is enabled.
+//!
+//! [LDtk]: https://ldtk.io
+//! [`Project`]: struct.Project.html
+//! [`Level`]: struct.Level.html
+//! [`Layer`]: struct.Layer.html
+//! [`Tilemap`]: struct.Tilemap.html
+//! [`Entity`]: struct.Entity.html
+mod entity;
+mod fields;
+mod level;
+mod project;
+mod tilemap;
+
+pub use entity::Entity;
+pub use level::{Layer, Level};
+pub use project::Project;
+pub use tilemap::{Tile, Tilemap};
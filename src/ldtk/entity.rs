@@ -0,0 +1,48 @@
+use serde::de::DeserializeOwned;
+
+use crate::graphics::Point;
+use crate::ldtk::fields::Fields;
+use crate::Result;
+
+/// An entity placed in a [`Layer`], like a spawn point, an enemy, or a door.
+///
+/// [`Layer`]: struct.Layer.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entity {
+    /// The identifier of the entity definition.
+    pub identifier: String,
+
+    /// The position of the pivot of the entity in its [`Level`], in pixels.
+    ///
+    /// [`Level`]: struct.Level.html
+    pub position: Point,
+
+    /// The size of the entity, in pixels.
+    pub size: (f32, f32),
+
+    /// The pivot of the entity, relative to its size.
+    ///
+    /// `(0.0, 0.0)` is the top-left corner and `(1.0, 1.0)` is the
+    /// bottom-right corner.
+    pub pivot: (f32, f32),
+
+    pub(super) fields: Fields,
+}
+
+impl Entity {
+    /// Deserializes the value of the field with the given identifier.
+    ///
+    /// A missing field or a field without a value deserializes as `null`. Use
+    /// an `Option` to handle them.
+    pub fn field<T: DeserializeOwned>(&self, identifier: &str) -> Result<T> {
+        self.fields.get(identifier)
+    }
+
+    /// Deserializes all the fields of the [`Entity`] into your own type, using
+    /// the field identifiers as keys.
+    ///
+    /// [`Entity`]: struct.Entity.html
+    pub fn fields<T: DeserializeOwned>(&self) -> Result<T> {
+        self.fields.all()
+    }
+}
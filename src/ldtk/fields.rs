@@ -0,0 +1,38 @@
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::{Error, Result};
+
+// The custom fields of a level or an entity, by identifier
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Fields(Map<String, Value>);
+
+impl Fields {
+    pub(super) fn parse(instances: &Value) -> Fields {
+        Fields(
+            instances
+                .as_array()
+                .map(|instances| instances.iter())
+                .into_iter()
+                .flatten()
+                .filter_map(|instance| {
+                    let name = instance["__identifier"].as_str()?;
+
+                    Some((String::from(name), instance["__value"].clone()))
+                })
+                .collect(),
+        )
+    }
+
+    pub(super) fn get<T: DeserializeOwned>(&self, name: &str) -> Result<T> {
+        let value = self.0.get(name).cloned().unwrap_or(Value::Null);
+
+        serde_json::from_value(value)
+            .map_err(|error| Error::Deserialization(Box::new(error)))
+    }
+
+    pub(super) fn all<T: DeserializeOwned>(&self) -> Result<T> {
+        serde_json::from_value(Value::Object(self.0.clone()))
+            .map_err(|error| Error::Deserialization(Box::new(error)))
+    }
+}
@@ -0,0 +1,213 @@
+use serde::de::DeserializeOwned;
+
+use crate::graphics::{Point, Target, Vector};
+use crate::ldtk::fields::Fields;
+use crate::ldtk::{Entity, Tilemap};
+use crate::Result;
+
+/// A level of an LDtk [`Project`].
+///
+/// [`Project`]: struct.Project.html
+#[derive(Debug)]
+pub struct Level {
+    pub(super) identifier: String,
+    pub(super) position: Point,
+    pub(super) width: u32,
+    pub(super) height: u32,
+    pub(super) layers: Vec<Layer>,
+    pub(super) fields: Fields,
+}
+
+/// A layer of a [`Level`].
+///
+/// Depending on its type in LDtk, a [`Layer`] contains an integer grid,
+/// tiles, or entities.
+///
+/// [`Level`]: struct.Level.html
+/// [`Layer`]: struct.Layer.html
+#[derive(Debug)]
+pub struct Layer {
+    pub(super) identifier: String,
+    pub(super) grid_size: u16,
+    pub(super) columns: usize,
+    pub(super) rows: usize,
+    pub(super) offset: Vector,
+    pub(super) is_visible: bool,
+    pub(super) int_grid: Vec<i32>,
+    pub(super) tilemap: Option<Tilemap>,
+    pub(super) entities: Vec<Entity>,
+}
+
+impl Level {
+    /// Returns the identifier of the [`Level`].
+    ///
+    /// [`Level`]: struct.Level.html
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Returns the position of the [`Level`] in its world, in pixels.
+    ///
+    /// [`Level`]: struct.Level.html
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    /// Returns the width of the [`Level`], in pixels.
+    ///
+    /// [`Level`]: struct.Level.html
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the [`Level`], in pixels.
+    ///
+    /// [`Level`]: struct.Level.html
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the layers of the [`Level`] in drawing order, from the bottom
+    /// to the top.
+    ///
+    /// [`Level`]: struct.Level.html
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    /// Returns the [`Layer`] with the given identifier, if any.
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn layer(&self, identifier: &str) -> Option<&Layer> {
+        self.layers
+            .iter()
+            .find(|layer| layer.identifier == identifier)
+    }
+
+    /// Returns the entities with the given identifier in every [`Layer`] of
+    /// the [`Level`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    /// [`Level`]: struct.Level.html
+    pub fn entities<'a>(
+        &'a self,
+        identifier: &'a str,
+    ) -> impl Iterator<Item = &'a Entity> {
+        self.layers
+            .iter()
+            .flat_map(|layer| layer.entities.iter())
+            .filter(move |entity| entity.identifier == identifier)
+    }
+
+    /// Deserializes the value of the field with the given identifier.
+    ///
+    /// A missing field or a field without a value deserializes as `null`. Use
+    /// an `Option` to handle them.
+    pub fn field<T: DeserializeOwned>(&self, identifier: &str) -> Result<T> {
+        self.fields.get(identifier)
+    }
+
+    /// Deserializes all the fields of the [`Level`] into your own type, using
+    /// the field identifiers as keys.
+    ///
+    /// [`Level`]: struct.Level.html
+    pub fn fields<T: DeserializeOwned>(&self) -> Result<T> {
+        self.fields.all()
+    }
+
+    /// Draws the tiles of every visible [`Layer`] of the [`Level`] on the
+    /// given [`Target`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    /// [`Level`]: struct.Level.html
+    /// [`Target`]: ../graphics/struct.Target.html
+    pub fn draw(&self, target: &mut Target<'_>) {
+        for layer in self.layers.iter().filter(|layer| layer.is_visible) {
+            if let Some(tilemap) = &layer.tilemap {
+                tilemap.draw(target);
+            }
+        }
+    }
+}
+
+impl Layer {
+    /// Returns the identifier of the [`Layer`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Returns the size of a cell of the [`Layer`], in pixels.
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn grid_size(&self) -> u16 {
+        self.grid_size
+    }
+
+    /// Returns the number of columns of the [`Layer`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the number of rows of the [`Layer`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the offset of the [`Layer`] in its [`Level`], in pixels.
+    ///
+    /// It is already applied to the positions of its tiles and entities.
+    ///
+    /// [`Layer`]: struct.Layer.html
+    /// [`Level`]: struct.Level.html
+    pub fn offset(&self) -> Vector {
+        self.offset
+    }
+
+    /// Returns whether the [`Layer`] is visible.
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    /// Returns the value of the integer grid of the [`Layer`] in the given
+    /// cell.
+    ///
+    /// Returns `None` if the cell is empty or out of bounds.
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn int_value(&self, column: usize, row: usize) -> Option<i32> {
+        if column >= self.columns {
+            return None;
+        }
+
+        self.int_grid
+            .get(row * self.columns + column)
+            .copied()
+            .filter(|value| *value != 0)
+    }
+
+    /// Returns the [`Tilemap`] of the [`Layer`], if it has tiles.
+    ///
+    /// It contains both the tiles placed manually and the auto-tiles
+    /// generated by the rules of the [`Layer`].
+    ///
+    /// [`Tilemap`]: struct.Tilemap.html
+    /// [`Layer`]: struct.Layer.html
+    pub fn tilemap(&self) -> Option<&Tilemap> {
+        self.tilemap.as_ref()
+    }
+
+    /// Returns the entities of the [`Layer`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+}
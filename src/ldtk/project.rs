@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::assets;
+use crate::graphics::{Image, Point, Rectangle, Vector};
+use crate::ldtk::fields::Fields;
+use crate::ldtk::{Entity, Layer, Level, Tile, Tilemap};
+use crate::load::{Join, Task};
+use crate::{Error, Result};
+
+/// An LDtk project.
+///
+/// A [`Project`] is loaded from an `.ldtk` file together with its tilesets.
+/// Levels saved in separate files are loaded too.
+///
+/// [`Project`]: struct.Project.html
+#[derive(Debug)]
+pub struct Project {
+    levels: Vec<Level>,
+}
+
+impl Project {
+    /// Creates a [`Task`] that loads a [`Project`] from the given path.
+    ///
+    /// The tilesets are loaded from their paths relative to the project file.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Project`]: struct.Project.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<Project> {
+        let path = path.into();
+
+        Task::offload(move || {
+            let project = read(&path)?;
+
+            Ok((path, project))
+        })
+        .and_then(|(path, project)| {
+            let directory = path.parent().unwrap_or(Path::new("")).to_owned();
+
+            let load_tilesets = array(&project["defs"]["tilesets"])
+                .filter_map(|tileset| {
+                    Some((
+                        tileset["uid"].as_i64()?,
+                        directory.join(tileset["relPath"].as_str()?),
+                    ))
+                })
+                .fold(Task::succeed(HashMap::new), |tilesets, (uid, path)| {
+                    (tilesets, Image::load(path)).join().map(
+                        move |(mut tilesets, tileset)| {
+                            let _ = tilesets.insert(uid, tileset);
+                            tilesets
+                        },
+                    )
+                });
+
+            load_tilesets.and_then(move |tilesets| {
+                Task::new(move || {
+                    Project::parse(&project, &tilesets).map_err(|error| {
+                        Error::Asset {
+                            path,
+                            source: Box::new(Error::Deserialization(
+                                error.into(),
+                            )),
+                        }
+                    })
+                })
+            })
+        })
+    }
+
+    /// Returns the levels of the [`Project`].
+    ///
+    /// [`Project`]: struct.Project.html
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
+    /// Returns the [`Level`] with the given identifier, if any.
+    ///
+    /// [`Level`]: struct.Level.html
+    pub fn level(&self, identifier: &str) -> Option<&Level> {
+        self.levels
+            .iter()
+            .find(|level| level.identifier == identifier)
+    }
+
+    fn parse(
+        project: &Value,
+        tilesets: &HashMap<i64, Image>,
+    ) -> std::result::Result<Project, String> {
+        let levels = array(&project["levels"])
+            .map(|level| {
+                Ok(Level {
+                    identifier: string(&level["identifier"])?,
+                    position: Point::new(
+                        number(&level["worldX"])?,
+                        number(&level["worldY"])?,
+                    ),
+                    width: number(&level["pxWid"])? as u32,
+                    height: number(&level["pxHei"])? as u32,
+                    // LDtk lists layers from the top to the bottom
+                    layers: array(&level["layerInstances"])
+                        .rev()
+                        .map(|layer| parse_layer(layer, tilesets))
+                        .collect::<std::result::Result<_, String>>()?,
+                    fields: Fields::parse(&level["fieldInstances"]),
+                })
+            })
+            .collect::<std::result::Result<_, String>>()?;
+
+        Ok(Project { levels })
+    }
+}
+
+// Reads a project, replacing the levels saved in separate files
+fn read(path: &Path) -> Result<Value> {
+    let read_json = |path: &Path| -> Result<Value> {
+        let bytes = assets::read(path)?;
+
+        serde_json::from_slice(&bytes)
+            .map_err(|error| Error::Deserialization(Box::new(error)))
+    };
+
+    let directory = path.parent().unwrap_or(Path::new(""));
+
+    let read = || -> Result<Value> {
+        let mut project = read_json(path)?;
+
+        if let Some(levels) = project["levels"].as_array_mut() {
+            for level in levels.iter_mut() {
+                let external = level["externalRelPath"]
+                    .as_str()
+                    .map(|external| directory.join(external));
+
+                if let Some(external) = external {
+                    *level = read_json(&external)?;
+                }
+            }
+        }
+
+        Ok(project)
+    };
+
+    read().map_err(|error| Error::Asset {
+        path: path.to_path_buf(),
+        source: Box::new(error),
+    })
+}
+
+fn parse_layer(
+    layer: &Value,
+    tilesets: &HashMap<i64, Image>,
+) -> std::result::Result<Layer, String> {
+    let grid_size = number(&layer["__gridSize"])? as u16;
+
+    let offset = Vector::new(
+        number(&layer["__pxTotalOffsetX"]).unwrap_or(0.0),
+        number(&layer["__pxTotalOffsetY"]).unwrap_or(0.0),
+    );
+
+    let tiles = array(&layer["autoLayerTiles"])
+        .chain(array(&layer["gridTiles"]))
+        .map(|tile| {
+            let flip = tile["f"].as_u64().unwrap_or(0);
+
+            Ok(Tile {
+                source: Rectangle {
+                    x: number(&tile["src"][0])? as u16,
+                    y: number(&tile["src"][1])? as u16,
+                    width: grid_size,
+                    height: grid_size,
+                },
+                position: Point::new(
+                    number(&tile["px"][0])?,
+                    number(&tile["px"][1])?,
+                ) + offset,
+                flip_x: flip & 1 != 0,
+                flip_y: flip & 2 != 0,
+            })
+        })
+        .collect::<std::result::Result<Vec<_>, String>>()?;
+
+    let tilemap = match layer["__tilesetDefUid"].as_i64() {
+        Some(uid) if !tiles.is_empty() => {
+            let tileset = tilesets
+                .get(&uid)
+                .ok_or_else(|| format!("tileset {} has no image", uid))?;
+
+            Some(Tilemap::new(tileset.clone(), tiles))
+        }
+        _ => None,
+    };
+
+    let entities = array(&layer["entityInstances"])
+        .map(|entity| {
+            Ok(Entity {
+                identifier: string(&entity["__identifier"])?,
+                position: Point::new(
+                    number(&entity["px"][0])?,
+                    number(&entity["px"][1])?,
+                ) + offset,
+                size: (number(&entity["width"])?, number(&entity["height"])?),
+                pivot: (
+                    number(&entity["__pivot"][0]).unwrap_or(0.0),
+                    number(&entity["__pivot"][1]).unwrap_or(0.0),
+                ),
+                fields: Fields::parse(&entity["fieldInstances"]),
+            })
+        })
+        .collect::<std::result::Result<_, String>>()?;
+
+    Ok(Layer {
+        identifier: string(&layer["__identifier"])?,
+        grid_size,
+        columns: number(&layer["__cWid"])? as usize,
+        rows: number(&layer["__cHei"])? as usize,
+        offset,
+        is_visible: layer["visible"].as_bool().unwrap_or(true),
+        int_grid: array(&layer["intGridCsv"])
+            .map(|value| value.as_i64().unwrap_or(0) as i32)
+            .collect(),
+        tilemap,
+        entities,
+    })
+}
+
+fn array(value: &Value) -> impl DoubleEndedIterator<Item = &Value> {
+    value
+        .as_array()
+        .map(|values| values.iter())
+        .into_iter()
+        .flatten()
+}
+
+fn string(value: &Value) -> std::result::Result<String, String> {
+    value
+        .as_str()
+        .map(String::from)
+        .ok_or_else(|| format!("expected a string, found {}", value))
+}
+
+fn number(value: &Value) -> std::result::Result<f32, String> {
+    value
+        .as_f64()
+        .map(|number| number as f32)
+        .ok_or_else(|| format!("expected a number, found {}", value))
+}
@@ -0,0 +1,91 @@
+use crate::graphics::{
+    Batch, Image, IntoQuad, Packing, Point, Quad, Rectangle, Target,
+};
+
+/// A set of tiles that share the same tileset [`Image`].
+///
+/// The tiles are batched when the [`Tilemap`] is created, so it can be drawn
+/// with a single draw call.
+///
+/// [`Image`]: ../graphics/struct.Image.html
+/// [`Tilemap`]: struct.Tilemap.html
+pub struct Tilemap {
+    tiles: Vec<Tile>,
+    batch: Batch,
+}
+
+/// A tile of a [`Tilemap`].
+///
+/// [`Tilemap`]: struct.Tilemap.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tile {
+    /// The portion of the tileset containing the tile, in absolute
+    /// coordinates.
+    pub source: Rectangle<u16>,
+
+    /// The position where the tile should be drawn.
+    pub position: Point,
+
+    /// Whether the tile is flipped horizontally.
+    pub flip_x: bool,
+
+    /// Whether the tile is flipped vertically.
+    pub flip_y: bool,
+}
+
+impl Tilemap {
+    /// Creates a new [`Tilemap`] with the given tileset and tiles.
+    ///
+    /// [`Tilemap`]: struct.Tilemap.html
+    pub fn new(tileset: Image, tiles: Vec<Tile>) -> Tilemap {
+        let mut batch = Batch::new(tileset);
+
+        batch.extend(tiles.iter().copied());
+
+        Tilemap { tiles, batch }
+    }
+
+    /// Returns the tiles of the [`Tilemap`].
+    ///
+    /// [`Tilemap`]: struct.Tilemap.html
+    pub fn tiles(&self) -> &[Tile] {
+        &self.tiles
+    }
+
+    /// Draws the [`Tilemap`] on the given [`Target`].
+    ///
+    /// [`Tilemap`]: struct.Tilemap.html
+    /// [`Target`]: ../graphics/struct.Target.html
+    pub fn draw(&self, target: &mut Target<'_>) {
+        self.batch.draw(target);
+    }
+}
+
+impl std::fmt::Debug for Tilemap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Tilemap {{ tiles: {} }}", self.tiles.len())
+    }
+}
+
+impl IntoQuad for Tile {
+    fn into_quad(self, x_unit: f32, y_unit: f32) -> Quad {
+        let width = f32::from(self.source.width);
+        let height = f32::from(self.source.height);
+
+        Quad {
+            source: Rectangle {
+                x: f32::from(self.source.x) * x_unit,
+                y: f32::from(self.source.y) * y_unit,
+                width: width * x_unit,
+                height: height * y_unit,
+            },
+            position: self.position,
+            size: (width, height),
+            packing: Packing {
+                flip_horizontally: self.flip_x,
+                flip_vertically: self.flip_y,
+                ..Packing::NONE
+            },
+        }
+    }
+}
@@ -38,10 +38,7 @@
 //! fn main() -> Result<()> {
 //!     MyGame::run(WindowSettings {
 //!         title: String::from("A caffeinated game"),
-//!         size: (1280, 1024),
-//!         resizable: true,
-//!         fullscreen: false,
-//!         maximized: false,
+//!         ..Default::default()
 //!     })
 //! }
 //!
@@ -67,6 +64,14 @@
 //! }
 //! ```
 //!
+//! # Graphics backends
+//! A graphics backend needs to be chosen by enabling one of the `opengl`,
+//! `vulkan`, `metal`, `dx11`, or `dx12` features.
+//!
+//! Android is only supported by the `vulkan` backend. The `opengl` backend
+//! cannot recreate its surface when the app is resumed, so it fails to compile
+//! for Android.
+//!
 //! [`Game`]: trait.Game.html
 //! [`Game::run`]: trait.Game.html#method.run
 //! [`WindowSettings`]: graphics/struct.WindowSettings.html
@@ -81,12 +86,32 @@ mod game;
 mod result;
 mod timer;
 
+pub mod anim;
+pub mod assets;
+#[cfg(feature = "audio")]
+pub mod audio;
+#[cfg(feature = "dialogs")]
+pub mod dialog;
+#[cfg(feature = "egui")]
+pub mod egui;
 pub mod graphics;
+#[cfg(feature = "i18n")]
+pub mod i18n;
 pub mod input;
+#[cfg(feature = "json")]
+pub mod ldtk;
 pub mod load;
+pub mod profile;
+pub mod scene;
+pub mod time;
 pub mod ui;
 
-pub use debug::Debug;
-pub use game::Game;
+pub use debug::{Debug, DebugPosition, DebugSettings};
+pub use game::{
+    Background, CatchUp, CrashHandler, Game, Headless, Runtime, Timestep,
+};
 pub use result::{Error, Result};
 pub use timer::Timer;
+
+#[cfg(feature = "raw-events")]
+pub use graphics::window::winit;
@@ -12,15 +12,38 @@
 //!
 //! # Loading screens
 //! The [`LoadingScreen`] trait allows you to implement a loading screen that is
-//! compatible with any [`Task`]. Currently, Coffee includes a built-in loading
-//! screen: [`ProgressBar`], which shows a simple progress bar with some text.
+//! compatible with any [`Task`]. Coffee includes some built-in loading screens,
+//! like [`ProgressBar`], which shows a simple progress bar with some text.
+//!
+//! # Game data
+//! If you enable the `json` or `ron` features, you can also load your own
+//! types from data files using the [`data`] module.
+//!
+//! # Streaming
+//! You can also load assets while your game is running by queueing tasks in a
+//! [`Loader`].
+//!
+//! # Hot reloading
+//! During development, you can [`hot_reload`] your assets to see your changes
+//! without restarting your game.
 //!
 //! [`Task`]: struct.Task.html
 //! [`LoadingScreen`]: loading_screen/trait.LoadingScreen.html
 //! [`ProgressBar`]: loading_screen/struct.ProgressBar.html
+//! [`data`]: data/index.html
+//! [`Loader`]: struct.Loader.html
+//! [`hot_reload`]: hot_reload/index.html
+mod loader;
 mod task;
 
+#[cfg(feature = "http")]
+mod fetch;
+
+#[cfg(any(feature = "json", feature = "ron"))]
+pub mod data;
+pub mod hot_reload;
 pub mod loading_screen;
 
+pub use loader::{Loader, Ticket};
 pub use loading_screen::LoadingScreen;
-pub use task::{Join, Progress, Task};
+pub use task::{Join, JoinParallel, Progress, Stage, Task};
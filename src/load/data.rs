@@ -0,0 +1,88 @@
+//! Load game data files, like configuration or levels, into your own types.
+//!
+//! The functions in this module produce a [`Task`] that reads a file from the
+//! mounted [`assets::Source`] and deserializes it on a background thread. This
+//! way, your game data participates in your loading screens like any other
+//! asset.
+//!
+//! Each format needs its own feature to be enabled:
+//!   * `json` for [`json`]
+//!   * `ron` for [`ron`]
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # {
+//! use coffee::load::{data, Task};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Settings {
+//!     volume: f32,
+//!     difficulty: u8,
+//! }
+//!
+//! let load_settings: Task<Settings> = data::json("settings.json");
+//! # }
+//! ```
+//!
+//! [`Task`]: ../struct.Task.html
+//! [`assets::Source`]: ../../assets/trait.Source.html
+//! [`json`]: fn.json.html
+//! [`ron`]: fn.ron.html
+use crate::assets;
+use crate::load::Task;
+use crate::{Error, Result};
+
+use serde::de::DeserializeOwned;
+use std::path::PathBuf;
+
+/// Creates a [`Task`] that deserializes the JSON file in the given path.
+///
+/// This is only available when the `json` feature is enabled.
+///
+/// [`Task`]: ../struct.Task.html
+#[cfg(feature = "json")]
+pub fn json<T, P>(path: P) -> Task<T>
+where
+    T: 'static + Send + DeserializeOwned,
+    P: Into<PathBuf>,
+{
+    load(path.into(), |bytes| {
+        serde_json::from_slice(bytes)
+            .map_err(|error| Error::Deserialization(Box::new(error)))
+    })
+}
+
+/// Creates a [`Task`] that deserializes the [RON] file in the given path.
+///
+/// This is only available when the `ron` feature is enabled.
+///
+/// [`Task`]: ../struct.Task.html
+/// [RON]: https://github.com/ron-rs/ron
+#[cfg(feature = "ron")]
+pub fn ron<T, P>(path: P) -> Task<T>
+where
+    T: 'static + Send + DeserializeOwned,
+    P: Into<PathBuf>,
+{
+    load(path.into(), |bytes| {
+        ron::de::from_bytes(bytes)
+            .map_err(|error| Error::Deserialization(Box::new(error)))
+    })
+}
+
+fn load<T, F>(path: PathBuf, deserialize: F) -> Task<T>
+where
+    T: 'static + Send,
+    F: 'static + Send + FnOnce(&[u8]) -> Result<T>,
+{
+    Task::offload(move || {
+        assets::read(&path)
+            .map_err(Error::from)
+            .and_then(|bytes| deserialize(&bytes))
+            .map_err(|error| Error::Asset {
+                path,
+                source: Box::new(error),
+            })
+    })
+}
@@ -0,0 +1,127 @@
+use crate::load::Task;
+use crate::Result;
+
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+const TOTAL_WORK: u32 = 100;
+const CHUNK_SIZE: usize = 16 * 1024;
+const REFRESH_INTERVAL: Duration = Duration::from_millis(16);
+
+enum Message {
+    Progress(u32),
+    Finished(thread::Result<Result<Vec<u8>>>),
+}
+
+impl Task<Vec<u8>> {
+    /// Creates a new [`Task`] that downloads the contents of the given URL.
+    ///
+    /// The download happens on a background thread. If the server reports the
+    /// size of the contents, the [`Task`] will notify its progress as bytes
+    /// arrive. Otherwise, all of its work will be completed at once.
+    ///
+    /// This is only available when the `http` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use coffee::load::Task;
+    ///
+    /// let news = Task::fetch("https://example.com/news.txt")
+    ///     .map(|bytes| String::from_utf8_lossy(&bytes).into_owned());
+    /// ```
+    ///
+    /// [`Task`]: struct.Task.html
+    pub fn fetch<U: Into<String>>(url: U) -> Task<Vec<u8>> {
+        let url = url.into();
+
+        Task::sequence(TOTAL_WORK, move |worker| {
+            let (sender, receiver) = mpsc::channel();
+
+            rayon::spawn(move || {
+                let progress = sender.clone();
+
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    download(&url, |work| {
+                        let _ = progress.send(Message::Progress(work));
+                    })
+                }));
+
+                let _ = sender.send(Message::Finished(result));
+            });
+
+            let mut work_completed = 0;
+
+            loop {
+                match receiver.recv_timeout(REFRESH_INTERVAL) {
+                    Ok(Message::Progress(work)) => {
+                        work_completed += work;
+                        worker.notify_progress(work);
+                    }
+                    Ok(Message::Finished(Ok(result))) => {
+                        worker.notify_progress(TOTAL_WORK - work_completed);
+
+                        return result;
+                    }
+                    Ok(Message::Finished(Err(panic))) => {
+                        panic::resume_unwind(panic)
+                    }
+                    Err(mpsc::RecvTimeoutError::Timeout) => {
+                        worker.notify_progress(0);
+                    }
+                    Err(mpsc::RecvTimeoutError::Disconnected) => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::Other,
+                            "Download finished unexpectedly",
+                        )
+                        .into());
+                    }
+                }
+            }
+        })
+    }
+}
+
+fn download<F>(url: &str, mut on_progress: F) -> Result<Vec<u8>>
+where
+    F: FnMut(u32),
+{
+    let response = ureq::get(url)
+        .call()
+        .map_err(|error| io::Error::new(io::ErrorKind::Other, error))?;
+
+    let length = response
+        .header("Content-Length")
+        .and_then(|length| length.parse::<usize>().ok())
+        .filter(|length| *length > 0);
+
+    let mut reader = response.into_reader();
+    let mut bytes = Vec::with_capacity(length.unwrap_or(0));
+    let mut chunk = vec![0; CHUNK_SIZE];
+    let mut work_completed = 0;
+
+    loop {
+        let read = reader.read(&mut chunk)?;
+
+        if read == 0 {
+            break;
+        }
+
+        bytes.extend_from_slice(&chunk[..read]);
+
+        if let Some(length) = length {
+            let work = ((bytes.len().min(length) * TOTAL_WORK as usize)
+                / length) as u32;
+
+            if work > work_completed {
+                on_progress(work - work_completed);
+                work_completed = work;
+            }
+        }
+    }
+
+    Ok(bytes)
+}
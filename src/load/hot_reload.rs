@@ -0,0 +1,247 @@
+//! Reload your assets while your game is running.
+//!
+//! Hot reloading is opt-in and meant to be used during development. Once
+//! [`enable`]d, every [`Image`], [`Font`], and [`Shader`] loaded from a path is
+//! watched for changes. When one of its files is modified, the asset is loaded
+//! and uploaded to the GPU again, swapping the contents behind all the
+//! existing handles. This way, you can iterate on your art and your effects
+//! without restarting your game.
+//!
+//! ```
+//! use coffee::load::hot_reload;
+//!
+//! if cfg!(debug_assertions) {
+//!     hot_reload::enable();
+//! }
+//! ```
+//!
+//! Hot reloading needs to be enabled _before_ loading the assets you want to
+//! watch. Watched files are checked for changes at most twice per second.
+//!
+//! If a modified file cannot be decoded, or a [`Shader`] fails to compile, the
+//! error is logged and the old contents are kept until the file is modified
+//! again. Keep in mind that any [`Batch`] created before a reload will keep
+//! the dimensions of the old [`Image`], and that the data of every reloaded
+//! [`Font`] is kept in memory until your game exits.
+//!
+//! [`enable`]: fn.enable.html
+//! [`Image`]: ../../graphics/struct.Image.html
+//! [`Font`]: ../../graphics/struct.Font.html
+//! [`Shader`]: ../../graphics/struct.Shader.html
+//! [`Batch`]: ../../graphics/struct.Batch.html
+use crate::assets;
+use crate::graphics::gpu::{self, Texture};
+use crate::graphics::{Font, Gpu, Image, Shader, ShaderKind};
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::{Rc, Weak};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime};
+
+use instant::Instant;
+
+const CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static WATCHER: RefCell<Watcher> = RefCell::new(Watcher::new());
+}
+
+/// Enables hot reloading of assets.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Disables hot reloading of assets.
+///
+/// Assets that are already being watched will stop being reloaded.
+pub fn disable() {
+    ENABLED.store(false, Ordering::Relaxed);
+
+    WATCHER.with(|watcher| watcher.borrow_mut().assets.clear());
+}
+
+/// Returns true if hot reloading of assets is enabled.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub(crate) fn watch_image(path: PathBuf, texture: &Rc<RefCell<Texture>>) {
+    watch(vec![path], Asset::Image(Rc::downgrade(texture)));
+}
+
+pub(crate) fn watch_font(path: PathBuf, font: &Rc<RefCell<gpu::Font>>) {
+    watch(vec![path], Asset::Font(Rc::downgrade(font)));
+}
+
+pub(crate) fn watch_shader(
+    glsl: PathBuf,
+    spirv: PathBuf,
+    kind: ShaderKind,
+    program: &Rc<RefCell<gpu::Program>>,
+) {
+    watch(
+        vec![glsl, spirv],
+        Asset::Shader {
+            kind,
+            program: Rc::downgrade(program),
+        },
+    );
+}
+
+fn watch(paths: Vec<PathBuf>, asset: Asset) {
+    if !is_enabled() {
+        return;
+    }
+
+    let files = paths
+        .into_iter()
+        .map(|path| {
+            let modified = assets::modified(&path);
+
+            (path, modified)
+        })
+        .collect();
+
+    WATCHER.with(|watcher| {
+        watcher.borrow_mut().assets.push(Watched { files, asset })
+    });
+}
+
+pub(crate) fn reload(gpu: &mut Gpu) {
+    if !is_enabled() {
+        return;
+    }
+
+    WATCHER.with(|watcher| watcher.borrow_mut().reload(gpu));
+}
+
+struct Watcher {
+    assets: Vec<Watched>,
+    last_check: Option<Instant>,
+}
+
+struct Watched {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+    asset: Asset,
+}
+
+enum Asset {
+    Image(Weak<RefCell<Texture>>),
+    Font(Weak<RefCell<gpu::Font>>),
+    Shader {
+        kind: ShaderKind,
+        program: Weak<RefCell<gpu::Program>>,
+    },
+}
+
+impl Watcher {
+    fn new() -> Watcher {
+        Watcher {
+            assets: Vec::new(),
+            last_check: None,
+        }
+    }
+
+    fn reload(&mut self, gpu: &mut Gpu) {
+        let now = Instant::now();
+
+        match self.last_check {
+            Some(last_check) if now - last_check < CHECK_INTERVAL => return,
+            _ => self.last_check = Some(now),
+        }
+
+        self.assets.retain(|watched| watched.asset.is_alive());
+
+        for watched in self.assets.iter_mut() {
+            let mut is_modified = false;
+
+            for (path, last_modified) in watched.files.iter_mut() {
+                let modified = assets::modified(path);
+
+                if modified != *last_modified {
+                    *last_modified = modified;
+                    is_modified = true;
+                }
+            }
+
+            if is_modified {
+                watched.asset.reload(gpu, &watched.files);
+            }
+        }
+    }
+}
+
+impl Asset {
+    fn is_alive(&self) -> bool {
+        match self {
+            Asset::Image(texture) => texture.strong_count() > 0,
+            Asset::Font(font) => font.strong_count() > 0,
+            Asset::Shader { program, .. } => program.strong_count() > 0,
+        }
+    }
+
+    fn reload(&self, gpu: &mut Gpu, files: &[(PathBuf, Option<SystemTime>)]) {
+        match self {
+            Asset::Image(texture) => {
+                let image = Image::decode(&files[0].0)
+                    .and_then(|image| Image::from_image(gpu, &image));
+
+                match (texture.upgrade(), image) {
+                    (Some(texture), Ok(image)) => {
+                        *texture.borrow_mut() = image.texture.borrow().clone();
+                    }
+                    (_, Err(error)) => {
+                        log::warn!("The image could not be reloaded: {}", error)
+                    }
+                    _ => {}
+                }
+            }
+            Asset::Font(font) => {
+                let new_font = Font::read(&files[0].0)
+                    .and_then(|bytes| Font::from_bytes(gpu, bytes));
+
+                match (font.upgrade(), new_font) {
+                    (Some(font), Ok(new_font)) => {
+                        std::mem::swap(
+                            &mut *font.borrow_mut(),
+                            &mut *new_font.raw.borrow_mut(),
+                        );
+                    }
+                    (_, Err(error)) => {
+                        log::warn!("The font could not be reloaded: {}", error)
+                    }
+                    _ => {}
+                }
+            }
+            Asset::Shader { kind, program } => {
+                // Only the program is replaced, the uniforms are kept
+                let shader = Shader::from_files(
+                    gpu,
+                    *kind,
+                    &files[0].0,
+                    &files[1].0,
+                    &[],
+                );
+
+                match (program.upgrade(), shader) {
+                    (Some(program), Ok(shader)) => {
+                        std::mem::swap(
+                            &mut *program.borrow_mut(),
+                            &mut *shader.program.borrow_mut(),
+                        );
+                    }
+                    (_, Err(error)) => {
+                        log::warn!(
+                            "The shader could not be reloaded: {}",
+                            error
+                        )
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
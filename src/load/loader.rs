@@ -0,0 +1,190 @@
+use crate::graphics::Gpu;
+use crate::load::task::{Started, Worker};
+use crate::load::Task;
+use crate::Result;
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use instant::Instant;
+
+/// A loader that runs tasks in the background while your game is running.
+///
+/// A [`Loader`] can be handed a [`Task`] at any moment during gameplay. Its
+/// offloaded work, like decoding an [`Image`], starts right away on a
+/// background thread. Then, once the background work is done, the [`Task`] is
+/// finished on the main thread when you [`update`] the [`Loader`].
+///
+/// Finishing tasks normally involves uploading resources to the GPU. A
+/// [`Loader`] finishes as many tasks as it can within a time budget per
+/// [`update`], avoiding hitches when streaming many assets.
+///
+/// Only the offloaded work of a [`Task`] is streamed. The parts that are not
+/// offloaded, like the ones created with [`Task::new`], run all at once when
+/// the [`Task`] is finished, and they cannot be split across updates. A single
+/// [`Task`] with a lot of local work can exceed the budget; queue many small
+/// tasks instead.
+///
+/// # Example
+///
+/// ```
+/// use coffee::graphics::{Image, Window};
+/// use coffee::load::{Loader, Ticket};
+///
+/// struct Chunk {
+///     ticket: Ticket,
+///     tiles: Option<Image>,
+/// }
+///
+/// fn stream(loader: &mut Loader<Image>, window: &mut Window) {
+///     let chunk = Chunk {
+///         ticket: loader.queue(Image::load("chunks/0_0.png")),
+///         tiles: None,
+///     };
+///
+///     // Every frame...
+///     loader.update(window.gpu());
+///
+///     for (ticket, result) in loader.completed() {
+///         // Match the ticket with your chunks and use the result!
+///     }
+/// }
+/// ```
+///
+/// [`Loader`]: struct.Loader.html
+/// [`Task`]: struct.Task.html
+/// [`Image`]: ../graphics/struct.Image.html
+/// [`update`]: #method.update
+/// [`Task::new`]: struct.Task.html#method.new
+pub struct Loader<T> {
+    pending: VecDeque<(Ticket, Started<T>)>,
+    completed: VecDeque<(Ticket, Result<T>)>,
+    budget: Duration,
+    next_ticket: u64,
+}
+
+impl<T> Loader<T> {
+    /// Creates a new empty [`Loader`].
+    ///
+    /// By default, the [`Loader`] spends at most 4 milliseconds finishing
+    /// tasks per [`update`].
+    ///
+    /// [`Loader`]: struct.Loader.html
+    /// [`update`]: #method.update
+    pub fn new() -> Loader<T> {
+        Loader {
+            pending: VecDeque::new(),
+            completed: VecDeque::new(),
+            budget: Duration::from_millis(4),
+            next_ticket: 0,
+        }
+    }
+
+    /// Sets the time budget that the [`Loader`] can spend finishing tasks per
+    /// [`update`].
+    ///
+    /// At least one task will be finished per [`update`], if ready, even if it
+    /// takes longer than the budget.
+    ///
+    /// [`Loader`]: struct.Loader.html
+    /// [`update`]: #method.update
+    pub fn budget(mut self, budget: Duration) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// Queues a [`Task`] in the [`Loader`], starting its background work.
+    ///
+    /// It returns a [`Ticket`] that identifies the result of the [`Task`]
+    /// once it is [`completed`].
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Ticket`]: struct.Ticket.html
+    /// [`completed`]: #method.completed
+    pub fn queue(&mut self, task: Task<T>) -> Ticket {
+        let ticket = Ticket(self.next_ticket);
+        self.next_ticket += 1;
+
+        self.pending.push_back((ticket, task.start()));
+
+        ticket
+    }
+
+    /// Finishes the queued tasks that are ready, within the time budget of the
+    /// [`Loader`].
+    ///
+    /// You should call this once per frame. Tasks are finished in the same
+    /// order they were queued, skipping the ones whose background work is not
+    /// done yet.
+    ///
+    /// The budget is checked between tasks. The local work of a [`Task`]
+    /// always runs to completion once it starts.
+    ///
+    /// [`Loader`]: struct.Loader.html
+    /// [`Task`]: struct.Task.html
+    pub fn update(&mut self, gpu: &mut Gpu) {
+        let start = Instant::now();
+        let mut worker = Worker::Headless(Some(gpu));
+        let mut i = 0;
+
+        while i < self.pending.len() {
+            if !self.pending[i].1.is_ready() {
+                i += 1;
+                continue;
+            }
+
+            let (ticket, task) =
+                self.pending.remove(i).expect("Remove ready task");
+
+            self.completed.push_back((ticket, task.finish(&mut worker)));
+
+            if start.elapsed() >= self.budget {
+                break;
+            }
+        }
+    }
+
+    /// Obtains the results of the completed tasks, in order of completion.
+    pub fn completed(
+        &mut self,
+    ) -> impl Iterator<Item = (Ticket, Result<T>)> + '_ {
+        self.completed.drain(..)
+    }
+
+    /// Returns the amount of tasks that have not been completed yet.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns true if there are no pending tasks in the [`Loader`].
+    ///
+    /// [`Loader`]: struct.Loader.html
+    pub fn is_idle(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+impl<T> Default for Loader<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for Loader<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Loader {{ pending: {}, completed: {}, budget: {:?} }}",
+            self.pending.len(),
+            self.completed.len(),
+            self.budget
+        )
+    }
+}
+
+/// An identifier of a [`Task`] queued in a [`Loader`].
+///
+/// [`Task`]: struct.Task.html
+/// [`Loader`]: struct.Loader.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Ticket(u64);
@@ -8,18 +8,25 @@
 //! [`LoadingScreen`] trait.
 //!
 //! If you want a simple placeholder, you can try out the built-in
-//! [`ProgressBar`] loading screen.
+//! [`ProgressBar`] or [`Spinner`] loading screens. If you want to show your
+//! logo and some tips, you can configure a [`Splash`] loading screen.
 //!
 //! [`Task`]: ../struct.Task.html
 //! [`LoadingScreen`]: trait.LoadingScreen.html
 //! [`ProgressBar`]: struct.ProgressBar.html
+//! [`Spinner`]: struct.Spinner.html
+//! [`Splash`]: struct.Splash.html
 mod progress_bar;
+mod spinner;
+mod splash;
 
 pub use progress_bar::ProgressBar;
+pub use spinner::Spinner;
+pub use splash::Splash;
 
 use crate::graphics;
 use crate::load::{Progress, Task};
-use crate::Result;
+use crate::{Result, Timer};
 
 /// A loading screen keeps track of the progress of a task and provides feedback
 /// to the user.
@@ -28,9 +35,8 @@ use crate::Result;
 /// If you have a [`LoadingScreen`], set it as your [`Game::LoadingScreen`]
 /// associated type. Coffee will automatically use it when your game starts!
 ///
-/// # Future plans
-/// As of now, Coffee only ships with the [`ProgressBar`] loading screen. In the
-/// near future, the plan is to add more interesting (and configurable!) loading
+/// # Built-in loading screens
+/// Coffee ships with the [`ProgressBar`], [`Spinner`], and [`Splash`] loading
 /// screens. If you make a cool loading screen or have an interesting idea and
 /// you would like to share it, feel free to [create an issue] or
 /// [open a pull request]!
@@ -38,6 +44,8 @@ use crate::Result;
 /// [`Task`]: ../struct.Task.html
 /// [`LoadingScreen`]: trait.LoadingScreen.html
 /// [`ProgressBar`]: struct.ProgressBar.html
+/// [`Spinner`]: struct.Spinner.html
+/// [`Splash`]: struct.Splash.html
 /// [`Game::LoadingScreen`]: ../../trait.Game.html#associatedtype.LoadingScreen
 /// [create an issue]: https://github.com/hecrj/coffee/issues
 /// [open a pull request]: https://github.com/hecrj/coffee/pulls
@@ -57,27 +65,58 @@ pub trait LoadingScreen {
     /// You should provide feedback to the user here. You can draw on the given
     /// [`Frame`], like in [`Game::draw`].
     ///
+    /// The provided [`Timer`] is updated before every draw. You can use
+    /// [`Timer::elapsed`] or [`Timer::delta`] to animate your loading screen.
+    ///
     /// [`LoadingScreen`]: trait.LoadingScreen.html
     /// [`Progress`]: ../struct.Progress.html
     /// [`Frame`]: ../../graphics/struct.Frame.html
     /// [`Game::draw`]: ../../trait.Game.html#tymethod.draw
-    fn draw(&mut self, progress: &Progress, frame: &mut graphics::Frame<'_>);
+    /// [`Timer`]: ../../struct.Timer.html
+    /// [`Timer::elapsed`]: ../../struct.Timer.html#method.elapsed
+    /// [`Timer::delta`]: ../../struct.Timer.html#method.delta
+    fn draw(
+        &mut self,
+        progress: &Progress,
+        frame: &mut graphics::Frame<'_>,
+        timer: &Timer,
+    );
 
     /// Runs the [`LoadingScreen`] with a task and obtain its result.
     ///
     /// By default, it runs the task and refreshes the window when there is
-    /// progress.
+    /// progress. While tasks wait for background work, like the decoding of an
+    /// [`Image`], the window keeps being refreshed at around 60 frames per
+    /// second, even if no progress is made.
     ///
     /// [`LoadingScreen`]: trait.LoadingScreen.html
+    /// [`Image`]: ../../graphics/struct.Image.html
     fn run<T>(
         &mut self,
         task: Task<T>,
         window: &mut graphics::Window,
     ) -> Result<T> {
-        task.run_with_window(window, |progress, window| {
-            self.draw(progress, &mut window.frame());
-            window.swap_buffers();
-        })
+        let mut timer = Timer::new(0);
+        let mut failure = None;
+
+        let result = task.run_with_window(window, |progress, window| {
+            if failure.is_some() {
+                return;
+            }
+
+            timer.update();
+
+            self.draw(progress, &mut window.frame(), &timer);
+
+            if let Err(error) = window.swap_buffers() {
+                failure = Some(error);
+            }
+        });
+
+        match failure {
+            Some(error) => Err(error),
+            None => result,
+        }
     }
 }
 
@@ -86,6 +125,11 @@ impl LoadingScreen for () {
         Ok(())
     }
 
-    fn draw(&mut self, _progress: &Progress, _frame: &mut graphics::Frame<'_>) {
+    fn draw(
+        &mut self,
+        _progress: &Progress,
+        _frame: &mut graphics::Frame<'_>,
+        _timer: &Timer,
+    ) {
     }
 }
@@ -1,6 +1,6 @@
 use super::{LoadingScreen, Progress};
 use crate::graphics;
-use crate::Result;
+use crate::{Result, Timer};
 
 /// A simple loading screen showing a progress bar and the current stage.
 ///
@@ -31,7 +31,12 @@ impl LoadingScreen for ProgressBar {
         })
     }
 
-    fn draw(&mut self, progress: &Progress, frame: &mut graphics::Frame<'_>) {
+    fn draw(
+        &mut self,
+        progress: &Progress,
+        frame: &mut graphics::Frame<'_>,
+        _timer: &Timer,
+    ) {
         frame.clear(graphics::Color::BLACK);
 
         self.pencil.draw(
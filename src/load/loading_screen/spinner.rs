@@ -0,0 +1,68 @@
+use super::{LoadingScreen, Progress};
+use crate::graphics;
+use crate::{Result, Timer};
+
+use std::f32::consts::PI;
+
+/// A minimal loading screen showing a spinner in the center of the screen.
+///
+/// # Usage
+/// Set [`Spinner`] as your [`Game::LoadingScreen`] associated type.
+///
+/// [`Spinner`]: struct.Spinner.html
+/// [`Game::LoadingScreen`]: ../../trait.Game.html#associatedtype.LoadingScreen
+#[derive(Debug)]
+pub struct Spinner {
+    _private: (),
+}
+
+impl Spinner {
+    const DOTS: usize = 8;
+    const RADIUS: f32 = 30.0;
+    const DOT_RADIUS: f32 = 6.0;
+    const REVOLUTIONS_PER_SECOND: f32 = 1.0;
+}
+
+impl LoadingScreen for Spinner {
+    fn new(_gpu: &mut graphics::Gpu) -> Result<Self> {
+        Ok(Spinner { _private: () })
+    }
+
+    fn draw(
+        &mut self,
+        _progress: &Progress,
+        frame: &mut graphics::Frame<'_>,
+        timer: &Timer,
+    ) {
+        frame.clear(graphics::Color::BLACK);
+
+        let center =
+            graphics::Point::new(frame.width() / 2.0, frame.height() / 2.0);
+
+        let revolutions =
+            timer.elapsed().as_secs_f32() * Self::REVOLUTIONS_PER_SECOND;
+        let head = (revolutions.fract() * Self::DOTS as f32) as usize;
+
+        let mut mesh = graphics::Mesh::new();
+
+        for i in 0..Self::DOTS {
+            let angle = 2.0 * PI * i as f32 / Self::DOTS as f32;
+            let distance = (head + Self::DOTS - i) % Self::DOTS;
+
+            mesh.fill(
+                graphics::Shape::Circle {
+                    center: center
+                        + graphics::Vector::new(angle.cos(), angle.sin())
+                            * Self::RADIUS,
+                    radius: Self::DOT_RADIUS,
+                },
+                graphics::Color {
+                    a: 1.0 - distance as f32 / Self::DOTS as f32,
+                    ..graphics::Color::WHITE
+                },
+            );
+        }
+
+        mesh.draw(&mut frame.as_target());
+    }
+}
@@ -0,0 +1,243 @@
+use super::{LoadingScreen, Progress};
+use crate::graphics;
+use crate::{Result, Timer};
+
+use std::time::Duration;
+
+/// A configurable loading screen showing a logo, a progress bar, and some
+/// rotating tips.
+///
+/// A [`Splash`] is configured using its builder methods, which means you do
+/// not need to implement the [`LoadingScreen`] trait to customize it.
+///
+/// # Usage
+/// Set [`Splash`] as your [`Game::LoadingScreen`] associated type to use it
+/// with its default configuration.
+///
+/// You can also build a custom [`Splash`] and [`run`] any [`Task`] with it,
+/// which is useful to load new levels of your game:
+///
+/// ```no_run
+/// use coffee::graphics::{Color, Image, Window};
+/// use coffee::load::loading_screen::{LoadingScreen, Splash};
+/// use coffee::load::Task;
+/// use coffee::Result;
+///
+/// # struct Level;
+/// # impl Level {
+/// # fn load() -> Task<Level> { Task::succeed(|| Level) }
+/// # }
+/// #
+/// fn load_level(window: &mut Window, logo: Image) -> Result<Level> {
+///     let mut splash = Splash::new(window.gpu())?
+///         .background(Color::from_rgb(20, 20, 40))
+///         .logo(logo)
+///         .tips(vec![
+///             "Press space to jump",
+///             "Coins can be spent at the shop",
+///         ]);
+///
+///     splash.run(Level::load(), window)
+/// }
+/// ```
+///
+/// [`Splash`]: struct.Splash.html
+/// [`LoadingScreen`]: trait.LoadingScreen.html
+/// [`Game::LoadingScreen`]: ../../trait.Game.html#associatedtype.LoadingScreen
+/// [`run`]: trait.LoadingScreen.html#method.run
+/// [`Task`]: ../struct.Task.html
+#[allow(missing_debug_implementations)]
+pub struct Splash {
+    font: graphics::Font,
+    background: graphics::Color,
+    background_image: Option<graphics::Image>,
+    logo: Option<graphics::Image>,
+    tips: Vec<String>,
+    tip_interval: Duration,
+    bar_color: graphics::Color,
+    text_color: graphics::Color,
+}
+
+impl Splash {
+    const BAR_HEIGHT: f32 = 6.0;
+    const MARGIN: f32 = 50.0;
+
+    /// Sets the background color of the [`Splash`].
+    ///
+    /// By default, it is set to black.
+    ///
+    /// [`Splash`]: struct.Splash.html
+    pub fn background(mut self, color: graphics::Color) -> Self {
+        self.background = color;
+        self
+    }
+
+    /// Sets an [`Image`] that will be stretched to cover the background of the
+    /// [`Splash`].
+    ///
+    /// [`Image`]: ../../graphics/struct.Image.html
+    /// [`Splash`]: struct.Splash.html
+    pub fn background_image(mut self, image: graphics::Image) -> Self {
+        self.background_image = Some(image);
+        self
+    }
+
+    /// Sets the logo [`Image`] shown in the [`Splash`].
+    ///
+    /// The logo is centered and scaled down if it does not fit.
+    ///
+    /// [`Image`]: ../../graphics/struct.Image.html
+    /// [`Splash`]: struct.Splash.html
+    pub fn logo(mut self, image: graphics::Image) -> Self {
+        self.logo = Some(image);
+        self
+    }
+
+    /// Sets the tips that will be shown, one at a time, under the progress
+    /// bar.
+    pub fn tips<I, S>(mut self, tips: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tips = tips.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets how long each tip is shown before rotating to the next one.
+    ///
+    /// By default, it is set to 5 seconds.
+    pub fn tip_interval(mut self, interval: Duration) -> Self {
+        self.tip_interval = interval;
+        self
+    }
+
+    /// Sets the color of the progress bar.
+    ///
+    /// By default, it is set to white.
+    pub fn bar_color(mut self, color: graphics::Color) -> Self {
+        self.bar_color = color;
+        self
+    }
+
+    /// Sets the color of the text.
+    ///
+    /// By default, it is set to white.
+    pub fn text_color(mut self, color: graphics::Color) -> Self {
+        self.text_color = color;
+        self
+    }
+
+    fn current_tip(&self, timer: &Timer) -> Option<&String> {
+        if self.tips.is_empty() {
+            return None;
+        }
+
+        let interval = self.tip_interval.as_secs_f32().max(0.001);
+        let index = (timer.elapsed().as_secs_f32() / interval) as usize;
+
+        self.tips.get(index % self.tips.len())
+    }
+}
+
+impl LoadingScreen for Splash {
+    fn new(gpu: &mut graphics::Gpu) -> Result<Self> {
+        Ok(Self {
+            font: graphics::Font::from_bytes(gpu, graphics::Font::DEFAULT)?,
+            background: graphics::Color::BLACK,
+            background_image: None,
+            logo: None,
+            tips: Vec::new(),
+            tip_interval: Duration::from_secs(5),
+            bar_color: graphics::Color::WHITE,
+            text_color: graphics::Color::WHITE,
+        })
+    }
+
+    fn draw(
+        &mut self,
+        progress: &Progress,
+        frame: &mut graphics::Frame<'_>,
+        timer: &Timer,
+    ) {
+        let width = frame.width();
+        let height = frame.height();
+
+        frame.clear(self.background);
+
+        if let Some(background) = &self.background_image {
+            background.draw(
+                graphics::Quad {
+                    position: graphics::Point::new(0.0, 0.0),
+                    size: (width, height),
+                    ..Default::default()
+                },
+                &mut frame.as_target(),
+            );
+        }
+
+        if let Some(logo) = &self.logo {
+            let logo_width = logo.width() as f32;
+            let logo_height = logo.height() as f32;
+
+            let scale = (width * 0.6 / logo_width)
+                .min(height * 0.4 / logo_height)
+                .min(1.0);
+
+            let size = (logo_width * scale, logo_height * scale);
+
+            logo.draw(
+                graphics::Quad {
+                    position: graphics::Point::new(
+                        (width - size.0) / 2.0,
+                        height * 0.4 - size.1 / 2.0,
+                    ),
+                    size,
+                    ..Default::default()
+                },
+                &mut frame.as_target(),
+            );
+        }
+
+        let bar_y = height - Self::MARGIN * 2.0;
+
+        let mut bar = graphics::Mesh::new();
+
+        bar.fill(
+            graphics::Shape::Rectangle(graphics::Rectangle {
+                x: Self::MARGIN,
+                y: bar_y,
+                width: (width - Self::MARGIN * 2.0)
+                    * (progress.percentage() / 100.0),
+                height: Self::BAR_HEIGHT,
+            }),
+            self.bar_color,
+        );
+
+        bar.draw(&mut frame.as_target());
+
+        if let Some(stage) = progress.stage() {
+            self.font.add(graphics::Text {
+                content: stage,
+                position: graphics::Point::new(Self::MARGIN, bar_y - 40.0),
+                size: 20.0,
+                color: self.text_color,
+                ..graphics::Text::default()
+            });
+        }
+
+        if let Some(tip) = self.current_tip(timer) {
+            self.font.add(graphics::Text {
+                content: tip,
+                position: graphics::Point::new(0.0, bar_y + 30.0),
+                bounds: (width, Self::MARGIN),
+                size: 20.0,
+                color: self.text_color,
+                horizontal_alignment: graphics::HorizontalAlignment::Center,
+                ..graphics::Text::default()
+            });
+        }
+
+        self.font.draw(&mut frame.as_target());
+    }
+}
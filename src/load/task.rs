@@ -1,5 +1,11 @@
 use crate::graphics;
-use crate::Result;
+use crate::{Error, Result};
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
 
 /// A `Task<T>` represents an operation that produces a value of type `T`.
 ///
@@ -70,7 +76,7 @@ use crate::Result;
 /// [`map`]: #method.map
 pub struct Task<T> {
     total_work: u32,
-    function: Box<dyn FnOnce(&mut Worker<'_>) -> Result<T>>,
+    function: Function<T>,
 }
 
 impl<T> Task<T> {
@@ -98,16 +104,13 @@ impl<T> Task<T> {
     where
         F: 'static + FnOnce() -> Result<T>,
     {
-        Task {
-            total_work: 1,
-            function: Box::new(move |worker| {
-                let result = f();
+        Task::sequence(1, move |worker| {
+            let result = f();
 
-                worker.notify_progress(1);
+            worker.notify_progress(1);
 
-                result
-            }),
-        }
+            result
+        })
     }
 
     /// Creates a new [`Task`] from a lazy operation that cannot fail.
@@ -155,7 +158,7 @@ impl<T> Task<T> {
         F: 'static + FnOnce(&mut graphics::Gpu) -> Result<T>,
     {
         Task::sequence(1, move |worker| {
-            let result = f(worker.gpu());
+            let result = worker.gpu().and_then(f);
 
             worker.notify_progress(1);
 
@@ -163,13 +166,133 @@ impl<T> Task<T> {
         })
     }
 
+    /// Creates a new [`Task`] that runs a lazy operation on a background
+    /// thread.
+    ///
+    /// Use this for expensive CPU work that does not need a [`Gpu`], like
+    /// generating a map or decoding big files. While the operation is running,
+    /// the current task runner will keep being notified. This way, loading
+    /// screens stay responsive instead of freezing until the work is done.
+    ///
+    /// ```
+    /// # use coffee::load::Task;
+    /// struct Map {
+    ///     // ...
+    /// }
+    ///
+    /// impl Map {
+    ///     pub fn generate() -> Map {
+    ///         Map { /*...*/ }
+    ///     }
+    /// }
+    ///
+    /// let generate_map = Task::offload(|| Ok(Map::generate()));
+    /// ```
+    ///
+    /// If the operation panics, the panic will be propagated when running the
+    /// [`Task`].
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Gpu`]: ../graphics/struct.Gpu.html
+    pub fn offload<F>(f: F) -> Task<T>
+    where
+        T: 'static + Send,
+        F: 'static + Send + FnOnce() -> Result<T>,
+    {
+        Task::offload_then(f, |_, value| Ok(value))
+    }
+
+    /// Creates a new [`Task`] that runs a lazy operation on a background
+    /// thread and then finishes its work on the main thread using a [`Gpu`].
+    ///
+    /// This is useful to decode assets concurrently before uploading them.
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Gpu`]: ../graphics/struct.Gpu.html
+    pub(crate) fn offload_then<F, G, A>(f: F, g: G) -> Task<T>
+    where
+        T: 'static,
+        A: 'static + Send,
+        F: 'static + Send + FnOnce() -> Result<A>,
+        G: 'static + FnOnce(&mut graphics::Gpu, A) -> Result<T>,
+    {
+        Task {
+            total_work: 1,
+            function: Function::Offloaded(Box::new(move || {
+                let background = Background::spawn(f);
+
+                Started {
+                    ready: vec![background.done.clone()],
+                    finish: Box::new(move |worker: &mut Worker<'_>| {
+                        let result = background
+                            .wait(worker)
+                            .and_then(|value| g(worker.gpu()?, value));
+
+                        worker.notify_progress(1);
+
+                        result
+                    }),
+                }
+            })),
+        }
+    }
+
+    /// Creates a new [`Task`] that runs all the given tasks, starting their
+    /// offloaded work concurrently.
+    ///
+    /// This is useful when the amount of tasks is only known at runtime. The
+    /// results keep the order of the given tasks.
+    ///
+    /// ```
+    /// # use coffee::load::Task;
+    /// # struct Chunk;
+    /// # impl Chunk {
+    /// #     fn generate(_index: usize) -> Chunk { Chunk }
+    /// # }
+    /// let generate_chunks: Task<Vec<Chunk>> = Task::parallel(
+    ///     (0..16)
+    ///         .map(|i| Task::offload(move || Ok(Chunk::generate(i))))
+    ///         .collect(),
+    /// );
+    /// ```
+    ///
+    /// For a fixed amount of tasks of different types, use [`JoinParallel`].
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`JoinParallel`]: trait.JoinParallel.html
+    pub fn parallel(tasks: Vec<Task<T>>) -> Task<Vec<T>>
+    where
+        T: 'static,
+    {
+        Task {
+            total_work: tasks.iter().map(Task::total_work).sum(),
+            function: Function::Offloaded(Box::new(move || {
+                let started: Vec<Started<T>> =
+                    tasks.into_iter().map(Task::start).collect();
+
+                Started {
+                    ready: started
+                        .iter()
+                        .flat_map(|task| task.ready.iter().cloned())
+                        .collect(),
+                    finish: Box::new(move |worker: &mut Worker<'_>| {
+                        started
+                            .into_iter()
+                            .map(|task| task.finish(worker))
+                            .collect()
+                    }),
+                }
+            })),
+        }
+    }
+
     pub(crate) fn sequence<F>(total_work: u32, f: F) -> Task<T>
     where
         F: 'static + FnOnce(&mut Worker<'_>) -> Result<T>,
     {
         Task {
             total_work,
-            function: Box::new(f),
+            function: Function::Local(Box::new(f)),
         }
     }
 
@@ -213,10 +336,61 @@ impl<T> Task<T> {
     {
         let title = title.into();
 
+        let total_work = task.total_work;
+
+        Task {
+            total_work,
+            function: task.function.wrap(move |finish| {
+                Box::new(move |worker: &mut Worker<'_>| {
+                    worker
+                        .with_stage(title.clone(), total_work, finish)
+                        .map_err(|error| Error::Task {
+                            stage: title,
+                            source: Box::new(error),
+                        })
+                })
+            }),
+        }
+    }
+
+    /// Sets the weight of the [`Task`].
+    ///
+    /// Every unit of work of a [`Task`] counts the same when reporting
+    /// [`Progress`]. However, some tasks take much longer than others! The
+    /// weight multiplies the units of work of a [`Task`], allowing you to
+    /// balance the progress of your loading screens.
+    ///
+    /// For instance, let's say that loading a huge texture atlas takes ten
+    /// times longer than loading a small configuration file:
+    ///
+    /// ```
+    /// # use coffee::load::Task;
+    /// # use coffee::graphics::Image;
+    /// # struct Configuration;
+    /// # impl Configuration {
+    /// # fn load() -> Task<Configuration> { Task::succeed(|| Configuration) }
+    /// # }
+    /// use coffee::load::Join;
+    ///
+    /// let load_game = (
+    ///     Image::load("atlas.png").weight(10),
+    ///     Configuration::load(),
+    /// )
+    ///     .join();
+    /// ```
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Progress`]: struct.Progress.html
+    pub fn weight(self, weight: u32) -> Task<T>
+    where
+        T: 'static,
+    {
         Task {
-            total_work: task.total_work,
-            function: Box::new(move |worker| {
-                worker.with_stage(title.clone(), task.function)
+            total_work: self.total_work * weight,
+            function: self.function.wrap(move |finish| {
+                Box::new(move |worker: &mut Worker<'_>| {
+                    worker.with_weight(weight, finish)
+                })
             }),
         }
     }
@@ -242,20 +416,97 @@ impl<T> Task<T> {
     {
         Task {
             total_work: self.total_work,
-            function: Box::new(move |worker| match (self.function)(worker) {
-                Ok(value) => Ok(f(value)),
-                Err(error) => Err(error),
+            function: self.function.wrap(move |finish| {
+                Box::new(move |worker: &mut Worker<'_>| match finish(worker) {
+                    Ok(value) => Ok(f(value)),
+                    Err(error) => Err(error),
+                })
             }),
         }
     }
 
+    /// Chains a [`Task`] that depends on the output of this one.
+    ///
+    /// This allows you to express multi-step loading pipelines. For instance,
+    /// we could load a manifest and then load the images it lists:
+    ///
+    /// ```
+    /// # use coffee::load::Task;
+    /// # use coffee::graphics::Image;
+    /// # struct Manifest { images: Vec<String> }
+    /// # impl Manifest {
+    /// # fn load() -> Task<Manifest> {
+    /// #     Task::succeed(|| Manifest { images: Vec::new() })
+    /// # }
+    /// # }
+    /// use coffee::load::Join;
+    ///
+    /// let load_images = Manifest::load().and_then(|manifest| {
+    ///     manifest.images.into_iter().fold(
+    ///         Task::succeed(Vec::new),
+    ///         |images, path| {
+    ///             (images, Image::load(path)).join().map(|(mut images, image)| {
+    ///                 images.push(image);
+    ///                 images
+    ///             })
+    ///         },
+    ///     )
+    /// });
+    /// ```
+    ///
+    /// The work of the chained [`Task`] cannot be known until this one
+    /// finishes. Until then, it counts as a single unit of work. Once the
+    /// chained [`Task`] is produced, its work is added to the total work of the
+    /// [`Progress`].
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Progress`]: struct.Progress.html
+    pub fn and_then<F, A>(self, f: F) -> Task<A>
+    where
+        T: 'static,
+        F: 'static + FnOnce(T) -> Task<A>,
+    {
+        Task::sequence(self.total_work + 1, move |worker| {
+            let value = self.function.call(worker)?;
+            let task = f(value);
+
+            worker.extend_work(task.total_work);
+
+            let result = task.function.call(worker)?;
+
+            worker.notify_progress(1);
+
+            Ok(result)
+        })
+    }
+
     /// Runs a [`Task`] and obtains the produced value.
     ///
     /// [`Task`]: struct.Task.html
     pub fn run(self, gpu: &mut graphics::Gpu) -> Result<T> {
-        let mut worker = Worker::Headless(gpu);
+        let mut worker = Worker::Headless(Some(gpu));
 
-        (self.function)(&mut worker)
+        self.function.call(&mut worker)
+    }
+
+    /// Runs a [`Task`] without a [`Gpu`].
+    ///
+    /// Any work that needs a [`Gpu`] fails with [`Error::GpuUnavailable`].
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Gpu`]: ../graphics/struct.Gpu.html
+    /// [`Error::GpuUnavailable`]: ../enum.Error.html#variant.GpuUnavailable
+    pub(crate) fn run_headless(self) -> Result<T> {
+        let mut worker = Worker::Headless(None);
+
+        self.function.call(&mut worker)
+    }
+
+    /// Starts the background work of a [`Task`], if any, without finishing it.
+    ///
+    /// [`Task`]: struct.Task.html
+    pub(crate) fn start(self) -> Started<T> {
+        self.function.start()
     }
 
     /// Runs a [`Task`] and obtains the produced value.
@@ -282,11 +533,12 @@ impl<T> Task<T> {
                 work_completed: 0,
                 stages: Vec::new(),
             },
+            weight: 1,
         };
 
         worker.notify_progress(0);
 
-        (self.function)(&mut worker)
+        self.function.call(&mut worker)
     }
 }
 
@@ -296,20 +548,162 @@ impl<T> std::fmt::Debug for Task<T> {
     }
 }
 
+type Local<T> = Box<dyn FnOnce(&mut Worker<'_>) -> Result<T>>;
+
+enum Function<T> {
+    Local(Local<T>),
+    Offloaded(Box<dyn FnOnce() -> Started<T>>),
+}
+
+impl<T> Function<T> {
+    fn start(self) -> Started<T> {
+        match self {
+            Function::Local(finish) => Started {
+                ready: Vec::new(),
+                finish,
+            },
+            Function::Offloaded(spawn) => spawn(),
+        }
+    }
+
+    fn call(self, worker: &mut Worker<'_>) -> Result<T> {
+        self.start().finish(worker)
+    }
+
+    /// Wraps the work that finishes on the main thread, keeping any offloaded
+    /// work in the background.
+    fn wrap<A, F>(self, f: F) -> Function<A>
+    where
+        T: 'static,
+        F: 'static + FnOnce(Local<T>) -> Local<A>,
+    {
+        match self {
+            Function::Local(finish) => Function::Local(f(finish)),
+            Function::Offloaded(spawn) => {
+                Function::Offloaded(Box::new(move || {
+                    let started = spawn();
+
+                    Started {
+                        ready: started.ready,
+                        finish: f(started.finish),
+                    }
+                }))
+            }
+        }
+    }
+}
+
+/// A task whose background work, if any, has already been started.
+pub(crate) struct Started<T> {
+    ready: Vec<Arc<AtomicBool>>,
+    finish: Local<T>,
+}
+
+impl<T> Started<T> {
+    /// Returns true if finishing the task will not wait for background work.
+    pub fn is_ready(&self) -> bool {
+        self.ready.iter().all(|done| done.load(Ordering::Acquire))
+    }
+
+    pub fn finish(self, worker: &mut Worker<'_>) -> Result<T> {
+        (self.finish)(worker)
+    }
+
+    fn join<B: 'static>(self, other: Started<B>) -> Started<(T, B)>
+    where
+        T: 'static,
+    {
+        let mut ready = self.ready;
+        ready.extend(other.ready);
+
+        let (a, b) = (self.finish, other.finish);
+
+        Started {
+            ready,
+            finish: Box::new(move |worker: &mut Worker<'_>| {
+                a(worker).and_then(|a| b(worker).map(|b| (a, b)))
+            }),
+        }
+    }
+}
+
+struct Background<T> {
+    receiver: mpsc::Receiver<thread::Result<Result<T>>>,
+    done: Arc<AtomicBool>,
+}
+
+impl<T: 'static + Send> Background<T> {
+    fn spawn<F>(f: F) -> Background<T>
+    where
+        F: 'static + Send + FnOnce() -> Result<T>,
+    {
+        let (sender, receiver) = mpsc::channel();
+        let done = Arc::new(AtomicBool::new(false));
+        let notify = done.clone();
+
+        let work = move || {
+            let _ = sender.send(panic::catch_unwind(AssertUnwindSafe(f)));
+
+            notify.store(true, Ordering::Release);
+        };
+
+        // There are no threads in the browser, so we do the work right away
+        if cfg!(target_arch = "wasm32") {
+            work();
+        } else {
+            let waiter = thread::current();
+
+            rayon::spawn(move || {
+                work();
+                waiter.unpark();
+            });
+        }
+
+        Background { receiver, done }
+    }
+}
+
+impl<T> Background<T> {
+    const REFRESH_INTERVAL: Duration = Duration::from_millis(16);
+
+    fn wait(self, worker: &mut Worker<'_>) -> Result<T> {
+        loop {
+            // We cannot block in the browser, but there the work is already
+            // done by the time we get here
+            match self.receiver.try_recv() {
+                Ok(Ok(result)) => return result,
+                Ok(Err(panic)) => panic::resume_unwind(panic),
+                Err(mpsc::TryRecvError::Empty) => {
+                    // Keep the task runner responsive while we wait
+                    worker.notify_progress(0);
+
+                    // The background thread unparks us once it is done
+                    thread::park_timeout(Self::REFRESH_INTERVAL);
+                }
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    unreachable!("Background work finished without a result")
+                }
+            }
+        }
+    }
+}
+
 pub(crate) enum Worker<'a> {
-    Headless(&'a mut graphics::Gpu),
+    Headless(Option<&'a mut graphics::Gpu>),
     Windowed {
         window: &'a mut graphics::Window,
         listener: &'a mut dyn FnMut(&Progress, &mut graphics::Window) -> (),
         progress: Progress,
+        weight: u32,
     },
 }
 
 impl<'a> Worker<'a> {
-    pub fn gpu(&mut self) -> &mut graphics::Gpu {
+    pub fn gpu(&mut self) -> Result<&mut graphics::Gpu> {
         match self {
-            Worker::Headless(gpu) => gpu,
-            Worker::Windowed { window, .. } => window.gpu(),
+            Worker::Headless(Some(gpu)) => Ok(gpu),
+            Worker::Headless(None) => Err(Error::GpuUnavailable),
+            Worker::Windowed { window, .. } => Ok(window.gpu()),
         }
     }
 
@@ -320,25 +714,79 @@ impl<'a> Worker<'a> {
                 progress,
                 window,
                 listener,
-                ..
+                weight,
             } => {
+                let work = work * *weight;
+
                 progress.work_completed += work;
 
+                for stage in progress.stages.iter_mut() {
+                    stage.work_completed += work;
+                }
+
                 listener(&progress, window);
             }
         };
     }
 
+    pub fn extend_work(&mut self, work: u32) {
+        if let Worker::Windowed {
+            progress, weight, ..
+        } = self
+        {
+            let work = work * *weight;
+
+            progress.total_work += work;
+
+            for stage in progress.stages.iter_mut() {
+                stage.total_work += work;
+            }
+        }
+    }
+
+    pub fn with_weight<T, F>(&mut self, weight: u32, f: F) -> T
+    where
+        F: FnOnce(&mut Worker<'_>) -> T,
+    {
+        let previous = match self {
+            Worker::Headless(_) => return f(self),
+            Worker::Windowed {
+                weight: current, ..
+            } => {
+                let previous = *current;
+                *current *= weight;
+
+                previous
+            }
+        };
+
+        let result = f(self);
+
+        if let Worker::Windowed { weight, .. } = self {
+            *weight = previous;
+        }
+
+        result
+    }
+
     pub fn with_stage<T>(
         &mut self,
         title: String,
+        total_work: u32,
         f: Box<dyn FnOnce(&mut Worker<'_>) -> T>,
     ) -> T {
         match self {
             Worker::Headless(_) => f(self),
             Worker::Windowed { .. } => {
-                if let Worker::Windowed { progress, .. } = self {
-                    progress.stages.push(title);
+                if let Worker::Windowed {
+                    progress, weight, ..
+                } = self
+                {
+                    progress.stages.push(Stage {
+                        title,
+                        total_work: total_work * *weight,
+                        work_completed: 0,
+                    });
                 }
 
                 self.notify_progress(0);
@@ -362,7 +810,7 @@ impl<'a> Worker<'a> {
 pub struct Progress {
     total_work: u32,
     work_completed: u32,
-    stages: Vec<String>,
+    stages: Vec<Stage>,
 }
 
 impl Progress {
@@ -397,7 +845,61 @@ impl Progress {
     ///
     /// [`Task::state`]: struct.Task.html#method.stage
     pub fn stage(&self) -> Option<&String> {
-        self.stages.last()
+        self.stages.last().map(|stage| &stage.title)
+    }
+
+    /// Returns all the nested stages that are currently running, from the
+    /// outermost to the innermost one.
+    ///
+    /// You can use this to show the progress of each [`Task::stage`]
+    /// separately.
+    ///
+    /// [`Task::stage`]: struct.Task.html#method.stage
+    pub fn stages(&self) -> &[Stage] {
+        &self.stages
+    }
+}
+
+/// A running [`Task::stage`] of some [`Progress`].
+///
+/// [`Task::stage`]: struct.Task.html#method.stage
+/// [`Progress`]: struct.Progress.html
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Stage {
+    title: String,
+    total_work: u32,
+    work_completed: u32,
+}
+
+impl Stage {
+    /// Returns the title of the [`Stage`].
+    ///
+    /// [`Stage`]: struct.Stage.html
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the total amount of work of the [`Stage`].
+    ///
+    /// [`Stage`]: struct.Stage.html
+    pub fn total_work(&self) -> u32 {
+        self.total_work
+    }
+
+    /// Returns the amount of completed work of the [`Stage`].
+    ///
+    /// The returned value is guaranteed to be in [0, total_work].
+    ///
+    /// [`Stage`]: struct.Stage.html
+    pub fn completed_work(&self) -> u32 {
+        self.work_completed.min(self.total_work)
+    }
+
+    /// Returns the amount of progress of the [`Stage`] as a percentage.
+    ///
+    /// [`Stage`]: struct.Stage.html
+    pub fn percentage(&self) -> f32 {
+        self.completed_work() as f32 / self.total_work.max(1) as f32 * 100.0
     }
 }
 
@@ -425,8 +927,10 @@ impl<A: 'static, B: 'static> Join for (Task<A>, Task<B>) {
         Task::sequence(
             loader_a.total_work() + loader_b.total_work(),
             move |task| {
-                (loader_a.function)(task)
-                    .and_then(|a| (loader_b.function)(task).map(|b| (a, b)))
+                loader_a
+                    .function
+                    .call(task)
+                    .and_then(|a| loader_b.function.call(task).map(|b| (a, b)))
             },
         )
     }
@@ -583,3 +1087,342 @@ impl<
             .map(|((a, b, c, d, e, f, g), h)| (a, b, c, d, e, f, g, h))
     }
 }
+
+/// Join multiple tasks, running the offloaded ones concurrently.
+///
+/// Tasks that do not need a [`Gpu`], like the ones created with
+/// [`Task::offload`], are started right away on a thread pool. The rest of the
+/// tasks run on the main thread, in order, while the offloaded work makes
+/// progress in the background.
+///
+/// For instance, the images of a [`JoinParallel`] are decoded concurrently,
+/// and only their upload to the [`Gpu`] happens sequentially:
+///
+/// ```
+/// # use coffee::load::Task;
+/// # use coffee::graphics::Image;
+/// #
+/// use coffee::load::JoinParallel;
+///
+/// let load_images = (
+///     Image::load("player/idle.png"),
+///     Image::load("player/running.png"),
+///     Image::load("player/jumping.png"),
+/// )
+///     .join_parallel();
+/// ```
+///
+/// Keep in mind that [`Task::map`] and [`Task::stage`] run on the main
+/// thread. Therefore, use them _after_ joining your tasks to keep the
+/// benefits of parallelism.
+///
+/// [`Gpu`]: ../graphics/struct.Gpu.html
+/// [`Task::offload`]: struct.Task.html#method.offload
+/// [`Task::map`]: struct.Task.html#method.map
+/// [`Task::stage`]: struct.Task.html#method.stage
+/// [`JoinParallel`]: trait.JoinParallel.html
+pub trait JoinParallel {
+    /// The resulting output of the [`Task`] after joining.
+    ///
+    /// [`Task`]: struct.Task.html#composition
+    type Type;
+
+    /// Joins tasks into a new one that collects the results, running the
+    /// offloaded tasks concurrently.
+    fn join_parallel(self) -> Task<Self::Type>;
+}
+
+impl<A: 'static, B: 'static> JoinParallel for (Task<A>, Task<B>) {
+    type Type = (A, B);
+
+    fn join_parallel(self) -> Task<(A, B)> {
+        let (loader_a, loader_b) = self;
+
+        Task {
+            total_work: loader_a.total_work() + loader_b.total_work(),
+            function: Function::Offloaded(Box::new(move || {
+                let a = loader_a.function.start();
+                let b = loader_b.function.start();
+
+                a.join(b)
+            })),
+        }
+    }
+}
+
+impl<A: 'static, B: 'static, C: 'static> JoinParallel
+    for (Task<A>, Task<B>, Task<C>)
+{
+    type Type = (A, B, C);
+
+    fn join_parallel(self) -> Task<(A, B, C)> {
+        let (loader_a, loader_b, loader_c) = self;
+
+        ((loader_a, loader_b).join_parallel(), loader_c)
+            .join_parallel()
+            .map(|((a, b), c)| (a, b, c))
+    }
+}
+
+impl<A: 'static, B: 'static, C: 'static, D: 'static> JoinParallel
+    for (Task<A>, Task<B>, Task<C>, Task<D>)
+{
+    type Type = (A, B, C, D);
+
+    fn join_parallel(self) -> Task<(A, B, C, D)> {
+        let (loader_a, loader_b, loader_c, loader_d) = self;
+
+        ((loader_a, loader_b, loader_c).join_parallel(), loader_d)
+            .join_parallel()
+            .map(|((a, b, c), d)| (a, b, c, d))
+    }
+}
+
+impl<A: 'static, B: 'static, C: 'static, D: 'static, E: 'static> JoinParallel
+    for (Task<A>, Task<B>, Task<C>, Task<D>, Task<E>)
+{
+    type Type = (A, B, C, D, E);
+
+    fn join_parallel(self) -> Task<(A, B, C, D, E)> {
+        let (loader_a, loader_b, loader_c, loader_d, loader_e) = self;
+
+        (
+            (loader_a, loader_b, loader_c, loader_d).join_parallel(),
+            loader_e,
+        )
+            .join_parallel()
+            .map(|((a, b, c, d), e)| (a, b, c, d, e))
+    }
+}
+
+impl<
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        D: 'static,
+        E: 'static,
+        F: 'static,
+    > JoinParallel for (Task<A>, Task<B>, Task<C>, Task<D>, Task<E>, Task<F>)
+{
+    type Type = (A, B, C, D, E, F);
+
+    fn join_parallel(self) -> Task<(A, B, C, D, E, F)> {
+        let (loader_a, loader_b, loader_c, loader_d, loader_e, loader_f) = self;
+
+        (
+            (loader_a, loader_b, loader_c, loader_d, loader_e).join_parallel(),
+            loader_f,
+        )
+            .join_parallel()
+            .map(|((a, b, c, d, e), f)| (a, b, c, d, e, f))
+    }
+}
+
+impl<
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        D: 'static,
+        E: 'static,
+        F: 'static,
+        G: 'static,
+    > JoinParallel
+    for (
+        Task<A>,
+        Task<B>,
+        Task<C>,
+        Task<D>,
+        Task<E>,
+        Task<F>,
+        Task<G>,
+    )
+{
+    type Type = (A, B, C, D, E, F, G);
+
+    fn join_parallel(self) -> Task<(A, B, C, D, E, F, G)> {
+        let (
+            loader_a,
+            loader_b,
+            loader_c,
+            loader_d,
+            loader_e,
+            loader_f,
+            loader_g,
+        ) = self;
+
+        (
+            (loader_a, loader_b, loader_c, loader_d, loader_e, loader_f)
+                .join_parallel(),
+            loader_g,
+        )
+            .join_parallel()
+            .map(|((a, b, c, d, e, f), g)| (a, b, c, d, e, f, g))
+    }
+}
+
+impl<
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        D: 'static,
+        E: 'static,
+        F: 'static,
+        G: 'static,
+        H: 'static,
+    > JoinParallel
+    for (
+        Task<A>,
+        Task<B>,
+        Task<C>,
+        Task<D>,
+        Task<E>,
+        Task<F>,
+        Task<G>,
+        Task<H>,
+    )
+{
+    type Type = (A, B, C, D, E, F, G, H);
+
+    fn join_parallel(self) -> Task<(A, B, C, D, E, F, G, H)> {
+        let (
+            loader_a,
+            loader_b,
+            loader_c,
+            loader_d,
+            loader_e,
+            loader_f,
+            loader_g,
+            loader_h,
+        ) = self;
+
+        (
+            (
+                loader_a, loader_b, loader_c, loader_d, loader_e, loader_f,
+                loader_g,
+            )
+                .join_parallel(),
+            loader_h,
+        )
+            .join_parallel()
+            .map(|((a, b, c, d, e, f, g), h)| (a, b, c, d, e, f, g, h))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::{Window, WindowSettings};
+
+    type Snapshot = (u32, u32, Vec<(String, u32, u32)>);
+
+    fn run_with_progress<T>(task: Task<T>) -> (T, Vec<Snapshot>) {
+        let mut window = Window::headless(&WindowSettings::default());
+
+        let mut snapshots = Vec::new();
+
+        let result = task
+            .run_with_window(&mut window, |progress, _| {
+                snapshots.push((
+                    progress.completed_work(),
+                    progress.total_work(),
+                    progress
+                        .stages()
+                        .iter()
+                        .map(|stage| {
+                            (
+                                String::from(stage.title()),
+                                stage.completed_work(),
+                                stage.total_work(),
+                            )
+                        })
+                        .collect(),
+                ))
+            })
+            .expect("Run task");
+
+        (result, snapshots)
+    }
+
+    #[test]
+    fn total_work_is_weighted() {
+        assert_eq!(Task::succeed(|| ()).weight(3).total_work(), 3);
+        assert_eq!(Task::succeed(|| ()).weight(2).weight(5).total_work(), 10);
+
+        let joined = (Task::succeed(|| ()).weight(4), Task::succeed(|| ()))
+            .join()
+            .map(|_| ());
+
+        assert_eq!(joined.total_work(), 5);
+        assert_eq!(Task::stage("Stage", joined).total_work(), 5);
+    }
+
+    #[test]
+    fn progress_is_weighted() {
+        let task = (Task::succeed(|| 1).weight(3), Task::succeed(|| 2)).join();
+
+        let (result, snapshots) = run_with_progress(task);
+        let completed: Vec<u32> = snapshots
+            .iter()
+            .map(|(completed, _, _)| *completed)
+            .collect();
+
+        assert_eq!(result, (1, 2));
+        assert_eq!(completed, vec![0, 3, 4]);
+        assert!(snapshots.iter().all(|(_, total, _)| *total == 4));
+    }
+
+    #[test]
+    fn progress_aggregates_nested_stages() {
+        let task = Task::stage(
+            "Outer",
+            (
+                Task::stage("Inner", Task::succeed(|| ()).weight(2)),
+                Task::succeed(|| ()),
+            )
+                .join(),
+        );
+
+        let (_, snapshots) = run_with_progress(task);
+
+        let outer = |completed| (String::from("Outer"), completed, 3);
+        let inner = |completed| (String::from("Inner"), completed, 2);
+
+        assert_eq!(
+            snapshots,
+            vec![
+                (0, 3, vec![]),
+                (0, 3, vec![outer(0)]),
+                (0, 3, vec![outer(0), inner(0)]),
+                (2, 3, vec![outer(2), inner(2)]),
+                (3, 3, vec![outer(3)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn parallel_keeps_order() {
+        let tasks = (0..8)
+            .map(|i| {
+                if i % 2 == 0 {
+                    Task::offload(move || Ok(i))
+                } else {
+                    Task::succeed(move || i)
+                }
+            })
+            .collect();
+
+        let result = Task::parallel(tasks).run_headless().expect("Run task");
+
+        assert_eq!(result, (0..8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn headless_tasks_have_no_gpu() {
+        let result = Task::using_gpu(|_| Ok(())).run_headless();
+
+        assert!(match result {
+            Err(Error::GpuUnavailable) => true,
+            _ => false,
+        });
+    }
+}
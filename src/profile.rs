@@ -0,0 +1,82 @@
+//! Mark parts of your game to analyze them with a profiler.
+//!
+//! The [`scope!`] macro opens a profiling span that lasts until the end of the
+//! enclosing block. Coffee already marks the update, draw, and UI stages of
+//! every frame, so you only need to add spans to the code you want to inspect
+//! further:
+//!
+//! ```
+//! use coffee::profile;
+//!
+//! fn find_path() {
+//!     profile::scope!("find_path");
+//!
+//!     // ...
+//! }
+//! ```
+//!
+//! By default, spans do nothing and have no cost. Enable the `tracy` or
+//! `puffin` features to forward them to [Tracy] or [puffin], respectively,
+//! and get a flame graph of your frames.
+//!
+//! [`scope!`]: macro.scope.html
+//! [Tracy]: https://github.com/wolfpld/tracy
+//! [puffin]: https://github.com/EmbarkStudios/puffin
+
+/// Opens a profiling span with the given name until the end of the current
+/// block.
+///
+/// The name must be a `&'static str`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __profile_scope {
+    ($name:expr) => {
+        let _profile_scope =
+            $crate::profile::Scope::new($name, file!(), line!());
+    };
+}
+
+#[doc(inline)]
+pub use crate::__profile_scope as scope;
+
+/// An open profiling span.
+///
+/// It is closed when dropped. You will normally want to use the [`scope!`]
+/// macro instead of creating it directly.
+///
+/// [`scope!`]: macro.scope.html
+#[allow(missing_debug_implementations)]
+pub struct Scope {
+    #[cfg(feature = "tracy")]
+    _tracy: tracy_client::Span,
+
+    #[cfg(feature = "puffin")]
+    _puffin: Option<puffin::ProfilerScope>,
+}
+
+impl Scope {
+    /// Opens a new profiling span with the given name and location.
+    #[allow(unused_variables)]
+    pub fn new(name: &'static str, file: &'static str, line: u32) -> Scope {
+        Scope {
+            #[cfg(feature = "tracy")]
+            _tracy: tracy_client::Span::new(name, name, file, line, 0),
+
+            #[cfg(feature = "puffin")]
+            _puffin: if puffin::are_scopes_on() {
+                Some(puffin::ProfilerScope::new(name, file, ""))
+            } else {
+                None
+            },
+        }
+    }
+}
+
+// Marks the end of a frame, so profilers can group spans per frame
+pub(crate) fn finish_frame() {
+    #[cfg(feature = "tracy")]
+    tracy_client::finish_continuous_frame!();
+
+    #[cfg(feature = "puffin")]
+    puffin::GlobalProfiler::lock().new_frame();
+}
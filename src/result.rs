@@ -1,6 +1,7 @@
 use std::error;
 use std::fmt;
 use std::io;
+use std::path::PathBuf;
 
 use crate::graphics::texture_array;
 
@@ -14,10 +15,35 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// They are mostly errors that happen during the initialization stage of your
 /// game.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// The window creation failed.
     WindowCreation(String),
 
+    /// The window surface failed to present a frame.
+    Surface(String),
+
+    /// The window surface was lost and could not be recreated.
+    SurfaceLost,
+
+    /// No graphics adapter compatible with the enabled graphics backend was
+    /// found.
+    NoAdapterFound,
+
+    /// A shader failed to compile.
+    ShaderCompilation(String),
+
+    /// The uniforms of a [`Shader`] were declared or set incorrectly.
+    ///
+    /// [`Shader`]: graphics/struct.Shader.html
+    Uniform(String),
+
+    /// A [`Gpu`] was needed while running a [`Headless`] game.
+    ///
+    /// [`Gpu`]: graphics/struct.Gpu.html
+    /// [`Headless`]: struct.Headless.html
+    GpuUnavailable,
+
     /// A texture array failed to load.
     TextureArray(texture_array::Error),
 
@@ -26,6 +52,59 @@ pub enum Error {
 
     /// An image failed to load.
     Image(image::ImageError),
+
+    /// Some audio failed to decode.
+    Audio(String),
+
+    /// An SVG file failed to parse.
+    Svg(String),
+
+    /// The clipboard could not be read or written.
+    Clipboard(String),
+
+    /// A [`Task::stage`] failed.
+    ///
+    /// [`Task::stage`]: load/struct.Task.html#method.stage
+    Task {
+        /// The title of the stage
+        stage: String,
+
+        /// The error that caused the stage to fail
+        source: Box<Error>,
+    },
+
+    /// Some data failed to deserialize.
+    Deserialization(Box<dyn error::Error + Send + Sync>),
+
+    /// An asset failed to load.
+    Asset {
+        /// The path of the asset
+        path: PathBuf,
+
+        /// The error that caused the asset to fail loading
+        source: Box<Error>,
+    },
+
+    /// Your game failed at runtime.
+    ///
+    /// You can stop your game with an error using [`Game::take_error`].
+    ///
+    /// [`Game::take_error`]: trait.Game.html#method.take_error
+    Runtime(Box<dyn error::Error + Send + Sync>),
+
+    /// A replayed [`Recording`] diverged from the original session.
+    ///
+    /// [`Recording`]: input/struct.Recording.html
+    Desync {
+        /// The tick where the checksums stopped matching
+        tick: u64,
+
+        /// The checksum of the original session
+        expected: u64,
+
+        /// The checksum of the replay
+        found: u64,
+    },
 }
 
 impl fmt::Display for Error {
@@ -34,11 +113,47 @@ impl fmt::Display for Error {
             Error::WindowCreation(error) => {
                 write!(f, "Window creation error: {}", error)
             }
+            Error::Surface(error) => write!(f, "Surface error: {}", error),
+            Error::SurfaceLost => write!(f, "The window surface was lost"),
+            Error::NoAdapterFound => write!(
+                f,
+                "No compatible graphics adapter was found, \
+                 make sure your graphics drivers are up to date"
+            ),
+            Error::ShaderCompilation(error) => {
+                write!(f, "Shader compilation error: {}", error)
+            }
+            Error::Uniform(error) => write!(f, "Uniform error: {}", error),
+            Error::GpuUnavailable => {
+                write!(f, "No GPU is available in a headless game")
+            }
             Error::TextureArray(error) => {
                 write!(f, "Texture array error: {}", error)
             }
             Error::IO(error) => write!(f, "IO error: {}", error),
             Error::Image(error) => write!(f, "Image error: {}", error),
+            Error::Audio(error) => write!(f, "Audio error: {}", error),
+            Error::Svg(error) => write!(f, "SVG error: {}", error),
+            Error::Clipboard(error) => write!(f, "Clipboard error: {}", error),
+            Error::Task { stage, source } => {
+                write!(f, "Stage \"{}\" failed: {}", stage, source)
+            }
+            Error::Deserialization(error) => {
+                write!(f, "Deserialization error: {}", error)
+            }
+            Error::Asset { path, source } => {
+                write!(f, "Asset {} failed to load: {}", path.display(), source)
+            }
+            Error::Runtime(error) => write!(f, "Runtime error: {}", error),
+            Error::Desync {
+                tick,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Desync at tick {}: expected checksum {:x}, found {:x}",
+                tick, expected, found
+            ),
         }
     }
 }
@@ -48,6 +163,10 @@ impl error::Error for Error {
         match self {
             Error::IO(error) => Some(error),
             Error::Image(error) => Some(error),
+            Error::Deserialization(error) => Some(error.as_ref()),
+            Error::Runtime(error) => Some(error.as_ref()),
+            Error::Task { source, .. } => Some(source.as_ref()),
+            Error::Asset { source, .. } => Some(source.as_ref()),
             _ => None,
         }
     }
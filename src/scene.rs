@@ -0,0 +1,450 @@
+//! Split your game into scenes, like menus, levels, or pause screens.
+//!
+//! A [`Scene`] is like a small [`Game`]. It draws itself and reacts to input
+//! and updates. However, instead of managing the flow of your whole game, it
+//! returns a [`Transition`] to tell what should happen next.
+//!
+//! A [`SceneStack`] keeps track of the active scenes and implements [`Game`].
+//! Only the scene on top of the stack is updated and receives input. This
+//! way, flows like _menu → gameplay → pause_ can be expressed by pushing and
+//! popping scenes.
+//!
+//! # Example
+//! ```no_run
+//! use coffee::graphics::{Color, Frame, Window, WindowSettings};
+//! use coffee::input::{keyboard, KeyboardAndMouse};
+//! use coffee::load::{loading_screen::ProgressBar, Task};
+//! use coffee::scene::{Initial, Scene, SceneStack, Transition};
+//! use coffee::{Game, Result, Timer};
+//!
+//! struct Menu;
+//! struct Gameplay;
+//!
+//! impl Scene for Menu {
+//!     type Input = KeyboardAndMouse;
+//!
+//!     fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
+//!         frame.clear(Color::BLACK);
+//!     }
+//!
+//!     fn interact(
+//!         &mut self,
+//!         input: &mut KeyboardAndMouse,
+//!         _window: &mut Window,
+//!     ) -> Transition<KeyboardAndMouse> {
+//!         if input.keyboard().is_key_pressed(keyboard::KeyCode::Return) {
+//!             Transition::push(Gameplay)
+//!         } else {
+//!             Transition::None
+//!         }
+//!     }
+//! }
+//!
+//! impl Initial for Menu {
+//!     type LoadingScreen = ProgressBar;
+//!
+//!     fn load(_window: &Window) -> Task<Menu> {
+//!         Task::succeed(|| Menu)
+//!     }
+//! }
+//!
+//! impl Scene for Gameplay {
+//!     type Input = KeyboardAndMouse;
+//!
+//!     fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
+//!         frame.clear(Color::WHITE);
+//!     }
+//!
+//!     fn interact(
+//!         &mut self,
+//!         input: &mut KeyboardAndMouse,
+//!         _window: &mut Window,
+//!     ) -> Transition<KeyboardAndMouse> {
+//!         if input.keyboard().is_key_pressed(keyboard::KeyCode::Escape) {
+//!             Transition::Pop
+//!         } else {
+//!             Transition::None
+//!         }
+//!     }
+//! }
+//!
+//! fn main() -> Result<()> {
+//!     SceneStack::<Menu>::run(WindowSettings {
+//!         title: String::from("Scenes - Coffee"),
+//!         ..Default::default()
+//!     })
+//! }
+//! ```
+//!
+//! [`Scene`]: trait.Scene.html
+//! [`Game`]: ../trait.Game.html
+//! [`Transition`]: enum.Transition.html
+//! [`SceneStack`]: struct.SceneStack.html
+use crate::graphics::{CursorIcon, Frame, Window};
+use crate::input::{keyboard, Input};
+use crate::load::{LoadingScreen, Task};
+use crate::{Background, CrashHandler, DebugSettings, Game, Result, Timer};
+
+use std::marker::PhantomData;
+
+/// A part of your game, like a menu or a level.
+pub trait Scene {
+    /// The input data of the [`Scene`].
+    ///
+    /// All the scenes in a [`SceneStack`] share the same input type.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    /// [`SceneStack`]: struct.SceneStack.html
+    type Input: Input;
+
+    /// Draws the [`Scene`].
+    ///
+    /// [`Scene`]: trait.Scene.html
+    fn draw(&mut self, frame: &mut Frame<'_>, timer: &Timer);
+
+    /// Consumes [`Input`] to let users interact with the [`Scene`], returning
+    /// the [`Transition`] that should happen next.
+    ///
+    /// It is only called when the [`Scene`] is on top of the stack. It works
+    /// exactly like [`Game::interact`].
+    ///
+    /// By default, it does nothing and returns [`Transition::None`].
+    ///
+    /// [`Input`]: #associatedtype.Input
+    /// [`Scene`]: trait.Scene.html
+    /// [`Transition`]: enum.Transition.html
+    /// [`Game::interact`]: ../trait.Game.html#method.interact
+    /// [`Transition::None`]: enum.Transition.html#variant.None
+    fn interact(
+        &mut self,
+        _input: &mut Self::Input,
+        _window: &mut Window,
+    ) -> Transition<Self::Input> {
+        Transition::None
+    }
+
+    /// Updates the [`Scene`], returning the [`Transition`] that should happen
+    /// next.
+    ///
+    /// It is only called when the [`Scene`] is on top of the stack. It works
+    /// exactly like [`Game::update`].
+    ///
+    /// By default, it does nothing and returns [`Transition::None`].
+    ///
+    /// [`Scene`]: trait.Scene.html
+    /// [`Transition`]: enum.Transition.html
+    /// [`Game::update`]: ../trait.Game.html#method.update
+    /// [`Transition::None`]: enum.Transition.html#variant.None
+    fn update(&mut self, _window: &Window) -> Transition<Self::Input> {
+        Transition::None
+    }
+
+    /// Returns whether the [`Scene`] is an overlay.
+    ///
+    /// The scenes below an overlay keep being drawn, which is useful for pause
+    /// menus or dialogs. However, they are not updated.
+    ///
+    /// By default, it returns false.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    fn is_overlay(&self) -> bool {
+        false
+    }
+
+    /// Defines the cursor icon of the window while the [`Scene`] is on top of
+    /// the stack.
+    ///
+    /// By default, it returns platform-dependent default cursor.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    fn cursor_icon(&self) -> CursorIcon {
+        CursorIcon::Default
+    }
+
+    /// Handles a close request from the operating system while the [`Scene`]
+    /// is on top of the stack.
+    ///
+    /// It works exactly like [`Game::on_close_request`].
+    ///
+    /// [`Scene`]: trait.Scene.html
+    /// [`Game::on_close_request`]: ../trait.Game.html#method.on_close_request
+    fn on_close_request(&mut self) -> bool {
+        true
+    }
+
+    /// Handles a reset of the graphics device.
+    ///
+    /// It is called for every [`Scene`] in the stack, from the bottom to the
+    /// top. It works exactly like [`Game::on_device_reset`].
+    ///
+    /// [`Scene`]: trait.Scene.html
+    /// [`Game::on_device_reset`]: ../trait.Game.html#method.on_device_reset
+    fn on_device_reset(&mut self, _window: &mut Window) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// The first [`Scene`] of a [`SceneStack`].
+///
+/// It describes how to load the [`Scene`] and configures the [`Game`]
+/// implementation of the [`SceneStack`].
+///
+/// [`Scene`]: trait.Scene.html
+/// [`SceneStack`]: struct.SceneStack.html
+/// [`Game`]: ../trait.Game.html
+pub trait Initial: Scene + Sized {
+    /// The loading screen that will be used when your game starts.
+    ///
+    /// It works exactly like [`Game::LoadingScreen`].
+    ///
+    /// [`Game::LoadingScreen`]: ../trait.Game.html#associatedtype.LoadingScreen
+    type LoadingScreen: LoadingScreen;
+
+    /// Defines how many times the scenes should be updated per second.
+    ///
+    /// By default, it is set to `60`.
+    const TICKS_PER_SECOND: u16 = 60;
+
+    /// Defines the maximum amount of frames that will be drawn per second.
+    ///
+    /// By default, it is set to `None`, which means there is no limit.
+    const MAX_FRAME_RATE: Option<u16> = None;
+
+    /// Defines the behavior of the scenes while the window is not focused.
+    ///
+    /// By default, it is set to [`Background::Continue`].
+    ///
+    /// [`Background::Continue`]: ../enum.Background.html#variant.Continue
+    const BACKGROUND_BEHAVIOR: Background = Background::Continue;
+
+    /// Defines the behavior of the audio while the window is not focused or is
+    /// minimized.
+    ///
+    /// It is only available when the `audio` feature is enabled.
+    ///
+    /// By default, it is set to [`audio::Background::Continue`].
+    ///
+    /// [`audio::Background::Continue`]: ../audio/enum.Background.html#variant.Continue
+    #[cfg(feature = "audio")]
+    const BACKGROUND_AUDIO: crate::audio::Background =
+        crate::audio::Background::Continue;
+
+    /// Defines the key that will be used to toggle the debug view.
+    ///
+    /// By default, it is set to `F12`.
+    const DEBUG_KEY: Option<keyboard::KeyCode> = Some(keyboard::KeyCode::F12);
+
+    /// Defines the initial settings of the debug view.
+    ///
+    /// By default, it is set to [`DebugSettings::DEFAULT`].
+    ///
+    /// [`DebugSettings::DEFAULT`]: ../struct.DebugSettings.html#associatedconstant.DEFAULT
+    const DEBUG_SETTINGS: DebugSettings = DebugSettings::DEFAULT;
+
+    /// Defines the crash handler of the scenes.
+    ///
+    /// It works exactly like [`Game::CRASH_HANDLER`].
+    ///
+    /// [`Game::CRASH_HANDLER`]: ../trait.Game.html#associatedconstant.CRASH_HANDLER
+    const CRASH_HANDLER: Option<CrashHandler> = None;
+
+    /// Loads the [`Scene`].
+    ///
+    /// [`Scene`]: trait.Scene.html
+    fn load(window: &Window) -> Task<Self>;
+}
+
+/// What should happen after a [`Scene`] interacts or updates.
+///
+/// [`Scene`]: trait.Scene.html
+pub enum Transition<I> {
+    /// Nothing happens.
+    None,
+
+    /// A new [`Scene`] is pushed on top of the current one.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    Push(Box<dyn Scene<Input = I>>),
+
+    /// The current [`Scene`] is removed, resuming the one below it.
+    ///
+    /// If there are no scenes left, the game finishes.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    Pop,
+
+    /// The current [`Scene`] is replaced by a new one.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    Replace(Box<dyn Scene<Input = I>>),
+
+    /// All the scenes are removed and the game finishes.
+    Exit,
+}
+
+impl<I> Transition<I> {
+    /// Creates a [`Transition::Push`] with the given [`Scene`].
+    ///
+    /// [`Transition::Push`]: enum.Transition.html#variant.Push
+    /// [`Scene`]: trait.Scene.html
+    pub fn push<S>(scene: S) -> Transition<I>
+    where
+        S: 'static + Scene<Input = I>,
+    {
+        Transition::Push(Box::new(scene))
+    }
+
+    /// Creates a [`Transition::Replace`] with the given [`Scene`].
+    ///
+    /// [`Transition::Replace`]: enum.Transition.html#variant.Replace
+    /// [`Scene`]: trait.Scene.html
+    pub fn replace<S>(scene: S) -> Transition<I>
+    where
+        S: 'static + Scene<Input = I>,
+    {
+        Transition::Replace(Box::new(scene))
+    }
+}
+
+impl<I> std::fmt::Debug for Transition<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Transition::None => write!(f, "Transition::None"),
+            Transition::Push(_) => write!(f, "Transition::Push"),
+            Transition::Pop => write!(f, "Transition::Pop"),
+            Transition::Replace(_) => write!(f, "Transition::Replace"),
+            Transition::Exit => write!(f, "Transition::Exit"),
+        }
+    }
+}
+
+/// A stack of scenes that implements [`Game`].
+///
+/// It starts with the [`Initial`] scene `S`.
+///
+/// [`Game`]: ../trait.Game.html
+/// [`Initial`]: trait.Initial.html
+pub struct SceneStack<S: Initial> {
+    scenes: Vec<Box<dyn Scene<Input = S::Input>>>,
+    initial: PhantomData<S>,
+}
+
+impl<S: 'static + Initial> SceneStack<S> {
+    /// Creates a new [`SceneStack`] with the given initial [`Scene`].
+    ///
+    /// [`SceneStack`]: struct.SceneStack.html
+    /// [`Scene`]: trait.Scene.html
+    pub fn new(scene: S) -> SceneStack<S> {
+        SceneStack {
+            scenes: vec![Box::new(scene)],
+            initial: PhantomData,
+        }
+    }
+
+    /// Returns the amount of scenes in the [`SceneStack`].
+    ///
+    /// [`SceneStack`]: struct.SceneStack.html
+    pub fn len(&self) -> usize {
+        self.scenes.len()
+    }
+
+    /// Returns true if there are no scenes left in the [`SceneStack`].
+    ///
+    /// [`SceneStack`]: struct.SceneStack.html
+    pub fn is_empty(&self) -> bool {
+        self.scenes.is_empty()
+    }
+
+    fn apply(&mut self, transition: Transition<S::Input>) {
+        match transition {
+            Transition::None => {}
+            Transition::Push(scene) => self.scenes.push(scene),
+            Transition::Pop => {
+                let _ = self.scenes.pop();
+            }
+            Transition::Replace(scene) => {
+                let _ = self.scenes.pop();
+                self.scenes.push(scene);
+            }
+            Transition::Exit => self.scenes.clear(),
+        }
+    }
+}
+
+impl<S: Initial> std::fmt::Debug for SceneStack<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SceneStack {{ scenes: {} }}", self.scenes.len())
+    }
+}
+
+impl<S: 'static + Initial> Game for SceneStack<S> {
+    type Input = S::Input;
+    type LoadingScreen = S::LoadingScreen;
+
+    const TICKS_PER_SECOND: u16 = S::TICKS_PER_SECOND;
+    const MAX_FRAME_RATE: Option<u16> = S::MAX_FRAME_RATE;
+    const BACKGROUND_BEHAVIOR: Background = S::BACKGROUND_BEHAVIOR;
+    #[cfg(feature = "audio")]
+    const BACKGROUND_AUDIO: crate::audio::Background = S::BACKGROUND_AUDIO;
+    const DEBUG_KEY: Option<keyboard::KeyCode> = S::DEBUG_KEY;
+    const DEBUG_SETTINGS: DebugSettings = S::DEBUG_SETTINGS;
+    const CRASH_HANDLER: Option<CrashHandler> = S::CRASH_HANDLER;
+
+    fn load(window: &Window) -> Task<SceneStack<S>> {
+        S::load(window).map(SceneStack::new)
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>, timer: &Timer) {
+        let first = self
+            .scenes
+            .iter()
+            .rposition(|scene| !scene.is_overlay())
+            .unwrap_or(0);
+
+        for scene in self.scenes[first..].iter_mut() {
+            scene.draw(frame, timer);
+        }
+    }
+
+    fn interact(&mut self, input: &mut S::Input, window: &mut Window) {
+        if let Some(scene) = self.scenes.last_mut() {
+            let transition = scene.interact(input, window);
+
+            self.apply(transition);
+        }
+    }
+
+    fn update(&mut self, window: &Window) {
+        if let Some(scene) = self.scenes.last_mut() {
+            let transition = scene.update(window);
+
+            self.apply(transition);
+        }
+    }
+
+    fn cursor_icon(&self) -> CursorIcon {
+        self.scenes
+            .last()
+            .map(|scene| scene.cursor_icon())
+            .unwrap_or(CursorIcon::Default)
+    }
+
+    fn on_close_request(&mut self) -> bool {
+        self.scenes
+            .last_mut()
+            .map(|scene| scene.on_close_request())
+            .unwrap_or(true)
+    }
+
+    fn on_device_reset(&mut self, window: &mut Window) -> Result<()> {
+        for scene in self.scenes.iter_mut() {
+            scene.on_device_reset(window)?;
+        }
+
+        Ok(())
+    }
+
+    fn is_finished(&self) -> bool {
+        self.scenes.is_empty()
+    }
+}
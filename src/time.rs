@@ -0,0 +1,12 @@
+//! Schedule things to happen later in your game.
+//!
+//! A [`Scheduler`] keeps track of delayed and repeating messages measured in
+//! ticks or seconds. Advance it in [`Game::update`] and handle the messages it
+//! produces, like you would do with the messages of a [`UserInterface`].
+//!
+//! [`Scheduler`]: struct.Scheduler.html
+//! [`Game::update`]: ../trait.Game.html#method.update
+//! [`UserInterface`]: ../ui/trait.UserInterface.html
+mod scheduler;
+
+pub use scheduler::{Handle, Scheduler};
@@ -0,0 +1,313 @@
+use std::time::Duration;
+
+/// A queue of delayed and repeating messages.
+///
+/// A [`Scheduler`] is advanced one tick at a time using [`tick`], which you
+/// should call once in [`Game::update`]. Because of this, durations are
+/// converted to ticks using the tick rate of your game, which keeps scheduled
+/// events in sync with the fixed timestep.
+///
+/// # Example
+/// ```
+/// use coffee::time::Scheduler;
+/// use std::time::Duration;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Event {
+///     SpawnWave,
+///     Flash,
+/// }
+///
+/// let mut scheduler = Scheduler::new(60);
+///
+/// let _ = scheduler.after(Duration::from_secs(5), Event::SpawnWave);
+/// let _ = scheduler.every_ticks(30, Event::Flash);
+///
+/// for _ in 0..29 {
+///     assert!(scheduler.tick().is_empty());
+/// }
+///
+/// assert_eq!(scheduler.tick(), vec![Event::Flash]);
+/// ```
+///
+/// [`Scheduler`]: struct.Scheduler.html
+/// [`tick`]: #method.tick
+/// [`Game::update`]: ../trait.Game.html#method.update
+pub struct Scheduler<T> {
+    ticks_per_second: u16,
+    now: u64,
+    next_id: u64,
+    entries: Vec<Entry<T>>,
+}
+
+struct Entry<T> {
+    id: u64,
+    due: u64,
+    payload: Payload<T>,
+}
+
+enum Payload<T> {
+    Once(T),
+    Repeat {
+        interval: u64,
+        message: Box<dyn FnMut() -> T>,
+    },
+}
+
+/// A handle to a message in a [`Scheduler`].
+///
+/// You can use it to [`cancel`] the message.
+///
+/// [`Scheduler`]: struct.Scheduler.html
+/// [`cancel`]: struct.Scheduler.html#method.cancel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Handle(u64);
+
+impl<T> Scheduler<T> {
+    /// Creates a new [`Scheduler`] that will be ticked the given amount of
+    /// times per second.
+    ///
+    /// You will normally want to use [`Game::TICKS_PER_SECOND`] here.
+    ///
+    /// [`Scheduler`]: struct.Scheduler.html
+    /// [`Game::TICKS_PER_SECOND`]: ../trait.Game.html#associatedconstant.TICKS_PER_SECOND
+    pub fn new(ticks_per_second: u16) -> Scheduler<T> {
+        Scheduler {
+            ticks_per_second,
+            now: 0,
+            next_id: 0,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Schedules a message to be produced after the given amount of ticks.
+    ///
+    /// A delay of `0` ticks is treated as `1`, as messages are only produced
+    /// by [`tick`].
+    ///
+    /// [`tick`]: #method.tick
+    pub fn after_ticks(&mut self, ticks: u64, message: T) -> Handle {
+        self.schedule(ticks, Payload::Once(message))
+    }
+
+    /// Schedules a message to be produced after the given [`Duration`].
+    ///
+    /// The [`Duration`] is rounded to the nearest amount of ticks.
+    ///
+    /// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    pub fn after(&mut self, duration: Duration, message: T) -> Handle {
+        let ticks = self.to_ticks(duration);
+
+        self.after_ticks(ticks, message)
+    }
+
+    /// Schedules a message to be produced every given amount of ticks, until
+    /// it is cancelled.
+    pub fn every_ticks(&mut self, ticks: u64, message: T) -> Handle
+    where
+        T: 'static + Clone,
+    {
+        let interval = ticks.max(1);
+
+        self.schedule(
+            interval,
+            Payload::Repeat {
+                interval,
+                message: Box::new(move || message.clone()),
+            },
+        )
+    }
+
+    /// Schedules a message to be produced every given [`Duration`], until it
+    /// is cancelled.
+    ///
+    /// The [`Duration`] is rounded to the nearest amount of ticks.
+    ///
+    /// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    pub fn every(&mut self, duration: Duration, message: T) -> Handle
+    where
+        T: 'static + Clone,
+    {
+        let ticks = self.to_ticks(duration);
+
+        self.every_ticks(ticks, message)
+    }
+
+    /// Cancels a scheduled message.
+    ///
+    /// It returns `true` if the message was still scheduled.
+    pub fn cancel(&mut self, handle: Handle) -> bool {
+        let length = self.entries.len();
+
+        self.entries.retain(|entry| entry.id != handle.0);
+
+        self.entries.len() != length
+    }
+
+    /// Returns whether the message of the given [`Handle`] is still
+    /// scheduled.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn is_scheduled(&self, handle: Handle) -> bool {
+        self.entries.iter().any(|entry| entry.id == handle.0)
+    }
+
+    /// Advances the [`Scheduler`] one tick and returns the messages that are
+    /// due, in the order they were scheduled to happen.
+    ///
+    /// [`Scheduler`]: struct.Scheduler.html
+    pub fn tick(&mut self) -> Vec<T> {
+        self.now += 1;
+
+        let now = self.now;
+        let mut due: Vec<(u64, u64, T)> = Vec::new();
+
+        for entry in self.entries.iter_mut().filter(|entry| entry.due <= now) {
+            match &mut entry.payload {
+                Payload::Once(_) => {}
+                Payload::Repeat { interval, message } => {
+                    due.push((entry.due, entry.id, message()));
+
+                    entry.due += *interval;
+                }
+            }
+        }
+
+        let mut i = 0;
+
+        while i < self.entries.len() {
+            let is_due = self.entries[i].due <= now;
+
+            match self.entries[i].payload {
+                Payload::Once(_) if is_due => {
+                    let entry = self.entries.swap_remove(i);
+
+                    if let Payload::Once(message) = entry.payload {
+                        due.push((entry.due, entry.id, message));
+                    }
+                }
+                _ => {
+                    i += 1;
+                }
+            }
+        }
+
+        due.sort_by_key(|(due, id, _)| (*due, *id));
+        due.into_iter().map(|(_, _, message)| message).collect()
+    }
+
+    /// Returns the amount of ticks the [`Scheduler`] has been advanced.
+    ///
+    /// [`Scheduler`]: struct.Scheduler.html
+    pub fn now(&self) -> u64 {
+        self.now
+    }
+
+    /// Returns the amount of scheduled messages.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no scheduled messages.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Cancels all the scheduled messages.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn schedule(&mut self, ticks: u64, payload: Payload<T>) -> Handle {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.entries.push(Entry {
+            id,
+            due: self.now + ticks.max(1),
+            payload,
+        });
+
+        Handle(id)
+    }
+
+    fn to_ticks(&self, duration: Duration) -> u64 {
+        (duration.as_secs_f64() * f64::from(self.ticks_per_second)).round()
+            as u64
+    }
+}
+
+impl<T> std::fmt::Debug for Scheduler<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Scheduler")
+            .field("ticks_per_second", &self.ticks_per_second)
+            .field("now", &self.now)
+            .field("scheduled", &self.entries.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn advance(
+        scheduler: &mut Scheduler<&'static str>,
+        ticks: u64,
+    ) -> Vec<(u64, &'static str)> {
+        (0..ticks)
+            .flat_map(|_| {
+                let messages = scheduler.tick();
+                let now = scheduler.now();
+
+                messages.into_iter().map(move |message| (now, message))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn produces_messages_in_order() {
+        let mut scheduler = Scheduler::new(60);
+
+        let _ = scheduler.after_ticks(3, "c");
+        let _ = scheduler.after_ticks(1, "a");
+        let _ = scheduler.after_ticks(3, "d");
+        let _ = scheduler.after_ticks(0, "b");
+
+        assert_eq!(
+            advance(&mut scheduler, 5),
+            vec![(1, "a"), (1, "b"), (3, "c"), (3, "d")]
+        );
+        assert!(scheduler.is_empty());
+    }
+
+    #[test]
+    fn converts_durations_to_ticks() {
+        let mut scheduler = Scheduler::new(10);
+
+        let _ = scheduler.after(Duration::from_millis(240), "a");
+        let _ = scheduler.after(Duration::from_millis(260), "b");
+
+        assert_eq!(advance(&mut scheduler, 3), vec![(2, "a"), (3, "b")]);
+    }
+
+    #[test]
+    fn repeats_until_cancelled() {
+        let mut scheduler = Scheduler::new(60);
+
+        let flash = scheduler.every_ticks(2, "flash");
+        let _ = scheduler.after_ticks(4, "spawn");
+
+        assert_eq!(
+            advance(&mut scheduler, 4),
+            vec![(2, "flash"), (4, "flash"), (4, "spawn")]
+        );
+
+        assert!(scheduler.is_scheduled(flash));
+        assert!(scheduler.cancel(flash));
+        assert!(!scheduler.cancel(flash));
+
+        assert!(advance(&mut scheduler, 4).is_empty());
+        assert!(scheduler.is_empty());
+    }
+}
@@ -1,5 +1,9 @@
 use std::time;
 
+use instant::Instant;
+
+use crate::anim::Interpolate;
+
 /// The timer of your game state.
 ///
 /// A [`Timer`] is updated once per frame, and it ticks [`Game::TICKS_PER_SECOND`]
@@ -10,47 +14,202 @@ use std::time;
 #[derive(Debug)]
 pub struct Timer {
     target_ticks: u16,
-    target_delta: time::Duration,
-    last_tick: time::Instant,
+    target_delta: Option<time::Duration>,
+    last_tick: Instant,
     accumulated_delta: time::Duration,
     has_ticked: bool,
+    ticks: u64,
+    started_at: Instant,
+    delta: time::Duration,
+    speed: f32,
 }
 
 impl Timer {
+    /// The maximum speed of a [`Timer`].
+    ///
+    /// Speeds set with [`set_speed`] are clamped to it.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`set_speed`]: #method.set_speed
+    pub const MAX_SPEED: f32 = 64.0;
+
     pub(crate) fn new(ticks_per_second: u16) -> Timer {
-        let (target_seconds, target_nanos) = match ticks_per_second {
-            0 => (std::u64::MAX, 0),
-            1 => (1, 0),
-            _ => (0, ((1.0 / ticks_per_second as f64) * 1e9) as u32),
-        };
+        let now = Instant::now();
 
         Timer {
             target_ticks: ticks_per_second,
-            target_delta: time::Duration::new(target_seconds, target_nanos),
-            last_tick: time::Instant::now(),
+            target_delta: target_delta(ticks_per_second),
+            last_tick: now,
             accumulated_delta: time::Duration::from_secs(0),
             has_ticked: false,
+            ticks: 0,
+            started_at: now,
+            delta: time::Duration::from_secs(0),
+            speed: 1.0,
         }
     }
 
     pub(crate) fn update(&mut self) {
-        let now = time::Instant::now();
-        let diff = now - self.last_tick;
+        let now = Instant::now();
+        let diff = if self.speed == 1.0 {
+            now - self.last_tick
+        } else {
+            (now - self.last_tick).mul_f64(f64::from(self.speed))
+        };
 
         self.last_tick = now;
         self.accumulated_delta += diff;
         self.has_ticked = false;
+        self.delta = diff;
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.last_tick = Instant::now();
+        self.accumulated_delta = time::Duration::from_secs(0);
+        self.has_ticked = false;
+        self.delta = time::Duration::from_secs(0);
+    }
+
+    pub(crate) fn tick_variable(&mut self) -> bool {
+        self.accumulated_delta = time::Duration::from_secs(0);
+        self.has_ticked = true;
+        self.ticks += 1;
+
+        true
     }
 
     pub(crate) fn tick(&mut self) -> bool {
-        if self.accumulated_delta >= self.target_delta {
-            self.accumulated_delta -= self.target_delta;
-            self.has_ticked = true;
+        match self.target_delta {
+            Some(target_delta) if self.accumulated_delta >= target_delta => {
+                self.accumulated_delta -= target_delta;
+                self.has_ticked = true;
+                self.ticks += 1;
 
-            true
-        } else {
-            false
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // Drops the whole ticks lagging behind more than the given duration
+    pub(crate) fn drop_lag(&mut self, max_lag: time::Duration) -> u64 {
+        let target_delta = match self.target_delta {
+            Some(target_delta) => target_delta,
+            None => return 0,
+        };
+
+        if self.accumulated_delta <= max_lag {
+            return 0;
         }
+
+        let excess = (self.accumulated_delta - max_lag).as_nanos();
+        let target = target_delta.as_nanos().max(1);
+        let dropped =
+            ((excess + target - 1) / target).min(u128::from(u32::MAX)) as u32;
+
+        self.accumulated_delta = self
+            .accumulated_delta
+            .checked_sub(target_delta * dropped)
+            .unwrap_or_default();
+
+        u64::from(dropped)
+    }
+
+    /// Sets the speed of the [`Timer`].
+    ///
+    /// The time measured by the [`Timer`] is multiplied by the given speed,
+    /// which affects how often it ticks and its [`delta`]. For instance, `0.0`
+    /// pauses the game, `0.25` runs it in slow motion, and `4.0` fast
+    /// forwards it.
+    ///
+    /// Negative speeds are treated as `0.0`, speeds over [`MAX_SPEED`] are
+    /// clamped to it, and `NaN` is ignored.
+    ///
+    /// It is `1.0` by default. When the [`debug`] view is shown, it can also be
+    /// changed using the [`Game::TIME_SCALE_KEYS`].
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`delta`]: #method.delta
+    /// [`MAX_SPEED`]: #associatedconstant.MAX_SPEED
+    /// [`debug`]: trait.Game.html#method.debug
+    /// [`Game::TIME_SCALE_KEYS`]: trait.Game.html#associatedconstant.TIME_SCALE_KEYS
+    pub fn set_speed(&mut self, speed: f32) {
+        if speed.is_nan() {
+            return;
+        }
+
+        self.speed = speed.max(0.0).min(Self::MAX_SPEED);
+    }
+
+    /// Returns the current speed of the [`Timer`].
+    ///
+    /// [`Timer`]: struct.Timer.html
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Changes the amount of times the [`Timer`] ticks every second.
+    ///
+    /// This allows changing the update rate of your game at runtime. It is
+    /// useful to implement slow-motion effects, fast-forward, or to pause your
+    /// game by using `0`. The change takes effect on the next tick.
+    ///
+    /// Your game can request a new tick rate from [`Game::interact`] using
+    /// [`Window::set_ticks_per_second`].
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`Game::interact`]: trait.Game.html#method.interact
+    /// [`Window::set_ticks_per_second`]: graphics/struct.Window.html#method.set_ticks_per_second
+    pub fn set_ticks_per_second(&mut self, ticks_per_second: u16) {
+        if self.target_ticks == ticks_per_second {
+            return;
+        }
+
+        self.target_ticks = ticks_per_second;
+        self.target_delta = target_delta(ticks_per_second);
+
+        // Avoid a burst of ticks when the tick rate increases
+        if let Some(target_delta) = self.target_delta {
+            if self.accumulated_delta > target_delta {
+                self.accumulated_delta = target_delta;
+            }
+        }
+    }
+
+    /// Returns the amount of times the [`Timer`] ticks every second.
+    ///
+    /// It is [`Game::TICKS_PER_SECOND`] by default, and it can be changed
+    /// using [`set_ticks_per_second`].
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`Game::TICKS_PER_SECOND`]: trait.Game.html#associatedconstant.TICKS_PER_SECOND
+    /// [`set_ticks_per_second`]: #method.set_ticks_per_second
+    pub fn ticks_per_second(&self) -> u16 {
+        self.target_ticks
+    }
+
+    /// Returns the duration of a tick of the [`Timer`], the inverse of
+    /// [`ticks_per_second`].
+    ///
+    /// It is `None` when the [`Timer`] is paused by setting its
+    /// [`ticks_per_second`] to `0`.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`ticks_per_second`]: #method.ticks_per_second
+    pub fn tick_duration(&self) -> Option<time::Duration> {
+        self.target_delta
+    }
+
+    /// Returns the amount of times the [`Timer`] has ticked since it was
+    /// created.
+    ///
+    /// It matches the amount of times your game has been updated. Lockstep
+    /// and networked games can use it to reason about simulation time in
+    /// whole ticks.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    pub fn ticks_elapsed(&self) -> u64 {
+        self.ticks
     }
 
     /// Returns `true` if the [`Timer`] has ticked since its last update.
@@ -81,4 +240,160 @@ impl Timer {
             * (delta.as_secs() as f32
                 + (delta.subsec_micros() as f32 / 1_000_000.0))
     }
+
+    /// Interpolates between the values of the previous and the current tick,
+    /// using [`next_tick_proximity`].
+    ///
+    /// Keep the previous value of anything that moves in your game state and
+    /// use this in [`Game::draw`] to render it smoothly, even with a low tick
+    /// rate:
+    ///
+    /// ```
+    /// use coffee::graphics::Point;
+    /// use coffee::Timer;
+    ///
+    /// struct Player {
+    ///     previous_position: Point,
+    ///     position: Point,
+    /// }
+    ///
+    /// impl Player {
+    ///     fn draw_position(&self, timer: &Timer) -> Point {
+    ///         timer.interpolate(&self.previous_position, &self.position)
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// It works with any type implementing [`Interpolate`], like [`Point`],
+    /// [`Vector`], [`Color`], or [`Transformation`].
+    ///
+    /// [`next_tick_proximity`]: #method.next_tick_proximity
+    /// [`Game::draw`]: trait.Game.html#tymethod.draw
+    /// [`Interpolate`]: anim/trait.Interpolate.html
+    /// [`Point`]: graphics/type.Point.html
+    /// [`Vector`]: graphics/type.Vector.html
+    /// [`Color`]: graphics/struct.Color.html
+    /// [`Transformation`]: graphics/struct.Transformation.html
+    pub fn interpolate<T: Interpolate>(&self, previous: &T, current: &T) -> T {
+        let proximity = self.next_tick_proximity().max(0.0).min(1.0);
+
+        previous.interpolate(current, proximity)
+    }
+
+    /// Returns the time elapsed between the last two updates of the [`Timer`].
+    ///
+    /// In other words, this is the duration of the last frame. You can use it
+    /// to animate things that do not depend on your game state.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    pub fn delta(&self) -> time::Duration {
+        self.delta
+    }
+
+    /// Returns the time elapsed since the [`Timer`] was created until its last
+    /// update.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    pub fn elapsed(&self) -> time::Duration {
+        self.last_tick - self.started_at
+    }
+}
+
+fn target_delta(ticks_per_second: u16) -> Option<time::Duration> {
+    match ticks_per_second {
+        0 => None,
+        1 => Some(time::Duration::new(1, 0)),
+        _ => Some(time::Duration::new(
+            0,
+            ((1.0 / ticks_per_second as f64) * 1e9) as u32,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lagging(ticks: u32) -> Timer {
+        let mut timer = Timer::new(50);
+        timer.accumulated_delta = tick_duration(&timer) * ticks;
+
+        timer
+    }
+
+    fn tick_duration(timer: &Timer) -> time::Duration {
+        timer.tick_duration().expect("Timer is not paused")
+    }
+
+    fn ticks(timer: &mut Timer) -> u32 {
+        let mut ticks = 0;
+
+        while timer.tick() {
+            ticks += 1;
+        }
+
+        ticks
+    }
+
+    #[test]
+    fn catches_up_with_all_the_lag() {
+        let mut timer = lagging(7);
+
+        assert_eq!(ticks(&mut timer), 7);
+        assert_eq!(timer.ticks_elapsed(), 7);
+        assert!(timer.has_ticked());
+    }
+
+    #[test]
+    fn drops_the_lag_over_the_limit() {
+        let mut timer = lagging(10);
+        let max_lag = tick_duration(&timer) * 3;
+
+        assert_eq!(timer.drop_lag(max_lag), 7);
+        assert_eq!(ticks(&mut timer), 3);
+        assert_eq!(timer.drop_lag(max_lag), 0);
+    }
+
+    #[test]
+    fn keeps_the_lag_under_the_limit() {
+        let mut timer = lagging(2);
+        let max_lag = tick_duration(&timer) * 3;
+
+        assert_eq!(timer.drop_lag(max_lag), 0);
+        assert_eq!(ticks(&mut timer), 2);
+    }
+
+    #[test]
+    fn avoids_bursts_when_the_tick_rate_increases() {
+        let mut timer = lagging(1);
+
+        timer.set_ticks_per_second(100);
+
+        assert_eq!(ticks(&mut timer), 1);
+    }
+
+    #[test]
+    fn has_no_tick_duration_while_paused() {
+        let mut timer = lagging(3);
+
+        timer.set_ticks_per_second(0);
+
+        assert_eq!(timer.tick_duration(), None);
+        assert_eq!(ticks(&mut timer), 0);
+        assert_eq!(timer.drop_lag(time::Duration::from_secs(0)), 0);
+    }
+
+    #[test]
+    fn clamps_the_speed() {
+        let mut timer = Timer::new(50);
+
+        timer.set_speed(-1.0);
+        assert_eq!(timer.speed(), 0.0);
+
+        timer.set_speed(std::f32::INFINITY);
+        assert_eq!(timer.speed(), Timer::MAX_SPEED);
+
+        timer.set_speed(std::f32::NAN);
+        assert_eq!(timer.speed(), Timer::MAX_SPEED);
+    }
 }
@@ -139,14 +139,21 @@
 //! [`core`]: core/index.html
 pub mod core;
 mod renderer;
+mod shortcut;
+mod virtual_cursor;
 pub mod widget;
 
 #[doc(no_inline)]
-pub use self::core::{Align, Justify};
-pub use renderer::{Configuration, Renderer};
+pub use self::core::{Align, Direction, Justify};
+pub use renderer::{
+    ButtonSlices, Configuration, NineSlice, Renderer, Slice, SliderSlices,
+    Theme, ToggleSlices,
+};
+pub use shortcut::Shortcut;
+pub use virtual_cursor::VirtualCursor;
 pub use widget::{
-    button, image, progress_bar, slider, Button, Checkbox, Image, ProgressBar,
-    Radio, Slider, Text,
+    button, image, progress_bar, slider, toasts, Button, Checkbox, Image,
+    ProgressBar, Radio, Slider, Text,
 };
 
 /// A [`Column`] using the built-in [`Renderer`].
@@ -167,18 +174,33 @@ pub type Row<'a, Message> = widget::Row<'a, Message, Renderer>;
 /// [`Renderer`]: struct.Renderer.html
 pub type Panel<'a, Message> = widget::Panel<'a, Message, Renderer>;
 
+/// A [`Toasts`] widget using the built-in [`Renderer`].
+///
+/// [`Toasts`]: widget/toasts/struct.Toasts.html
+/// [`Renderer`]: struct.Renderer.html
+pub type Toasts<'a, Message> = widget::Toasts<'a, Message, Renderer>;
+
 /// An [`Element`] using the built-in [`Renderer`].
 ///
 /// [`Element`]: core/struct.Element.html
 /// [`Renderer`]: struct.Renderer.html
 pub type Element<'a, Message> = self::core::Element<'a, Message, Renderer>;
 
+/// A [`Layer`] using the built-in [`Renderer`].
+///
+/// [`Layer`]: core/struct.Layer.html
+/// [`Renderer`]: struct.Renderer.html
+pub type Layer<'a, Message> = self::core::Layer<'a, Message, Renderer>;
+
 use crate::game::{self, Loop as _};
-use crate::graphics::{Point, Window, WindowSettings};
-use crate::input::{self, mouse, Input as _};
+use crate::graphics::{Frame, Point, Rectangle, Window, WindowSettings};
+use crate::input::keyboard::{self, KeyCode};
+use crate::input::{self, gamepad, mouse, ButtonState, Input as _};
 use crate::load::Task;
+use crate::profile;
 use crate::ui::core::{Event, Interface, MouseCursor, Renderer as _};
-use crate::{Debug, Game, Result};
+use crate::{Debug, Game, Result, Timer};
+use std::collections::HashSet;
 use std::convert::TryInto;
 
 /// The user interface of your game.
@@ -252,6 +274,122 @@ pub trait UserInterface: Game {
         window: &Window,
     ) -> self::core::Element<'_, Self::Message, Self::Renderer>;
 
+    /// The [`VirtualCursor`] of the user interface, if any.
+    ///
+    /// Set it to let players use the user interface with a gamepad, by moving
+    /// a cursor with a stick.
+    ///
+    /// By default, it is `None`.
+    ///
+    /// [`VirtualCursor`]: struct.VirtualCursor.html
+    const VIRTUAL_CURSOR: Option<VirtualCursor> = None;
+
+    /// Draws on top of the game, right before the user interface.
+    ///
+    /// This is useful to draw markers or overlays that should stay below the
+    /// user interface. Anything drawn in [`Game::draw`] is below it, including
+    /// any post-processing of the game.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Game::draw`]: ../trait.Game.html#tymethod.draw
+    fn before_draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) {}
+
+    /// Draws on top of the user interface.
+    ///
+    /// Anything drawn here is only below the debug view of [`Game::debug`].
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Game::debug`]: ../trait.Game.html#method.debug
+    fn after_draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) {}
+
+    /// Produces the layers of the user interface, from bottom to top.
+    ///
+    /// Every [`Layer`] is laid out and cached independently, and they are
+    /// drawn in order. This is useful to keep a pause menu or a dialog
+    /// separate from the HUD of your game.
+    ///
+    /// By default, it returns a single [`Layer`] with the result of
+    /// [`layout`].
+    ///
+    /// [`Layer`]: core/struct.Layer.html
+    /// [`layout`]: #tymethod.layout
+    fn layers(
+        &mut self,
+        window: &Window,
+    ) -> Vec<self::core::Layer<'_, Self::Message, Self::Renderer>> {
+        vec![self::core::Layer::new(self.layout(window))]
+    }
+
+    /// Produces the keyboard shortcuts of the user interface.
+    ///
+    /// The key presses of the shortcuts are consumed before reaching
+    /// [`Game::interact`], and their messages are passed to [`react`]. If
+    /// multiple shortcuts share the same keys, only the first one is used and
+    /// a warning is logged.
+    ///
+    /// Shortcuts do not trigger while the keyboard is captured, as explained
+    /// in [`captures_keyboard`], so typing in a text input never triggers
+    /// them.
+    ///
+    /// Shortcuts are queried every frame, so you can enable them depending on
+    /// your state, like closing a menu with `Escape` only when it is open.
+    ///
+    /// By default, it returns no shortcuts.
+    ///
+    /// [`Game::interact`]: ../trait.Game.html#method.interact
+    /// [`react`]: #tymethod.react
+    /// [`captures_keyboard`]: #method.captures_keyboard
+    fn shortcuts(&self) -> Vec<Shortcut<Self::Message>> {
+        Vec::new()
+    }
+
+    /// Returns whether the user interface takes the keyboard for itself.
+    ///
+    /// While the keyboard is taken, key presses and text stop reaching
+    /// [`Game::interact`]. This is useful to type in a chat without
+    /// controlling the game. The keyboard is also taken when any widget
+    /// [`captures_keyboard`], like a focused text input.
+    ///
+    /// By default, it returns `false`.
+    ///
+    /// [`Game::interact`]: ../trait.Game.html#method.interact
+    /// [`captures_keyboard`]: core/trait.Widget.html#method.captures_keyboard
+    fn captures_keyboard(&self) -> bool {
+        false
+    }
+
+    /// Whether the runtime describes the user interface for assistive
+    /// technologies, like screen readers.
+    ///
+    /// When enabled, the [`Semantics`] of the widgets are gathered every frame
+    /// and passed to [`on_semantics_change`] whenever they change.
+    ///
+    /// By default, it is `false`.
+    ///
+    /// [`Semantics`]: core/struct.Semantics.html
+    /// [`on_semantics_change`]: #method.on_semantics_change
+    const ACCESSIBLE: bool = false;
+
+    /// Receives the [`Semantics`] of the user interface when they change.
+    ///
+    /// They describe the widgets that can receive input, from the bottom
+    /// layer to the top one. Forward them to a screen reader or an
+    /// accessibility library, like [AccessKit], so your menus are announced.
+    ///
+    /// Coffee does not talk to screen readers by itself. The AccessKit adapter
+    /// for `winit` needs a newer `winit` than the one Coffee uses, so there is
+    /// no built-in integration and this hook is the only way out.
+    ///
+    /// It is only called when [`ACCESSIBLE`] is `true`. By default, it does
+    /// nothing.
+    ///
+    /// [`Semantics`]: core/struct.Semantics.html
+    /// [AccessKit]: https://github.com/AccessKit/accesskit
+    /// [`ACCESSIBLE`]: #associatedconstant.ACCESSIBLE
+    fn on_semantics_change(&mut self, _semantics: &[core::Semantics]) {}
+
     /// Builds the renderer configuration for the user interface.
     ///
     /// By default, it returns `Default::default()`.
@@ -279,8 +417,13 @@ struct Loop<UI: UserInterface> {
     renderer: UI::Renderer,
     messages: Vec<UI::Message>,
     mouse_cursor: MouseCursor,
-    cache: Option<core::Cache>,
+    layers: Vec<LayerState>,
     cursor_position: Point,
+    virtual_cursor: Option<virtual_cursor::State>,
+    shortcuts: shortcut::Tracker,
+    is_keyboard_captured: bool,
+    game_keys: HashSet<KeyCode>,
+    semantics: Vec<core::Semantics>,
     events: Vec<Event>,
 }
 
@@ -288,13 +431,29 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
     type Attributes = UI::Renderer;
 
     fn new(renderer: UI::Renderer, game: &mut UI, window: &Window) -> Self {
-        let cache = Interface::compute(game.layout(window), &renderer).cache();
+        let layers = game
+            .layers(window)
+            .into_iter()
+            .map(|layer| LayerState {
+                cache: Interface::compute(layer.root, &renderer).cache(),
+                mouse_cursor: MouseCursor::OutOfBounds,
+            })
+            .collect();
+
+        let mut shortcuts = shortcut::Tracker::new();
+        shortcuts.update(&game.shortcuts());
+
         Loop {
             renderer,
             messages: Vec::new(),
             mouse_cursor: MouseCursor::OutOfBounds,
-            cache: Some(cache),
+            layers,
             cursor_position: Point::new(0.0, 0.0),
+            virtual_cursor: UI::VIRTUAL_CURSOR.map(virtual_cursor::State::new),
+            shortcuts,
+            is_keyboard_captured: false,
+            game_keys: HashSet::new(),
+            semantics: Vec::new(),
             events: Vec::new(),
         }
     }
@@ -303,12 +462,87 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
         UI::Renderer::load(UI::configuration())
     }
 
+    fn on_device_reset(
+        &mut self,
+        _ui: &mut UI,
+        window: &mut Window,
+    ) -> Result<()> {
+        self.renderer =
+            UI::Renderer::load(UI::configuration()).run(window.gpu())?;
+
+        Ok(())
+    }
+
     fn on_input(&mut self, input: &mut UI::Input, event: input::Event) {
-        input.update(event);
+        if self.shortcuts.on_input(event, self.is_keyboard_captured) {
+            return;
+        }
+
+        // The game keeps receiving the releases of the keys it saw pressed,
+        // so keys do not get stuck when the keyboard is captured
+        let reaches_game = match event {
+            input::Event::Keyboard(keyboard::Event::Input {
+                state: ButtonState::Pressed,
+                key_code,
+            }) => {
+                if !self.is_keyboard_captured {
+                    let _ = self.game_keys.insert(key_code);
+                }
+
+                !self.is_keyboard_captured
+            }
+            input::Event::Keyboard(keyboard::Event::Input {
+                state: ButtonState::Released,
+                key_code,
+            }) => self.game_keys.remove(&key_code),
+            input::Event::Keyboard(keyboard::Event::TextEntered { .. }) => {
+                !self.is_keyboard_captured
+            }
+            _ => true,
+        };
+
+        if reaches_game {
+            input.update(event);
+        }
 
         match event {
             input::Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
                 self.cursor_position = Point::new(x, y);
+
+                if let Some(virtual_cursor) = &mut self.virtual_cursor {
+                    virtual_cursor.hide();
+                }
+            }
+            input::Event::Gamepad { event, .. } => {
+                if let Some(virtual_cursor) = &mut self.virtual_cursor {
+                    let state = match event {
+                        gamepad::Event::AxisChanged(axis, value) => {
+                            virtual_cursor.update_axis(axis, value);
+                            None
+                        }
+                        gamepad::Event::ButtonPressed(button)
+                            if button == virtual_cursor.button() =>
+                        {
+                            Some(ButtonState::Pressed)
+                        }
+                        gamepad::Event::ButtonReleased(button)
+                            if button == virtual_cursor.button() =>
+                        {
+                            Some(ButtonState::Released)
+                        }
+                        _ => None,
+                    };
+
+                    // The button clicks like the left mouse button
+                    if let Some(state) = state {
+                        virtual_cursor.show();
+
+                        self.events.push(Event::Mouse(mouse::Event::Input {
+                            state,
+                            button: mouse::Button::Left,
+                        }));
+                    }
+                }
             }
             _ => {}
         };
@@ -318,34 +552,176 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
         }
     }
 
+    fn on_tick(&mut self, delta: std::time::Duration) {
+        widget::toasts::advance(delta);
+    }
+
     fn after_draw(
         &mut self,
         ui: &mut UI,
         input: &mut UI::Input,
         window: &mut Window,
+        timer: &Timer,
         debug: &mut Debug,
     ) {
+        profile::scope!("ui");
+
         debug.ui_started();
-        let mut interface = Interface::compute_with_cache(
-            ui.layout(window),
-            &self.renderer,
-            self.cache.take().unwrap(),
-        );
+
+        for combination in self.shortcuts.drain_triggered() {
+            if let Some(message) =
+                shortcut::take_message(ui.shortcuts(), combination)
+            {
+                ui.react(message, window);
+            }
+        }
+
+        ui.before_draw(&mut window.frame(), timer);
+
+        let renderer = &self.renderer;
+        let mut states = std::mem::take(&mut self.layers).into_iter();
+
+        let mut interfaces: Vec<_> = ui
+            .layers(window)
+            .into_iter()
+            .map(|layer| {
+                let (interface, mouse_cursor) = match states.next() {
+                    Some(state) => (
+                        Interface::compute_with_cache(
+                            layer.root,
+                            renderer,
+                            state.cache,
+                        ),
+                        state.mouse_cursor,
+                    ),
+                    None => (
+                        Interface::compute(layer.root, renderer),
+                        MouseCursor::OutOfBounds,
+                    ),
+                };
+
+                (interface, layer.is_modal, mouse_cursor)
+            })
+            .collect();
+
+        let cached = interfaces
+            .iter()
+            .filter(|(interface, _, _)| interface.is_layout_cached())
+            .count() as u32;
+
+        debug.ui_cached(core::CacheStatistics {
+            hits: cached,
+            misses: interfaces.len() as u32 - cached,
+        });
+
+        // Only the layers above the top modal one can receive events
+        let first_layer = interfaces
+            .iter()
+            .rposition(|(_, is_modal, _)| *is_modal)
+            .unwrap_or(0);
+
+        if let Some(virtual_cursor) = &mut self.virtual_cursor {
+            let mut focusable = Vec::new();
+
+            for (interface, _, _) in &interfaces[first_layer..] {
+                interface.focusable_bounds(&mut focusable);
+            }
+
+            let bounds = Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: window.width(),
+                height: window.height(),
+            };
+
+            if let Some(position) =
+                virtual_cursor.update(self.cursor_position, bounds, &focusable)
+            {
+                self.cursor_position = position;
+
+                // Mouse events are processed in order, so the cursor needs
+                // to move before any pending click
+                self.events.insert(
+                    0,
+                    Event::Mouse(mouse::Event::CursorMoved {
+                        x: position.x,
+                        y: position.y,
+                    }),
+                );
+            }
+        }
 
         let cursor_position = self.cursor_position;
         let messages = &mut self.messages;
 
-        self.events.drain(..).for_each(|event| {
-            interface.on_event(event, cursor_position, messages)
-        });
+        // Events go from the top layer to the bottom one, until consumed
+        for event in self.events.drain(..) {
+            let is_mouse_event = match event {
+                Event::Mouse(_) => true,
+                _ => false,
+            };
+
+            for (interface, is_modal, cursor) in interfaces.iter_mut().rev() {
+                interface.on_event(event, cursor_position, messages);
+
+                let is_hovered = *cursor != MouseCursor::OutOfBounds;
 
-        let new_cursor = interface.draw(
-            &mut self.renderer,
-            &mut window.frame(),
-            cursor_position,
-        );
+                if *is_modal || (is_mouse_event && is_hovered) {
+                    break;
+                }
+            }
+        }
 
-        self.cache = Some(interface.cache());
+        let has_new_semantics = UI::ACCESSIBLE && {
+            let mut semantics = Vec::new();
+
+            for (interface, _, _) in &interfaces[first_layer..] {
+                interface.semantics(&mut semantics);
+            }
+
+            let has_changed = semantics != self.semantics;
+            self.semantics = semantics;
+
+            has_changed
+        };
+
+        let mut new_cursor = MouseCursor::OutOfBounds;
+        let mut is_keyboard_captured = false;
+
+        for (interface, is_modal, _) in interfaces {
+            is_keyboard_captured |= interface.captures_keyboard();
+
+            let mouse_cursor = interface.draw(
+                &mut self.renderer,
+                &mut window.frame(),
+                cursor_position,
+            );
+
+            // Upper layers take precedence if they are hovered or modal
+            if mouse_cursor != MouseCursor::OutOfBounds || is_modal {
+                new_cursor = mouse_cursor;
+            }
+
+            self.layers.push(LayerState {
+                cache: interface.cache(),
+                mouse_cursor,
+            });
+        }
+
+        debug.ui_drawn(self.renderer.take_draw_calls());
+
+        self.is_keyboard_captured =
+            is_keyboard_captured || ui.captures_keyboard();
+
+        if has_new_semantics {
+            ui.on_semantics_change(&self.semantics);
+        }
+
+        ui.after_draw(&mut window.frame(), timer);
+
+        if let Some(virtual_cursor) = &self.virtual_cursor {
+            virtual_cursor.draw(cursor_position, &mut window.frame());
+        }
 
         if new_cursor != self.mouse_cursor {
             if new_cursor == MouseCursor::OutOfBounds {
@@ -367,6 +743,13 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
         for message in messages.drain(..) {
             ui.react(message, window);
         }
+
+        self.shortcuts.update(&ui.shortcuts());
         debug.ui_finished();
     }
 }
+
+struct LayerState {
+    cache: core::Cache,
+    mouse_cursor: MouseCursor,
+}
@@ -9,10 +9,12 @@ mod element;
 mod event;
 mod hasher;
 mod interface;
+mod layer;
 mod layout;
 mod mouse_cursor;
 mod node;
 mod renderer;
+mod semantics;
 mod style;
 mod widget;
 
@@ -22,10 +24,13 @@ pub use stretch::{geometry::Size, number::Number};
 pub use element::Element;
 pub use event::Event;
 pub use hasher::Hasher;
+pub use interface::CacheStatistics;
 pub(crate) use interface::{Cache, Interface};
+pub use layer::Layer;
 pub use layout::Layout;
 pub use mouse_cursor::MouseCursor;
 pub use node::Node;
 pub use renderer::Renderer;
-pub use style::{Align, Justify, Style};
+pub use semantics::{Role, Semantics};
+pub use style::{Align, Direction, Justify, Style};
 pub use widget::Widget;
@@ -1,7 +1,9 @@
 use stretch::{geometry, result};
 
-use crate::graphics::{Color, Point};
-use crate::ui::core::{self, Event, Hasher, Layout, MouseCursor, Node, Widget};
+use crate::graphics::{Color, Point, Rectangle};
+use crate::ui::core::{
+    self, Event, Hasher, Layout, MouseCursor, Node, Role, Semantics, Widget,
+};
 
 /// A generic [`Widget`].
 ///
@@ -161,6 +163,47 @@ impl<'a, Message, Renderer> Element<'a, Message, Renderer> {
         }
     }
 
+    /// Overrides the [`MouseCursor`] while the [`Element`] is hovered or in
+    /// use.
+    ///
+    /// This is useful to show a busy cursor over a part of your user interface
+    /// that is loading, or a resize cursor over a handle.
+    ///
+    /// [`MouseCursor`]: enum.MouseCursor.html
+    /// [`Element`]: struct.Element.html
+    pub fn mouse_cursor(
+        self,
+        mouse_cursor: MouseCursor,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Message: 'static,
+        Renderer: 'a,
+    {
+        Element {
+            widget: Box::new(Cursor::new(self, mouse_cursor)),
+        }
+    }
+
+    /// Sets the label that assistive technologies announce for the
+    /// [`Element`].
+    ///
+    /// Use it to name widgets without text, like a [`Slider`] or an
+    /// [`Image`]. If the [`Element`] has multiple parts, they are grouped
+    /// under the label.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Slider`]: ../widget/slider/struct.Slider.html
+    /// [`Image`]: ../widget/image/struct.Image.html
+    pub fn label(self, label: &str) -> Element<'a, Message, Renderer>
+    where
+        Message: 'static,
+        Renderer: 'a,
+    {
+        Element {
+            widget: Box::new(Label::new(self, label)),
+        }
+    }
+
     pub(crate) fn compute_layout(&self, renderer: &Renderer) -> result::Layout {
         let node = self.widget.node(renderer);
 
@@ -237,6 +280,22 @@ where
         self.widget.draw(renderer, layout, cursor_position)
     }
 
+    fn focusable_bounds(
+        &self,
+        layout: Layout<'_>,
+        bounds: &mut Vec<Rectangle<f32>>,
+    ) {
+        self.widget.focusable_bounds(layout, bounds);
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        self.widget.captures_keyboard()
+    }
+
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        self.widget.semantics(layout, semantics);
+    }
+
     fn hash(&self, state: &mut Hasher) {
         self.widget.hash(state);
     }
@@ -293,6 +352,193 @@ where
         self.element.widget.draw(renderer, layout, cursor_position)
     }
 
+    fn focusable_bounds(
+        &self,
+        layout: Layout<'_>,
+        bounds: &mut Vec<Rectangle<f32>>,
+    ) {
+        self.element.widget.focusable_bounds(layout, bounds);
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        self.element.widget.captures_keyboard()
+    }
+
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        self.element.widget.semantics(layout, semantics);
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.element.widget.hash(state);
+    }
+}
+
+struct Cursor<'a, Message, Renderer> {
+    element: Element<'a, Message, Renderer>,
+    mouse_cursor: MouseCursor,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Cursor<'a, Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cursor")
+            .field("element", &self.element)
+            .field("mouse_cursor", &self.mouse_cursor)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Cursor<'a, Message, Renderer> {
+    fn new(
+        element: Element<'a, Message, Renderer>,
+        mouse_cursor: MouseCursor,
+    ) -> Self {
+        Cursor {
+            element,
+            mouse_cursor,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Cursor<'a, Message, Renderer>
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        self.element.widget.node(renderer)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.element
+            .widget
+            .on_event(event, layout, cursor_position, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let is_hovered = layout.bounds().contains(cursor_position);
+        let mouse_cursor =
+            self.element.widget.draw(renderer, layout, cursor_position);
+
+        if is_hovered || mouse_cursor != MouseCursor::OutOfBounds {
+            self.mouse_cursor
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+
+    fn focusable_bounds(
+        &self,
+        layout: Layout<'_>,
+        bounds: &mut Vec<Rectangle<f32>>,
+    ) {
+        self.element.widget.focusable_bounds(layout, bounds);
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        self.element.widget.captures_keyboard()
+    }
+
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        self.element.widget.semantics(layout, semantics);
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.element.widget.hash(state);
+    }
+}
+
+struct Label<'a, Message, Renderer> {
+    element: Element<'a, Message, Renderer>,
+    label: String,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Label<'a, Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Label")
+            .field("element", &self.element)
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Label<'a, Message, Renderer> {
+    fn new(element: Element<'a, Message, Renderer>, label: &str) -> Self {
+        Label {
+            element,
+            label: String::from(label),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Label<'a, Message, Renderer>
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        self.element.widget.node(renderer)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.element
+            .widget
+            .on_event(event, layout, cursor_position, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        self.element.widget.draw(renderer, layout, cursor_position)
+    }
+
+    fn focusable_bounds(
+        &self,
+        layout: Layout<'_>,
+        bounds: &mut Vec<Rectangle<f32>>,
+    ) {
+        self.element.widget.focusable_bounds(layout, bounds);
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        self.element.widget.captures_keyboard()
+    }
+
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        let bounds = layout.bounds();
+        let mut children = Vec::new();
+
+        self.element.widget.semantics(layout, &mut children);
+
+        // A single part is renamed, multiple parts are grouped
+        if children.len() == 1 {
+            let mut child = children.remove(0);
+            child.label = self.label.clone();
+
+            semantics.push(child);
+        } else {
+            semantics.push(Semantics {
+                children,
+                ..Semantics::new(Role::Group, &self.label, bounds)
+            });
+        }
+    }
+
     fn hash(&self, state: &mut Hasher) {
         self.element.widget.hash(state);
     }
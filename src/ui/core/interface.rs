@@ -1,13 +1,14 @@
 use std::hash::Hasher;
 use stretch::result;
 
-use crate::graphics::{Frame, Point};
-use crate::ui::core::{self, Element, Event, Layout, MouseCursor};
+use crate::graphics::{Frame, Point, Rectangle};
+use crate::ui::core::{self, Element, Event, Layout, MouseCursor, Semantics};
 
 pub struct Interface<'a, Message, Renderer> {
     hash: u64,
     root: Element<'a, Message, Renderer>,
     layout: result::Layout,
+    is_layout_cached: bool,
 }
 
 pub struct Cache {
@@ -15,6 +16,24 @@ pub struct Cache {
     layout: result::Layout,
 }
 
+/// The usage of the layout cache of a user interface during a frame.
+///
+/// Every layer of a user interface keeps the layout of the last frame. It is
+/// reused as long as the layer does not change. A high amount of misses
+/// means your layers change every frame; consider splitting them.
+///
+/// The cache works per layer. When a layer changes, its whole layout is
+/// computed again, including the subtrees that did not change. Only the
+/// measurements of text are kept by the built-in renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStatistics {
+    /// The amount of layers that reused their layout.
+    pub hits: u32,
+
+    /// The amount of layers that computed their layout again.
+    pub misses: u32,
+}
+
 impl<'a, Message, Renderer> Interface<'a, Message, Renderer>
 where
     Renderer: core::Renderer,
@@ -23,13 +42,15 @@ where
         root: Element<'a, Message, Renderer>,
         renderer: &Renderer,
     ) -> Interface<'a, Message, Renderer> {
-        let hasher = &mut twox_hash::XxHash::default();
-        root.hash(hasher);
-
-        let hash = hasher.finish();
+        let hash = hash(&root);
         let layout = root.compute_layout(renderer);
 
-        Interface { hash, root, layout }
+        Interface {
+            hash,
+            root,
+            layout,
+            is_layout_cached: false,
+        }
     }
 
     pub fn compute_with_cache(
@@ -37,18 +58,26 @@ where
         renderer: &Renderer,
         cache: Cache,
     ) -> Interface<'a, Message, Renderer> {
-        let hasher = &mut twox_hash::XxHash::default();
-        root.hash(hasher);
+        let hash = hash(&root);
 
-        let hash = hasher.finish();
+        let is_layout_cached = hash == cache.hash;
 
-        let layout = if hash == cache.hash {
+        let layout = if is_layout_cached {
             cache.layout
         } else {
             root.compute_layout(renderer)
         };
 
-        Interface { hash, root, layout }
+        Interface {
+            hash,
+            root,
+            layout,
+            is_layout_cached,
+        }
+    }
+
+    pub fn is_layout_cached(&self) -> bool {
+        self.is_layout_cached
     }
 
     pub fn on_event(
@@ -84,6 +113,22 @@ where
         cursor
     }
 
+    pub fn focusable_bounds(&self, bounds: &mut Vec<Rectangle<f32>>) {
+        let Interface { root, layout, .. } = self;
+
+        root.widget.focusable_bounds(Self::layout(layout), bounds);
+    }
+
+    pub fn captures_keyboard(&self) -> bool {
+        self.root.widget.captures_keyboard()
+    }
+
+    pub fn semantics(&self, semantics: &mut Vec<Semantics>) {
+        let Interface { root, layout, .. } = self;
+
+        root.widget.semantics(Self::layout(layout), semantics);
+    }
+
     pub fn cache(self) -> Cache {
         Cache {
             hash: self.hash,
@@ -95,3 +140,14 @@ where
         Layout::new(layout, Point::new(0.0, 0.0))
     }
 }
+
+fn hash<Message, Renderer>(root: &Element<'_, Message, Renderer>) -> u64 {
+    let hasher = &mut twox_hash::XxHash::default();
+    root.hash(hasher);
+
+    // Switching languages changes the contents of localized widgets
+    #[cfg(feature = "i18n")]
+    std::hash::Hash::hash(&crate::i18n::revision(), hasher);
+
+    hasher.finish()
+}
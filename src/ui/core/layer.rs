@@ -0,0 +1,67 @@
+use crate::ui::core::Element;
+
+/// An independent part of a user interface, drawn on top of the layers below.
+///
+/// Every [`Layer`] is laid out and cached on its own, so changes in one layer
+/// do not cause the others to be laid out again.
+///
+/// Events are handled from the top layer to the bottom one:
+///
+///   * Mouse events are consumed by the first layer with a widget under the
+///     mouse cursor.
+///   * A [`modal`] layer consumes every event. The layers below it stop
+///     receiving input until it is gone.
+///
+/// Layers produce the same type of messages. You can use [`Element::map`] to
+/// build each layer with its own message type.
+///
+/// [`Layer`]: struct.Layer.html
+/// [`modal`]: #method.modal
+/// [`Element::map`]: struct.Element.html#method.map
+pub struct Layer<'a, Message, Renderer> {
+    pub(crate) root: Element<'a, Message, Renderer>,
+    pub(crate) is_modal: bool,
+}
+
+impl<'a, Message, Renderer> Layer<'a, Message, Renderer> {
+    /// Creates a new [`Layer`] with the given root [`Element`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    /// [`Element`]: struct.Element.html
+    pub fn new<E>(root: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Layer {
+            root: root.into(),
+            is_modal: false,
+        }
+    }
+
+    /// Makes the [`Layer`] consume all the events, blocking the layers below.
+    ///
+    /// This is useful for pause menus and dialogs.
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn modal(mut self) -> Self {
+        self.is_modal = true;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Layer<'a, Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Layer")
+            .field("root", &self.root)
+            .field("is_modal", &self.is_modal)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> From<Element<'a, Message, Renderer>>
+    for Layer<'a, Message, Renderer>
+{
+    fn from(root: Element<'a, Message, Renderer>) -> Self {
+        Layer::new(root)
+    }
+}
@@ -20,6 +20,18 @@ pub enum MouseCursor {
 
     /// The cursor is grabbing a widget.
     Grabbing,
+
+    /// The cursor is over a widget with editable text.
+    Text,
+
+    /// The cursor is resizing a widget horizontally.
+    ResizingHorizontally,
+
+    /// The cursor is resizing a widget vertically.
+    ResizingVertically,
+
+    /// The cursor is over a disabled widget.
+    NotAllowed,
 }
 
 #[doc(hidden)]
@@ -32,6 +44,14 @@ impl From<MouseCursor> for winit::window::CursorIcon {
             MouseCursor::Working => winit::window::CursorIcon::Progress,
             MouseCursor::Grab => winit::window::CursorIcon::Grab,
             MouseCursor::Grabbing => winit::window::CursorIcon::Grabbing,
+            MouseCursor::Text => winit::window::CursorIcon::Text,
+            MouseCursor::ResizingHorizontally => {
+                winit::window::CursorIcon::EwResize
+            }
+            MouseCursor::ResizingVertically => {
+                winit::window::CursorIcon::NsResize
+            }
+            MouseCursor::NotAllowed => winit::window::CursorIcon::NotAllowed,
         }
     }
 }
@@ -47,4 +47,15 @@ pub trait Renderer {
     /// [`Batch`]: ../../graphics/struct.Batch.html
     /// [`Batch::draw`]: ../../graphics/struct.Batch.html#method.draw
     fn flush(&mut self, frame: &mut Frame<'_>);
+
+    /// Returns the amount of draw calls issued by [`flush`] since the last
+    /// time this method was called.
+    ///
+    /// The runtime calls it once per frame and shows the result in the debug
+    /// view. By default, it returns `0`.
+    ///
+    /// [`flush`]: #tymethod.flush
+    fn take_draw_calls(&mut self) -> u32 {
+        0
+    }
 }
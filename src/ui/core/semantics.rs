@@ -0,0 +1,90 @@
+use crate::graphics::Rectangle;
+
+/// The meaning of a part of a user interface, for assistive technologies.
+///
+/// Widgets describe themselves with [`Semantics`] in
+/// [`Widget::semantics`]. The runtime gathers them into a tree and hands it
+/// to [`UserInterface::on_semantics_change`], where it can be forwarded to a
+/// screen reader or an accessibility library like [AccessKit]. Coffee does not
+/// integrate with any of them by itself.
+///
+/// [`Semantics`]: struct.Semantics.html
+/// [`Widget::semantics`]: trait.Widget.html#method.semantics
+/// [`UserInterface::on_semantics_change`]: ../trait.UserInterface.html#method.on_semantics_change
+/// [AccessKit]: https://github.com/AccessKit/accesskit
+#[derive(Debug, Clone, PartialEq)]
+pub struct Semantics {
+    /// The [`Role`] of the widget.
+    ///
+    /// [`Role`]: enum.Role.html
+    pub role: Role,
+
+    /// The text that names the widget, announced by screen readers.
+    pub label: String,
+
+    /// The current value of the widget, like the position of a slider.
+    pub value: Option<String>,
+
+    /// The bounds of the widget, in screen coordinates.
+    pub bounds: Rectangle<f32>,
+
+    /// Whether the widget is checked or selected, if it can be.
+    pub is_checked: Option<bool>,
+
+    /// Whether the widget ignores interaction.
+    pub is_disabled: bool,
+
+    /// The [`Semantics`] of the contents of the widget.
+    ///
+    /// [`Semantics`]: struct.Semantics.html
+    pub children: Vec<Semantics>,
+}
+
+impl Semantics {
+    /// Creates new [`Semantics`] with the given [`Role`], label, and bounds.
+    ///
+    /// [`Semantics`]: struct.Semantics.html
+    /// [`Role`]: enum.Role.html
+    pub fn new(role: Role, label: &str, bounds: Rectangle<f32>) -> Semantics {
+        Semantics {
+            role,
+            label: String::from(label),
+            value: None,
+            bounds,
+            is_checked: None,
+            is_disabled: false,
+            children: Vec::new(),
+        }
+    }
+}
+
+/// The kind of a widget, as understood by assistive technologies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A set of related widgets, like a menu.
+    Group,
+
+    /// Some text to read.
+    Text,
+
+    /// A button that can be pressed.
+    Button,
+
+    /// A box that can be checked.
+    Checkbox,
+
+    /// An option that can be selected among others.
+    RadioButton,
+
+    /// A control that picks a value in a range.
+    Slider,
+
+    /// The progress of an action.
+    ProgressBar,
+
+    /// An image.
+    Image,
+
+    /// A message that should be announced as soon as it appears.
+    Alert,
+}
@@ -224,7 +224,7 @@ impl From<Align> for style::AlignSelf {
 ///
 /// [`Column`]: widget/struct.Column.html
 /// [`Row`]: widget/struct.Row.html
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Justify {
     /// Place items at the start of the main axis.
     Start,
@@ -258,3 +258,31 @@ impl From<Justify> for style::JustifyContent {
         }
     }
 }
+
+/// The order in which the contents of a [`Row`] are placed.
+///
+/// Use [`RightToLeft`] to mirror the layout of a [`Row`] for right-to-left
+/// languages.
+///
+/// A [`Direction`] only changes the placement of widgets. Coffee does not
+/// shape text nor apply the Unicode bidirectional algorithm, so the text of
+/// scripts that need them, like Arabic, Hebrew, or Devanagari, is still drawn
+/// character by character in logical order.
+///
+/// [`Direction`]: enum.Direction.html
+/// [`Row`]: widget/struct.Row.html
+/// [`RightToLeft`]: #variant.RightToLeft
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Place the first element on the left.
+    LeftToRight,
+
+    /// Place the first element on the right.
+    RightToLeft,
+}
+
+impl Default for Direction {
+    fn default() -> Direction {
+        Direction::LeftToRight
+    }
+}
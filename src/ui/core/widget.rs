@@ -1,5 +1,5 @@
-use crate::graphics::Point;
-use crate::ui::core::{Event, Hasher, Layout, MouseCursor, Node};
+use crate::graphics::{Point, Rectangle};
+use crate::ui::core::{Event, Hasher, Layout, MouseCursor, Node, Semantics};
 
 /// A component that displays information or allows interaction.
 ///
@@ -71,4 +71,47 @@ pub trait Widget<Message, Renderer>: std::fmt::Debug {
         _messages: &mut Vec<Message>,
     ) {
     }
+
+    /// Collects the bounds of the interactive parts of the [`Widget`].
+    ///
+    /// The runtime uses them to snap the virtual cursor of gamepads to
+    /// widgets. Widgets containing other widgets should forward the call to
+    /// their children.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    fn focusable_bounds(
+        &self,
+        _layout: Layout<'_>,
+        _bounds: &mut Vec<Rectangle<f32>>,
+    ) {
+    }
+
+    /// Returns whether the [`Widget`] takes the keyboard for itself.
+    ///
+    /// Text inputs should return `true` while focused. The runtime then stops
+    /// sending key presses and text to [`Game::interact`], so typing does not
+    /// control the game. Widgets containing other widgets should forward the
+    /// call to their children.
+    ///
+    /// By default, it returns `false`.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Game::interact`]: ../../trait.Game.html#method.interact
+    fn captures_keyboard(&self) -> bool {
+        false
+    }
+
+    /// Describes the [`Widget`] for assistive technologies, like screen
+    /// readers.
+    ///
+    /// Interactive widgets and text should push their [`Semantics`]. Widgets
+    /// containing other widgets should forward the call to their children.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Semantics`]: struct.Semantics.html
+    fn semantics(&self, _layout: Layout<'_>, _semantics: &mut Vec<Semantics>) {}
 }
@@ -6,8 +6,13 @@ mod progress_bar;
 mod radio;
 mod slider;
 mod text;
+mod theme;
+mod toasts;
 
-use crate::graphics::{Batch, Color, Font, Frame, Image, Mesh, Shape};
+pub use panel::NineSlice;
+pub use theme::{ButtonSlices, Slice, SliderSlices, Theme, ToggleSlices};
+
+use crate::graphics::{self, Batch, Color, Font, Frame, Image, Mesh, Shape};
 use crate::load::{Join, Task};
 use crate::ui::core;
 
@@ -25,8 +30,15 @@ use std::rc::Rc;
 pub struct Renderer {
     pub(crate) sprites: Batch,
     pub(crate) images: Vec<Batch>,
+    panel: Option<panel::Slices>,
+    theme: Theme,
     pub(crate) font: Rc<RefCell<Font>>,
+    measurements: Rc<RefCell<text::Measurements>>,
+    mesh: Mesh,
     explain_mesh: Mesh,
+    toasts: Vec<toasts::Toast>,
+    has_text: bool,
+    draw_calls: u32,
 }
 
 impl std::fmt::Debug for Renderer {
@@ -42,14 +54,29 @@ impl core::Renderer for Renderer {
     type Configuration = Configuration;
 
     fn load(config: Configuration) -> Task<Renderer> {
-        (config.sprites, config.font)
-            .join()
-            .map(|(sprites, font)| Renderer {
+        let theme = config.theme;
+        let panel = match config.panel {
+            Some(nine_slice) => nine_slice.load().map(Some),
+            None => Task::succeed(|| None),
+        };
+
+        (config.sprites, config.font, panel).join().map(
+            |(sprites, font, panel)| Renderer {
                 sprites: Batch::new(sprites),
                 images: Vec::new(),
+                panel,
+                theme,
                 font: Rc::new(RefCell::new(font)),
+                measurements: Rc::new(RefCell::new(
+                    text::Measurements::default(),
+                )),
+                mesh: Mesh::new(),
                 explain_mesh: Mesh::new(),
-            })
+                toasts: Vec::new(),
+                has_text: false,
+                draw_calls: 0,
+            },
+        )
     }
 
     fn explain(&mut self, layout: &core::Layout<'_>, color: Color) {
@@ -64,21 +91,59 @@ impl core::Renderer for Renderer {
     fn flush(&mut self, frame: &mut Frame<'_>) {
         let target = &mut frame.as_target();
 
-        self.sprites.draw(target);
-        self.sprites.clear();
+        // Every widget shares the same batches, so the amount of draw calls
+        // does not grow with the amount of widgets
+        if let Some(panel) = &mut self.panel {
+            // Panels are drawn below the widgets they contain
+            if !panel.is_empty() {
+                panel.flush(target);
+                self.draw_calls += 1;
+            }
+        }
+
+        if !self.sprites.is_empty() {
+            self.sprites.draw(target);
+            self.sprites.clear();
+            self.draw_calls += 1;
+        }
+
+        if !self.mesh.is_empty() {
+            self.mesh.draw(target);
+            self.mesh = Mesh::new();
+            self.draw_calls += 1;
+        }
 
         for image in &self.images {
             image.draw(target);
         }
 
+        self.draw_calls += self.images.len() as u32;
         self.images.clear();
 
-        self.font.borrow_mut().draw(target);
+        if self.has_text {
+            self.font.borrow_mut().draw(target);
+            self.has_text = false;
+            self.draw_calls += 1;
+        }
 
         if !self.explain_mesh.is_empty() {
             self.explain_mesh.draw(target);
             self.explain_mesh = Mesh::new();
+            self.draw_calls += 1;
         }
+
+        self.flush_toasts(target);
+    }
+
+    fn take_draw_calls(&mut self) -> u32 {
+        std::mem::replace(&mut self.draw_calls, 0)
+    }
+}
+
+impl Renderer {
+    fn add_text(&mut self, text: graphics::Text<'_>) {
+        self.font.borrow_mut().add(text);
+        self.has_text = true;
     }
 }
 
@@ -105,8 +170,10 @@ impl core::Renderer for Renderer {
 pub struct Configuration {
     /// The spritesheet used to render the [different widgets] of the user interface.
     ///
-    /// The spritesheet needs to be structured like [the default spritesheet].
+    /// The spritesheet needs to be structured like [the default spritesheet],
+    /// unless a different [`theme`] is provided.
     ///
+    /// [`theme`]: #structfield.theme
     /// [different widgets]: widget/index.html
     /// [the default spritesheet]: https://raw.githubusercontent.com/hecrj/coffee/92aa6b64673116fdc49d8694a10ee5bf53afb1b5/resources/ui.png
     pub sprites: Task<Image>,
@@ -118,6 +185,23 @@ pub struct Configuration {
     /// [`Text`]: widget/text/struct.Text.html
     /// [Inconsolata Regular]: https://fonts.google.com/specimen/Inconsolata
     pub font: Task<Font>,
+
+    /// The [`NineSlice`] image used to draw the background of a [`Panel`].
+    ///
+    /// By default, the panel of the spritesheet is used.
+    ///
+    /// [`NineSlice`]: struct.NineSlice.html
+    /// [`Panel`]: widget/panel/struct.Panel.html
+    pub panel: Option<NineSlice>,
+
+    /// The [`Theme`] describing where every widget is in the [`sprites`].
+    ///
+    /// By default, it is [`Theme::DEFAULT`].
+    ///
+    /// [`Theme`]: struct.Theme.html
+    /// [`sprites`]: #structfield.sprites
+    /// [`Theme::DEFAULT`]: struct.Theme.html#associatedconstant.DEFAULT
+    pub theme: Theme,
 }
 
 impl Default for Configuration {
@@ -134,6 +218,8 @@ impl Default for Configuration {
             font: Font::load_from_bytes(include_bytes!(
                 "../../resources/font/Inconsolata-Regular.ttf"
             )),
+            panel: None,
+            theme: Theme::DEFAULT,
         }
     }
 }
@@ -3,29 +3,9 @@ use crate::graphics::{
     VerticalAlignment,
 };
 use crate::ui::core::MouseCursor;
+use crate::ui::renderer::theme::Slice;
 use crate::ui::{button, Renderer};
 
-const LEFT: Rectangle<u16> = Rectangle {
-    x: 0,
-    y: 34,
-    width: 6,
-    height: 49,
-};
-
-const BACKGROUND: Rectangle<u16> = Rectangle {
-    x: LEFT.width,
-    y: LEFT.y,
-    width: 1,
-    height: LEFT.height,
-};
-
-const RIGHT: Rectangle<u16> = Rectangle {
-    x: LEFT.height - LEFT.width,
-    y: LEFT.y,
-    width: LEFT.width,
-    height: LEFT.height,
-};
-
 impl button::Renderer for Renderer {
     fn draw(
         &mut self,
@@ -37,57 +17,66 @@ impl button::Renderer for Renderer {
     ) -> MouseCursor {
         let mouse_over = bounds.contains(cursor_position);
 
-        let mut state_offset = 0;
+        let slices = match class {
+            button::Class::Primary => self.theme.primary_button,
+            button::Class::Secondary => self.theme.secondary_button,
+            button::Class::Positive => self.theme.positive_button,
+        };
+
+        let mut slice = slices.idle;
 
         if mouse_over {
             if state.is_pressed() {
                 bounds.y += 4.0;
-                state_offset = RIGHT.x + RIGHT.width;
+                slice = slices.pressed;
             } else {
                 bounds.y -= 1.0;
             }
         }
 
-        let class_index = match class {
-            button::Class::Primary => 0,
-            button::Class::Secondary => 1,
-            button::Class::Positive => 2,
-        };
+        let Slice { source, border } = slice;
+        let center_width = source.width.saturating_sub(border * 2).max(1);
 
         self.sprites.add(Sprite {
             source: Rectangle {
-                x: LEFT.x + state_offset,
-                y: LEFT.y + class_index * LEFT.height,
-                ..LEFT
+                width: border,
+                ..source
             },
             position: Point::new(bounds.x, bounds.y),
             scale: (1.0, 1.0),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
             source: Rectangle {
-                x: BACKGROUND.x + state_offset,
-                y: BACKGROUND.y + class_index * BACKGROUND.height,
-                ..BACKGROUND
+                x: source.x + border,
+                width: center_width,
+                ..source
             },
-            position: Point::new(bounds.x + LEFT.width as f32, bounds.y),
-            scale: (bounds.width - (LEFT.width + RIGHT.width) as f32, 1.0),
+            position: Point::new(bounds.x + f32::from(border), bounds.y),
+            scale: (
+                (bounds.width - f32::from(border * 2))
+                    / f32::from(center_width),
+                1.0,
+            ),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
             source: Rectangle {
-                x: RIGHT.x + state_offset,
-                y: RIGHT.y + class_index * RIGHT.height,
-                ..RIGHT
+                x: source.x + source.width.saturating_sub(border),
+                width: border,
+                ..source
             },
             position: Point::new(
-                bounds.x + bounds.width - RIGHT.width as f32,
+                bounds.x + bounds.width - f32::from(border),
                 bounds.y,
             ),
             scale: (1.0, 1.0),
+            ..Sprite::default()
         });
 
-        self.font.borrow_mut().add(Text {
+        self.add_text(Text {
             content: label,
             position: Point::new(bounds.x, bounds.y - 4.0),
             bounds: (bounds.width, bounds.height),
@@ -1,15 +1,8 @@
-use crate::graphics::{Point, Rectangle, Sprite};
+use crate::graphics::{Color, Point, Rectangle, Shape, Sprite};
 use crate::ui::core::MouseCursor;
 use crate::ui::widget::checkbox;
 use crate::ui::Renderer;
 
-const SPRITE: Rectangle<u16> = Rectangle {
-    x: 98,
-    y: 0,
-    width: 28,
-    height: 28,
-};
-
 impl checkbox::Renderer for Renderer {
     fn draw(
         &mut self,
@@ -17,32 +10,79 @@ impl checkbox::Renderer for Renderer {
         bounds: Rectangle<f32>,
         text_bounds: Rectangle<f32>,
         is_checked: bool,
+        appearance: checkbox::Appearance,
+        is_disabled: bool,
     ) -> MouseCursor {
-        let mouse_over = bounds.contains(cursor_position)
+        let is_hovered = bounds.contains(cursor_position)
             || text_bounds.contains(cursor_position);
+        let mouse_over = is_hovered && !is_disabled;
+
+        let slices = self.theme.checkbox;
 
-        self.sprites.add(Sprite {
-            source: Rectangle {
-                x: SPRITE.x + (if mouse_over { SPRITE.width } else { 0 }),
-                ..SPRITE
-            },
+        // Every part is stretched to the bounds of the box
+        let sprite = |source: Rectangle<u16>| Sprite {
+            source,
             position: Point::new(bounds.x, bounds.y),
-            scale: (1.0, 1.0),
-        });
+            scale: (
+                bounds.width / f32::from(source.width.max(1)),
+                bounds.height / f32::from(source.height.max(1)),
+            ),
+            ..Sprite::default()
+        };
+
+        let tint = if is_disabled {
+            Color {
+                a: checkbox::DISABLED_OPACITY,
+                ..Color::WHITE
+            }
+        } else {
+            Color::WHITE
+        };
+
+        self.sprites.add_tinted(
+            sprite(if mouse_over {
+                slices.hovered
+            } else {
+                slices.idle
+            }),
+            tint,
+        );
 
         if is_checked {
-            self.sprites.add(Sprite {
-                source: Rectangle {
-                    x: SPRITE.x + SPRITE.width * 2,
-                    ..SPRITE
-                },
-                position: Point::new(bounds.x, bounds.y),
-                scale: (1.0, 1.0),
-            });
+            match appearance.check_color {
+                None => {
+                    self.sprites.add_tinted(sprite(slices.mark), tint);
+                }
+                Some(color) => {
+                    let point = |x: f32, y: f32| {
+                        Point::new(
+                            bounds.x + bounds.width * x,
+                            bounds.y + bounds.height * y,
+                        )
+                    };
+
+                    self.mesh.stroke(
+                        Shape::Polyline {
+                            points: vec![
+                                point(0.25, 0.5),
+                                point(0.42, 0.68),
+                                point(0.75, 0.32),
+                            ],
+                        },
+                        Color {
+                            a: color.a * tint.a,
+                            ..color
+                        },
+                        bounds.width * 0.12,
+                    );
+                }
+            }
         }
 
         if mouse_over {
             MouseCursor::Pointer
+        } else if is_hovered {
+            MouseCursor::NotAllowed
         } else {
             MouseCursor::OutOfBounds
         }
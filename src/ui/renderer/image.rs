@@ -1,5 +1,5 @@
-use crate::graphics::{Rectangle, Sprite, Point, Image, Batch};
-use crate::ui::{Renderer, image};
+use crate::graphics::{Batch, Color, Image, Point, Rectangle, Sprite};
+use crate::ui::{image, Renderer};
 
 impl image::Renderer for Renderer {
     fn draw(
@@ -7,29 +7,31 @@ impl image::Renderer for Renderer {
         bounds: Rectangle<f32>,
         image: Image,
         source: Rectangle<u16>,
+        color: Color,
     ) {
-        let ratio_x = bounds.width / (source.width as f32);
-        let ratio_y = bounds.height / (source.height as f32);
-        let center = bounds.center();
+        let scale = (
+            bounds.width / f32::from(source.width.max(1)),
+            bounds.height / f32::from(source.height.max(1)),
+        );
 
-        let (scale, position) = if ratio_x > ratio_y {
-            let position_x = center.x - source.width as f32 * ratio_y / 2.0;
-            let position_y = bounds.y;
-            ((ratio_y, ratio_y), Point::new(position_x, position_y))
-        } else {
-            let position_x = bounds.x;
-            let position_y = center.y - source.height as f32 * ratio_x / 2.0;
-            ((ratio_x, ratio_x), Point::new(position_x, position_y))
-        };
-
-        let mut batch = Batch::new(image); 
-        batch.add(Sprite {
+        let sprite = Sprite {
             source,
-            position,
+            position: Point::new(bounds.x, bounds.y),
             scale,
-        });
+            ..Sprite::default()
+        };
 
-        self.images.push(batch);
+        match self.images.last_mut() {
+            // Consecutive images sharing a texture are drawn at once
+            Some(batch) if batch.has_image(&image) => {
+                batch.add_tinted(sprite, color);
+            }
+            _ => {
+                let mut batch = Batch::new(image);
+                batch.add_tinted(sprite, color);
+
+                self.images.push(batch);
+            }
+        }
     }
 }
-
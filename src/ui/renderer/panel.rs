@@ -1,159 +1,196 @@
-use crate::graphics::{Point, Rectangle, Sprite};
+use crate::graphics::{Batch, Image, Point, Rectangle, Sprite, Target};
+use crate::load::Task;
 use crate::ui::widget::panel;
 use crate::ui::Renderer;
 
-const PANEL_WIDTH: u16 = 28;
-const PANEL_HEIGHT: u16 = 34;
-
-const TOP_LEFT: Rectangle<u16> = Rectangle {
-    x: 0,
-    y: 0,
-    width: 8,
-    height: 8,
-};
-
-const TOP_BORDER: Rectangle<u16> = Rectangle {
-    x: TOP_LEFT.width,
-    y: 0,
-    width: 1,
-    height: TOP_LEFT.height,
-};
-
-const TOP_RIGHT: Rectangle<u16> = Rectangle {
-    x: PANEL_WIDTH - TOP_LEFT.height,
-    y: 0,
-    width: TOP_LEFT.width,
-    height: TOP_LEFT.height,
-};
-
-const CONTENT_BACKGROUND: Rectangle<u16> = Rectangle {
-    x: TOP_LEFT.width,
-    y: TOP_LEFT.height,
-    width: 1,
-    height: 1,
-};
-
-const LEFT_BORDER: Rectangle<u16> = Rectangle {
-    x: TOP_LEFT.x,
-    y: TOP_LEFT.height,
-    width: TOP_LEFT.width,
-    height: 1,
-};
-
-const RIGHT_BORDER: Rectangle<u16> = Rectangle {
-    x: TOP_RIGHT.x,
-    y: TOP_RIGHT.height,
-    width: TOP_RIGHT.width,
-    height: 1,
-};
-
-const BOTTOM_LEFT: Rectangle<u16> = Rectangle {
-    x: TOP_LEFT.x,
-    y: PANEL_HEIGHT - TOP_LEFT.height,
-    width: TOP_LEFT.width,
-    height: TOP_LEFT.height,
-};
-
-const BOTTOM_BORDER: Rectangle<u16> = Rectangle {
-    x: TOP_BORDER.x,
-    y: PANEL_HEIGHT - TOP_BORDER.height,
-    width: 1,
-    height: TOP_BORDER.height,
-};
-
-const BOTTOM_RIGHT: Rectangle<u16> = Rectangle {
-    x: TOP_RIGHT.x,
-    y: PANEL_HEIGHT - TOP_RIGHT.height,
-    width: TOP_RIGHT.width,
-    height: TOP_RIGHT.height,
-};
+/// An image split in nine slices, used to draw the background of a
+/// [`Panel`] with any size.
+///
+/// The corners are drawn as they are, the edges are stretched along their
+/// side, and the center is stretched in both directions. This way, the
+/// borders of the image keep their look.
+///
+/// [`Panel`]: widget/panel/struct.Panel.html
+#[derive(Debug)]
+pub struct NineSlice {
+    /// The image to slice.
+    pub image: Task<Image>,
+
+    /// The width of the left border of the image, in pixels.
+    pub left: u16,
+
+    /// The width of the right border of the image, in pixels.
+    pub right: u16,
+
+    /// The height of the top border of the image, in pixels.
+    pub top: u16,
+
+    /// The height of the bottom border of the image, in pixels.
+    pub bottom: u16,
+}
+
+impl NineSlice {
+    /// Creates a new [`NineSlice`] from an image with borders of the same
+    /// size on every side.
+    ///
+    /// [`NineSlice`]: struct.NineSlice.html
+    pub fn new(image: Task<Image>, border: u16) -> NineSlice {
+        NineSlice {
+            image,
+            left: border,
+            right: border,
+            top: border,
+            bottom: border,
+        }
+    }
+
+    pub(crate) fn load(self) -> Task<Slices> {
+        let NineSlice {
+            image,
+            left,
+            right,
+            top,
+            bottom,
+        } = self;
+
+        image.map(move |image| Slices {
+            width: image.width(),
+            height: image.height(),
+            batch: Batch::new(image),
+            left,
+            right,
+            top,
+            bottom,
+        })
+    }
+}
+
+// A loaded nine-slice image, batching every panel drawn with it
+#[derive(Debug)]
+pub(crate) struct Slices {
+    batch: Batch,
+    width: u16,
+    height: u16,
+    left: u16,
+    right: u16,
+    top: u16,
+    bottom: u16,
+}
+
+impl Slices {
+    pub fn is_empty(&self) -> bool {
+        self.batch.is_empty()
+    }
+
+    pub fn flush(&mut self, target: &mut Target<'_>) {
+        self.batch.draw(target);
+        self.batch.clear();
+    }
+
+    fn add(&mut self, bounds: Rectangle<f32>) {
+        let source = Rectangle {
+            x: 0,
+            y: 0,
+            width: self.width,
+            height: self.height,
+        };
+
+        add_nine_slice(
+            &mut self.batch,
+            source,
+            [self.left, self.right, self.top, self.bottom],
+            bounds,
+        );
+    }
+}
 
 impl panel::Renderer for Renderer {
     fn draw(&mut self, bounds: Rectangle<f32>) {
-        self.sprites.add(Sprite {
-            source: TOP_LEFT,
-            position: Point::new(bounds.x, bounds.y),
-            ..Sprite::default()
-        });
-
-        self.sprites.add(Sprite {
-            source: TOP_BORDER,
-            position: Point::new(bounds.x + TOP_LEFT.width as f32, bounds.y),
-            scale: (
-                bounds.width - (TOP_LEFT.width + TOP_RIGHT.width) as f32,
-                1.0,
-            ),
-        });
-
-        self.sprites.add(Sprite {
-            source: TOP_RIGHT,
-            position: Point::new(
-                bounds.x + bounds.width - TOP_RIGHT.width as f32,
-                bounds.y,
-            ),
-            ..Sprite::default()
-        });
-
-        self.sprites.add(Sprite {
-            source: CONTENT_BACKGROUND,
-            position: Point::new(bounds.x, bounds.y + TOP_BORDER.height as f32),
-            scale: (
-                bounds.width,
-                bounds.height
-                    - (TOP_BORDER.height + BOTTOM_BORDER.height) as f32,
-            ),
-        });
-
-        self.sprites.add(Sprite {
-            source: LEFT_BORDER,
-            position: Point::new(bounds.x, bounds.y + TOP_BORDER.height as f32),
-            scale: (
-                1.0,
-                bounds.height - (TOP_BORDER.height + BOTTOM_LEFT.height) as f32,
-            ),
-        });
-
-        self.sprites.add(Sprite {
-            source: RIGHT_BORDER,
-            position: Point::new(
-                bounds.x + bounds.width - RIGHT_BORDER.width as f32,
-                bounds.y + TOP_BORDER.height as f32,
-            ),
-            scale: (
-                1.0,
-                bounds.height
-                    - (TOP_BORDER.height + BOTTOM_RIGHT.height) as f32,
-            ),
-        });
-
-        self.sprites.add(Sprite {
-            source: BOTTOM_LEFT,
-            position: Point::new(
-                bounds.x,
-                bounds.y + bounds.height - BOTTOM_LEFT.height as f32,
-            ),
-            ..Sprite::default()
-        });
-
-        self.sprites.add(Sprite {
-            source: BOTTOM_BORDER,
-            position: Point::new(
-                bounds.x + BOTTOM_LEFT.width as f32,
-                bounds.y + bounds.height - BOTTOM_BORDER.height as f32,
-            ),
-            scale: (
-                bounds.width - (BOTTOM_LEFT.width + BOTTOM_LEFT.width) as f32,
-                1.0,
-            ),
-        });
-
-        self.sprites.add(Sprite {
-            source: BOTTOM_RIGHT,
-            position: Point::new(
-                bounds.x + bounds.width - BOTTOM_RIGHT.width as f32,
-                bounds.y + bounds.height - BOTTOM_RIGHT.height as f32,
-            ),
-            ..Sprite::default()
-        });
+        match &mut self.panel {
+            Some(panel) => panel.add(bounds),
+            None => {
+                let slice = self.theme.panel;
+                let border = slice.border;
+
+                add_nine_slice(
+                    &mut self.sprites,
+                    slice.source,
+                    [border, border, border, border],
+                    bounds,
+                );
+            }
+        }
+    }
+}
+
+// Adds the slices of the source to the batch, stretching them to the bounds.
+// The borders are given in left, right, top, and bottom order.
+fn add_nine_slice(
+    batch: &mut Batch,
+    source: Rectangle<u16>,
+    borders: [u16; 4],
+    bounds: Rectangle<f32>,
+) {
+    let [left, right, top, bottom] = borders;
+
+    // The source offset and size of every column and row, followed by
+    // their position and size on the screen
+    let columns = [
+        (source.x, left, bounds.x, f32::from(left)),
+        (
+            source.x + left,
+            source.width.saturating_sub(left + right),
+            bounds.x + f32::from(left),
+            bounds.width - f32::from(left + right),
+        ),
+        (
+            source.x + source.width.saturating_sub(right),
+            right,
+            bounds.x + bounds.width - f32::from(right),
+            f32::from(right),
+        ),
+    ];
+
+    let rows = [
+        (source.y, top, bounds.y, f32::from(top)),
+        (
+            source.y + top,
+            source.height.saturating_sub(top + bottom),
+            bounds.y + f32::from(top),
+            bounds.height - f32::from(top + bottom),
+        ),
+        (
+            source.y + source.height.saturating_sub(bottom),
+            bottom,
+            bounds.y + bounds.height - f32::from(bottom),
+            f32::from(bottom),
+        ),
+    ];
+
+    for &(source_x, source_width, x, width) in &columns {
+        for &(source_y, source_height, y, height) in &rows {
+            if source_width == 0
+                || source_height == 0
+                || width <= 0.0
+                || height <= 0.0
+            {
+                continue;
+            }
+
+            batch.add(Sprite {
+                source: Rectangle {
+                    x: source_x,
+                    y: source_y,
+                    width: source_width,
+                    height: source_height,
+                },
+                position: Point::new(x, y),
+                scale: (
+                    width / f32::from(source_width),
+                    height / f32::from(source_height),
+                ),
+                ..Sprite::default()
+            });
+        }
     }
 }
@@ -1,56 +1,89 @@
-use crate::graphics::{Rectangle, Sprite, Point};
+use crate::graphics::{Point, Rectangle, Shape, Sprite};
+use crate::ui::renderer::theme::Slice;
 use crate::ui::{progress_bar, Renderer};
 
-const LEFT: Rectangle<u16> = Rectangle {
-    x: 0,
-    y: 34,
-    width: 6,
-    height: 49,
-};
-
-const BACKGROUND: Rectangle<u16> = Rectangle {
-    x: LEFT.width,
-    y: LEFT.y,
-    width: 1,
-    height: LEFT.height,
-};
-
-const RIGHT: Rectangle<u16> = Rectangle {
-    x: LEFT.height - LEFT.width,
-    y: LEFT.y,
-    width: LEFT.width,
-    height: LEFT.height,
-};
-
 impl progress_bar::Renderer for Renderer {
     fn draw(
         &mut self,
         bounds: Rectangle<f32>,
-        progress: f32,
+        start: f32,
+        end: f32,
+        appearance: Option<progress_bar::Appearance>,
     ) {
-        let active_class = 0;
-        let background_class = 1;
+        match appearance {
+            None if start <= 0.0 => self.draw_progress_sprites(bounds, end),
+            _ => self.draw_progress_mesh(
+                bounds,
+                start,
+                end,
+                appearance.unwrap_or_default(),
+            ),
+        }
+    }
+}
+
+impl Renderer {
+    fn draw_progress_sprites(&mut self, bounds: Rectangle<f32>, progress: f32) {
+        let background = self.theme.progress_bar;
+        let fill = self.theme.progress_bar_fill;
         let full = 1.0;
-        let left_width_f32 = LEFT.width as f32 / 100.0;
+        let left_width_f32 = f32::from(fill.border) / 100.0;
         let background_width = 1.0 - 2.0 * left_width_f32;
 
-        self.sprites.add(left_sprite(bounds, background_class, full));
-        self.sprites.add(background_sprite(bounds, background_class, full));
-        self.sprites.add(right_sprite(bounds, background_class, full));
+        self.sprites.add(left_sprite(bounds, background, full));
+        self.sprites
+            .add(background_sprite(bounds, background, full));
+        self.sprites.add(right_sprite(bounds, background, full));
 
         if progress > 0.0 {
             let area = bound(progress / left_width_f32);
-            self.sprites.add(left_sprite(bounds, active_class, area));
+            self.sprites.add(left_sprite(bounds, fill, area));
         }
 
         if progress > left_width_f32 {
             let area = bound((progress - left_width_f32) / background_width);
-            self.sprites.add(background_sprite(bounds, active_class, area));
+            self.sprites.add(background_sprite(bounds, fill, area));
         }
 
         if progress > left_width_f32 + background_width {
-            let area = bound((progress - left_width_f32 - background_width) / left_width_f32);
-            self.sprites.add(right_sprite(bounds, active_class, area));
+            let area = bound(
+                (progress - left_width_f32 - background_width) / left_width_f32,
+            );
+            self.sprites.add(right_sprite(bounds, fill, area));
+        }
+    }
+
+    fn draw_progress_mesh(
+        &mut self,
+        bounds: Rectangle<f32>,
+        start: f32,
+        end: f32,
+        appearance: progress_bar::Appearance,
+    ) {
+        let radius = appearance
+            .border_radius
+            .min(bounds.width / 2.0)
+            .min(bounds.height / 2.0);
+
+        self.mesh.fill(
+            Shape::RoundedRectangle { bounds, radius },
+            appearance.background,
+        );
+
+        if end > start {
+            let width = bounds.width * (end - start);
+
+            self.mesh.fill(
+                Shape::RoundedRectangle {
+                    bounds: Rectangle {
+                        x: bounds.x + bounds.width * start,
+                        width,
+                        ..bounds
+                    },
+                    radius: radius.min(width / 2.0),
+                },
+                appearance.bar,
+            );
         }
     }
 }
@@ -63,43 +96,56 @@ fn bound(v: f32) -> f32 {
     }
 }
 
-fn left_sprite(bounds: Rectangle<f32>, class_index: u16, area: f32) -> Sprite {
+fn left_sprite(bounds: Rectangle<f32>, slice: Slice, area: f32) -> Sprite {
     Sprite {
         source: Rectangle {
-            x: LEFT.x,
-            y: LEFT.y + class_index * LEFT.height,
-            width: (LEFT.width as f32 * area) as u16,
-            height: LEFT.height,
+            width: (f32::from(slice.border) * area) as u16,
+            ..slice.source
         },
         position: Point::new(bounds.x, bounds.y),
         scale: (1.0, 1.0),
+        ..Sprite::default()
     }
 }
 
-fn background_sprite(bounds: Rectangle<f32>, class_index: u16, area: f32) -> Sprite {
+fn background_sprite(
+    bounds: Rectangle<f32>,
+    slice: Slice,
+    area: f32,
+) -> Sprite {
+    let Slice { source, border } = slice;
+    let center_width = source.width.saturating_sub(border * 2).max(1);
+
     Sprite {
         source: Rectangle {
-            x: BACKGROUND.x,
-            y: BACKGROUND.y + class_index * BACKGROUND.height,
-            ..BACKGROUND
+            x: source.x + border,
+            width: center_width,
+            ..source
         },
-        position: Point::new(bounds.x + LEFT.width as f32, bounds.y),
-        scale: ((bounds.width - (LEFT.width + RIGHT.width) as f32) * area, 1.0),
+        position: Point::new(bounds.x + f32::from(border), bounds.y),
+        scale: (
+            (bounds.width - f32::from(border * 2)) * area
+                / f32::from(center_width),
+            1.0,
+        ),
+        ..Sprite::default()
     }
 }
 
-fn right_sprite(bounds: Rectangle<f32>, class_index: u16, area: f32) -> Sprite {
+fn right_sprite(bounds: Rectangle<f32>, slice: Slice, area: f32) -> Sprite {
+    let Slice { source, border } = slice;
+
     Sprite {
         source: Rectangle {
-            x: RIGHT.x,
-            y: RIGHT.y + class_index * RIGHT.height,
-            width: (RIGHT.width as f32 * area) as u16,
-            height: RIGHT.height,
+            x: source.x + source.width.saturating_sub(border),
+            width: (f32::from(border) * area) as u16,
+            ..source
         },
         position: Point::new(
-            bounds.x + bounds.width - RIGHT.width as f32,
+            bounds.x + bounds.width - f32::from(border),
             bounds.y,
         ),
         scale: (1.0, 1.0),
+        ..Sprite::default()
     }
 }
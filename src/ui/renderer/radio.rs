@@ -1,15 +1,8 @@
-use crate::graphics::{Point, Rectangle, Sprite};
+use crate::graphics::{Color, Point, Rectangle, Shape, Sprite};
 use crate::ui::core::MouseCursor;
 use crate::ui::widget::radio;
 use crate::ui::Renderer;
 
-const SPRITE: Rectangle<u16> = Rectangle {
-    x: 98,
-    y: 28,
-    width: 28,
-    height: 28,
-};
-
 impl radio::Renderer for Renderer {
     fn draw(
         &mut self,
@@ -17,31 +10,67 @@ impl radio::Renderer for Renderer {
         bounds: Rectangle<f32>,
         bounds_with_label: Rectangle<f32>,
         is_selected: bool,
+        appearance: radio::Appearance,
+        is_disabled: bool,
     ) -> MouseCursor {
-        let mouse_over = bounds_with_label.contains(cursor_position);
+        let is_hovered = bounds_with_label.contains(cursor_position);
+        let mouse_over = is_hovered && !is_disabled;
+
+        let slices = self.theme.radio;
 
-        self.sprites.add(Sprite {
-            source: Rectangle {
-                x: SPRITE.x + (if mouse_over { SPRITE.width } else { 0 }),
-                ..SPRITE
-            },
+        // Every part is stretched to the bounds of the box
+        let sprite = |source: Rectangle<u16>| Sprite {
+            source,
             position: Point::new(bounds.x, bounds.y),
-            scale: (1.0, 1.0),
-        });
+            scale: (
+                bounds.width / f32::from(source.width.max(1)),
+                bounds.height / f32::from(source.height.max(1)),
+            ),
+            ..Sprite::default()
+        };
+
+        let tint = if is_disabled {
+            Color {
+                a: radio::DISABLED_OPACITY,
+                ..Color::WHITE
+            }
+        } else {
+            Color::WHITE
+        };
+
+        self.sprites.add_tinted(
+            sprite(if mouse_over {
+                slices.hovered
+            } else {
+                slices.idle
+            }),
+            tint,
+        );
 
         if is_selected {
-            self.sprites.add(Sprite {
-                source: Rectangle {
-                    x: SPRITE.x + SPRITE.width * 2,
-                    ..SPRITE
-                },
-                position: Point::new(bounds.x, bounds.y),
-                scale: (1.0, 1.0),
-            });
+            match appearance.dot_color {
+                None => {
+                    self.sprites.add_tinted(sprite(slices.mark), tint);
+                }
+                Some(color) => {
+                    self.mesh.fill(
+                        Shape::Circle {
+                            center: bounds.center(),
+                            radius: bounds.width.min(bounds.height) * 0.2,
+                        },
+                        Color {
+                            a: color.a * tint.a,
+                            ..color
+                        },
+                    );
+                }
+            }
         }
 
         if mouse_over {
             MouseCursor::Pointer
+        } else if is_hovered {
+            MouseCursor::NotAllowed
         } else {
             MouseCursor::OutOfBounds
         }
@@ -6,14 +6,69 @@ use crate::ui::widget::text;
 use crate::ui::Renderer;
 
 use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::f32;
+use std::hash::{Hash, Hasher};
+
+// The most measurements kept between layouts
+const MAX_MEASUREMENTS: usize = 4096;
+
+// Text measurements shared by the layouts of a user interface, so unchanged
+// text is not measured again when another part of the layout changes.
+//
+// Measurements are keyed by a hash of the text and its bounds, which avoids
+// allocating and comparing strings on every lookup.
+#[derive(Debug, Default)]
+pub(crate) struct Measurements {
+    sizes: HashMap<u64, (f32, f32)>,
+}
+
+impl Measurements {
+    fn measure(
+        &mut self,
+        font: &mut graphics::Font,
+        content_hash: u64,
+        text: graphics::Text<'_>,
+    ) -> (f32, f32) {
+        let mut hasher = DefaultHasher::new();
+
+        content_hash.hash(&mut hasher);
+        text.size.to_bits().hash(&mut hasher);
+        text.bounds.0.to_bits().hash(&mut hasher);
+        text.bounds.1.to_bits().hash(&mut hasher);
+
+        let key = hasher.finish();
+
+        if let Some(size) = self.sizes.get(&key) {
+            return *size;
+        }
+
+        if self.sizes.len() >= MAX_MEASUREMENTS {
+            self.sizes.clear();
+        }
+
+        let size = font.measure(text);
+        let _ = self.sizes.insert(key, size);
+
+        size
+    }
+}
 
 impl text::Renderer for Renderer {
     fn node(&self, style: Style, content: &str, size: f32) -> Node {
         let font = self.font.clone();
+        let measurements = self.measurements.clone();
         let content = String::from(content);
         let measure = RefCell::new(None);
 
+        let content_hash = {
+            let mut hasher = DefaultHasher::new();
+            content.hash(&mut hasher);
+
+            hasher.finish()
+        };
+
         Node::with_measure(style, move |bounds| {
             // TODO: Investigate why stretch tries to measure this MANY times
             // with every ancestor's bounds.
@@ -43,7 +98,11 @@ impl text::Renderer for Renderer {
                     ..graphics::Text::default()
                 };
 
-                let (width, height) = font.borrow_mut().measure(text);
+                let (width, height) = measurements.borrow_mut().measure(
+                    &mut font.borrow_mut(),
+                    content_hash,
+                    text,
+                );
 
                 let size = Size { width, height };
 
@@ -69,7 +128,7 @@ impl text::Renderer for Renderer {
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
     ) {
-        self.font.borrow_mut().add(graphics::Text {
+        self.add_text(graphics::Text {
             content,
             position: Point::new(bounds.x, bounds.y),
             bounds: (bounds.width, bounds.height),
@@ -0,0 +1,239 @@
+use crate::graphics::Rectangle;
+
+/// The parts of the spritesheet used by the built-in [`Renderer`] to draw
+/// every widget.
+///
+/// Combined with the [`sprites`] of a [`Configuration`], a [`Theme`] lets you
+/// re-skin the built-in widgets with a single image. Every field points to a
+/// region of the spritesheet, in pixels.
+///
+/// [`Renderer`]: struct.Renderer.html
+/// [`sprites`]: struct.Configuration.html#structfield.sprites
+/// [`Configuration`]: struct.Configuration.html
+/// [`Theme`]: struct.Theme.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// The background of a [`Panel`].
+    ///
+    /// [`Panel`]: widget/panel/struct.Panel.html
+    pub panel: Slice,
+
+    /// A [`Button`] of the [`Primary`] class.
+    ///
+    /// [`Button`]: widget/button/struct.Button.html
+    /// [`Primary`]: widget/button/enum.Class.html#variant.Primary
+    pub primary_button: ButtonSlices,
+
+    /// A [`Button`] of the [`Secondary`] class.
+    ///
+    /// [`Button`]: widget/button/struct.Button.html
+    /// [`Secondary`]: widget/button/enum.Class.html#variant.Secondary
+    pub secondary_button: ButtonSlices,
+
+    /// A [`Button`] of the [`Positive`] class.
+    ///
+    /// [`Button`]: widget/button/struct.Button.html
+    /// [`Positive`]: widget/button/enum.Class.html#variant.Positive
+    pub positive_button: ButtonSlices,
+
+    /// A [`Checkbox`].
+    ///
+    /// [`Checkbox`]: widget/checkbox/struct.Checkbox.html
+    pub checkbox: ToggleSlices,
+
+    /// A [`Radio`] button.
+    ///
+    /// [`Radio`]: widget/radio/struct.Radio.html
+    pub radio: ToggleSlices,
+
+    /// A [`Slider`].
+    ///
+    /// [`Slider`]: widget/slider/struct.Slider.html
+    pub slider: SliderSlices,
+
+    /// The empty part of a [`ProgressBar`].
+    ///
+    /// [`ProgressBar`]: widget/progress_bar/struct.ProgressBar.html
+    pub progress_bar: Slice,
+
+    /// The filled part of a [`ProgressBar`].
+    ///
+    /// [`ProgressBar`]: widget/progress_bar/struct.ProgressBar.html
+    pub progress_bar_fill: Slice,
+}
+
+impl Theme {
+    /// The [`Theme`] of the default spritesheet.
+    ///
+    /// [`Theme`]: struct.Theme.html
+    pub const DEFAULT: Theme = Theme {
+        panel: Slice {
+            source: Rectangle {
+                x: 0,
+                y: 0,
+                width: 28,
+                height: 34,
+            },
+            border: 8,
+        },
+        primary_button: ButtonSlices::row(34),
+        secondary_button: ButtonSlices::row(34 + 49),
+        positive_button: ButtonSlices::row(34 + 49 * 2),
+        checkbox: ToggleSlices::row(98, 0),
+        radio: ToggleSlices::row(98, 28),
+        slider: SliderSlices {
+            rail: Rectangle {
+                x: 98,
+                y: 56,
+                width: 1,
+                height: 4,
+            },
+            marker: Rectangle {
+                x: 126,
+                y: 56,
+                width: 16,
+                height: 24,
+            },
+            active_marker: Rectangle {
+                x: 142,
+                y: 56,
+                width: 16,
+                height: 24,
+            },
+        },
+        progress_bar: Slice {
+            source: Rectangle {
+                x: 0,
+                y: 34 + 49,
+                width: 49,
+                height: 49,
+            },
+            border: 6,
+        },
+        progress_bar_fill: Slice {
+            source: Rectangle {
+                x: 0,
+                y: 34,
+                width: 49,
+                height: 49,
+            },
+            border: 6,
+        },
+    };
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme::DEFAULT
+    }
+}
+
+/// A region of the spritesheet with borders that keep their size when the
+/// region is stretched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slice {
+    /// The region of the spritesheet.
+    pub source: Rectangle<u16>,
+
+    /// The size of the borders of the region, in pixels.
+    pub border: u16,
+}
+
+/// The regions of the spritesheet used to draw a [`Button`].
+///
+/// Buttons are only stretched horizontally, so their height should match the
+/// height of your buttons.
+///
+/// [`Button`]: widget/button/struct.Button.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ButtonSlices {
+    /// The [`Button`] when it is not pressed.
+    ///
+    /// [`Button`]: widget/button/struct.Button.html
+    pub idle: Slice,
+
+    /// The [`Button`] while it is pressed.
+    ///
+    /// [`Button`]: widget/button/struct.Button.html
+    pub pressed: Slice,
+}
+
+impl ButtonSlices {
+    const fn row(y: u16) -> ButtonSlices {
+        ButtonSlices {
+            idle: Slice {
+                source: Rectangle {
+                    x: 0,
+                    y,
+                    width: 49,
+                    height: 49,
+                },
+                border: 6,
+            },
+            pressed: Slice {
+                source: Rectangle {
+                    x: 49,
+                    y,
+                    width: 49,
+                    height: 49,
+                },
+                border: 6,
+            },
+        }
+    }
+}
+
+/// The regions of the spritesheet used to draw a widget that can be checked,
+/// like a [`Checkbox`] or a [`Radio`] button.
+///
+/// They are stretched to the size of the widget.
+///
+/// [`Checkbox`]: widget/checkbox/struct.Checkbox.html
+/// [`Radio`]: widget/radio/struct.Radio.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToggleSlices {
+    /// The box of the widget.
+    pub idle: Rectangle<u16>,
+
+    /// The box of the widget while hovered.
+    pub hovered: Rectangle<u16>,
+
+    /// The mark drawn on top of the box when checked.
+    pub mark: Rectangle<u16>,
+}
+
+impl ToggleSlices {
+    const SIZE: u16 = 28;
+
+    const fn row(x: u16, y: u16) -> ToggleSlices {
+        ToggleSlices {
+            idle: Self::square(x, y),
+            hovered: Self::square(x + Self::SIZE, y),
+            mark: Self::square(x + Self::SIZE * 2, y),
+        }
+    }
+
+    const fn square(x: u16, y: u16) -> Rectangle<u16> {
+        Rectangle {
+            x,
+            y,
+            width: Self::SIZE,
+            height: Self::SIZE,
+        }
+    }
+}
+
+/// The regions of the spritesheet used to draw a [`Slider`].
+///
+/// [`Slider`]: widget/slider/struct.Slider.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SliderSlices {
+    /// The rail, stretched horizontally.
+    pub rail: Rectangle<u16>,
+
+    /// The marker of the value.
+    pub marker: Rectangle<u16>,
+
+    /// The marker of the value while hovered or dragged.
+    pub active_marker: Rectangle<u16>,
+}
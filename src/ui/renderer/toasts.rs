@@ -0,0 +1,69 @@
+use crate::graphics::{
+    Color, HorizontalAlignment, Mesh, Point, Rectangle, Shape, Target, Text,
+    VerticalAlignment,
+};
+use crate::ui::{toasts, Renderer};
+
+const PADDING: f32 = 15.0;
+const BACKGROUND_ALPHA: f32 = 0.8;
+
+#[derive(Debug)]
+pub(crate) struct Toast {
+    bounds: Rectangle<f32>,
+    message: String,
+    opacity: f32,
+}
+
+impl toasts::Renderer for Renderer {
+    fn draw(&mut self, bounds: Rectangle<f32>, message: &str, opacity: f32) {
+        self.toasts.push(Toast {
+            bounds,
+            message: String::from(message),
+            opacity,
+        });
+    }
+}
+
+impl Renderer {
+    pub(super) fn flush_toasts(&mut self, target: &mut Target<'_>) {
+        if self.toasts.is_empty() {
+            return;
+        }
+
+        self.draw_calls += 2;
+
+        let mut font = self.font.borrow_mut();
+        let mut background = Mesh::new();
+
+        for toast in self.toasts.drain(..) {
+            background.fill(
+                Shape::Rectangle(toast.bounds),
+                Color {
+                    a: BACKGROUND_ALPHA * toast.opacity,
+                    ..Color::BLACK
+                },
+            );
+
+            font.add(Text {
+                content: &toast.message,
+                position: Point::new(toast.bounds.x + PADDING, toast.bounds.y),
+                bounds: (
+                    toast.bounds.width - PADDING * 2.0,
+                    toast.bounds.height,
+                ),
+                size: 20.0,
+                color: Color {
+                    a: toast.opacity,
+                    ..Color::WHITE
+                },
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+        }
+
+        // Toasts are drawn on top of everything else, so we need to flush the
+        // text again after drawing their background.
+        background.draw(target);
+        font.draw(target);
+    }
+}
@@ -0,0 +1,269 @@
+use crate::input::keyboard::{self, KeyCode};
+use crate::input::{self, ButtonState};
+
+use std::collections::HashSet;
+
+/// A keyboard shortcut that produces a message.
+///
+/// Return your shortcuts in [`UserInterface::shortcuts`]. The user interface
+/// consumes the key presses of its shortcuts, so they never reach
+/// [`Game::interact`]. Shortcuts do not trigger while the keyboard is
+/// captured, like when a text input is focused.
+///
+/// ```
+/// use coffee::input::keyboard::KeyCode;
+/// use coffee::ui::Shortcut;
+///
+/// enum Message {
+///     Undo,
+///     CloseMenu,
+/// }
+///
+/// let shortcuts = vec![
+///     Shortcut::new(KeyCode::Z, Message::Undo).control(),
+///     Shortcut::new(KeyCode::Escape, Message::CloseMenu),
+/// ];
+/// ```
+///
+/// [`UserInterface::shortcuts`]: trait.UserInterface.html#method.shortcuts
+/// [`Game::interact`]: ../trait.Game.html#method.interact
+pub struct Shortcut<Message> {
+    combination: Combination,
+    message: Message,
+}
+
+impl<Message> std::fmt::Debug for Shortcut<Message> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Shortcut")
+            .field("key_code", &self.combination.key_code)
+            .field("modifiers", &self.combination.modifiers)
+            .finish()
+    }
+}
+
+impl<Message> Shortcut<Message> {
+    /// Creates a new [`Shortcut`] that produces the given message when the
+    /// key is pressed.
+    ///
+    /// The [`Shortcut`] only triggers when its exact modifiers are held.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn new(key_code: KeyCode, message: Message) -> Self {
+        Shortcut {
+            combination: Combination {
+                key_code,
+                modifiers: Modifiers::default(),
+            },
+            message,
+        }
+    }
+
+    /// Requires either control key to be held.
+    pub fn control(mut self) -> Self {
+        self.combination.modifiers.control = true;
+        self
+    }
+
+    /// Requires either shift key to be held.
+    pub fn shift(mut self) -> Self {
+        self.combination.modifiers.shift = true;
+        self
+    }
+
+    /// Requires either alt key to be held.
+    pub fn alt(mut self) -> Self {
+        self.combination.modifiers.alt = true;
+        self
+    }
+
+    /// Requires either logo key to be held, like the Windows or Command key.
+    pub fn logo(mut self) -> Self {
+        self.combination.modifiers.logo = true;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+struct Modifiers {
+    control: bool,
+    shift: bool,
+    alt: bool,
+    logo: bool,
+}
+
+impl Modifiers {
+    fn union(self, other: Modifiers) -> Modifiers {
+        Modifiers {
+            control: self.control || other.control,
+            shift: self.shift || other.shift,
+            alt: self.alt || other.alt,
+            logo: self.logo || other.logo,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Combination {
+    key_code: KeyCode,
+    modifiers: Modifiers,
+}
+
+// Matches key presses against the shortcuts of a user interface
+#[derive(Debug)]
+pub(crate) struct Tracker {
+    left: Modifiers,
+    right: Modifiers,
+    combinations: Vec<Combination>,
+    conflicts: HashSet<Combination>,
+    consumed_keys: HashSet<KeyCode>,
+    triggered: Vec<Combination>,
+}
+
+impl Tracker {
+    pub fn new() -> Tracker {
+        Tracker {
+            left: Modifiers::default(),
+            right: Modifiers::default(),
+            combinations: Vec::new(),
+            conflicts: HashSet::new(),
+            consumed_keys: HashSet::new(),
+            triggered: Vec::new(),
+        }
+    }
+
+    pub fn update<Message>(&mut self, shortcuts: &[Shortcut<Message>]) {
+        self.combinations.clear();
+
+        for shortcut in shortcuts {
+            let combination = shortcut.combination;
+
+            if self.combinations.contains(&combination) {
+                // Only the first shortcut triggers, warn once about the rest
+                if self.conflicts.insert(combination) {
+                    log::warn!(
+                        "Conflicting shortcuts for {:?} with {:?}",
+                        combination.key_code,
+                        combination.modifiers
+                    );
+                }
+            } else {
+                self.combinations.push(combination);
+            }
+        }
+    }
+
+    // Returns true if the event is consumed by a shortcut. A captured keyboard
+    // takes precedence, so presses are only matched when it is not captured.
+    pub fn on_input(
+        &mut self,
+        event: input::Event,
+        is_keyboard_captured: bool,
+    ) -> bool {
+        let (state, key_code) = match event {
+            input::Event::Keyboard(keyboard::Event::Input {
+                state,
+                key_code,
+            }) => (state, key_code),
+            _ => return false,
+        };
+
+        let is_pressed = state == ButtonState::Pressed;
+
+        // Both sides are tracked, so releasing one of them does not release
+        // the modifier while the other one is held
+        match key_code {
+            KeyCode::LControl => self.left.control = is_pressed,
+            KeyCode::RControl => self.right.control = is_pressed,
+            KeyCode::LShift => self.left.shift = is_pressed,
+            KeyCode::RShift => self.right.shift = is_pressed,
+            KeyCode::LAlt => self.left.alt = is_pressed,
+            KeyCode::RAlt => self.right.alt = is_pressed,
+            KeyCode::LWin => self.left.logo = is_pressed,
+            KeyCode::RWin => self.right.logo = is_pressed,
+            _ => {}
+        }
+
+        if !is_pressed {
+            // The release of a consumed press is consumed too
+            return self.consumed_keys.remove(&key_code);
+        }
+
+        if is_keyboard_captured {
+            return false;
+        }
+
+        let combination = Combination {
+            key_code,
+            modifiers: self.left.union(self.right),
+        };
+
+        if self.combinations.contains(&combination) {
+            let _ = self.consumed_keys.insert(key_code);
+            self.triggered.push(combination);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn drain_triggered(&mut self) -> Vec<Combination> {
+        std::mem::take(&mut self.triggered)
+    }
+}
+
+// Takes the message of the first shortcut with the given combination
+pub(crate) fn take_message<Message>(
+    shortcuts: Vec<Shortcut<Message>>,
+    combination: Combination,
+) -> Option<Message> {
+    shortcuts
+        .into_iter()
+        .find(|shortcut| shortcut.combination == combination)
+        .map(|shortcut| shortcut.message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(state: ButtonState, key_code: KeyCode) -> input::Event {
+        input::Event::Keyboard(keyboard::Event::Input { state, key_code })
+    }
+
+    fn tracker(shortcuts: &[Shortcut<()>]) -> Tracker {
+        let mut tracker = Tracker::new();
+        tracker.update(shortcuts);
+
+        tracker
+    }
+
+    #[test]
+    fn keeps_modifiers_held_on_either_side() {
+        let mut tracker = tracker(&[Shortcut::new(KeyCode::Z, ()).control()]);
+
+        for event in &[
+            key(ButtonState::Pressed, KeyCode::LControl),
+            key(ButtonState::Pressed, KeyCode::RControl),
+            key(ButtonState::Released, KeyCode::RControl),
+        ] {
+            assert!(!tracker.on_input(*event, false));
+        }
+
+        assert!(tracker.on_input(key(ButtonState::Pressed, KeyCode::Z), false));
+        assert!(tracker.on_input(key(ButtonState::Released, KeyCode::Z), false));
+        assert_eq!(tracker.drain_triggered().len(), 1);
+    }
+
+    #[test]
+    fn ignores_presses_while_the_keyboard_is_captured() {
+        let mut tracker = tracker(&[Shortcut::new(KeyCode::Escape, ())]);
+
+        let press = key(ButtonState::Pressed, KeyCode::Escape);
+        let release = key(ButtonState::Released, KeyCode::Escape);
+
+        assert!(!tracker.on_input(press, true));
+        assert!(!tracker.on_input(release, true));
+        assert!(tracker.drain_triggered().is_empty());
+    }
+}
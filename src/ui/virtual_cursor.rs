@@ -0,0 +1,212 @@
+use crate::graphics::{Color, Frame, Mesh, Point, Rectangle, Shape, Vector};
+use crate::input::gamepad;
+
+use instant::Instant;
+
+/// A mouse cursor controlled with a gamepad stick.
+///
+/// When enabled in [`UserInterface::VIRTUAL_CURSOR`], moving the stick moves
+/// a cursor drawn on top of the user interface, and pressing the [`button`]
+/// clicks. The runtime feeds the resulting mouse events to the user
+/// interface, so widgets built for the mouse work with gamepads too.
+///
+/// The cursor speeds up while the stick is held. Once the stick is released,
+/// the cursor snaps to the closest interactive widget within the
+/// [`snapping`] distance. The cursor is hidden as soon as the mouse moves.
+///
+/// [`UserInterface::VIRTUAL_CURSOR`]: trait.UserInterface.html#associatedconstant.VIRTUAL_CURSOR
+/// [`button`]: #structfield.button
+/// [`snapping`]: #structfield.snapping
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VirtualCursor {
+    /// The axis that moves the cursor horizontally.
+    pub horizontal_axis: gamepad::Axis,
+
+    /// The axis that moves the cursor vertically.
+    pub vertical_axis: gamepad::Axis,
+
+    /// The button that clicks.
+    pub button: gamepad::Button,
+
+    /// The stick values below this threshold are ignored.
+    pub dead_zone: f32,
+
+    /// The maximum speed of the cursor, in pixels per second.
+    pub speed: f32,
+
+    /// How fast the cursor reaches its maximum speed, in pixels per second
+    /// squared.
+    pub acceleration: f32,
+
+    /// The maximum distance, in pixels, to snap to a widget. `0.0` disables
+    /// snapping.
+    pub snapping: f32,
+
+    /// The radius of the drawn cursor, in pixels.
+    pub radius: f32,
+
+    /// The color of the drawn cursor.
+    pub color: Color,
+}
+
+impl VirtualCursor {
+    /// The default [`VirtualCursor`].
+    ///
+    /// It is moved with the left stick and clicks with the south button.
+    ///
+    /// [`VirtualCursor`]: struct.VirtualCursor.html
+    pub const DEFAULT: VirtualCursor = VirtualCursor {
+        horizontal_axis: gamepad::Axis::LeftStickX,
+        vertical_axis: gamepad::Axis::LeftStickY,
+        button: gamepad::Button::South,
+        dead_zone: 0.2,
+        speed: 1000.0,
+        acceleration: 2000.0,
+        snapping: 40.0,
+        radius: 6.0,
+        color: Color::WHITE,
+    };
+}
+
+impl Default for VirtualCursor {
+    fn default() -> VirtualCursor {
+        VirtualCursor::DEFAULT
+    }
+}
+
+// The state of a virtual cursor in the runtime
+pub(crate) struct State {
+    settings: VirtualCursor,
+    stick: Vector,
+    speed: f32,
+    is_moving: bool,
+    is_visible: bool,
+    last_update: Instant,
+}
+
+impl State {
+    pub fn new(settings: VirtualCursor) -> State {
+        State {
+            settings,
+            stick: Vector::new(0.0, 0.0),
+            speed: 0.0,
+            is_moving: false,
+            is_visible: false,
+            last_update: Instant::now(),
+        }
+    }
+
+    pub fn button(&self) -> gamepad::Button {
+        self.settings.button
+    }
+
+    pub fn update_axis(&mut self, axis: gamepad::Axis, value: f32) {
+        if axis == self.settings.horizontal_axis {
+            self.stick.x = value;
+        } else if axis == self.settings.vertical_axis {
+            // The stick points up with positive values
+            self.stick.y = -value;
+        }
+    }
+
+    pub fn show(&mut self) {
+        self.is_visible = true;
+    }
+
+    pub fn hide(&mut self) {
+        self.is_visible = false;
+    }
+
+    // Moves the cursor according to the stick, returning its new position
+    // if it changed
+    pub fn update(
+        &mut self,
+        position: Point,
+        bounds: Rectangle<f32>,
+        focusable: &[Rectangle<f32>],
+    ) -> Option<Point> {
+        let now = Instant::now();
+        let delta = (now - self.last_update).as_secs_f32();
+        self.last_update = now;
+
+        let tilt = self.stick.norm().min(1.0);
+
+        if tilt < self.settings.dead_zone {
+            self.speed = 0.0;
+
+            if !self.is_moving {
+                return None;
+            }
+
+            self.is_moving = false;
+
+            return self.snap(position, focusable);
+        }
+
+        self.is_moving = true;
+        self.is_visible = true;
+
+        let max_speed = self.settings.speed * tilt;
+        self.speed =
+            (self.speed + self.settings.acceleration * delta).min(max_speed);
+
+        let movement = self.stick.normalize() * self.speed * delta;
+
+        Some(Point::new(
+            (position.x + movement.x)
+                .max(bounds.x)
+                .min(bounds.x + bounds.width),
+            (position.y + movement.y)
+                .max(bounds.y)
+                .min(bounds.y + bounds.height),
+        ))
+    }
+
+    fn snap(
+        &self,
+        position: Point,
+        focusable: &[Rectangle<f32>],
+    ) -> Option<Point> {
+        if self.settings.snapping <= 0.0
+            || focusable.iter().any(|bounds| bounds.contains(position))
+        {
+            return None;
+        }
+
+        // Widgets are measured from their closest point to the cursor
+        let distance = |bounds: &Rectangle<f32>| {
+            let closest = Point::new(
+                position.x.max(bounds.x).min(bounds.x + bounds.width),
+                position.y.max(bounds.y).min(bounds.y + bounds.height),
+            );
+
+            (closest - position).norm()
+        };
+
+        focusable
+            .iter()
+            .map(|bounds| (bounds, distance(bounds)))
+            .filter(|(_, distance)| *distance <= self.settings.snapping)
+            .min_by(|(_, a), (_, b)| {
+                a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(bounds, _)| bounds.center())
+    }
+
+    pub fn draw(&self, position: Point, frame: &mut Frame<'_>) {
+        if !self.is_visible {
+            return;
+        }
+
+        let circle = Shape::Circle {
+            center: position,
+            radius: self.settings.radius,
+        };
+
+        let mut mesh = Mesh::new();
+        mesh.fill(circle.clone(), self.settings.color);
+        mesh.stroke(circle, Color::BLACK, 1.0);
+
+        mesh.draw(&mut frame.as_target());
+    }
+}
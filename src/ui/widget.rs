@@ -34,6 +34,7 @@ pub mod progress_bar;
 pub mod radio;
 pub mod slider;
 pub mod text;
+pub mod toasts;
 
 pub use self::image::Image;
 pub use button::Button;
@@ -45,3 +46,4 @@ pub use radio::Radio;
 pub use row::Row;
 pub use slider::Slider;
 pub use text::Text;
+pub use toasts::Toasts;
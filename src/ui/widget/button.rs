@@ -9,7 +9,8 @@
 use crate::graphics::{Point, Rectangle};
 use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Role, Semantics,
+    Style, Widget,
 };
 
 use std::hash::Hash;
@@ -179,13 +180,37 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> MouseCursor {
-        renderer.draw(
+        let mouse_cursor = renderer.draw(
             cursor_position,
             layout.bounds(),
             self.state,
             &self.label,
             self.class,
-        )
+        );
+
+        // A button without a message cannot be pressed
+        if self.on_press.is_none() && mouse_cursor != MouseCursor::OutOfBounds {
+            MouseCursor::NotAllowed
+        } else {
+            mouse_cursor
+        }
+    }
+
+    fn focusable_bounds(
+        &self,
+        layout: Layout<'_>,
+        bounds: &mut Vec<Rectangle<f32>>,
+    ) {
+        if self.on_press.is_some() {
+            bounds.push(layout.bounds());
+        }
+    }
+
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        semantics.push(Semantics {
+            is_disabled: self.on_press.is_none(),
+            ..Semantics::new(Role::Button, &self.label, layout.bounds())
+        });
     }
 
     fn hash(&self, state: &mut Hasher) {
@@ -6,7 +6,8 @@ use crate::graphics::{
 };
 use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Widget,
+    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Role, Semantics,
+    Widget,
 };
 use crate::ui::widget::{text, Column, Row, Text};
 
@@ -41,6 +42,8 @@ pub struct Checkbox<Message> {
     on_toggle: Box<dyn Fn(bool) -> Message>,
     label: String,
     label_color: Color,
+    appearance: Appearance,
+    is_disabled: bool,
 }
 
 impl<Message> std::fmt::Debug for Checkbox<Message> {
@@ -49,6 +52,8 @@ impl<Message> std::fmt::Debug for Checkbox<Message> {
             .field("is_checked", &self.is_checked)
             .field("label", &self.label)
             .field("label_color", &self.label_color)
+            .field("appearance", &self.appearance)
+            .field("is_disabled", &self.is_disabled)
             .finish()
     }
 }
@@ -73,6 +78,8 @@ impl<Message> Checkbox<Message> {
             on_toggle: Box::new(f),
             label: String::from(label),
             label_color: Color::WHITE,
+            appearance: Appearance::DEFAULT,
+            is_disabled: false,
         }
     }
 
@@ -84,6 +91,25 @@ impl<Message> Checkbox<Message> {
         self.label_color = color;
         self
     }
+
+    /// Sets the [`Appearance`] of the [`Checkbox`].
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn appearance(mut self, appearance: Appearance) -> Self {
+        self.appearance = appearance;
+        self
+    }
+
+    /// Disables the [`Checkbox`].
+    ///
+    /// A disabled [`Checkbox`] is dimmed and ignores any interaction.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn disabled(mut self) -> Self {
+        self.is_disabled = true;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Checkbox<Message>
@@ -91,10 +117,12 @@ where
     Renderer: self::Renderer + text::Renderer,
 {
     fn node(&self, renderer: &Renderer) -> Node {
+        let size = u32::from(self.appearance.size);
+
         Row::<(), Renderer>::new()
-            .spacing(15)
+            .spacing(self.appearance.spacing)
             .align_items(Align::Center)
-            .push(Column::new().width(28).height(28))
+            .push(Column::new().width(size).height(size))
             .push(Text::new(&self.label))
             .node(renderer)
     }
@@ -106,6 +134,10 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
     ) {
+        if self.is_disabled {
+            return;
+        }
+
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
@@ -138,7 +170,14 @@ where
             text_bounds,
             &self.label,
             20.0,
-            self.label_color,
+            if self.is_disabled {
+                Color {
+                    a: self.label_color.a * DISABLED_OPACITY,
+                    ..self.label_color
+                }
+            } else {
+                self.label_color
+            },
             HorizontalAlignment::Left,
             VerticalAlignment::Top,
         );
@@ -149,11 +188,33 @@ where
             children[0].bounds(),
             text_bounds,
             self.is_checked,
+            self.appearance,
+            self.is_disabled,
         )
     }
 
+    fn focusable_bounds(
+        &self,
+        layout: Layout<'_>,
+        bounds: &mut Vec<Rectangle<f32>>,
+    ) {
+        if !self.is_disabled {
+            bounds.push(layout.bounds());
+        }
+    }
+
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        semantics.push(Semantics {
+            is_checked: Some(self.is_checked),
+            is_disabled: self.is_disabled,
+            ..Semantics::new(Role::Checkbox, &self.label, layout.bounds())
+        });
+    }
+
     fn hash(&self, state: &mut Hasher) {
         self.label.hash(state);
+        self.appearance.size.hash(state);
+        self.appearance.spacing.hash(state);
     }
 }
 
@@ -172,17 +233,68 @@ pub trait Renderer {
     ///   * the bounds of the [`Checkbox`]
     ///   * the bounds of the label of the [`Checkbox`]
     ///   * whether the [`Checkbox`] is checked or not
+    ///   * the [`Appearance`] of the [`Checkbox`]
+    ///   * whether the [`Checkbox`] is disabled or not
+    ///
+    /// A disabled [`Checkbox`] should look dimmed, multiplying its opacity by
+    /// [`DISABLED_OPACITY`], and should not react to the mouse.
     ///
     /// [`Checkbox`]: struct.Checkbox.html
+    /// [`Appearance`]: struct.Appearance.html
+    /// [`DISABLED_OPACITY`]: constant.DISABLED_OPACITY.html
     fn draw(
         &mut self,
         cursor_position: Point,
         bounds: Rectangle<f32>,
         label_bounds: Rectangle<f32>,
         is_checked: bool,
+        appearance: Appearance,
+        is_disabled: bool,
     ) -> MouseCursor;
 }
 
+/// The opacity multiplier of a disabled [`Checkbox`].
+///
+/// [`Checkbox`]: struct.Checkbox.html
+pub const DISABLED_OPACITY: f32 = 0.5;
+
+/// The size, spacing, and colors of a [`Checkbox`].
+///
+/// [`Checkbox`]: struct.Checkbox.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    /// The size of the box, in pixels.
+    pub size: u16,
+
+    /// The space between the box and the label, in pixels.
+    pub spacing: u16,
+
+    /// The [`Color`] of the check mark.
+    ///
+    /// When `None`, the [`Renderer`] decides the look of the check mark.
+    ///
+    /// [`Color`]: ../../../graphics/struct.Color.html
+    /// [`Renderer`]: trait.Renderer.html
+    pub check_color: Option<Color>,
+}
+
+impl Appearance {
+    /// The default [`Appearance`].
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    pub const DEFAULT: Appearance = Appearance {
+        size: 28,
+        spacing: 15,
+        check_color: None,
+    };
+}
+
+impl Default for Appearance {
+    fn default() -> Appearance {
+        Appearance::DEFAULT
+    }
+}
+
 impl<'a, Message, Renderer> From<Checkbox<Message>>
     for Element<'a, Message, Renderer>
 where
@@ -1,9 +1,9 @@
 use std::hash::Hash;
 
-use crate::graphics::Point;
+use crate::graphics::{Point, Rectangle};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Justify, Layout, MouseCursor, Node, Style,
-    Widget,
+    Align, Element, Event, Hasher, Justify, Layout, MouseCursor, Node, Role,
+    Semantics, Style, Widget,
 };
 
 /// A container that places its contents vertically.
@@ -200,6 +200,28 @@ impl<'a, Message, Renderer> Widget<Message, Renderer>
         cursor
     }
 
+    fn focusable_bounds(
+        &self,
+        layout: Layout<'_>,
+        bounds: &mut Vec<Rectangle<f32>>,
+    ) {
+        self.children.iter().zip(layout.children()).for_each(
+            |(child, layout)| child.widget.focusable_bounds(layout, bounds),
+        );
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        self.children
+            .iter()
+            .any(|child| child.widget.captures_keyboard())
+    }
+
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        self.children.iter().zip(layout.children()).for_each(
+            |(child, layout)| child.widget.semantics(layout, semantics),
+        );
+    }
+
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
         self.spacing.hash(state);
@@ -1,10 +1,11 @@
 //! Displays image to your users.
 
 use crate::graphics::{
-    self, Rectangle, Point,
+    self, Color, Rectangle, Point,
 };
 use crate::ui::core:: {
-    Style, Node, Element, MouseCursor, Layout, Hasher, Widget,
+    Style, Node, Element, MouseCursor, Layout, Hasher, Role, Semantics,
+    Widget,
 };
 
 use std::hash::Hash;
@@ -30,6 +31,8 @@ use std::hash::Hash;
 pub struct Image {
     image: graphics::Image,
     source: Rectangle<u16>,
+    fit: ContentFit,
+    color: Color,
     style: Style,
 }
 
@@ -46,6 +49,8 @@ impl Image {
                 width: image.width(),
                 height: image.height(),
             },
+            fit: ContentFit::Fill,
+            color: Color::WHITE,
             style: Style::default().fill_width().fill_height(),
         }
     }
@@ -58,6 +63,31 @@ impl Image {
         self
     }
 
+    /// Sets how the [`Image`] fits its boundaries.
+    ///
+    /// By default, it is [`ContentFit::Fill`], which stretches the [`Image`]
+    /// like previous versions did.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`ContentFit::Fill`]: enum.ContentFit.html#variant.Fill
+    pub fn fit(mut self, fit: ContentFit) -> Self {
+        self.fit = fit;
+        self
+    }
+
+    /// Sets the [`Color`] that multiplies the [`Image`].
+    ///
+    /// This is useful to draw white icons of an atlas with different colors.
+    /// By default, it is [`Color::WHITE`], which keeps the original colors.
+    ///
+    /// [`Color`]: ../../../graphics/struct.Color.html
+    /// [`Color::WHITE`]: ../../../graphics/struct.Color.html#associatedconstant.WHITE
+    /// [`Image`]: struct.Image.html
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
     /// Sets the width of the [`Image`] boundaries in pixels.
     ///
     /// [`Image`]: struct.Image.html
@@ -89,15 +119,17 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> MouseCursor {
-        renderer.draw(
-            layout.bounds(),
-            self.image.clone(),
-            self.source,
-        );
+        let (source, bounds) = self.fit.apply(self.source, layout.bounds());
+
+        renderer.draw(bounds, self.image.clone(), source, self.color);
 
         MouseCursor::OutOfBounds
     }
 
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        semantics.push(Semantics::new(Role::Image, "", layout.bounds()));
+    }
+
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
     }
@@ -114,19 +146,110 @@ pub trait Renderer {
     /// Draws a [`Image`].
     ///
     /// It receives:
-    ///   * the bounds of the [`Image`]
+    ///   * the bounds where the [`Image`] is drawn, already fitted
     ///   * the handle of the loaded [`Image`]
     ///   * the portion of the image that we wants to draw
+    ///   * the [`Color`] that multiplies the [`Image`]
     ///   
+    /// The portion of the image needs to be stretched to fill the bounds.
+    ///
     /// [`Image`]: struct.Image.html
+    /// [`Color`]: ../../../graphics/struct.Color.html
     fn draw(
         &mut self,
         bounds: Rectangle<f32>,
         image: graphics::Image,
         source: Rectangle<u16>,
+        color: Color,
     );
 }
 
+/// The way an [`Image`] fits its boundaries.
+///
+/// [`Image`]: struct.Image.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentFit {
+    /// Scales the [`Image`] to fit its boundaries, keeping its aspect ratio.
+    ///
+    /// [`Image`]: struct.Image.html
+    Contain,
+
+    /// Scales the [`Image`] to fill its boundaries, keeping its aspect ratio.
+    /// The parts of the [`Image`] that overflow are cut off.
+    ///
+    /// [`Image`]: struct.Image.html
+    Cover,
+
+    /// Stretches the [`Image`] to fill its boundaries, ignoring its aspect
+    /// ratio.
+    ///
+    /// [`Image`]: struct.Image.html
+    Fill,
+
+    /// Like [`Contain`], but never scales the [`Image`] up.
+    ///
+    /// [`Contain`]: #variant.Contain
+    /// [`Image`]: struct.Image.html
+    ScaleDown,
+}
+
+impl ContentFit {
+    // Returns the portion of the source to draw and where to draw it
+    fn apply(
+        self,
+        source: Rectangle<u16>,
+        bounds: Rectangle<f32>,
+    ) -> (Rectangle<u16>, Rectangle<f32>) {
+        let width = f32::from(source.width);
+        let height = f32::from(source.height);
+
+        let ratio_x = bounds.width / width;
+        let ratio_y = bounds.height / height;
+
+        let scale = match self {
+            ContentFit::Fill => return (source, bounds),
+            ContentFit::Cover => {
+                let scale = ratio_x.max(ratio_y);
+
+                // Cut off the overflow evenly on both sides
+                let visible_width = (bounds.width / scale).round() as u16;
+                let visible_height = (bounds.height / scale).round() as u16;
+
+                let visible = Rectangle {
+                    x: source.x
+                        + source.width.saturating_sub(visible_width) / 2,
+                    y: source.y
+                        + source.height.saturating_sub(visible_height) / 2,
+                    width: visible_width.min(source.width),
+                    height: visible_height.min(source.height),
+                };
+
+                return (visible, bounds);
+            }
+            ContentFit::Contain => ratio_x.min(ratio_y),
+            ContentFit::ScaleDown => ratio_x.min(ratio_y).min(1.0),
+        };
+
+        let center = bounds.center();
+
+        (
+            source,
+            Rectangle {
+                x: center.x - width * scale / 2.0,
+                y: center.y - height * scale / 2.0,
+                width: width * scale,
+                height: height * scale,
+            },
+        )
+    }
+}
+
+impl Default for ContentFit {
+    fn default() -> ContentFit {
+        ContentFit::Fill
+    }
+}
+
 impl<'a, Message, Renderer> From<Image> for Element<'a, Message, Renderer>
 where
     Renderer: self::Renderer,
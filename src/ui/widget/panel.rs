@@ -3,7 +3,8 @@ use std::hash::Hash;
 
 use crate::graphics::{Point, Rectangle};
 use crate::ui::core::{
-    Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+    Element, Event, Hasher, Layout, MouseCursor, Node, Role, Semantics, Style,
+    Widget,
 };
 
 /// A box that can wrap a widget.
@@ -136,6 +137,35 @@ where
         }
     }
 
+    fn focusable_bounds(
+        &self,
+        layout: Layout<'_>,
+        bounds: &mut Vec<Rectangle<f32>>,
+    ) {
+        [&self.content].iter().zip(layout.children()).for_each(
+            |(child, layout)| child.widget.focusable_bounds(layout, bounds),
+        );
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        self.content.widget.captures_keyboard()
+    }
+
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        let bounds = layout.bounds();
+        let mut children = Vec::new();
+
+        [&self.content].iter().zip(layout.children()).for_each(
+            |(child, layout)| child.widget.semantics(layout, &mut children),
+        );
+
+        // A panel groups its contents, like the options of a menu
+        semantics.push(Semantics {
+            children,
+            ..Semantics::new(Role::Group, "", bounds)
+        });
+    }
+
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
     }
@@ -1,18 +1,17 @@
 //! Displays action progress to your users.
 
-use crate::graphics::{
-    Point, Rectangle,
-};
+use crate::graphics::{Color, Point, Rectangle};
 use crate::ui::core::{
-    Style, Node, Element, MouseCursor, Layout, Hasher, Widget,
+    Element, Hasher, Layout, MouseCursor, Node, Role, Semantics, Style, Widget,
 };
 
+use instant::Instant;
 use std::hash::Hash;
 
 /// A widget that displays a progress of an action.
-/// 
+///
 /// It implements [`Widget`] when the associated [`core::Renderer`] implements
-/// the [`button::Renderer`] trait.
+/// the [`progress_bar::Renderer`] trait.
 ///
 /// [`Widget`]: ../../core/trait.Widget.html
 /// [`core::Renderer`]: ../../core/trait.Renderer.html
@@ -20,25 +19,45 @@ use std::hash::Hash;
 /// # Example
 ///
 /// ```
-/// use coffee::ui::ProgressBar;
+/// use coffee::graphics::Color;
+/// use coffee::ui::{progress_bar, ProgressBar};
 ///
 /// let progress = 0.75;
 ///
 /// ProgressBar::new(progress);
+///
+/// // Three out of five health pips, in red
+/// ProgressBar::new(3.0 / 5.0)
+///     .segments(5)
+///     .appearance(progress_bar::Appearance {
+///         bar: Color::RED,
+///         ..progress_bar::Appearance::default()
+///     });
 /// ```
 #[derive(Debug)]
 pub struct ProgressBar {
     progress: f32,
+    is_indeterminate: bool,
+    segments: u16,
+    appearance: Option<Appearance>,
     style: Style,
 }
 
 impl ProgressBar {
+    /// The space between the segments of a [`ProgressBar`], in pixels.
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub const SEGMENT_SPACING: f32 = 4.0;
+
     /// Creates a new [`ProgressBar`] with given progress.
     ///
     /// [`ProgressBar`]: struct.ProgressBar.html
     pub fn new(progress: f32) -> Self {
         ProgressBar {
             progress,
+            is_indeterminate: false,
+            segments: 1,
+            appearance: None,
             style: Style::default().fill_width(),
         }
     }
@@ -58,11 +77,68 @@ impl ProgressBar {
         self.style = self.style.fill_width();
         self
     }
+
+    /// Splits the [`ProgressBar`] in the given amount of segments, like the
+    /// pips of a health bar.
+    ///
+    /// The segments are separated by [`SEGMENT_SPACING`] pixels.
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    /// [`SEGMENT_SPACING`]: #associatedconstant.SEGMENT_SPACING
+    pub fn segments(mut self, segments: u16) -> Self {
+        self.segments = segments.max(1);
+        self
+    }
+
+    /// Makes the [`ProgressBar`] indeterminate.
+    ///
+    /// An indeterminate [`ProgressBar`] ignores its progress and shows a
+    /// chunk moving along the bar, for actions of unknown duration.
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn indeterminate(mut self) -> Self {
+        self.is_indeterminate = true;
+        self
+    }
+
+    /// Sets the [`Appearance`] of the [`ProgressBar`].
+    ///
+    /// By default, the [`Renderer`] decides the look of the [`ProgressBar`].
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    /// [`Renderer`]: trait.Renderer.html
+    pub fn appearance(mut self, appearance: Appearance) -> Self {
+        self.appearance = Some(appearance);
+        self
+    }
+
+    // Returns the filled portion of the bar
+    fn filled(&self) -> (f32, f32) {
+        if !self.is_indeterminate {
+            return (0.0, self.progress.max(0.0).min(1.0));
+        }
+
+        const PERIOD: f32 = 1.5;
+        const CHUNK: f32 = 0.25;
+
+        thread_local! {
+            static EPOCH: Instant = Instant::now();
+        }
+
+        let elapsed = EPOCH.with(|epoch| epoch.elapsed().as_secs_f32());
+        let phase = (elapsed % PERIOD) / PERIOD;
+
+        // The chunk enters from the left and leaves from the right
+        let start = phase * (1.0 + CHUNK) - CHUNK;
+
+        (start.max(0.0), (start + CHUNK).min(1.0))
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for ProgressBar
 where
-    Renderer: self::Renderer 
+    Renderer: self::Renderer,
 {
     fn node(&self, _renderer: &Renderer) -> Node {
         Node::new(self.style.height(50))
@@ -72,14 +148,65 @@ where
         &self,
         renderer: &mut Renderer,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        cursor_position: Point,
     ) -> MouseCursor {
-        renderer.draw(
-            layout.bounds(),
-            self.progress,
-        );
+        let bounds = layout.bounds();
+        let (start, end) = self.filled();
+
+        // The look of the renderer is only kept for the simplest bars
+        let appearance = if self.segments > 1 || self.is_indeterminate {
+            Some(self.appearance.unwrap_or_default())
+        } else {
+            self.appearance
+        };
+
+        let segments = f32::from(self.segments);
+        let spacing = Self::SEGMENT_SPACING * (segments - 1.0);
+        let segment_width = ((bounds.width - spacing) / segments).max(0.0);
+
+        for i in 0..self.segments {
+            let offset = f32::from(i);
+
+            let segment = Rectangle {
+                x: bounds.x + offset * (segment_width + Self::SEGMENT_SPACING),
+                width: segment_width,
+                ..bounds
+            };
+
+            let segment_start = (start * segments - offset).max(0.0).min(1.0);
+            let segment_end = (end * segments - offset).max(0.0).min(1.0);
+
+            renderer.draw(
+                segment,
+                segment_start.min(segment_end),
+                segment_end,
+                appearance,
+            );
+        }
+
+        // Show a busy cursor over unfinished progress
+        if (self.is_indeterminate || self.progress < 1.0)
+            && bounds.contains(cursor_position)
+        {
+            MouseCursor::Working
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        let value = if self.is_indeterminate {
+            None
+        } else {
+            let percentage = self.progress.max(0.0).min(1.0) * 100.0;
+
+            Some(format!("{}%", percentage.round()))
+        };
 
-        MouseCursor::OutOfBounds
+        semantics.push(Semantics {
+            value,
+            ..Semantics::new(Role::ProgressBar, "", layout.bounds())
+        });
     }
 
     fn hash(&self, state: &mut Hasher) {
@@ -87,6 +214,52 @@ where
     }
 }
 
+/// The colors and shape of a [`ProgressBar`].
+///
+/// [`ProgressBar`]: struct.ProgressBar.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    /// The [`Color`] of the empty part of the bar.
+    ///
+    /// [`Color`]: ../../../graphics/struct.Color.html
+    pub background: Color,
+
+    /// The [`Color`] of the filled part of the bar.
+    ///
+    /// [`Color`]: ../../../graphics/struct.Color.html
+    pub bar: Color,
+
+    /// The radius of the corners of the bar, in pixels.
+    pub border_radius: f32,
+}
+
+impl Appearance {
+    /// The default [`Appearance`], similar to the built-in spritesheet.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    pub const DEFAULT: Appearance = Appearance {
+        background: Color {
+            r: 0.3,
+            g: 0.3,
+            b: 0.3,
+            a: 1.0,
+        },
+        bar: Color {
+            r: 0.15,
+            g: 0.45,
+            b: 0.9,
+            a: 1.0,
+        },
+        border_radius: 6.0,
+    };
+}
+
+impl Default for Appearance {
+    fn default() -> Appearance {
+        Appearance::DEFAULT
+    }
+}
+
 /// The renderer of a [`ProgressBar`].
 ///
 /// Your [`core::Renderer`] will need to implement this trait before being
@@ -95,17 +268,22 @@ where
 /// [`ProgressBar`]: struct.ProgressBar.html
 /// [`core::Renderer`]: ../../core/trait.Renderer.html
 pub trait Renderer {
-    /// Draws a [`ProgressBar`].
+    /// Draws a [`ProgressBar`], or one of its segments.
     ///
     /// It receives:
     ///   * the bounds of the [`ProgressBar`]
-    ///   * the progress of the [`ProgressBar`]
-    ///   
+    ///   * the start of the filled part, in the `[0.0, 1.0]` interval
+    ///   * the end of the filled part, in the `[0.0, 1.0]` interval
+    ///   * the [`Appearance`] of the [`ProgressBar`], if it has a custom one
+    ///
     /// [`ProgressBar`]: struct.ProgressBar.html
+    /// [`Appearance`]: struct.Appearance.html
     fn draw(
         &mut self,
         bounds: Rectangle<f32>,
-        progress: f32,
+        start: f32,
+        end: f32,
+        appearance: Option<Appearance>,
     );
 }
 
@@ -4,7 +4,8 @@ use crate::graphics::{
 };
 use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Widget,
+    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Role, Semantics,
+    Widget,
 };
 use crate::ui::widget::{text, Column, Row, Text};
 
@@ -55,6 +56,8 @@ pub struct Radio<Message> {
     on_click: Message,
     label: String,
     label_color: Color,
+    appearance: Appearance,
+    is_disabled: bool,
 }
 
 impl<Message> std::fmt::Debug for Radio<Message>
@@ -67,6 +70,8 @@ where
             .field("on_click", &self.on_click)
             .field("label", &self.label)
             .field("label_color", &self.label_color)
+            .field("appearance", &self.appearance)
+            .field("is_disabled", &self.is_disabled)
             .finish()
     }
 }
@@ -92,6 +97,8 @@ impl<Message> Radio<Message> {
             on_click: f(value),
             label: String::from(label),
             label_color: Color::WHITE,
+            appearance: Appearance::DEFAULT,
+            is_disabled: false,
         }
     }
 
@@ -103,6 +110,25 @@ impl<Message> Radio<Message> {
         self.label_color = color;
         self
     }
+
+    /// Sets the [`Appearance`] of the [`Radio`].
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    /// [`Radio`]: struct.Radio.html
+    pub fn appearance(mut self, appearance: Appearance) -> Self {
+        self.appearance = appearance;
+        self
+    }
+
+    /// Disables the [`Radio`].
+    ///
+    /// A disabled [`Radio`] is dimmed and ignores any interaction.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn disabled(mut self) -> Self {
+        self.is_disabled = true;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Radio<Message>
@@ -111,10 +137,12 @@ where
     Message: Copy + std::fmt::Debug,
 {
     fn node(&self, renderer: &Renderer) -> Node {
+        let size = u32::from(self.appearance.size);
+
         Row::<(), Renderer>::new()
-            .spacing(15)
+            .spacing(self.appearance.spacing)
             .align_items(Align::Center)
-            .push(Column::new().width(28).height(28))
+            .push(Column::new().width(size).height(size))
             .push(Text::new(&self.label))
             .node(renderer)
     }
@@ -126,6 +154,10 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
     ) {
+        if self.is_disabled {
+            return;
+        }
+
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
@@ -155,7 +187,14 @@ where
             text_bounds,
             &self.label,
             20.0,
-            self.label_color,
+            if self.is_disabled {
+                Color {
+                    a: self.label_color.a * DISABLED_OPACITY,
+                    ..self.label_color
+                }
+            } else {
+                self.label_color
+            },
             HorizontalAlignment::Left,
             VerticalAlignment::Top,
         );
@@ -166,11 +205,33 @@ where
             children[0].bounds(),
             layout.bounds(),
             self.is_selected,
+            self.appearance,
+            self.is_disabled,
         )
     }
 
+    fn focusable_bounds(
+        &self,
+        layout: Layout<'_>,
+        bounds: &mut Vec<Rectangle<f32>>,
+    ) {
+        if !self.is_disabled {
+            bounds.push(layout.bounds());
+        }
+    }
+
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        semantics.push(Semantics {
+            is_checked: Some(self.is_selected),
+            is_disabled: self.is_disabled,
+            ..Semantics::new(Role::RadioButton, &self.label, layout.bounds())
+        });
+    }
+
     fn hash(&self, state: &mut Hasher) {
         self.label.hash(state);
+        self.appearance.size.hash(state);
+        self.appearance.spacing.hash(state);
     }
 }
 
@@ -189,17 +250,68 @@ pub trait Renderer {
     ///   * the bounds of the [`Radio`]
     ///   * the bounds of the label of the [`Radio`]
     ///   * whether the [`Radio`] is selected or not
+    ///   * the [`Appearance`] of the [`Radio`]
+    ///   * whether the [`Radio`] is disabled or not
+    ///
+    /// A disabled [`Radio`] should look dimmed, multiplying its opacity by
+    /// [`DISABLED_OPACITY`], and should not react to the mouse.
     ///
     /// [`Radio`]: struct.Radio.html
+    /// [`Appearance`]: struct.Appearance.html
+    /// [`DISABLED_OPACITY`]: constant.DISABLED_OPACITY.html
     fn draw(
         &mut self,
         cursor_position: Point,
         bounds: Rectangle<f32>,
         label_bounds: Rectangle<f32>,
         is_selected: bool,
+        appearance: Appearance,
+        is_disabled: bool,
     ) -> MouseCursor;
 }
 
+/// The opacity multiplier of a disabled [`Radio`].
+///
+/// [`Radio`]: struct.Radio.html
+pub const DISABLED_OPACITY: f32 = 0.5;
+
+/// The size, spacing, and colors of a [`Radio`].
+///
+/// [`Radio`]: struct.Radio.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    /// The size of the circle, in pixels.
+    pub size: u16,
+
+    /// The space between the circle and the label, in pixels.
+    pub spacing: u16,
+
+    /// The [`Color`] of the dot.
+    ///
+    /// When `None`, the [`Renderer`] decides the look of the dot.
+    ///
+    /// [`Color`]: ../../../graphics/struct.Color.html
+    /// [`Renderer`]: trait.Renderer.html
+    pub dot_color: Option<Color>,
+}
+
+impl Appearance {
+    /// The default [`Appearance`].
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    pub const DEFAULT: Appearance = Appearance {
+        size: 28,
+        spacing: 15,
+        dot_color: None,
+    };
+}
+
+impl Default for Appearance {
+    fn default() -> Appearance {
+        Appearance::DEFAULT
+    }
+}
+
 impl<'a, Message, Renderer> From<Radio<Message>>
     for Element<'a, Message, Renderer>
 where
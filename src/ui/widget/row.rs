@@ -1,9 +1,9 @@
 use std::hash::Hash;
 
-use crate::graphics::Point;
+use crate::graphics::{Point, Rectangle};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Justify, Layout, MouseCursor, Node, Style,
-    Widget,
+    Align, Direction, Element, Event, Hasher, Justify, Layout, MouseCursor,
+    Node, Semantics, Style, Widget,
 };
 
 /// A container that places its contents horizontally.
@@ -14,6 +14,8 @@ use crate::ui::core::{
 pub struct Row<'a, Message, Renderer> {
     style: Style,
     spacing: u16,
+    justify: Justify,
+    direction: Direction,
     children: Vec<Element<'a, Message, Renderer>>,
 }
 
@@ -22,6 +24,8 @@ impl<'a, Message, Renderer> std::fmt::Debug for Row<'a, Message, Renderer> {
         f.debug_struct("Row")
             .field("style", &self.style)
             .field("spacing", &self.spacing)
+            .field("justify", &self.justify)
+            .field("direction", &self.direction)
             .field("children", &self.children)
             .finish()
     }
@@ -35,6 +39,8 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
         Row {
             style: Style::default().fill_width(),
             spacing: 0,
+            justify: Justify::Start,
+            direction: Direction::LeftToRight,
             children: Vec::new(),
         }
     }
@@ -113,7 +119,21 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
     ///
     /// [`Row`]: struct.Row.html
     pub fn justify_content(mut self, justify: Justify) -> Self {
-        self.style = self.style.justify_content(justify);
+        self.justify = justify;
+        self
+    }
+
+    /// Sets the [`Direction`] of the [`Row`].
+    ///
+    /// A [`Row`] going from right to left places its first element on the
+    /// right, mirroring its layout. The distribution set with
+    /// [`justify_content`] is mirrored too.
+    ///
+    /// [`Direction`]: ../core/enum.Direction.html
+    /// [`Row`]: struct.Row.html
+    /// [`justify_content`]: #method.justify_content
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
         self
     }
 
@@ -128,6 +148,17 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
         self.children.push(child.into());
         self
     }
+
+    // Returns the layouts of the children in the order they were pushed
+    fn layouts<'b>(&self, layout: &'b Layout<'b>) -> Vec<Layout<'b>> {
+        let mut layouts: Vec<_> = layout.children().collect();
+
+        if self.direction == Direction::RightToLeft {
+            layouts.reverse();
+        }
+
+        layouts
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -149,6 +180,20 @@ impl<'a, Message, Renderer> Widget<Message, Renderer>
             })
             .collect();
 
+        // A mirrored row places its children in reverse order
+        let justify = match self.direction {
+            Direction::LeftToRight => self.justify,
+            Direction::RightToLeft => {
+                children.reverse();
+
+                match self.justify {
+                    Justify::Start => Justify::End,
+                    Justify::End => Justify::Start,
+                    justify => justify,
+                }
+            }
+        };
+
         if let Some(node) = children.last_mut() {
             let mut style = node.0.style();
             style.margin.end = stretch::style::Dimension::Undefined;
@@ -156,7 +201,7 @@ impl<'a, Message, Renderer> Widget<Message, Renderer>
             node.0.set_style(style);
         }
 
-        Node::with_children(self.style, children)
+        Node::with_children(self.style.justify_content(justify), children)
     }
 
     fn on_event(
@@ -166,13 +211,16 @@ impl<'a, Message, Renderer> Widget<Message, Renderer>
         cursor_position: Point,
         messages: &mut Vec<Message>,
     ) {
-        self.children.iter_mut().zip(layout.children()).for_each(
-            |(child, layout)| {
+        let layouts = self.layouts(&layout);
+
+        self.children
+            .iter_mut()
+            .zip(layouts)
+            .for_each(|(child, layout)| {
                 child
                     .widget
                     .on_event(event, layout, cursor_position, messages)
-            },
-        );
+            });
     }
 
     fn draw(
@@ -183,7 +231,7 @@ impl<'a, Message, Renderer> Widget<Message, Renderer>
     ) -> MouseCursor {
         let mut cursor = MouseCursor::OutOfBounds;
 
-        self.children.iter().zip(layout.children()).for_each(
+        self.children.iter().zip(self.layouts(&layout)).for_each(
             |(child, layout)| {
                 let new_cursor =
                     child.widget.draw(renderer, layout, cursor_position);
@@ -197,9 +245,33 @@ impl<'a, Message, Renderer> Widget<Message, Renderer>
         cursor
     }
 
+    fn focusable_bounds(
+        &self,
+        layout: Layout<'_>,
+        bounds: &mut Vec<Rectangle<f32>>,
+    ) {
+        self.children.iter().zip(self.layouts(&layout)).for_each(
+            |(child, layout)| child.widget.focusable_bounds(layout, bounds),
+        );
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        self.children
+            .iter()
+            .any(|child| child.widget.captures_keyboard())
+    }
+
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        self.children.iter().zip(self.layouts(&layout)).for_each(
+            |(child, layout)| child.widget.semantics(layout, semantics),
+        );
+    }
+
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
         self.spacing.hash(state);
+        self.justify.hash(state);
+        self.direction.hash(state);
 
         for child in &self.children {
             child.widget.hash(state);
@@ -10,7 +10,8 @@ use std::ops::RangeInclusive;
 use crate::graphics::{Point, Rectangle};
 use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+    Element, Event, Hasher, Layout, MouseCursor, Node, Role, Semantics, Style,
+    Widget,
 };
 
 /// An horizontal bar and a handle that selects a single value from a range of
@@ -170,6 +171,21 @@ where
         )
     }
 
+    fn focusable_bounds(
+        &self,
+        layout: Layout<'_>,
+        bounds: &mut Vec<Rectangle<f32>>,
+    ) {
+        bounds.push(layout.bounds());
+    }
+
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        semantics.push(Semantics {
+            value: Some(self.value.to_string()),
+            ..Semantics::new(Role::Slider, "", layout.bounds())
+        });
+    }
+
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
     }
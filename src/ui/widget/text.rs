@@ -3,7 +3,7 @@ use crate::graphics::{
     Color, HorizontalAlignment, Point, Rectangle, VerticalAlignment,
 };
 use crate::ui::core::{
-    Element, Hasher, Layout, MouseCursor, Node, Style, Widget,
+    Element, Hasher, Layout, MouseCursor, Node, Role, Semantics, Style, Widget,
 };
 
 use std::hash::Hash;
@@ -17,6 +17,10 @@ use std::hash::Hash;
 /// [`core::Renderer`]: ../../core/trait.Renderer.html
 /// [`text::Renderer`]: trait.Renderer.html
 ///
+/// The built-in renderer draws every character in logical order, without
+/// shaping nor bidirectional reordering. Scripts that need ligatures or
+/// right-to-left ordering are not supported.
+///
 /// # Example
 ///
 /// ```
@@ -54,6 +58,33 @@ impl Text {
         }
     }
 
+    /// Create a new fragment of [`Text`] with the translation of the given
+    /// message.
+    ///
+    /// The message is translated with the installed [`Localization`]. This
+    /// method is only available when the `i18n` feature is enabled.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Localization`]: ../../../i18n/struct.Localization.html
+    #[cfg(feature = "i18n")]
+    pub fn localized(id: &str) -> Self {
+        Self::new(&crate::i18n::translate(id))
+    }
+
+    /// Create a new fragment of [`Text`] with the translation of the given
+    /// message, using the given arguments for its variables.
+    ///
+    /// This method is only available when the `i18n` feature is enabled.
+    ///
+    /// [`Text`]: struct.Text.html
+    #[cfg(feature = "i18n")]
+    pub fn localized_with(
+        id: &str,
+        arguments: &[(&str, crate::i18n::Value)],
+    ) -> Self {
+        Self::new(&crate::i18n::translate_with(id, arguments))
+    }
+
     /// Sets the size of the [`Text`] in pixels.
     ///
     /// [`Text`]: struct.Text.html
@@ -135,6 +166,14 @@ where
         MouseCursor::OutOfBounds
     }
 
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        semantics.push(Semantics::new(
+            Role::Text,
+            &self.content,
+            layout.bounds(),
+        ));
+    }
+
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
 
@@ -0,0 +1,377 @@
+//! Show transient notifications on top of your user interface.
+//!
+//! A [`Toasts`] widget wraps some content and shows the queued messages of its
+//! local [`State`] in a [`Corner`], fading them out when they expire.
+//!
+//! Toasts are timed with the clock of the user interface, which the runtime
+//! advances every time your game is updated. Therefore, they do not expire
+//! while your game is not being updated.
+//!
+//! [`Toasts`]: struct.Toasts.html
+//! [`State`]: struct.State.html
+//! [`Corner`]: enum.Corner.html
+use crate::graphics::{Point, Rectangle};
+use crate::ui::core::{
+    Element, Event, Hasher, Layout, MouseCursor, Node, Role, Semantics, Widget,
+};
+
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+thread_local! {
+    static CLOCK: Cell<Duration> = Cell::new(Duration::from_secs(0));
+}
+
+// Advances the clock of the toasts of the current thread
+pub(crate) fn advance(delta: Duration) {
+    CLOCK.with(|clock| clock.set(clock.get() + delta));
+}
+
+fn now() -> Duration {
+    CLOCK.with(Cell::get)
+}
+
+/// A widget that shows transient notifications on top of some content.
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`toasts::Renderer`] trait.
+///
+/// The toasts do not affect the layout of the wrapped content. Therefore, you
+/// will normally want to wrap the root of your layout with it.
+///
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`toasts::Renderer`]: trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::{toasts, Column, Toasts};
+///
+/// pub enum Message { /* ... */ }
+///
+/// let state = &mut toasts::State::new();
+/// state.push("Game saved");
+///
+/// Toasts::<Message>::new(state, Column::new())
+///     .corner(toasts::Corner::BottomRight);
+/// ```
+pub struct Toasts<'a, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    corner: Corner,
+    width: u32,
+    now: Duration,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Toasts<'a, Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Toasts")
+            .field("state", &self.state)
+            .field("content", &self.content)
+            .field("corner", &self.corner)
+            .field("width", &self.width)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Toasts<'a, Message, Renderer> {
+    const HEIGHT: f32 = 50.0;
+    const SPACING: f32 = 10.0;
+    const MARGIN: f32 = 20.0;
+
+    /// Creates a new [`Toasts`] widget with some local [`State`] wrapping the
+    /// given content.
+    ///
+    /// Expired toasts are removed from the [`State`] here.
+    ///
+    /// [`Toasts`]: struct.Toasts.html
+    /// [`State`]: struct.State.html
+    pub fn new<E>(state: &'a mut State, content: E) -> Self
+    where
+        E: 'a + Into<Element<'a, Message, Renderer>>,
+    {
+        let now = now();
+
+        state.remove_expired(now);
+
+        Toasts {
+            state,
+            content: content.into(),
+            corner: Corner::TopRight,
+            width: 300,
+            now,
+        }
+    }
+
+    /// Sets the [`Corner`] where the toasts will be shown.
+    ///
+    /// By default, it is set to [`Corner::TopRight`].
+    ///
+    /// [`Corner`]: enum.Corner.html
+    /// [`Corner::TopRight`]: enum.Corner.html#variant.TopRight
+    pub fn corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Sets the width of each toast in pixels.
+    ///
+    /// By default, it is set to `300`.
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = width;
+        self
+    }
+
+    fn toast_bounds(&self, bounds: Rectangle<f32>, i: usize) -> Rectangle<f32> {
+        let width = self.width as f32;
+        let offset = Self::MARGIN + i as f32 * (Self::HEIGHT + Self::SPACING);
+
+        let x = match self.corner {
+            Corner::TopLeft | Corner::BottomLeft => bounds.x + Self::MARGIN,
+            Corner::TopRight | Corner::BottomRight => {
+                bounds.x + bounds.width - Self::MARGIN - width
+            }
+        };
+
+        let y = match self.corner {
+            Corner::TopLeft | Corner::TopRight => bounds.y + offset,
+            Corner::BottomLeft | Corner::BottomRight => {
+                bounds.y + bounds.height - offset - Self::HEIGHT
+            }
+        };
+
+        Rectangle {
+            x,
+            y,
+            width,
+            height: Self::HEIGHT,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Toasts<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        self.content.widget.node(renderer)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.content
+            .widget
+            .on_event(event, layout, cursor_position, messages);
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let bounds = layout.bounds();
+        let cursor =
+            self.content.widget.draw(renderer, layout, cursor_position);
+
+        for (i, toast) in self.state.toasts.iter().enumerate() {
+            renderer.draw(
+                self.toast_bounds(bounds, i),
+                &toast.message,
+                toast.opacity(self.now, self.state.fade_duration),
+            );
+        }
+
+        cursor
+    }
+
+    fn focusable_bounds(
+        &self,
+        layout: Layout<'_>,
+        bounds: &mut Vec<Rectangle<f32>>,
+    ) {
+        self.content.widget.focusable_bounds(layout, bounds);
+    }
+
+    fn captures_keyboard(&self) -> bool {
+        self.content.widget.captures_keyboard()
+    }
+
+    fn semantics(&self, layout: Layout<'_>, semantics: &mut Vec<Semantics>) {
+        let bounds = layout.bounds();
+
+        self.content.widget.semantics(layout, semantics);
+
+        for (i, toast) in self.state.toasts.iter().enumerate() {
+            semantics.push(Semantics::new(
+                Role::Alert,
+                &toast.message,
+                self.toast_bounds(bounds, i),
+            ));
+        }
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.content.widget.hash(state);
+    }
+}
+
+/// The local state of a [`Toasts`] widget.
+///
+/// It holds the queue of messages that are currently being shown.
+///
+/// [`Toasts`]: struct.Toasts.html
+#[derive(Debug, Clone)]
+pub struct State {
+    toasts: VecDeque<Toast>,
+    duration: Duration,
+    fade_duration: Duration,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// Toasts last 3 seconds by default and fade out during their last half a
+    /// second.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State {
+            toasts: VecDeque::new(),
+            duration: Duration::from_secs(3),
+            fade_duration: Duration::from_millis(500),
+        }
+    }
+
+    /// Sets the default duration of the toasts pushed to the [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets how long it takes for a toast to fade out before expiring.
+    pub fn fade_duration(mut self, fade_duration: Duration) -> Self {
+        self.fade_duration = fade_duration;
+        self
+    }
+
+    /// Queues a new toast with the given message using the default duration.
+    pub fn push<S: Into<String>>(&mut self, message: S) {
+        let duration = self.duration;
+
+        self.push_with_duration(message, duration);
+    }
+
+    /// Queues a new toast with the given message that will be shown for the
+    /// provided amount of time.
+    pub fn push_with_duration<S: Into<String>>(
+        &mut self,
+        message: S,
+        duration: Duration,
+    ) {
+        self.toasts.push_back(Toast {
+            message: message.into(),
+            expires_at: now() + duration,
+        });
+    }
+
+    /// Returns true if there are no toasts being shown.
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Removes all the toasts immediately.
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    fn remove_expired(&mut self, now: Duration) {
+        self.toasts.retain(|toast| toast.expires_at > now);
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Toast {
+    message: String,
+    expires_at: Duration,
+}
+
+impl Toast {
+    fn opacity(&self, now: Duration, fade_duration: Duration) -> f32 {
+        if self.expires_at <= now {
+            return 0.0;
+        }
+
+        let remaining = self.expires_at - now;
+
+        if remaining >= fade_duration {
+            1.0
+        } else {
+            remaining.as_secs_f32() / fade_duration.as_secs_f32().max(0.001)
+        }
+    }
+}
+
+/// The corner of the user interface where toasts are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Corner {
+    /// The top left corner
+    TopLeft,
+
+    /// The top right corner
+    TopRight,
+
+    /// The bottom left corner
+    BottomLeft,
+
+    /// The bottom right corner
+    BottomRight,
+}
+
+/// The renderer of a [`Toasts`] widget.
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use [`Toasts`] in your user interface.
+///
+/// [`Toasts`]: struct.Toasts.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws a single toast.
+    ///
+    /// It receives:
+    ///   * the bounds of the toast
+    ///   * the message of the toast
+    ///   * the opacity of the toast, in the `[0.0, 1.0]` interval
+    ///
+    /// Toasts should be drawn on top of any other widget.
+    fn draw(&mut self, bounds: Rectangle<f32>, message: &str, opacity: f32);
+}
+
+impl<'a, Message, Renderer> From<Toasts<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        toasts: Toasts<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(toasts)
+    }
+}
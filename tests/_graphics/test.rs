@@ -1,7 +1,9 @@
 use coffee::graphics::{Canvas, Gpu, Image};
 
+mod camera;
 mod mesh;
 
+use camera::Camera;
 use mesh::Mesh;
 
 use std::fs::File;
@@ -11,16 +13,18 @@ use std::path::PathBuf;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Test {
     Mesh,
+    Camera,
 }
 
 impl Test {
     pub fn all() -> Vec<Test> {
-        vec![Test::Mesh]
+        vec![Test::Mesh, Test::Camera]
     }
 
     pub fn run(&self, gpu: &mut Gpu) -> Drawing {
         let draw = match self {
             Test::Mesh => Mesh::draw(),
+            Test::Camera => Camera::draw(),
         };
 
         Drawing {
@@ -36,6 +40,7 @@ impl std::string::ToString for Test {
     fn to_string(&self) -> String {
         let name = match self {
             Test::Mesh => "mesh",
+            Test::Camera => "camera",
         };
 
         String::from(name)
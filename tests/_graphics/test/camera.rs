@@ -0,0 +1,39 @@
+use coffee::graphics::{
+    self, Canvas, Color, Rectangle, Shape, Transformation, Vector,
+};
+use coffee::load::Task;
+
+pub struct Camera {}
+
+impl Camera {
+    pub fn draw() -> Task<Canvas> {
+        Task::using_gpu(|gpu| {
+            // The width is not aligned on purpose, to test pixel reads
+            let mut canvas =
+                Canvas::new(gpu, 300, 200).expect("Canvas creation");
+
+            let mut mesh = graphics::Mesh::new();
+
+            // Only the top-left corner is filled, so flips are noticed
+            mesh.fill(
+                Shape::Rectangle(Rectangle {
+                    x: 0.0,
+                    y: 0.0,
+                    width: 50.0,
+                    height: 25.0,
+                }),
+                Color::RED,
+            );
+
+            mesh.draw(&mut canvas.as_target(gpu));
+
+            // The camera moves the same rectangle to the bottom-right corner
+            mesh.draw(&mut canvas.as_target_with(
+                gpu,
+                Transformation::translate(Vector::new(250.0, 175.0)),
+            ));
+
+            Ok(canvas)
+        })
+    }
+}
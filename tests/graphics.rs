@@ -19,10 +19,8 @@ fn graphics() -> Result<()> {
 
     <Runner as UserInterface>::run(WindowSettings {
         title: String::from("Graphics integration tests - Coffee"),
-        size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
-        maximized: false,
+        ..Default::default()
     })
 }
 